@@ -0,0 +1,167 @@
+// Measures decoding large responses, with `bytes` fields as `Vec<u8>` (default) or as `Bytes`.
+//
+// Compare the two with:
+// `cargo bench --bench decode` and `cargo bench --bench decode --features bytes`
+//
+// The allocation columns show the copies `Bytes` avoids: with the feature, `bytes` fields are
+// slices of the received buffer instead of fresh `Vec<u8>`s.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use divan::{AllocProfiler, Bencher};
+use prost::bytes::Bytes;
+use prost::Message;
+use voltage_tonic_lnd::lnrpc::invoice::InvoiceState;
+use voltage_tonic_lnd::lnrpc::{
+    ChannelAuthProof, ChannelEdge, ChannelGraph, Feature, Invoice, LightningNode,
+    ListInvoiceResponse, NodeAddress, RoutingPolicy,
+};
+use voltage_tonic_lnd::taprpc::ProofFile;
+
+#[global_allocator]
+static ALLOC: AllocProfiler = AllocProfiler::system();
+
+// Roughly the size of the public mainnet graph.
+const NODES: usize = 16_000;
+const EDGES: usize = 50_000;
+const INVOICES: usize = 10_000;
+const PROOF_FILE_LEN: usize = 1 << 20;
+
+fn main() {
+    divan::main();
+}
+
+/// Returns `len` bytes as a `bytes` field, whichever type it has.
+fn bytes<T: From<Vec<u8>>>(seed: usize, len: usize) -> T {
+    T::from((0..len).map(|i| (seed + i) as u8).collect::<Vec<u8>>())
+}
+
+fn pub_key(seed: usize) -> String {
+    hex::encode(bytes::<Vec<u8>>(seed, 33))
+}
+
+fn policy(seed: usize) -> RoutingPolicy {
+    RoutingPolicy {
+        time_lock_delta: 80,
+        min_htlc: 1_000,
+        fee_base_msat: 1_000,
+        fee_rate_milli_msat: (seed % 2_500) as i64,
+        max_htlc_msat: 990_000_000,
+        last_update: 1_700_000_000 + seed as u32,
+        ..Default::default()
+    }
+}
+
+fn encode(message: &impl Message) -> Bytes {
+    Bytes::from(message.encode_to_vec())
+}
+
+/// A `DescribeGraph` response with auth proofs (`include_auth_proof`).
+fn graph() -> &'static Bytes {
+    static GRAPH: OnceLock<Bytes> = OnceLock::new();
+    GRAPH.get_or_init(|| {
+        let features: HashMap<u32, Feature> = [1, 5, 7, 9, 12, 14, 17, 23, 27, 45, 47, 51, 55]
+            .into_iter()
+            .map(|bit| {
+                let feature = Feature {
+                    name: format!("feature-{bit}"),
+                    is_required: bit % 2 == 0,
+                    is_known: true,
+                };
+                (bit, feature)
+            })
+            .collect();
+        let nodes = (0..NODES)
+            .map(|i| LightningNode {
+                last_update: 1_700_000_000 + i as u32,
+                pub_key: pub_key(i),
+                alias: format!("node-{i}"),
+                addresses: vec![NodeAddress {
+                    network: "tcp".to_owned(),
+                    addr: format!("203.0.113.{}:9735", i % 256),
+                }],
+                color: "#3399ff".to_owned(),
+                features: features.clone(),
+                ..Default::default()
+            })
+            .collect();
+        let edges = (0..EDGES)
+            .map(|i| ChannelEdge {
+                channel_id: 900_000 << 40 | i as u64,
+                chan_point: format!("{}:{}", hex::encode(bytes::<Vec<u8>>(i, 32)), i % 4),
+                node1_pub: pub_key(i % NODES),
+                node2_pub: pub_key((i * 7 + 1) % NODES),
+                capacity: 5_000_000,
+                node1_policy: Some(policy(i)),
+                node2_policy: Some(policy(i + 1)),
+                auth_proof: Some(ChannelAuthProof {
+                    node_sig1: bytes(i, 71),
+                    bitcoin_sig1: bytes(i + 1, 71),
+                    node_sig2: bytes(i + 2, 71),
+                    bitcoin_sig2: bytes(i + 3, 71),
+                }),
+                ..Default::default()
+            })
+            .collect();
+        encode(&ChannelGraph {
+            nodes,
+            edges,
+        })
+    })
+}
+
+/// A `ListInvoices` response of settled invoices.
+fn invoices() -> &'static Bytes {
+    static INVOICES_RESPONSE: OnceLock<Bytes> = OnceLock::new();
+    INVOICES_RESPONSE.get_or_init(|| {
+        let invoices = (0..INVOICES)
+            .map(|i| Invoice {
+                memo: format!("order {i}"),
+                r_preimage: bytes(i, 32),
+                r_hash: bytes(i + 1, 32),
+                value_msat: 100_000,
+                payment_request: format!("lnbc1u1p{}", "q".repeat(300)),
+                payment_addr: bytes(i + 2, 32),
+                state: InvoiceState::Settled.into(),
+                add_index: i as u64,
+                settle_index: i as u64,
+                ..Default::default()
+            })
+            .collect();
+        encode(&ListInvoiceResponse {
+            invoices,
+            last_index_offset: INVOICES as u64,
+            first_index_offset: 0,
+        })
+    })
+}
+
+/// A `ExportProof` response with a 1 MiB proof file.
+fn proof_file() -> &'static Bytes {
+    static PROOF_FILE: OnceLock<Bytes> = OnceLock::new();
+    PROOF_FILE.get_or_init(|| {
+        encode(&ProofFile {
+            raw_proof_file: bytes(0, PROOF_FILE_LEN),
+            genesis_point: format!("{}:0", hex::encode([7; 32])),
+        })
+    })
+}
+
+#[divan::bench]
+fn describe_graph(bencher: Bencher) {
+    let buf = graph();
+    bencher.bench(|| ChannelGraph::decode(buf.clone()).unwrap());
+}
+
+#[divan::bench]
+fn list_invoices(bencher: Bencher) {
+    let buf = invoices();
+    bencher.bench(|| ListInvoiceResponse::decode(buf.clone()).unwrap());
+}
+
+#[divan::bench]
+fn export_proof(bencher: Bencher) {
+    let buf = proof_file();
+    bencher.bench(|| ProofFile::decode(buf.clone()).unwrap());
+}
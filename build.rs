@@ -1,15 +1,68 @@
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use prost_types::field_descriptor_proto::{Label, Type};
+use prost_types::{DescriptorProto, EnumDescriptorProto, FieldDescriptorProto, FileDescriptorSet};
+use tonic_prost_build::Builder;
+
+/// Where the checked-in code is generated to with `TONIC_LND_REGENERATE`.
+const GENERATED_DIR: &str = "src/generated";
+
+/// The code for the vendored protos is checked in under `src/generated`, so normal builds don't
+/// need `protoc`. Code is only generated here when building against other protos
+/// (`LND_REPO_DIR`/`TAPROOT_ASSETS_REPO_DIR`, written to `OUT_DIR`) or when refreshing the
+/// checked-in code (`TONIC_LND_REGENERATE`, see `make generate`).
 fn main() -> std::io::Result<()> {
     println!("cargo:rerun-if-env-changed=LND_REPO_DIR");
-    let lnd_dir = match std::env::var_os("LND_REPO_DIR") {
-        Some(lnd_repo_path) => PathBuf::from(lnd_repo_path).join("lnrpc"),
-        None => PathBuf::from("vendor"),
+    println!("cargo:rerun-if-env-changed=TAPROOT_ASSETS_REPO_DIR");
+    println!("cargo:rerun-if-env-changed=TONIC_LND_REGENERATE");
+    println!("cargo:rustc-check-cfg=cfg(out_dir_protos)");
+    println!("cargo:rustc-check-cfg=cfg(services)");
+
+    // Set when any service feature is enabled, to leave the code that only the service clients
+    // use out of builds with just the types, the transport or the REST client.
+    let service_feature = |name: std::ffi::OsString| {
+        let name = name.to_string_lossy();
+        name.starts_with("CARGO_FEATURE_") && name.ends_with("RPC")
     };
+    if std::env::vars_os().any(|(name, _)| service_feature(name)) {
+        println!("cargo:rustc-cfg=services");
+    }
 
-    let proto_file = lnd_dir.join("lightning.proto");
-    println!("cargo:rerun-if-changed={}", proto_file.display());
+    let bytes = std::env::var_os("CARGO_FEATURE_BYTES").is_some();
+    let lnd_repo_dir = std::env::var_os("LND_REPO_DIR");
+    let taproot_assets_repo_dir = std::env::var_os("TAPROOT_ASSETS_REPO_DIR");
+    let out_dir = PathBuf::from(std::env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo"));
+    if std::env::var_os("TONIC_LND_REGENERATE").is_some() {
+        generate(Path::new("vendor"), Path::new("vendor"), Path::new(GENERATED_DIR), false)?;
+    } else if lnd_repo_dir.is_some() || taproot_assets_repo_dir.is_some() {
+        println!("cargo:rustc-cfg=out_dir_protos");
+        let lnd_dir = match lnd_repo_dir {
+            Some(lnd_repo_path) => PathBuf::from(lnd_repo_path).join("lnrpc"),
+            None => PathBuf::from("vendor"),
+        };
+        let tap_dir = match taproot_assets_repo_dir {
+            Some(taproot_assets_repo_path) => {
+                PathBuf::from(taproot_assets_repo_path).join("taprpc")
+            }
+            None => PathBuf::from("vendor"),
+        };
+        generate(&lnd_dir, &tap_dir, &out_dir, bytes)?;
+        return Ok(());
+    }
+
+    if bytes {
+        println!("cargo:rustc-cfg=out_dir_protos");
+        write_bytes_fields(Path::new(GENERATED_DIR), &out_dir)?;
+    }
+    Ok(())
+}
 
+/// Generates the code for the LND protos in `lnd_dir` and the Taproot Assets protos in
+/// `tap_dir` into `out_dir`. With `bytes`, proto `bytes` fields are generated as `Bytes` rather
+/// than `Vec<u8>`.
+fn generate(lnd_dir: &Path, tap_dir: &Path, out_dir: &Path, bytes: bool) -> std::io::Result<()> {
     let protos = [
         "chainrpc/chainkit.proto",
         "chainrpc/chainnotifier.proto",
@@ -25,15 +78,6 @@ fn main() -> std::io::Result<()> {
 
     let lnd_proto_paths: Vec<_> = protos.iter().map(|proto| lnd_dir.join(proto)).collect();
 
-    println!("cargo:rerun-if-env-changed=TAPROOT_ASSETS_REPO_DIR");
-    let tap_dir = match std::env::var_os("TAPROOT_ASSETS_REPO_DIR") {
-        Some(taproot_assets_repo_path) => PathBuf::from(taproot_assets_repo_path).join("taprpc"),
-        None => PathBuf::from("vendor"),
-    };
-
-    let proto_file = tap_dir.join("taprootassets.proto");
-    println!("cargo:rerun-if-changed={}", proto_file.display());
-
     let protos = [
         "assetwalletrpc/assetwallet.proto",
         "mintrpc/mint.proto",
@@ -47,9 +91,463 @@ fn main() -> std::io::Result<()> {
 
     let tap_proto_paths: Vec<_> = protos.iter().map(|proto| tap_dir.join(proto)).collect();
 
-    tonic_prost_build::configure().build_client(true).build_server(false).compile_protos(
-        &lnd_proto_paths.into_iter().chain(tap_proto_paths).collect::<Vec<_>>(),
-        &[lnd_dir, tap_dir],
-    )?;
+    let all_proto_paths: Vec<_> = lnd_proto_paths.into_iter().chain(tap_proto_paths).collect();
+    for path in &all_proto_paths {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+
+    let fds = tonic_prost_build::Config::new().load_fds(&all_proto_paths, &[lnd_dir, tap_dir])?;
+    write_streaming_methods(&fds, out_dir)?;
+
+    let builder = tonic_prost_build::configure()
+        .build_client(true)
+        .build_server(true)
+        .server_mod_attribute(".", "#[cfg(feature = \"server\")]")
+        .out_dir(out_dir);
+    let builder = match bytes {
+        true => builder.bytes("."),
+        false => builder,
+    };
+    serde_attributes(builder, &fds).compile_fds(fds.clone())?;
+    flatten_oneofs(&fds, out_dir)?;
+    gate_connect(&fds, out_dir)?;
+    write_enum_accessors(&fds, out_dir)?;
+    write_blocking_clients(&fds, out_dir)
+}
+
+/// Writes `streaming_methods.rs` to `out_dir`, listing the gRPC paths of every RPC that streams
+/// requests, responses or both. The transport uses it to route long-lived streams separately
+/// from unary calls.
+fn write_streaming_methods(fds: &FileDescriptorSet, out_dir: &Path) -> std::io::Result<()> {
+    let mut methods = Vec::new();
+    for file in &fds.file {
+        for service in &file.service {
+            for method in &service.method {
+                if method.client_streaming() || method.server_streaming() {
+                    methods.push(format!(
+                        "/{}.{}/{}",
+                        file.package(),
+                        service.name(),
+                        method.name()
+                    ));
+                }
+            }
+        }
+    }
+    methods.sort();
+
+    let mut out = String::from("// This file is @generated by build.rs.\n");
+    out.push_str("pub(crate) const STREAMING_METHODS: &[&str] = &[\n");
+    for method in methods {
+        out.push_str(&format!("    \"{method}\",\n"));
+    }
+    out.push_str("];\n");
+
+    std::fs::write(out_dir.join("streaming_methods.rs"), out)
+}
+
+/// Adds the attributes behind the `serde` feature to every generated type, so that the JSON
+/// matches what LND's REST proxy and `lncli` produce: proto field names, 64-bit integers as
+/// strings, enums as names and bytes as base64 (or hex within `serde_hex::Hex`). The attributes
+/// are wrapped in `cfg_attr`, so the generated code is the same with or without the feature.
+fn serde_attributes(builder: Builder, fds: &FileDescriptorSet) -> Builder {
+    let types = TypeIndex::new(fds);
+    let mut builder = builder
+        .type_attribute(
+            ".",
+            "#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]",
+        )
+        .message_attribute(".", "#[cfg_attr(feature = \"serde\", serde(default))]");
+
+    for (path, message) in &types.messages {
+        if message.options.as_ref().is_some_and(|options| options.map_entry()) {
+            continue;
+        }
+        for field in &message.field {
+            let oneof = field
+                .oneof_index
+                .filter(|_| !field.proto3_optional())
+                .map(|index| message.oneof_decl[index as usize].name());
+            let mut serde = Vec::new();
+            match oneof {
+                // Oneof members become enum variants named in UpperCamelCase.
+                Some(_) => serde.push(format!("rename = \"{}\"", field.name())),
+                None if field.name().to_snake_case() != field.name() => {
+                    serde.push(format!("rename = \"{}\"", field.name()))
+                }
+                None => {}
+            }
+            if let Some(codec) = types.codec(field) {
+                serde.push(format!(
+                    "serialize_with = \"crate::serde_helpers::serialize::<{codec}, _, _>\""
+                ));
+                serde.push(format!(
+                    "deserialize_with = \"crate::serde_helpers::deserialize::<{codec}, _, _>\""
+                ));
+            }
+            if serde.is_empty() {
+                continue;
+            }
+            let field_path = match oneof {
+                Some(oneof) => format!("{path}.{oneof}.{}", field.name()),
+                None => format!("{path}.{}", field.name()),
+            };
+            builder = builder.field_attribute(
+                field_path,
+                format!("#[cfg_attr(feature = \"serde\", serde({}))]", serde.join(", ")),
+            );
+        }
+    }
+
+    for (path, enumeration) in &types.enums {
+        for value in &enumeration.value {
+            builder = builder.field_attribute(
+                format!("{path}.{}", value.name()),
+                format!("#[cfg_attr(feature = \"serde\", serde(rename = \"{}\"))]", value.name()),
+            );
+        }
+    }
+    builder
+}
+
+/// Marks the field holding each oneof with `serde(flatten)`, so that the set member appears
+/// directly in its message like in LND's JSON.
+///
+/// This is done on the generated code rather than through `field_attribute`, because prost
+/// applies an attribute on `.package.Message.oneof` to the members of the oneof as well.
+fn flatten_oneofs(fds: &FileDescriptorSet, out_dir: &Path) -> std::io::Result<()> {
+    let mut packages: Vec<_> = fds.file.iter().map(|file| file.package()).collect();
+    packages.sort();
+    packages.dedup();
+    for package in packages {
+        let path = out_dir.join(format!("{package}.rs"));
+        let source = std::fs::read_to_string(&path)?;
+        let mut out = String::with_capacity(source.len());
+        for line in source.lines() {
+            out.push_str(line);
+            out.push('\n');
+            let attribute = line.trim_start();
+            if attribute.starts_with("#[prost(oneof") {
+                out.push_str(&line[..line.len() - attribute.len()]);
+                out.push_str("#[cfg_attr(feature = \"serde\", serde(flatten))]\n");
+            }
+        }
+        std::fs::write(&path, out)?;
+    }
+    Ok(())
+}
+
+/// Puts the generated `connect` constructors of the clients behind the `transport` feature,
+/// since they need `tonic::transport::Channel`, which isn't available in the browser.
+fn gate_connect(fds: &FileDescriptorSet, out_dir: &Path) -> std::io::Result<()> {
+    let mut packages: Vec<_> = fds.file.iter().map(|file| file.package()).collect();
+    packages.sort();
+    packages.dedup();
+    for package in packages {
+        let path = out_dir.join(format!("{package}.rs"));
+        let source = std::fs::read_to_string(&path)?;
+        let mut out = String::with_capacity(source.len());
+        for line in source.lines() {
+            let item = line.trim_start();
+            if item.starts_with("impl ") && item.ends_with("<tonic::transport::Channel> {") {
+                out.push_str(&line[..line.len() - item.len()]);
+                out.push_str("#[cfg(feature = \"transport\")]\n");
+            }
+            out.push_str(line);
+            out.push('\n');
+        }
+        std::fs::write(&path, out)?;
+    }
+    Ok(())
+}
+
+/// Appends a `try_<field>` getter for every enum field to the generated code.
+///
+/// prost stores enum fields as `i32` and its getters (`state()`) silently return the default
+/// variant for values it doesn't know, which happens when the node is newer than the protos.
+/// These getters return `prost::UnknownEnumValue` instead, so callers handle that case.
+fn write_enum_accessors(fds: &FileDescriptorSet, out_dir: &Path) -> std::io::Result<()> {
+    let types = TypeIndex::new(fds);
+    let mut accessors: HashMap<&str, String> = HashMap::new();
+    for (path, message) in &types.messages {
+        if types.map_entries.contains_key(path) {
+            continue;
+        }
+        let mut getters = String::new();
+        for field in &message.field {
+            if field.r#type() != Type::Enum
+                || field.oneof_index.is_some() && !field.proto3_optional()
+            {
+                continue;
+            }
+            let name = field.name().to_snake_case();
+            let ident = rust_ident(&name);
+            let enumeration = &types.rust_paths[field.type_name()];
+            let (doc, output, body) = if field.label() == Label::Repeated {
+                (
+                    "Returns the values of",
+                    format!(
+                        "impl ::core::iter::Iterator<Item = ::core::result::Result<{enumeration}, ::prost::UnknownEnumValue>> + '_"
+                    ),
+                    format!("self.{ident}.iter().map(|&value| {enumeration}::try_from(value))"),
+                )
+            } else if field.proto3_optional() {
+                (
+                    "Returns",
+                    format!(
+                        "::core::result::Result<::core::option::Option<{enumeration}>, ::prost::UnknownEnumValue>"
+                    ),
+                    format!("self.{ident}.map({enumeration}::try_from).transpose()"),
+                )
+            } else {
+                (
+                    "Returns",
+                    format!("::core::result::Result<{enumeration}, ::prost::UnknownEnumValue>"),
+                    format!("{enumeration}::try_from(self.{ident})"),
+                )
+            };
+            getters.push_str(&format!(
+                "    /// {doc} `{name}`, or an error for values unknown to this version of the protos.\n"
+            ));
+            getters.push_str(&format!("    pub fn try_{name}(&self) -> {output} {{\n"));
+            getters.push_str(&format!("        {body}\n"));
+            getters.push_str("    }\n");
+        }
+        if getters.is_empty() {
+            continue;
+        }
+        let package = fds
+            .file
+            .iter()
+            .map(|file| file.package())
+            .filter(|package| path[1..].starts_with(&format!("{package}.")))
+            .max_by_key(|package| package.len())
+            .expect("every message belongs to a package");
+        let out = accessors.entry(package).or_default();
+        out.push_str(&format!("impl {} {{\n{getters}}}\n", types.rust_paths[path]));
+    }
+
+    for (package, out) in accessors {
+        let path = out_dir.join(format!("{package}.rs"));
+        let mut source = std::fs::read_to_string(&path)?;
+        source.push_str(&out);
+        std::fs::write(&path, source)?;
+    }
+    Ok(())
+}
+
+/// Appends a `<service>_blocking_client` module to the generated code for every service, behind
+/// the `blocking` feature. Its client wraps the async one and blocks on a runtime for each call;
+/// responses that stream are returned as `crate::blocking::Streaming` iterators.
+fn write_blocking_clients(fds: &FileDescriptorSet, out_dir: &Path) -> std::io::Result<()> {
+    let types = TypeIndex::new(fds);
+    let mut clients: HashMap<&str, String> = HashMap::new();
+    for file in &fds.file {
+        for service in &file.service {
+            let module = service.name().to_snake_case();
+            let client = format!("{}Client", service.name());
+            let blocking = format!("{}BlockingClient", service.name());
+            let mut methods = String::new();
+            for method in &service.method {
+                let name = rust_ident(&method.name().to_snake_case());
+                let input = &types.rust_paths[method.input_type()];
+                let output = &types.rust_paths[method.output_type()];
+                let request = match method.client_streaming() {
+                    true => format!("impl tonic::IntoStreamingRequest<Message = {input}>"),
+                    false => format!("impl tonic::IntoRequest<{input}>"),
+                };
+                methods
+                    .push_str(&format!("        /// Blocking version of [`{client}::{name}`].\n"));
+                if method.options.as_ref().is_some_and(|options| options.deprecated()) {
+                    methods.push_str("        #[deprecated]\n        #[allow(deprecated)]\n");
+                }
+                methods.push_str(&format!("        pub fn {name}(\n"));
+                methods.push_str("            &mut self,\n");
+                methods.push_str(&format!("            request: {request},\n"));
+                if method.server_streaming() {
+                    methods.push_str(&format!(
+                        "        ) -> std::result::Result<tonic::Response<crate::blocking::Streaming<{output}>>, tonic::Status> {{\n"
+                    ));
+                    methods.push_str(&format!(
+                        "            let response = self.runtime.block_on(self.inner.{name}(request))?;\n"
+                    ));
+                    methods.push_str(
+                        "            Ok(response.map(|stream| crate::blocking::Streaming::new(stream, self.runtime.clone())))\n",
+                    );
+                } else {
+                    methods.push_str(&format!(
+                        "        ) -> std::result::Result<tonic::Response<{output}>, tonic::Status> {{\n"
+                    ));
+                    methods.push_str(&format!(
+                        "            self.runtime.block_on(self.inner.{name}(request))\n"
+                    ));
+                }
+                methods.push_str("        }\n");
+            }
+
+            let out = clients.entry(file.package()).or_default();
+            out.push_str(&format!(
+                r#"/// Generated blocking client implementations.
+#[cfg(feature = "blocking")]
+pub mod {module}_blocking_client {{
+    #![allow(clippy::wildcard_imports)]
+    use tonic::codegen::*;
+    use super::{module}_client::{client};
+    /// A [`{client}`] that blocks the current thread on every call, driving the
+    /// requests on its runtime.
+    #[derive(Debug, Clone)]
+    pub struct {blocking}<T> {{
+        inner: {client}<T>,
+        runtime: std::sync::Arc<crate::blocking::Runtime>,
+    }}
+    impl<T> {blocking}<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {{
+        /// Wraps `inner`, running its calls on `runtime`.
+        pub fn new(inner: {client}<T>, runtime: std::sync::Arc<crate::blocking::Runtime>) -> Self {{
+            Self {{ inner, runtime }}
+        }}
+        /// Returns the async client.
+        pub fn into_inner(self) -> {client}<T> {{
+            self.inner
+        }}
+{methods}    }}
+}}
+"#
+            ));
+        }
+    }
+
+    for (package, out) in clients {
+        let path = out_dir.join(format!("{package}.rs"));
+        let mut source = std::fs::read_to_string(&path)?;
+        source.push_str(&out);
+        std::fs::write(&path, source)?;
+    }
+    Ok(())
+}
+
+/// Returns `name` as prost writes it in field accesses, escaping keywords.
+fn rust_ident(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern",
+        "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+        "ref", "return", "static", "struct", "trait", "type", "unsafe", "use", "where", "while",
+        "abstract", "become", "box", "do", "final", "gen", "macro", "override", "priv", "try",
+        "typeof", "unsized", "virtual", "yield",
+    ];
+    match KEYWORDS.contains(&name) {
+        true => format!("r#{name}"),
+        false => name.to_owned(),
+    }
+}
+
+/// The messages and enums of a descriptor set by fully qualified proto name (`.package.Name`),
+/// along with the Rust paths prost generates for them.
+struct TypeIndex<'a> {
+    messages: Vec<(String, &'a DescriptorProto)>,
+    enums: Vec<(String, &'a EnumDescriptorProto)>,
+    map_entries: HashMap<String, &'a DescriptorProto>,
+    rust_paths: HashMap<String, String>,
+}
+
+impl<'a> TypeIndex<'a> {
+    fn new(fds: &'a FileDescriptorSet) -> Self {
+        let mut index = Self {
+            messages: Vec::new(),
+            enums: Vec::new(),
+            map_entries: HashMap::new(),
+            rust_paths: HashMap::new(),
+        };
+        for file in &fds.file {
+            let proto = format!(".{}", file.package());
+            let rust = format!("crate::{}", file.package().replace('.', "::"));
+            for message in &file.message_type {
+                index.add_message(&proto, &rust, message);
+            }
+            for enumeration in &file.enum_type {
+                index.add_enum(&proto, &rust, enumeration);
+            }
+        }
+        index
+    }
+
+    fn add_message(&mut self, proto: &str, rust: &str, message: &'a DescriptorProto) {
+        let path = format!("{proto}.{}", message.name());
+        if message.options.as_ref().is_some_and(|options| options.map_entry()) {
+            self.map_entries.insert(path.clone(), message);
+        }
+        self.rust_paths
+            .insert(path.clone(), format!("{rust}::{}", message.name().to_upper_camel_case()));
+        let module = format!("{rust}::{}", message.name().to_snake_case());
+        for nested in &message.nested_type {
+            self.add_message(&path, &module, nested);
+        }
+        for enumeration in &message.enum_type {
+            self.add_enum(&path, &module, enumeration);
+        }
+        self.messages.push((path, message));
+    }
+
+    fn add_enum(&mut self, proto: &str, rust: &str, enumeration: &'a EnumDescriptorProto) {
+        let path = format!("{proto}.{}", enumeration.name());
+        self.rust_paths
+            .insert(path.clone(), format!("{rust}::{}", enumeration.name().to_upper_camel_case()));
+        self.enums.push((path, enumeration));
+    }
+
+    /// Returns the `serde_helpers::Codec` to use for `field`, if its type needs one.
+    fn codec(&self, field: &FieldDescriptorProto) -> Option<String> {
+        if field.label() == Label::Repeated && field.r#type() == Type::Message {
+            let entry = self.map_entries.get(field.type_name())?;
+            let value = self.scalar_codec(entry.field.iter().find(|field| field.number() == 2)?)?;
+            return Some(format!("crate::serde_helpers::Map<{value}>"));
+        }
+        let codec = self.scalar_codec(field)?;
+        Some(if field.label() == Label::Repeated {
+            format!("crate::serde_helpers::Repeated<{codec}>")
+        } else if field.proto3_optional() {
+            format!("crate::serde_helpers::Optional<{codec}>")
+        } else {
+            codec
+        })
+    }
+
+    fn scalar_codec(&self, field: &FieldDescriptorProto) -> Option<String> {
+        match field.r#type() {
+            Type::Bytes => Some("crate::serde_helpers::Bytes".to_owned()),
+            Type::Int64 | Type::Uint64 | Type::Sint64 | Type::Fixed64 | Type::Sfixed64 => {
+                Some("crate::serde_helpers::Int64".to_owned())
+            }
+            Type::Enum => {
+                Some(format!("crate::serde_helpers::Enum<{}>", self.rust_paths[field.type_name()]))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Writes the checked-in code in `from` to `out_dir` with proto `bytes` fields as `Bytes`, for the
+/// `bytes` feature. This is what prost generates with `bytes(".")` (checked against it), apart
+/// from line wrapping, and doesn't need `protoc`.
+fn write_bytes_fields(from: &Path, out_dir: &Path) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(from)? {
+        let path = entry?.path();
+        if path.extension().map_or(true, |extension| extension != "rs") {
+            continue;
+        }
+        println!("cargo:rerun-if-changed={}", path.display());
+        let source = std::fs::read_to_string(&path)?
+            .replace("bytes = \"vec\"", "bytes = \"bytes\"")
+            .replace("::prost::alloc::vec::Vec<u8>", "::prost::bytes::Bytes");
+        std::fs::write(
+            out_dir.join(path.file_name().expect("read_dir returns file names")),
+            source,
+        )?;
+    }
     Ok(())
 }
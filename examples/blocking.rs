@@ -0,0 +1,38 @@
+// This example connects to LND without an async runtime, prints the node info and then every
+// invoice as it is added or settled.
+//
+// The program accepts three arguments: address, cert file, macaroon file
+// The address must start with `https://`!
+//
+// Example run: `cargo run --features=blocking --example blocking <address> <tls.cert> <file.macaroon>`
+
+use voltage_tonic_lnd::blocking::BlockingClient;
+use voltage_tonic_lnd::lnrpc::{GetInfoRequest, InvoiceSubscription};
+
+fn main() {
+    let mut args = std::env::args_os();
+    args.next().expect("not even zeroth arg given");
+    let address = args.next().expect("missing arguments: address, cert file, macaroon file");
+    let cert_file = args.next().expect("missing arguments: cert file, macaroon file");
+    let macaroon_file = args.next().expect("missing argument: macaroon file");
+    let address = address.into_string().expect("address is not UTF-8");
+
+    let client = BlockingClient::builder()
+        .address(address)
+        .cert_path(cert_file)
+        .macaroon_path(macaroon_file)
+        .build_blocking()
+        .expect("failed to connect");
+
+    let mut lightning = client.lightning().expect("lightning service is disabled");
+    let info = lightning.get_info(GetInfoRequest {}).expect("failed to get info");
+    println!("{:#?}", info.into_inner());
+
+    let invoices = lightning
+        .subscribe_invoices(InvoiceSubscription::default())
+        .expect("failed to subscribe to invoices")
+        .into_inner();
+    for invoice in invoices {
+        println!("{:?}", invoice.expect("failed to receive invoices"));
+    }
+}
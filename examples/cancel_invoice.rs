@@ -25,16 +25,16 @@ async fn main() {
         .expect("missing argument: macaroon file, payment hash")
         .into_string()
         .expect("macaroon_file is not UTF-8");
-    let payment_hash: Vec<u8> = hex::decode(
-        args.next()
-            .expect("missing argument: payment hash")
-            .into_string()
-            .expect("payment_hash is not UTF-8"),
-    )
-    .expect("payment_hash is not a valid hex");
+    let payment_hash: voltage_tonic_lnd::types::PaymentHash = args
+        .next()
+        .expect("missing argument: payment hash")
+        .into_string()
+        .expect("payment_hash is not UTF-8")
+        .parse()
+        .expect("payment_hash is not a valid hex");
 
     // Connecting to LND requires only address, cert file, and macaroon file
-    let mut client = voltage_tonic_lnd::Client::builder()
+    let client = voltage_tonic_lnd::Client::builder()
         .address(address)
         .cert_path(cert_file)
         .macaroon_path(macaroon_file)
@@ -44,8 +44,9 @@ async fn main() {
 
     client
         .invoices()
+        .expect("invoices service is disabled")
         .cancel_invoice(voltage_tonic_lnd::invoicesrpc::CancelInvoiceMsg {
-            payment_hash,
+            payment_hash: payment_hash.into(),
         })
         .await
         .expect("Failed to cancel invoice");
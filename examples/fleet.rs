@@ -0,0 +1,46 @@
+// This example connects to several LND nodes sharing one macaroon, then prints the block height
+// each of them reports, using `Fleet::fan_out`.
+//
+// The program accepts a macaroon file followed by one `name=address` argument per node.
+// The addresses must start with `https://`!
+//
+// Example run: `cargo run --features=fleet,lightningrpc --example fleet <file.macaroon> alice=<address> bob=<address>`
+
+#[tokio::main]
+#[cfg(all(feature = "fleet", feature = "lightningrpc"))]
+async fn main() {
+    use std::collections::HashMap;
+
+    use voltage_tonic_lnd::fleet::Fleet;
+    use voltage_tonic_lnd::lnrpc::GetInfoRequest;
+    use voltage_tonic_lnd::Client;
+
+    let mut args = std::env::args();
+    args.next().expect("not even zeroth arg given");
+    let macaroon_file = args.next().expect("missing arguments: macaroon file, nodes");
+
+    let mut nodes = HashMap::new();
+    for arg in args {
+        let (name, address) = arg.split_once('=').expect("node must be given as name=address");
+        nodes.insert(
+            name.to_owned(),
+            Client::builder().address(address).macaroon_path(macaroon_file.clone()),
+        );
+    }
+
+    let fleet = Fleet::builder(nodes).max_clients(16).build();
+    let heights = fleet
+        .fan_out(|_node, client| async move {
+            let info = client.lightning()?.get_info(GetInfoRequest {}).await?;
+            Ok(info.into_inner().block_height)
+        })
+        .await
+        .expect("failed to list nodes");
+
+    for (node, height) in heights {
+        match height {
+            Ok(height) => println!("{node}: {height}"),
+            Err(error) => println!("{node}: {error}"),
+        }
+    }
+}
@@ -30,10 +30,11 @@ async fn main() {
         client = client.cert_path(cert_file);
     }
 
-    let mut client = client.build().await.expect("failed to build client");
+    let client = client.build().await.expect("failed to build client");
 
     let info = client
         .lightning()
+        .expect("lightning service is disabled")
         // All calls require at least empty parameter
         .get_info(voltage_tonic_lnd::lnrpc::GetInfoRequest {})
         .await
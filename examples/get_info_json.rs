@@ -0,0 +1,52 @@
+// This example fetches the node info and prints it as JSON, in the same shape as `lncli getinfo`
+// (with bytes as hex like in `lncli` output, rather than base64 like LND's REST API).
+//
+// The program accepts three arguments: address, cert file, macaroon file
+// The address must start with `https://`!
+//
+// Example run: `cargo run --features=lightningrpc,serde --example get_info_json <address> [tls.cert] <file.macaroon>`
+use voltage_tonic_lnd::lnrpc::GetInfoResponse;
+use voltage_tonic_lnd::serde_hex::Hex;
+use voltage_tonic_lnd::Client;
+
+#[tokio::main]
+#[cfg(all(feature = "lightningrpc", feature = "serde"))]
+async fn main() {
+    let mut args = std::env::args_os();
+    args.next().expect("not even zeroth arg given");
+
+    let address = args.next().expect("missing arguments: address, cert file, macaroon file");
+    let mut macaroon_file = args.next().expect("missing arguments: cert file or macaroon file");
+
+    let mut cert_file = None;
+    if let Some(path) = args.next() {
+        // if we have three arguments, then the cert file was passed.
+        cert_file = Some(macaroon_file);
+        macaroon_file = path;
+    }
+
+    let address = address.into_string().expect("address is not UTF-8");
+
+    let mut client = Client::builder().address(address).macaroon_path(macaroon_file);
+    if let Some(cert_file) = cert_file {
+        client = client.cert_path(cert_file);
+    }
+
+    let client = client.build().await.expect("failed to build client");
+
+    let info = client
+        .lightning()
+        .expect("lightning service is disabled")
+        .get_info(voltage_tonic_lnd::lnrpc::GetInfoRequest {})
+        .await
+        .expect("failed to get info")
+        .into_inner();
+
+    let json = serde_json::to_string_pretty(&Hex(&info)).expect("failed to serialize info");
+    println!("{json}");
+
+    // The JSON can be read back, for example from a cache.
+    let Hex(parsed): Hex<GetInfoResponse> =
+        serde_json::from_str(&json).expect("failed to parse info");
+    assert_eq!(parsed, info);
+}
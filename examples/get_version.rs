@@ -16,7 +16,7 @@ async fn main() {
     let address = address.into_string().expect("address is not UTF-8");
 
     // Connecting to LND requires only address, cert file, and macaroon file
-    let mut client = voltage_tonic_lnd::Client::builder()
+    let client = voltage_tonic_lnd::Client::builder()
         .address(address)
         .cert_path(cert_file)
         .macaroon_path(macaroon_file)
@@ -26,6 +26,7 @@ async fn main() {
 
     let version = client
         .versioner()
+        .expect("versioner service is disabled")
         .get_version(voltage_tonic_lnd::verrpc::VersionRequest {})
         .await
         .expect("failed to get version");
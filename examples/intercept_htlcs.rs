@@ -10,6 +10,8 @@
 async fn main() {
     use std::collections::HashMap;
 
+    use voltage_tonic_lnd::routerrpc::ResolveHoldForwardAction;
+
     let mut args = std::env::args_os();
     args.next().expect("not even zeroth arg given");
     let address: String = args
@@ -29,7 +31,7 @@ async fn main() {
         .expect("macaroon_file is not UTF-8");
 
     // Connecting to LND requires only address, cert file, and macaroon file
-    let mut client = voltage_tonic_lnd::Client::builder()
+    let client = voltage_tonic_lnd::Client::builder()
         .address(address)
         .cert_path(cert_file)
         .macaroon_path(macaroon_file)
@@ -44,6 +46,7 @@ async fn main() {
 
     let mut htlc_stream = client
         .router()
+        .expect("router service is disabled")
         .htlc_interceptor(stream)
         .await
         .expect("Failed to call subscribe_invoices")
@@ -58,9 +61,9 @@ async fn main() {
 
         let response = voltage_tonic_lnd::routerrpc::ForwardHtlcInterceptResponse {
             incoming_circuit_key: htlc.incoming_circuit_key,
-            action: 2, // Resume fordwarding of intercepted HTLC
-            preimage: vec![],
-            failure_message: vec![],
+            action: ResolveHoldForwardAction::Resume.into(),
+            preimage: Default::default(),
+            failure_message: Default::default(),
             failure_code: 0,
             in_amount_msat: htlc.incoming_amount_msat,
             out_amount_msat: htlc.outgoing_amount_msat,
@@ -30,10 +30,11 @@ async fn main() {
         client = client.cert_path(cert_file);
     }
 
-    let mut client = client.build().await.expect("failed to build client");
+    let client = client.build().await.expect("failed to build client");
 
     let info = client
         .taproot_assets()
+        .expect("taproot assets service is disabled")
         .list_assets(voltage_tonic_lnd::taprpc::ListAssetRequest::default())
         .await
         .expect("failed to list assets");
@@ -0,0 +1,118 @@
+// This example runs the in-process mock node from the `testing` feature and talks to it through
+// a normal client, the way a downstream test would.
+//
+// Example run: `cargo run --features=testing --example mock_node`
+use voltage_tonic_lnd::lnrpc::{GetInfoRequest, GetInfoResponse, Invoice, InvoiceSubscription};
+use voltage_tonic_lnd::routerrpc::{
+    ForwardHtlcInterceptRequest, ForwardHtlcInterceptResponse, ResolveHoldForwardAction,
+};
+use voltage_tonic_lnd::testing::MockLnd;
+use voltage_tonic_lnd::tonic::Code;
+use voltage_tonic_lnd::Client;
+
+#[tokio::main]
+#[cfg(feature = "testing")]
+async fn main() {
+    let mock = MockLnd::start().await.expect("failed to start mock");
+
+    // Canned responses.
+    mock.respond(
+        "/lnrpc.Lightning/GetInfo",
+        GetInfoResponse {
+            alias: "mock".to_owned(),
+            block_height: 800_000,
+            ..Default::default()
+        },
+    );
+
+    // Connect with credentials from files, like against a real node.
+    let dir = std::env::temp_dir().join(format!("mock-lnd-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("failed to create credentials dir");
+    let (cert_path, macaroon_path) =
+        mock.write_credentials(&dir).expect("failed to write credentials");
+    let client = Client::builder()
+        .address(mock.address())
+        .cert_path(cert_path)
+        .macaroon_path(macaroon_path)
+        .build()
+        .await
+        .expect("failed to build client");
+
+    let info = client
+        .lightning()
+        .expect("lightning service is disabled")
+        .get_info(GetInfoRequest {})
+        .await
+        .expect("failed to get info")
+        .into_inner();
+    println!("get_info: alias={} height={}", info.alias, info.block_height);
+
+    // Calls with the wrong macaroon are rejected.
+    let intruder = mock
+        .client_builder()
+        .macaroon_contents("00")
+        .build()
+        .await
+        .expect("failed to build client");
+    let status = intruder
+        .lightning()
+        .expect("lightning service is disabled")
+        .get_info(GetInfoRequest {})
+        .await
+        .expect_err("wrong macaroon accepted");
+    assert_eq!(status.code(), Code::Unauthenticated);
+    println!("wrong macaroon: {}", status.message());
+
+    // Streams are driven from the test.
+    let invoices = mock.subscribe_invoices();
+    let mut stream = client
+        .lightning()
+        .expect("lightning service is disabled")
+        .subscribe_invoices(InvoiceSubscription {
+            add_index: 5,
+            ..Default::default()
+        })
+        .await
+        .expect("failed to subscribe to invoices")
+        .into_inner();
+    invoices.wait_for_subscriber().await;
+    invoices.send(Invoice {
+        memo: "coffee".to_owned(),
+        add_index: 6,
+        ..Default::default()
+    });
+    let invoice = stream.message().await.expect("stream failed").expect("stream ended");
+    println!(
+        "subscribe_invoices: {} (requested from add_index {})",
+        invoice.memo,
+        invoices.requests()[0].add_index
+    );
+
+    // So is the HTLC interceptor.
+    let interceptor = mock.htlc_interceptor();
+    let (responses, requests) = tokio::sync::mpsc::unbounded_channel();
+    let mut htlcs = client
+        .router()
+        .expect("router service is disabled")
+        .htlc_interceptor(tokio_stream::wrappers::UnboundedReceiverStream::new(requests))
+        .await
+        .expect("failed to start interceptor")
+        .into_inner();
+    interceptor.wait_for_subscriber().await;
+    interceptor.send(ForwardHtlcInterceptRequest {
+        outgoing_amount_msat: 1_000,
+        ..Default::default()
+    });
+    let htlc = htlcs.message().await.expect("stream failed").expect("stream ended");
+    responses
+        .send(ForwardHtlcInterceptResponse {
+            incoming_circuit_key: htlc.incoming_circuit_key,
+            action: ResolveHoldForwardAction::Resume.into(),
+            ..Default::default()
+        })
+        .expect("interceptor closed");
+    let resolution = interceptor.recv().await.expect("mock stopped");
+    println!("htlc_interceptor: resolved with {:?}", resolution.action());
+
+    std::fs::remove_dir_all(dir).ok();
+}
@@ -0,0 +1,72 @@
+// This example runs a minimal price oracle that tapd can be pointed at with
+// `--experimental.rfq.priceoracleaddress=rfqrpc://<address>`. It quotes the same fixed rate for
+// every asset and transaction, assuming the payment asset is BTC.
+//
+// The program accepts one optional argument: the address to listen on (default `127.0.0.1:8095`).
+//
+// Example run: `cargo run --features=priceoraclerpc,server --example price_oracle_server [address]`
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use voltage_tonic_lnd::priceoraclerpc::price_oracle_server::{PriceOracle, PriceOracleServer};
+use voltage_tonic_lnd::priceoraclerpc::{
+    query_asset_rates_response, AssetRates, FixedPoint, QueryAssetRatesOkResponse,
+    QueryAssetRatesRequest, QueryAssetRatesResponse,
+};
+use voltage_tonic_lnd::tonic::transport::Server;
+use voltage_tonic_lnd::tonic::{self, Request, Response, Status};
+
+/// Asset units per BTC, with a scale of 0.
+const UNITS_PER_BTC: &str = "100000";
+
+/// Millisatoshis per BTC, the rate of BTC as the payment asset.
+const MSAT_PER_BTC: &str = "100000000000";
+
+/// How long a quote is valid for, in seconds.
+const QUOTE_LIFETIME: u64 = 300;
+
+struct FixedRateOracle;
+
+#[tonic::async_trait]
+impl PriceOracle for FixedRateOracle {
+    async fn query_asset_rates(
+        &self,
+        request: Request<QueryAssetRatesRequest>,
+    ) -> Result<Response<QueryAssetRatesResponse>, Status> {
+        println!("quote requested: {:?}", request.get_ref());
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).expect("clock before 1970");
+        let rates = AssetRates {
+            subject_asset_rate: Some(FixedPoint {
+                coefficient: UNITS_PER_BTC.to_owned(),
+                scale: 0,
+            }),
+            payment_asset_rate: Some(FixedPoint {
+                coefficient: MSAT_PER_BTC.to_owned(),
+                scale: 0,
+            }),
+            expiry_timestamp: now.as_secs() + QUOTE_LIFETIME,
+        };
+        Ok(Response::new(QueryAssetRatesResponse {
+            result: Some(query_asset_rates_response::Result::Ok(QueryAssetRatesOkResponse {
+                asset_rates: Some(rates),
+            })),
+        }))
+    }
+}
+
+#[tokio::main]
+#[cfg(all(feature = "priceoraclerpc", feature = "server"))]
+async fn main() {
+    let address = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "127.0.0.1:8095".to_owned())
+        .parse()
+        .expect("invalid listen address");
+
+    println!("price oracle listening on {address}");
+    Server::builder()
+        .add_service(PriceOracleServer::new(FixedRateOracle))
+        .serve(address)
+        .await
+        .expect("server failed");
+}
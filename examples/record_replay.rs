@@ -0,0 +1,93 @@
+// This example records calls made against a node and replays them from a mock, so a test can run
+// without the node. A mock stands in for the real node here.
+//
+// Example run: `cargo run --features=testing --example record_replay`
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use tower::Layer;
+use voltage_tonic_lnd::lnrpc::{
+    AddInvoiceResponse, GetInfoRequest, GetInfoResponse, Invoice, InvoiceSubscription,
+};
+use voltage_tonic_lnd::testing::{MockLnd, Recorder, Session};
+use voltage_tonic_lnd::Client;
+
+// The field number of `creation_date` in `lnrpc.Invoice`.
+const INVOICE_CREATION_DATE: u32 = 7;
+
+#[tokio::main]
+#[cfg(feature = "testing")]
+async fn main() {
+    let path = std::env::temp_dir().join(format!("lnd-session-{}.json", std::process::id()));
+
+    // Record. Against a real node, only the address and credentials would change.
+    let node = MockLnd::start().await.expect("failed to start mock");
+    node.respond(
+        "/lnrpc.Lightning/GetInfo",
+        GetInfoResponse {
+            alias: "recorded".to_owned(),
+            ..Default::default()
+        },
+    );
+    node.respond(
+        "/lnrpc.Lightning/AddInvoice",
+        AddInvoiceResponse {
+            payment_request: "lnbcrt1...".to_owned(),
+            ..Default::default()
+        },
+    );
+    let invoices = node.subscribe_invoices();
+    // The recorder wraps the channel to the node, so it sees every call of the client.
+    let recorder = Recorder::new();
+    let channel = recorder.layer(node.channel().await.expect("failed to connect"));
+    let address = node.address().parse().unwrap();
+    let client = Client::from_channel(channel, node.macaroon_hex(), address);
+    let updates = invoices.clone();
+    tokio::spawn(async move {
+        updates.wait_for_subscriber().await;
+        updates.send(Invoice {
+            memo: "settled".to_owned(),
+            ..Default::default()
+        });
+    });
+    run(&client).await;
+    recorder.save(&path).expect("failed to save session");
+    println!("recorded {} calls to {}", recorder.session().calls.len(), path.display());
+
+    // Replay. The invoice creation date differs on every run, so it is left out of the matching.
+    let mock = MockLnd::start().await.expect("failed to start mock");
+    let replay = mock.replay(Session::load(&path).expect("failed to load session"));
+    replay.ignore_field("/lnrpc.Lightning/AddInvoice", &[INVOICE_CREATION_DATE]);
+    let client = mock.client_builder().build().await.unwrap();
+    run(&client).await;
+    println!("calls left unplayed: {}", replay.remaining().len());
+
+    std::fs::remove_file(&path).ok();
+}
+
+#[cfg(feature = "testing")]
+async fn run(client: &Client) {
+    let mut lightning = client.lightning().expect("lightning service is disabled");
+
+    let info = lightning.get_info(GetInfoRequest {}).await.expect("failed to get info");
+    println!("get_info: alias={}", info.into_inner().alias);
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+    let invoice = lightning
+        .add_invoice(Invoice {
+            memo: "coffee".to_owned(),
+            value: 1_000,
+            creation_date: now,
+            ..Default::default()
+        })
+        .await
+        .expect("failed to add invoice");
+    println!("add_invoice: {}", invoice.into_inner().payment_request);
+
+    let mut stream = lightning
+        .subscribe_invoices(InvoiceSubscription::default())
+        .await
+        .expect("failed to subscribe")
+        .into_inner();
+    let update = stream.message().await.expect("stream failed").expect("stream ended");
+    println!("subscribe_invoices: {}", update.memo);
+}
@@ -16,7 +16,7 @@ async fn main() {
     let address = address.into_string().expect("address is not UTF-8");
 
     // Connecting to LND requires only address, cert file, and macaroon file
-    let mut client = voltage_tonic_lnd::Client::builder()
+    let client = voltage_tonic_lnd::Client::builder()
         .address(address)
         .cert_path(cert_file)
         .macaroon_path(macaroon_file)
@@ -26,6 +26,7 @@ async fn main() {
 
     let mut invoice_stream = client
         .lightning()
+        .expect("lightning service is disabled")
         .subscribe_invoices(voltage_tonic_lnd::lnrpc::InvoiceSubscription {
             add_index: 0,
             settle_index: 0,
@@ -0,0 +1,620 @@
+//! Conversions between generated messages and [`bitcoin`] types.
+//!
+//! Responses get `parse_*` methods that decode their raw bytes and strings, such as
+//! [`FundPsbtResponse::parse_psbt`](crate::walletrpc::FundPsbtResponse::parse_psbt) or
+//! [`GetBlockHeaderResponse::parse_header`](crate::chainrpc::GetBlockHeaderResponse::parse_header).
+//! Requests that carry transactions, PSBTs, addresses or block hashes get constructors taking the
+//! [`bitcoin`] types directly. The [`types`](crate::types) convert to and from their [`bitcoin`]
+//! equivalents with `From`, except for [`NodeId`] to [`PublicKey`], which is a `TryFrom` because
+//! a [`NodeId`] may not be on the curve.
+//!
+//! # Example
+//! ```rust,no_run
+//! use voltage_tonic_lnd::bitcoin::Psbt;
+//! use voltage_tonic_lnd::walletrpc::{FinalizePsbtRequest, FundPsbtRequest, SignPsbtRequest};
+//!
+//! # async fn example(client: voltage_tonic_lnd::Client, template: Psbt) -> voltage_tonic_lnd::Result<()> {
+//! let mut wallet = client.wallet()?;
+//! let funded = wallet.fund_psbt(FundPsbtRequest::from_psbt(&template)).await?.into_inner();
+//! let psbt = funded.parse_psbt()?;
+//! let signed = wallet.sign_psbt(SignPsbtRequest::new(&psbt)).await?.into_inner();
+//! let finalized =
+//!     wallet.finalize_psbt(FinalizePsbtRequest::new(&signed.parse_psbt()?)).await?.into_inner();
+//! let tx = finalized.parse_tx()?;
+//! println!("{}", tx.compute_txid());
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(any(feature = "lightningrpc", feature = "walletrpc"))]
+use bitcoin::address::NetworkUnchecked;
+use bitcoin::hashes::Hash;
+use bitcoin::secp256k1::PublicKey;
+#[cfg(any(feature = "lightningrpc", feature = "walletrpc"))]
+use bitcoin::{consensus, Address, Psbt, Transaction};
+
+use crate::error::ParseError;
+#[cfg(any(feature = "lightningrpc", feature = "walletrpc", feature = "chainrpc"))]
+use crate::protos::bytes_field;
+use crate::types::{Amount, ChannelPoint, NodeId, OutPoint, Txid};
+
+fn invalid(error: impl std::fmt::Display) -> ParseError {
+    ParseError::InvalidFormat(error.to_string())
+}
+
+#[cfg(any(feature = "lightningrpc", feature = "walletrpc"))]
+fn parse_tx(bytes: &[u8]) -> Result<Transaction, ParseError> {
+    consensus::deserialize(bytes).map_err(invalid)
+}
+
+#[cfg(any(feature = "lightningrpc", feature = "walletrpc"))]
+fn parse_address(address: &str) -> Result<Address<NetworkUnchecked>, ParseError> {
+    address.parse().map_err(invalid)
+}
+
+#[cfg(any(feature = "lightningrpc", feature = "signrpc"))]
+fn parse_public_key(bytes: &[u8]) -> Result<PublicKey, ParseError> {
+    PublicKey::from_slice(bytes).map_err(invalid)
+}
+
+impl From<Txid> for bitcoin::Txid {
+    fn from(txid: Txid) -> Self {
+        Self::from_byte_array(txid.to_bytes())
+    }
+}
+
+impl From<bitcoin::Txid> for Txid {
+    fn from(txid: bitcoin::Txid) -> Self {
+        Self::from(txid.to_byte_array())
+    }
+}
+
+impl From<OutPoint> for bitcoin::OutPoint {
+    fn from(outpoint: OutPoint) -> Self {
+        Self::new(outpoint.txid.into(), outpoint.output_index)
+    }
+}
+
+impl From<bitcoin::OutPoint> for OutPoint {
+    fn from(outpoint: bitcoin::OutPoint) -> Self {
+        Self::new(outpoint.txid.into(), outpoint.vout)
+    }
+}
+
+impl From<ChannelPoint> for bitcoin::OutPoint {
+    fn from(point: ChannelPoint) -> Self {
+        OutPoint::from(point).into()
+    }
+}
+
+impl From<bitcoin::OutPoint> for ChannelPoint {
+    fn from(outpoint: bitcoin::OutPoint) -> Self {
+        OutPoint::from(outpoint).into()
+    }
+}
+
+impl From<Amount> for bitcoin::Amount {
+    fn from(amount: Amount) -> Self {
+        Self::from_sat(amount.sat())
+    }
+}
+
+impl From<bitcoin::Amount> for Amount {
+    fn from(amount: bitcoin::Amount) -> Self {
+        Self::from_sat(amount.to_sat())
+    }
+}
+
+/// Fails if the key isn't on the curve, which [`NodeId`] doesn't check.
+impl TryFrom<NodeId> for PublicKey {
+    type Error = ParseError;
+
+    fn try_from(node_id: NodeId) -> Result<Self, Self::Error> {
+        Self::from_slice(node_id.as_bytes()).map_err(invalid)
+    }
+}
+
+impl From<PublicKey> for NodeId {
+    fn from(key: PublicKey) -> Self {
+        Self::try_from(key.serialize().as_slice()).expect("compressed keys are 33 bytes")
+    }
+}
+
+#[cfg(feature = "lightningrpc")]
+mod lnrpc_conversions {
+    use bitcoin::BlockHash;
+
+    use super::*;
+    use crate::lnrpc;
+
+    impl lnrpc::Transaction {
+        /// Decodes `raw_tx_hex`.
+        pub fn parse_tx(&self) -> Result<Transaction, ParseError> {
+            parse_tx(&hex::decode(&self.raw_tx_hex)?)
+        }
+
+        /// Parses `block_hash`, or returns `None` for unconfirmed transactions.
+        pub fn parse_block_hash(&self) -> Result<Option<BlockHash>, ParseError> {
+            match self.block_hash.is_empty() {
+                true => Ok(None),
+                false => self.block_hash.parse().map(Some).map_err(invalid),
+            }
+        }
+    }
+
+    impl lnrpc::NewAddressResponse {
+        /// Parses `address`. Check the network with [`Address::require_network`].
+        pub fn parse_address(&self) -> Result<Address<NetworkUnchecked>, ParseError> {
+            parse_address(&self.address)
+        }
+    }
+
+    impl lnrpc::KeyDescriptor {
+        /// Parses `raw_key_bytes`.
+        pub fn parse_public_key(&self) -> Result<PublicKey, ParseError> {
+            parse_public_key(&self.raw_key_bytes)
+        }
+    }
+
+    impl lnrpc::SendCoinsRequest {
+        /// Creates a request sending `amount` to `address`, with the remaining fields left to their
+        /// defaults.
+        ///
+        /// Fails if `amount` doesn't fit in LND's `int64` amounts.
+        pub fn new(address: &Address, amount: bitcoin::Amount) -> Result<Self, ParseError> {
+            Ok(Self {
+                addr: address.to_string(),
+                amount: i64::try_from(Amount::from(amount))?,
+                ..Default::default()
+            })
+        }
+    }
+
+    impl lnrpc::SendManyRequest {
+        /// Creates a request paying each address its amount, with the remaining fields left to
+        /// their defaults.
+        ///
+        /// Fails if an amount doesn't fit in LND's `int64` amounts.
+        pub fn new<'a>(
+            outputs: impl IntoIterator<Item = (&'a Address, bitcoin::Amount)>,
+        ) -> Result<Self, ParseError> {
+            Ok(Self {
+                addr_to_amount: outputs
+                    .into_iter()
+                    .map(|(address, amount)| {
+                        Ok((address.to_string(), i64::try_from(Amount::from(amount))?))
+                    })
+                    .collect::<Result<_, ParseError>>()?,
+                ..Default::default()
+            })
+        }
+    }
+
+    impl lnrpc::PsbtShim {
+        /// Creates a shim funding a channel from `base_psbt`, or from an empty PSBT if `None`.
+        pub fn new(pending_chan_id: [u8; 32], base_psbt: Option<&Psbt>) -> Self {
+            Self {
+                pending_chan_id: bytes_field(pending_chan_id.to_vec()),
+                base_psbt: bytes_field(base_psbt.map(Psbt::serialize).unwrap_or_default()),
+                no_publish: false,
+            }
+        }
+    }
+
+    impl lnrpc::FundingPsbtVerify {
+        /// Creates a request verifying `funded_psbt` for a pending channel.
+        pub fn new(pending_chan_id: [u8; 32], funded_psbt: &Psbt) -> Self {
+            Self {
+                funded_psbt: bytes_field(funded_psbt.serialize()),
+                pending_chan_id: bytes_field(pending_chan_id.to_vec()),
+                skip_finalize: false,
+            }
+        }
+    }
+
+    impl lnrpc::FundingPsbtFinalize {
+        /// Creates a request finalizing a pending channel with `signed_psbt`.
+        pub fn new(pending_chan_id: [u8; 32], signed_psbt: &Psbt) -> Self {
+            Self {
+                signed_psbt: bytes_field(signed_psbt.serialize()),
+                pending_chan_id: bytes_field(pending_chan_id.to_vec()),
+                final_raw_tx: Default::default(),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "signrpc")]
+impl crate::signrpc::KeyDescriptor {
+    /// Parses `raw_key_bytes`.
+    pub fn parse_public_key(&self) -> Result<PublicKey, ParseError> {
+        parse_public_key(&self.raw_key_bytes)
+    }
+}
+
+#[cfg(feature = "walletrpc")]
+mod walletrpc_conversions {
+    use super::*;
+    use crate::walletrpc;
+    use crate::walletrpc::fund_psbt_request::Template;
+
+    fn parse_psbt(bytes: &[u8]) -> Result<Psbt, ParseError> {
+        Psbt::deserialize(bytes).map_err(invalid)
+    }
+
+    impl walletrpc::FundPsbtRequest {
+        /// Creates a request funding `template`, with the remaining fields left to their defaults.
+        /// Set `fees` before sending, LND requires it.
+        pub fn from_psbt(template: &Psbt) -> Self {
+            Self {
+                template: Some(Template::Psbt(bytes_field(template.serialize()))),
+                ..Default::default()
+            }
+        }
+    }
+
+    impl walletrpc::FundPsbtResponse {
+        /// Decodes `funded_psbt`.
+        pub fn parse_psbt(&self) -> Result<Psbt, ParseError> {
+            parse_psbt(&self.funded_psbt)
+        }
+    }
+
+    impl walletrpc::SignPsbtRequest {
+        /// Creates a request signing the inputs of `funded_psbt` that belong to the wallet.
+        pub fn new(funded_psbt: &Psbt) -> Self {
+            Self {
+                funded_psbt: bytes_field(funded_psbt.serialize()),
+            }
+        }
+    }
+
+    impl walletrpc::SignPsbtResponse {
+        /// Decodes `signed_psbt`.
+        pub fn parse_psbt(&self) -> Result<Psbt, ParseError> {
+            parse_psbt(&self.signed_psbt)
+        }
+    }
+
+    impl walletrpc::FinalizePsbtRequest {
+        /// Creates a request finalizing `funded_psbt` with the default account.
+        pub fn new(funded_psbt: &Psbt) -> Self {
+            Self {
+                funded_psbt: bytes_field(funded_psbt.serialize()),
+                account: String::new(),
+            }
+        }
+    }
+
+    impl walletrpc::FinalizePsbtResponse {
+        /// Decodes `signed_psbt`.
+        pub fn parse_psbt(&self) -> Result<Psbt, ParseError> {
+            parse_psbt(&self.signed_psbt)
+        }
+
+        /// Decodes `raw_final_tx`.
+        pub fn parse_tx(&self) -> Result<Transaction, ParseError> {
+            parse_tx(&self.raw_final_tx)
+        }
+    }
+
+    impl walletrpc::Transaction {
+        /// Creates a request publishing `tx` with an optional label.
+        pub fn new(tx: &Transaction, label: impl Into<String>) -> Self {
+            Self {
+                tx_hex: bytes_field(consensus::serialize(tx)),
+                label: label.into(),
+            }
+        }
+
+        /// Decodes `tx_hex`.
+        pub fn parse_tx(&self) -> Result<Transaction, ParseError> {
+            parse_tx(&self.tx_hex)
+        }
+    }
+
+    impl walletrpc::AddrResponse {
+        /// Parses `addr`. Check the network with [`Address::require_network`].
+        pub fn parse_address(&self) -> Result<Address<NetworkUnchecked>, ParseError> {
+            parse_address(&self.addr)
+        }
+    }
+}
+
+#[cfg(feature = "chainrpc")]
+mod chainrpc_conversions {
+    use bitcoin::{block, consensus, Block, BlockHash};
+
+    use super::*;
+    use crate::chainrpc;
+
+    /// Reads a block hash from a `block_hash` field, which is in internal byte order.
+    fn parse_block_hash(bytes: &[u8]) -> Result<BlockHash, ParseError> {
+        BlockHash::from_slice(bytes).map_err(|_| ParseError::InvalidLength {
+            expected: 32,
+            actual: bytes.len(),
+        })
+    }
+
+    impl chainrpc::GetBlockRequest {
+        /// Creates a request for the block with hash `block_hash`.
+        pub fn new(block_hash: BlockHash) -> Self {
+            Self {
+                block_hash: bytes_field(block_hash.to_byte_array().to_vec()),
+            }
+        }
+    }
+
+    impl chainrpc::GetBlockResponse {
+        /// Decodes `raw_block`.
+        pub fn parse_block(&self) -> Result<Block, ParseError> {
+            consensus::deserialize(&self.raw_block).map_err(invalid)
+        }
+    }
+
+    impl chainrpc::GetBlockHeaderRequest {
+        /// Creates a request for the header of the block with hash `block_hash`.
+        pub fn new(block_hash: BlockHash) -> Self {
+            Self {
+                block_hash: bytes_field(block_hash.to_byte_array().to_vec()),
+            }
+        }
+    }
+
+    impl chainrpc::GetBlockHeaderResponse {
+        /// Decodes `raw_block_header`.
+        pub fn parse_header(&self) -> Result<block::Header, ParseError> {
+            consensus::deserialize(&self.raw_block_header).map_err(invalid)
+        }
+    }
+
+    impl chainrpc::GetBestBlockResponse {
+        /// Reads `block_hash`.
+        pub fn parse_block_hash(&self) -> Result<BlockHash, ParseError> {
+            parse_block_hash(&self.block_hash)
+        }
+    }
+
+    impl chainrpc::GetBlockHashResponse {
+        /// Reads `block_hash`.
+        pub fn parse_block_hash(&self) -> Result<BlockHash, ParseError> {
+            parse_block_hash(&self.block_hash)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "lightningrpc", feature = "walletrpc", feature = "chainrpc"))]
+    use bitcoin::blockdata::constants::genesis_block;
+    #[cfg(any(feature = "lightningrpc", feature = "walletrpc"))]
+    use bitcoin::transaction::Version;
+    #[cfg(any(feature = "lightningrpc", feature = "walletrpc", feature = "chainrpc"))]
+    use bitcoin::Network;
+    #[cfg(any(feature = "lightningrpc", feature = "walletrpc"))]
+    use bitcoin::{absolute::LockTime, TxIn, TxOut};
+
+    use super::*;
+
+    #[cfg(any(feature = "lightningrpc", feature = "walletrpc"))]
+    const ADDRESS: &str = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+
+    #[cfg(any(feature = "lightningrpc", feature = "walletrpc"))]
+    fn address() -> Address {
+        ADDRESS.parse::<Address<_>>().unwrap().require_network(Network::Bitcoin).unwrap()
+    }
+
+    /// An unsigned transaction paying 1000 sat to [`ADDRESS`].
+    #[cfg(any(feature = "lightningrpc", feature = "walletrpc"))]
+    fn unsigned_tx() -> Transaction {
+        Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::ZERO,
+            input: vec![TxIn::default()],
+            output: vec![TxOut {
+                value: bitcoin::Amount::from_sat(1000),
+                script_pubkey: address().script_pubkey(),
+            }],
+        }
+    }
+
+    #[test]
+    fn node_id_to_public_key() {
+        let key: NodeId =
+            "02eec7245d6b7d2ccb30380bfbe2a3648cd7a942653f5aa340edcea1f283686619".parse().unwrap();
+        let public_key = PublicKey::try_from(key).unwrap();
+        assert_eq!(NodeId::from(public_key), key);
+
+        // Has a valid prefix, but x = 0 is not on the curve.
+        let off_curve: NodeId = format!("02{}", "00".repeat(32)).parse().unwrap();
+        assert!(matches!(PublicKey::try_from(off_curve), Err(ParseError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn ids_and_amounts() {
+        let txid: Txid =
+            "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b".parse().unwrap();
+        let bitcoin_txid = bitcoin::Txid::from(txid);
+        assert_eq!(bitcoin_txid.to_string(), txid.to_string());
+        assert_eq!(Txid::from(bitcoin_txid), txid);
+
+        let outpoint = OutPoint::new(txid, 2);
+        let bitcoin_outpoint = bitcoin::OutPoint::from(outpoint);
+        assert_eq!(bitcoin_outpoint, bitcoin::OutPoint::new(bitcoin_txid, 2));
+        assert_eq!(OutPoint::from(bitcoin_outpoint), outpoint);
+        assert_eq!(ChannelPoint::from(bitcoin_outpoint), ChannelPoint::from(outpoint));
+
+        assert_eq!(bitcoin::Amount::from(Amount::from_sat(21)), bitcoin::Amount::from_sat(21));
+        assert_eq!(Amount::from(bitcoin::Amount::from_sat(21)), Amount::from_sat(21));
+    }
+
+    #[cfg(feature = "lightningrpc")]
+    #[test]
+    fn lnrpc_transactions() {
+        let genesis = genesis_block(Network::Bitcoin);
+        let coinbase = &genesis.txdata[0];
+        let mut tx = crate::lnrpc::Transaction {
+            raw_tx_hex: hex::encode(consensus::serialize(coinbase)),
+            block_hash: genesis.block_hash().to_string(),
+            ..Default::default()
+        };
+        assert_eq!(tx.parse_tx().unwrap(), *coinbase);
+        assert_eq!(tx.parse_block_hash().unwrap(), Some(genesis.block_hash()));
+
+        tx.block_hash.clear();
+        assert_eq!(tx.parse_block_hash().unwrap(), None);
+        tx.raw_tx_hex = "00".to_owned();
+        assert!(matches!(tx.parse_tx(), Err(ParseError::InvalidFormat(_))));
+        tx.raw_tx_hex = "zz".to_owned();
+        assert!(matches!(tx.parse_tx(), Err(ParseError::InvalidHex(_))));
+    }
+
+    #[cfg(feature = "lightningrpc")]
+    #[test]
+    fn lnrpc_addresses_and_keys() {
+        let response = crate::lnrpc::NewAddressResponse {
+            address: ADDRESS.to_owned(),
+        };
+        let address = response.parse_address().unwrap();
+        assert_eq!(address.require_network(Network::Bitcoin).unwrap().to_string(), ADDRESS);
+        let response = crate::lnrpc::NewAddressResponse {
+            address: "not an address".to_owned(),
+        };
+        assert!(matches!(response.parse_address(), Err(ParseError::InvalidFormat(_))));
+
+        let node_id: NodeId =
+            "02eec7245d6b7d2ccb30380bfbe2a3648cd7a942653f5aa340edcea1f283686619".parse().unwrap();
+        let descriptor = crate::lnrpc::KeyDescriptor {
+            raw_key_bytes: bytes_field(node_id.as_bytes().to_vec()),
+            ..Default::default()
+        };
+        assert_eq!(NodeId::from(descriptor.parse_public_key().unwrap()), node_id);
+    }
+
+    #[cfg(feature = "lightningrpc")]
+    #[test]
+    fn lnrpc_requests() {
+        use crate::lnrpc::{
+            FundingPsbtFinalize, FundingPsbtVerify, PsbtShim, SendCoinsRequest, SendManyRequest,
+        };
+
+        let address = address();
+        let request = SendCoinsRequest::new(&address, bitcoin::Amount::from_sat(1000)).unwrap();
+        assert_eq!((request.addr.as_str(), request.amount), (ADDRESS, 1000));
+        let request = SendManyRequest::new([(&address, bitcoin::Amount::from_sat(5))]).unwrap();
+        assert_eq!(request.addr_to_amount[ADDRESS], 5);
+
+        let too_much = bitcoin::Amount::from_sat(i64::MAX as u64 + 1);
+        assert!(matches!(
+            SendCoinsRequest::new(&address, too_much),
+            Err(ParseError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            SendManyRequest::new([(&address, too_much)]),
+            Err(ParseError::OutOfRange(_))
+        ));
+
+        let psbt = Psbt::from_unsigned_tx(unsigned_tx()).unwrap();
+        let shim = PsbtShim::new([1; 32], Some(&psbt));
+        assert_eq!(
+            (&shim.pending_chan_id[..], &shim.base_psbt[..]),
+            (&[1; 32][..], &psbt.serialize()[..])
+        );
+        assert!(PsbtShim::new([1; 32], None).base_psbt.is_empty());
+        assert_eq!(FundingPsbtVerify::new([1; 32], &psbt).funded_psbt[..], psbt.serialize());
+        assert_eq!(FundingPsbtFinalize::new([1; 32], &psbt).signed_psbt[..], psbt.serialize());
+    }
+
+    #[cfg(feature = "walletrpc")]
+    #[test]
+    fn walletrpc_psbts() {
+        use crate::walletrpc::fund_psbt_request::Template;
+        use crate::walletrpc::{
+            FinalizePsbtRequest, FinalizePsbtResponse, FundPsbtRequest, FundPsbtResponse,
+            SignPsbtRequest, SignPsbtResponse,
+        };
+
+        let psbt = Psbt::from_unsigned_tx(unsigned_tx()).unwrap();
+        let serialized = psbt.serialize();
+        match FundPsbtRequest::from_psbt(&psbt).template {
+            Some(Template::Psbt(template)) => assert_eq!(template[..], serialized),
+            template => panic!("unexpected template {template:?}"),
+        }
+        assert_eq!(SignPsbtRequest::new(&psbt).funded_psbt[..], serialized);
+        assert_eq!(FinalizePsbtRequest::new(&psbt).funded_psbt[..], serialized);
+
+        let funded = FundPsbtResponse {
+            funded_psbt: bytes_field(serialized.clone()),
+            ..Default::default()
+        };
+        assert_eq!(funded.parse_psbt().unwrap(), psbt);
+        let signed = SignPsbtResponse {
+            signed_psbt: bytes_field(serialized.clone()),
+            ..Default::default()
+        };
+        assert_eq!(signed.parse_psbt().unwrap(), psbt);
+
+        let finalized = FinalizePsbtResponse {
+            signed_psbt: bytes_field(vec![0x70, 0x73, 0x62, 0x74]),
+            raw_final_tx: bytes_field(consensus::serialize(&unsigned_tx())),
+        };
+        assert!(matches!(finalized.parse_psbt(), Err(ParseError::InvalidFormat(_))));
+        assert_eq!(finalized.parse_tx().unwrap(), unsigned_tx());
+    }
+
+    #[cfg(feature = "walletrpc")]
+    #[test]
+    fn walletrpc_transactions_and_addresses() {
+        let tx = crate::walletrpc::Transaction::new(&unsigned_tx(), "label");
+        assert_eq!(tx.label, "label");
+        assert_eq!(tx.parse_tx().unwrap(), unsigned_tx());
+
+        let response = crate::walletrpc::AddrResponse {
+            addr: ADDRESS.to_owned(),
+        };
+        assert_eq!(response.parse_address().unwrap().assume_checked().to_string(), ADDRESS);
+    }
+
+    #[cfg(feature = "chainrpc")]
+    #[test]
+    fn chainrpc_blocks() {
+        use crate::chainrpc::{
+            GetBestBlockResponse, GetBlockHashResponse, GetBlockHeaderRequest,
+            GetBlockHeaderResponse, GetBlockRequest, GetBlockResponse,
+        };
+
+        let genesis = genesis_block(Network::Bitcoin);
+        let hash = genesis.block_hash();
+        assert_eq!(GetBlockRequest::new(hash).block_hash[..], hash.to_byte_array());
+        assert_eq!(GetBlockHeaderRequest::new(hash).block_hash[..], hash.to_byte_array());
+
+        let block = GetBlockResponse {
+            raw_block: bytes_field(bitcoin::consensus::serialize(&genesis)),
+        };
+        assert_eq!(block.parse_block().unwrap(), genesis);
+        let header = GetBlockHeaderResponse {
+            raw_block_header: bytes_field(bitcoin::consensus::serialize(&genesis.header)),
+        };
+        assert_eq!(header.parse_header().unwrap(), genesis.header);
+        let header = GetBlockHeaderResponse {
+            raw_block_header: bytes_field(vec![0; 79]),
+        };
+        assert!(matches!(header.parse_header(), Err(ParseError::InvalidFormat(_))));
+
+        let best = GetBestBlockResponse {
+            block_hash: bytes_field(hash.to_byte_array().to_vec()),
+            block_height: 0,
+        };
+        assert_eq!(best.parse_block_hash().unwrap(), hash);
+        let short = GetBlockHashResponse {
+            block_hash: bytes_field(vec![0; 31]),
+        };
+        assert_eq!(
+            short.parse_block_hash(),
+            Err(ParseError::InvalidLength {
+                expected: 32,
+                actual: 31
+            })
+        );
+    }
+}
@@ -0,0 +1,254 @@
+//! A blocking client, for callers without an async runtime.
+//!
+//! [`BlockingClient`] owns a Tokio runtime and mirrors the accessors of [`Client`]. Its service
+//! clients have the same methods as the async ones, but block the current thread until the
+//! response arrives. RPCs that stream responses return a [`Streaming`] iterator, which blocks
+//! for each message.
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "lightningrpc")]
+//! # fn example() -> voltage_tonic_lnd::Result<()> {
+//! use voltage_tonic_lnd::blocking::BlockingClient;
+//! use voltage_tonic_lnd::lnrpc::{GetInfoRequest, InvoiceSubscription};
+//!
+//! let client = BlockingClient::builder()
+//!     .address("https://localhost:10009")
+//!     .macaroon_path("/path/to/admin.macaroon")
+//!     .cert_path("/path/to/tls.cert")
+//!     .build_blocking()?;
+//!
+//! let mut lightning = client.lightning()?;
+//! let info = lightning.get_info(GetInfoRequest {})?;
+//! println!("{:?}", info.into_inner());
+//!
+//! let invoices = lightning.subscribe_invoices(InvoiceSubscription::default())?.into_inner();
+//! for invoice in invoices {
+//!     println!("{:?}", invoice?);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Like `reqwest::blocking`, calls must not be made from within an async runtime: blocking
+//! panics there. Use [`Client`] in async code instead. Clients and streams may still be dropped
+//! anywhere.
+
+use std::ops::Deref;
+use std::sync::Arc;
+
+use tonic::metadata::MetadataMap;
+use tonic::Status;
+
+#[cfg(services)]
+use crate::client::Service;
+use crate::error::{Error, Result};
+#[cfg(services)]
+use crate::protos::*;
+use crate::{Client, ClientBuilder};
+
+macro_rules! blocking_clients {
+    ($(
+        $(#[$attr:meta])*
+        $feature:literal, $accessor:ident, $alias:ident = $client:path;
+    )*) => {
+        $(
+            $(#[$attr])*
+            #[cfg(feature = $feature)]
+            pub type $alias = $client;
+        )*
+
+        impl BlockingClient {
+            $(
+                #[doc = concat!("Returns the blocking version of [`Client::", stringify!($accessor), "`].")]
+                ///
+                /// # Errors
+                /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+                /// [`ClientBuilder::services`].
+                #[cfg(feature = $feature)]
+                pub fn $accessor(&self) -> Result<$alias> {
+                    Ok(<$alias>::new(self.inner.$accessor()?, self.runtime.clone()))
+                }
+            )*
+        }
+    };
+}
+
+blocking_clients! {
+    /// Convenience type alias for blocking lightning client.
+    "lightningrpc", lightning,
+    LightningClient = lnrpc::lightning_blocking_client::LightningBlockingClient<Service>;
+    /// Convenience type alias for blocking wallet client.
+    "walletrpc", wallet,
+    WalletKitClient = walletrpc::wallet_kit_blocking_client::WalletKitBlockingClient<Service>;
+    /// Convenience type alias for blocking signer client.
+    "signrpc", signer, SignerClient = signrpc::signer_blocking_client::SignerBlockingClient<Service>;
+    /// Convenience type alias for blocking versioner service client.
+    "versionrpc", versioner,
+    VersionerClient = verrpc::versioner_blocking_client::VersionerBlockingClient<Service>;
+    /// Convenience type alias for blocking peers service client.
+    "peersrpc", peers, PeersClient = peersrpc::peers_blocking_client::PeersBlockingClient<Service>;
+    /// Convenience type alias for blocking router client.
+    "routerrpc", router,
+    RouterClient = routerrpc::router_blocking_client::RouterBlockingClient<Service>;
+    /// Convenience type alias for blocking invoices client.
+    "invoicesrpc", invoices,
+    InvoicesClient = invoicesrpc::invoices_blocking_client::InvoicesBlockingClient<Service>;
+    /// Convenience type alias for blocking state service client.
+    "staterpc", state, StateClient = lnrpc::state_blocking_client::StateBlockingClient<Service>;
+    /// Convenience type alias for blocking chain notifier client.
+    "chainrpc", chain_notifier,
+    ChainNotifierClient =
+        chainrpc::chain_notifier_blocking_client::ChainNotifierBlockingClient<Service>;
+    /// Convenience type alias for blocking chain kit client.
+    "chainrpc", chain_kit,
+    ChainKitClient = chainrpc::chain_kit_blocking_client::ChainKitBlockingClient<Service>;
+    /// Convenience type alias for blocking taproot assets client.
+    "taprpc", taproot_assets,
+    TaprootAssetsClient =
+        taprpc::taproot_assets_blocking_client::TaprootAssetsBlockingClient<Service>;
+    /// Convenience type alias for blocking asset wallet client.
+    "assetwalletrpc", asset_wallet,
+    AssetWalletClient =
+        assetwalletrpc::asset_wallet_blocking_client::AssetWalletBlockingClient<Service>;
+    /// Convenience type alias for blocking mint client.
+    "mintrpc", mint, MintClient = mintrpc::mint_blocking_client::MintBlockingClient<Service>;
+    /// Convenience type alias for blocking price oracle client.
+    "priceoraclerpc", price_oracle,
+    PriceOracleClient =
+        priceoraclerpc::price_oracle_blocking_client::PriceOracleBlockingClient<Service>;
+    /// Convenience type alias for blocking RFQ client.
+    "rfqrpc", rfq, RfqClient = rfqrpc::rfq_blocking_client::RfqBlockingClient<Service>;
+    /// Convenience type alias for blocking tap channel client.
+    "tapchannelrpc", tap_channel,
+    TapChannelClient = tapchannelrpc::taproot_asset_channels_blocking_client::TaprootAssetChannelsBlockingClient<Service>;
+    /// Convenience type alias for blocking tap dev client.
+    "tapdevrpc", tap_dev,
+    TapDevClient = tapdevrpc::tap_dev_blocking_client::TapDevBlockingClient<Service>;
+    /// Convenience type alias for blocking universal client.
+    "universerpc", universal,
+    UniversalClient = universerpc::universe_blocking_client::UniverseBlockingClient<Service>;
+}
+
+/// A [`Client`] that blocks the current thread on every call.
+///
+/// It owns the runtime the connections and calls run on. Clones, the service clients and the
+/// streams they return share it; it shuts down once the last of them is dropped.
+#[derive(Clone)]
+pub struct BlockingClient {
+    // Only read by the service accessors.
+    #[cfg_attr(not(services), allow(dead_code))]
+    inner: Client,
+    runtime: Arc<Runtime>,
+}
+
+/// The runtime a [`BlockingClient`] runs on, shared by its clones, service clients and streams.
+///
+/// Once the last of them is dropped, the runtime is shut down without waiting for the tasks
+/// still running on it. Unlike dropping a [`tokio::runtime::Runtime`], this is allowed within an
+/// async context too.
+#[derive(Debug)]
+pub struct Runtime(Option<tokio::runtime::Runtime>);
+
+impl Deref for Runtime {
+    type Target = tokio::runtime::Runtime;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref().expect("runtime is only taken when dropped")
+    }
+}
+
+impl Drop for Runtime {
+    fn drop(&mut self) {
+        if let Some(runtime) = self.0.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+impl BlockingClient {
+    /// Returns a builder for a client, finalized with [`ClientBuilder::build_blocking`].
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
+    /// Returns the runtime the client runs on, for running other async code alongside it.
+    pub fn runtime(&self) -> &tokio::runtime::Runtime {
+        &self.runtime
+    }
+}
+
+impl ClientBuilder {
+    /// Finalizes the builder and connects to the LND node on a new runtime, returning a
+    /// [`BlockingClient`].
+    ///
+    /// Requires the `blocking` feature.
+    ///
+    /// # Errors
+    /// Returns an error if the runtime can't be started, or for the same reasons as
+    /// [`build`](Self::build).
+    pub fn build_blocking(self) -> Result<BlockingClient> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("tonic-lnd-blocking")
+            .enable_all()
+            .build()
+            .map_err(Error::Runtime)?;
+        let inner = runtime.block_on(self.build())?;
+        Ok(BlockingClient {
+            inner,
+            runtime: Arc::new(Runtime(Some(runtime))),
+        })
+    }
+}
+
+/// A stream of responses that blocks for each message, returned by the RPCs that stream.
+///
+/// Iterate over it to receive the messages until the server ends the stream.
+#[derive(Debug)]
+pub struct Streaming<T> {
+    inner: tonic::Streaming<T>,
+    runtime: Arc<Runtime>,
+}
+
+impl<T> Streaming<T> {
+    /// Wraps `inner`, blocking on `runtime` for each message. The stream must come from a client
+    /// running on `runtime`.
+    pub fn new(inner: tonic::Streaming<T>, runtime: Arc<Runtime>) -> Self {
+        Self {
+            inner,
+            runtime,
+        }
+    }
+
+    /// Returns the next message, or `None` once the stream ended.
+    ///
+    /// # Errors
+    /// Returns the status the stream failed with.
+    pub fn message(&mut self) -> std::result::Result<Option<T>, Status> {
+        self.runtime.block_on(self.inner.message())
+    }
+
+    /// Returns the trailers of the stream, reading any remaining messages first.
+    ///
+    /// # Errors
+    /// Returns the status the stream failed with.
+    pub fn trailers(&mut self) -> std::result::Result<Option<MetadataMap>, Status> {
+        self.runtime.block_on(self.inner.trailers())
+    }
+
+    /// Returns the async stream.
+    ///
+    /// It has to be polled on the runtime of the client it came from, see
+    /// [`BlockingClient::runtime`].
+    pub fn into_inner(self) -> tonic::Streaming<T> {
+        self.inner
+    }
+}
+
+impl<T> Iterator for Streaming<T> {
+    type Item = std::result::Result<T, Status>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.message().transpose()
+    }
+}
@@ -0,0 +1,836 @@
+//! Decoding BOLT11 payment requests without a node.
+//!
+//! [`Invoice::decode`] parses a payment request, checks its checksum and signature, and returns
+//! the same [`PayReq`] that `DecodePayReq` would, including LND's defaults for the expiry and the
+//! final CLTV delta. [`Invoice::validate`] checks the network and the expiry, so services can
+//! reject invoices before sending a payment.
+//!
+//! # Example
+//! ```rust
+//! use voltage_tonic_lnd::bolt11::{Invoice, Network};
+//! use voltage_tonic_lnd::types::MilliSats;
+//!
+//! let invoice: Invoice = "lnbc2500u1pvjluezpp5qqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqypqsp5zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zygsdq5xysxxatsyp3k7enxv4jsxqzpucqzpgfpp3x9et2e20v6pu37c5d9vax37wxq72un98fppqw508d6qejxtdg4y5r3zarvary0c5xw7kfp4ppyysjzgfpyysjzgfpyysjzgfpyysjzgfpyysjzgfpyysjzgfpyysrzjq20q82gphp2nflc7jtzrcazrra7wwgzxqc8u7754cdlpfrmccae92qgzqvzq2ps8pqqqqqqpqqqqq9qqqv9q5sqqqqqqqqqqqqqqqqsgq3ppsqufj9gcyr3tn3yhnfpv0dwevhx9fzxctsvenxw4le46g0vgnemxrv2gput5ldqave96aqs8ldvqavmdtpzc66g0ggs444urm8jqpz5cyvd"
+//!     .parse()
+//!     .unwrap();
+//! assert_eq!(invoice.network(), Network::Bitcoin);
+//! assert_eq!(invoice.amount(), Some(MilliSats::from_msat(250_000_000)));
+//!
+//! let pay_req = invoice.pay_req();
+//! assert_eq!(pay_req.destination, "03e7156ae33b0a208d0744199163177e909e80176e55d97a2f221ede0f934dd9ad");
+//! assert_eq!(pay_req.description, "1 cup coffee");
+//! assert_eq!(pay_req.expiry, 60);
+//! assert_eq!(pay_req.fallback_addr, "15WTXVVv4JHN992HjbRxMwmekcppRpzJrK");
+//! assert_eq!(pay_req.route_hints[0].hop_hints[0].fee_proportional_millionths, 20);
+//! assert!(pay_req.features[&14].is_required);
+//!
+//! // Created in 2017, so long expired.
+//! assert!(invoice.validate(Network::Bitcoin).is_err());
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use secp256k1::ecdsa::{RecoverableSignature, RecoveryId};
+use secp256k1::{Message, PublicKey, Secp256k1};
+use sha2::{Digest, Sha256};
+
+use crate::error::InvoiceError;
+use crate::lnrpc::{Feature, HopHint, PayReq, RouteHint};
+use crate::protos::bytes_field;
+use crate::types::{MilliSats, NodeId, PaymentHash};
+
+/// The expiry of invoices without an `x` field, in seconds.
+const DEFAULT_EXPIRY: i64 = 3600;
+
+/// The final CLTV delta of invoices without a `c` field, as assumed by LND.
+const DEFAULT_CLTV_EXPIRY: i64 = 18;
+
+/// The chain an invoice is for, from the prefix of the payment request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Network {
+    /// `lnbc`
+    Bitcoin,
+    /// `lntb`
+    Testnet,
+    /// `lntbs`
+    Signet,
+    /// `lnbcrt`
+    Regtest,
+    /// `lnsb`
+    Simnet,
+}
+
+impl Network {
+    /// Longest prefix first, so `tbs` isn't read as `tb`.
+    const ALL: [Network; 5] =
+        [Network::Regtest, Network::Signet, Network::Bitcoin, Network::Testnet, Network::Simnet];
+
+    /// Returns the currency prefix following `ln` in payment requests.
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Network::Bitcoin => "bc",
+            Network::Testnet => "tb",
+            Network::Signet => "tbs",
+            Network::Regtest => "bcrt",
+            Network::Simnet => "sb",
+        }
+    }
+
+    /// The human-readable part of segwit addresses.
+    fn segwit_hrp(&self) -> &'static str {
+        match self {
+            Network::Bitcoin => "bc",
+            Network::Testnet | Network::Signet => "tb",
+            Network::Regtest => "bcrt",
+            Network::Simnet => "sb",
+        }
+    }
+
+    /// The version bytes of P2PKH and P2SH addresses.
+    fn base58_versions(&self) -> (u8, u8) {
+        match self {
+            Network::Bitcoin => (0x00, 0x05),
+            Network::Testnet | Network::Signet | Network::Regtest => (0x6f, 0xc4),
+            Network::Simnet => (0x3f, 0x7b),
+        }
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Network::Bitcoin => "bitcoin",
+            Network::Testnet => "testnet",
+            Network::Signet => "signet",
+            Network::Regtest => "regtest",
+            Network::Simnet => "simnet",
+        })
+    }
+}
+
+/// A decoded BOLT11 payment request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Invoice {
+    payment_request: String,
+    network: Network,
+    destination: NodeId,
+    payment_hash: PaymentHash,
+    pay_req: PayReq,
+}
+
+impl Invoice {
+    /// Decodes `payment_request` and checks its signature. A `lightning:` prefix is allowed.
+    ///
+    /// # Errors
+    /// Returns an error if the payment request is malformed, lacks a payment hash or a
+    /// description, or isn't signed by its destination.
+    pub fn decode(payment_request: &str) -> Result<Self, InvoiceError> {
+        let trimmed = payment_request.trim();
+        let trimmed = match trimmed.get(..10) {
+            Some(scheme) if scheme.eq_ignore_ascii_case("lightning:") => &trimmed[10..],
+            _ => trimmed,
+        };
+        if trimmed.chars().any(|c| c.is_ascii_lowercase())
+            && trimmed.chars().any(|c| c.is_ascii_uppercase())
+        {
+            return Err(invalid("mixed case"));
+        }
+        let lowercase = trimmed.to_ascii_lowercase();
+        let (hrp, data) = lowercase.rsplit_once('1').ok_or_else(|| invalid("missing separator"))?;
+        let data = data
+            .bytes()
+            .map(|c| CHARSET.iter().position(|&x| x == c).map(|v| v as u8))
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(|| invalid("invalid character"))?;
+        if data.len() < 7 + 104 + 6 {
+            return Err(invalid("too short"));
+        }
+        if polymod(&hrp_expand(hrp).chain(data.iter().copied()).collect::<Vec<_>>()) != 1 {
+            return Err(invalid("invalid checksum"));
+        }
+
+        let (network, amount_msat) = parse_hrp(hrp)?;
+        let data = &data[..data.len() - 6];
+        let (body, signature) = data.split_at(data.len() - 104);
+
+        let mut pay_req = PayReq {
+            timestamp: to_int(&body[..7]) as i64,
+            num_msat: amount_msat.unwrap_or(0) as i64,
+            num_satoshis: (amount_msat.unwrap_or(0) / 1000) as i64,
+            ..Default::default()
+        };
+        let mut payment_hash = None;
+        let mut payee = None;
+        let mut description = None;
+        let mut description_hash = None;
+        let mut expiry = None;
+        let mut cltv_expiry = None;
+        let mut fields = &body[7..];
+        while !fields.is_empty() {
+            let (tag, len) = match fields {
+                [tag, high, low, ..] => (*tag, (usize::from(*high) << 5) | usize::from(*low)),
+                _ => return Err(invalid("truncated field")),
+            };
+            let value = fields.get(3..3 + len).ok_or_else(|| invalid("truncated field"))?;
+            fields = &fields[3 + len..];
+            // As in BOLT11, unknown fields and fields of the wrong length are skipped, and the
+            // first field of each kind wins.
+            match CHARSET[usize::from(tag)] {
+                b'p' if len == 52 && payment_hash.is_none() => {
+                    payment_hash = Some(PaymentHash::try_from(to_bytes(value))?);
+                }
+                b's' if len == 52 && pay_req.payment_addr.is_empty() => {
+                    pay_req.payment_addr = bytes_field(to_bytes(value));
+                }
+                b'd' if description.is_none() => {
+                    let bytes = to_bytes(value);
+                    let text = String::from_utf8(bytes)
+                        .map_err(|_| invalid("description is not valid UTF-8"))?;
+                    description = Some(text);
+                }
+                b'h' if len == 52 && description_hash.is_none() => {
+                    description_hash = Some(hex::encode(to_bytes(value)));
+                }
+                b'n' if len == 53 && payee.is_none() => {
+                    payee = Some(
+                        PublicKey::from_slice(&to_bytes(value))
+                            .map_err(|_| invalid("invalid destination"))?,
+                    );
+                }
+                b'x' if expiry.is_none() && len <= 12 => {
+                    expiry = Some(to_int(value) as i64);
+                }
+                b'c' if cltv_expiry.is_none() && len <= 12 => {
+                    cltv_expiry = Some(to_int(value) as i64);
+                }
+                b'f' if pay_req.fallback_addr.is_empty() => {
+                    if let Some(address) = fallback_address(value, network) {
+                        pay_req.fallback_addr = address;
+                    }
+                }
+                b'r' => {
+                    pay_req.route_hints.push(parse_route_hint(&to_bytes(value))?);
+                }
+                b'9' if pay_req.features.is_empty() => {
+                    pay_req.features = parse_features(value);
+                }
+                _ => {}
+            }
+        }
+
+        pay_req.expiry = expiry.unwrap_or(DEFAULT_EXPIRY);
+        pay_req.cltv_expiry = cltv_expiry.unwrap_or(DEFAULT_CLTV_EXPIRY);
+        let payment_hash = payment_hash.ok_or_else(|| invalid("missing payment hash"))?;
+        match (description, description_hash) {
+            (Some(_), Some(_)) => return Err(invalid("both description and description hash")),
+            (None, None) => return Err(invalid("missing description")),
+            (description, description_hash) => {
+                pay_req.description = description.unwrap_or_default();
+                pay_req.description_hash = description_hash.unwrap_or_default();
+            }
+        }
+
+        // The signature covers the human-readable part and the data before the signature.
+        let mut preimage = hrp.as_bytes().to_vec();
+        preimage.extend(to_bytes_padded(body));
+        let message = Message::from_digest(Sha256::digest(&preimage).into());
+        let signature = to_bytes(signature);
+        let recovery_id = RecoveryId::from_i32(i32::from(signature[64]))
+            .map_err(|_| InvoiceError::InvalidSignature)?;
+        let signature = RecoverableSignature::from_compact(&signature[..64], recovery_id)
+            .map_err(|_| InvoiceError::InvalidSignature)?;
+        let secp = Secp256k1::verification_only();
+        let destination = match payee {
+            Some(payee) => {
+                secp.verify_ecdsa(&message, &signature.to_standard(), &payee)
+                    .map_err(|_| InvoiceError::InvalidSignature)?;
+                payee
+            }
+            None => secp
+                .recover_ecdsa(&message, &signature)
+                .map_err(|_| InvoiceError::InvalidSignature)?,
+        };
+        let destination = NodeId::try_from(destination.serialize().as_slice())?;
+
+        pay_req.destination = destination.to_string();
+        pay_req.payment_hash = payment_hash.to_string();
+        Ok(Self {
+            payment_request: lowercase,
+            network,
+            destination,
+            payment_hash,
+            pay_req,
+        })
+    }
+
+    /// Returns the payment request, lowercased and without a `lightning:` prefix.
+    pub fn payment_request(&self) -> &str {
+        &self.payment_request
+    }
+
+    /// Returns the invoice in the shape `DecodePayReq` returns.
+    pub fn pay_req(&self) -> &PayReq {
+        &self.pay_req
+    }
+
+    /// Returns the invoice in the shape `DecodePayReq` returns, consuming it.
+    pub fn into_pay_req(self) -> PayReq {
+        self.pay_req
+    }
+
+    /// Returns the network the invoice is for, taken from its human-readable prefix.
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// Returns the node to pay, which signed the invoice.
+    pub fn destination(&self) -> NodeId {
+        self.destination
+    }
+
+    /// Returns the hash of the preimage that paying the invoice reveals.
+    pub fn payment_hash(&self) -> PaymentHash {
+        self.payment_hash
+    }
+
+    /// Returns the amount to pay, or `None` if the payer chooses it.
+    pub fn amount(&self) -> Option<MilliSats> {
+        match self.pay_req.num_msat {
+            0 => None,
+            amount => Some(MilliSats::from_msat(amount as u64)),
+        }
+    }
+
+    /// Returns when the invoice was created.
+    pub fn timestamp(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.pay_req.timestamp as u64)
+    }
+
+    /// Returns when the invoice stops being payable.
+    pub fn expires_at(&self) -> SystemTime {
+        self.timestamp() + Duration::from_secs(self.pay_req.expiry as u64)
+    }
+
+    /// Returns `true` if the invoice has expired.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at() <= SystemTime::now()
+    }
+
+    /// Checks that the invoice is for `network` and hasn't expired.
+    ///
+    /// # Errors
+    /// Returns [`InvoiceError::WrongNetwork`] or [`InvoiceError::Expired`].
+    pub fn validate(&self, network: Network) -> Result<(), InvoiceError> {
+        if self.network != network {
+            return Err(InvoiceError::WrongNetwork {
+                expected: network,
+                actual: self.network,
+            });
+        }
+        if self.is_expired() {
+            return Err(InvoiceError::Expired);
+        }
+        Ok(())
+    }
+
+    /// Creates a `SendPaymentV2` request paying this invoice, with the remaining fields left to
+    /// their defaults.
+    ///
+    /// # Arguments
+    /// * `amount` - The amount to pay. Required for invoices without an amount and not
+    ///   allowed for invoices with one.
+    ///
+    /// # Errors
+    /// Returns [`InvoiceError::MissingAmount`] or [`InvoiceError::UnexpectedAmount`].
+    #[cfg(feature = "routerrpc")]
+    pub fn send_payment_request(
+        &self,
+        amount: Option<MilliSats>,
+    ) -> Result<crate::routerrpc::SendPaymentRequest, InvoiceError> {
+        let amt_msat = match (self.amount(), amount) {
+            (None, None) => return Err(InvoiceError::MissingAmount),
+            (Some(_), Some(_)) => return Err(InvoiceError::UnexpectedAmount),
+            (None, Some(amount)) => amount.try_into()?,
+            (Some(_), None) => 0,
+        };
+        Ok(crate::routerrpc::SendPaymentRequest {
+            payment_request: self.payment_request.clone(),
+            amt_msat,
+            ..Default::default()
+        })
+    }
+}
+
+impl FromStr for Invoice {
+    type Err = InvoiceError;
+
+    fn from_str(s: &str) -> Result<Self, InvoiceError> {
+        Self::decode(s)
+    }
+}
+
+impl fmt::Display for Invoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.payment_request)
+    }
+}
+
+fn invalid(reason: &str) -> InvoiceError {
+    InvoiceError::Invalid(reason.to_owned())
+}
+
+/// Parses `ln` + currency prefix + optional amount.
+fn parse_hrp(hrp: &str) -> Result<(Network, Option<u64>), InvoiceError> {
+    let rest = hrp.strip_prefix("ln").ok_or_else(|| invalid("missing ln prefix"))?;
+    let network = Network::ALL
+        .into_iter()
+        .find(|network| {
+            rest.strip_prefix(network.prefix())
+                .is_some_and(|amount| amount.chars().next().map_or(true, |c| c.is_ascii_digit()))
+        })
+        .ok_or_else(|| invalid("unknown currency prefix"))?;
+    let amount = &rest[network.prefix().len()..];
+    if amount.is_empty() {
+        return Ok((network, None));
+    }
+
+    // Amounts are in bitcoin, with an optional multiplier.
+    let (digits, divisor) = match amount.as_bytes()[amount.len() - 1] {
+        b'm' => (&amount[..amount.len() - 1], 1_000),
+        b'u' => (&amount[..amount.len() - 1], 1_000_000),
+        b'n' => (&amount[..amount.len() - 1], 1_000_000_000),
+        b'p' => (&amount[..amount.len() - 1], 1_000_000_000_000),
+        _ => (amount, 1),
+    };
+    if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(invalid("invalid amount"));
+    }
+    // Only digits are left, so parsing can only fail on overflow.
+    let value: u128 = digits.parse().map_err(|_| invalid("amount too large"))?;
+    let msat = value.checked_mul(100_000_000_000).ok_or_else(|| invalid("amount too large"))?;
+    if msat % divisor != 0 {
+        return Err(invalid("amount is not a whole number of millisatoshis"));
+    }
+    // `PayReq` keeps the amount as an `i64`.
+    let msat = i64::try_from(msat / divisor).map_err(|_| invalid("amount too large"))?;
+    Ok((network, Some(msat as u64)))
+}
+
+/// Parses the hops of an `r` field, 51 bytes each.
+fn parse_route_hint(bytes: &[u8]) -> Result<RouteHint, InvoiceError> {
+    if bytes.is_empty() || bytes.len() % 51 != 0 {
+        return Err(invalid("invalid route hint"));
+    }
+    let hop_hints = bytes
+        .chunks_exact(51)
+        .map(|hop| {
+            let int = |range: std::ops::Range<usize>| {
+                hop[range].iter().fold(0u64, |acc, &b| (acc << 8) | u64::from(b))
+            };
+            Ok(HopHint {
+                node_id: NodeId::try_from(&hop[..33])?.to_string(),
+                chan_id: int(33..41),
+                fee_base_msat: int(41..45) as u32,
+                fee_proportional_millionths: int(45..49) as u32,
+                cltv_expiry_delta: int(49..51) as u32,
+            })
+        })
+        .collect::<Result<_, InvoiceError>>()?;
+    Ok(RouteHint {
+        hop_hints,
+    })
+}
+
+/// Parses a `9` field, a big-endian bit field with bit 0 last.
+fn parse_features(value: &[u8]) -> HashMap<u32, Feature> {
+    let mut features = HashMap::new();
+    for (index, group) in value.iter().rev().enumerate() {
+        for bit in 0..5 {
+            if group & (1 << bit) == 0 {
+                continue;
+            }
+            let bit = (index * 5 + bit) as u32;
+            let name = feature_name(bit & !1);
+            features.insert(
+                bit,
+                Feature {
+                    name: name.unwrap_or_default().to_owned(),
+                    is_required: bit % 2 == 0,
+                    is_known: name.is_some(),
+                },
+            );
+        }
+    }
+    features
+}
+
+/// Returns LND's name of the feature with the even (required) bit `bit`.
+fn feature_name(bit: u32) -> Option<&'static str> {
+    Some(match bit {
+        0 => "data-loss-protect",
+        4 => "upfront-shutdown-script",
+        6 => "gossip-queries",
+        8 => "tlv-onion",
+        12 => "static-remote-key",
+        14 => "payment-addr",
+        16 => "multi-path",
+        18 => "wumbo-channels",
+        20 => "anchor-commitments",
+        22 => "anchors-zero-fee-htlc-tx",
+        24 => "route-blinding",
+        26 => "shutdown-any-segwit",
+        30 => "amp",
+        44 => "explicit-commitment-type",
+        46 => "scid-alias",
+        48 => "payment-metadata",
+        50 => "zero-conf",
+        54 => "keysend",
+        80 => "simple-taproot-chans",
+        _ => return None,
+    })
+}
+
+/// Encodes the address of an `f` field. Returns `None` for unknown versions.
+fn fallback_address(value: &[u8], network: Network) -> Option<String> {
+    let (&version, program) = value.split_first()?;
+    let program = to_bytes(program);
+    let (p2pkh, p2sh) = network.base58_versions();
+    match version {
+        0..=16 => Some(segwit_address(network.segwit_hrp(), version, &program)),
+        17 if program.len() == 20 => Some(base58check(p2pkh, &program)),
+        18 if program.len() == 20 => Some(base58check(p2sh, &program)),
+        _ => None,
+    }
+}
+
+fn segwit_address(hrp: &str, version: u8, program: &[u8]) -> String {
+    let mut data = vec![version];
+    data.extend(to_groups(program));
+    // Version 0 uses bech32, later versions bech32m.
+    let constant = if version == 0 {
+        1
+    } else {
+        0x2bc8_30a3
+    };
+    let mut values: Vec<u8> = hrp_expand(hrp).chain(data.iter().copied()).collect();
+    values.extend([0; 6]);
+    let checksum = polymod(&values) ^ constant;
+    data.extend((0..6).map(|i| ((checksum >> (5 * (5 - i))) & 31) as u8));
+    let mut address = format!("{hrp}1");
+    address.extend(data.iter().map(|&v| char::from(CHARSET[usize::from(v)])));
+    address
+}
+
+fn base58check(version: u8, payload: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    let mut bytes = vec![version];
+    bytes.extend_from_slice(payload);
+    let checksum = Sha256::digest(Sha256::digest(&bytes));
+    bytes.extend_from_slice(&checksum[..4]);
+
+    let mut digits: Vec<u8> = Vec::new();
+    for &byte in &bytes {
+        let mut carry = u32::from(byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+    std::iter::repeat('1')
+        .take(zeros)
+        .chain(digits.iter().rev().map(|&d| char::from(ALPHABET[usize::from(d)])))
+        .collect()
+}
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+fn polymod(values: &[u8]) -> u32 {
+    const GENERATOR: [u32; 5] = [0x3b6a_57b2, 0x2650_8e6d, 0x1ea1_19fa, 0x3d42_33dd, 0x2a14_62b3];
+    values.iter().fold(1, |checksum, &value| {
+        let top = checksum >> 25;
+        let checksum = ((checksum & 0x1ff_ffff) << 5) ^ u32::from(value);
+        (0..5).filter(|i| (top >> i) & 1 == 1).fold(checksum, |checksum, i| checksum ^ GENERATOR[i])
+    })
+}
+
+fn hrp_expand(hrp: &str) -> impl Iterator<Item = u8> + '_ {
+    hrp.bytes().map(|c| c >> 5).chain([0]).chain(hrp.bytes().map(|c| c & 31))
+}
+
+/// Reads 5-bit groups as a big-endian integer.
+fn to_int(groups: &[u8]) -> u64 {
+    groups.iter().fold(0, |acc, &group| (acc << 5) | u64::from(group))
+}
+
+/// Converts 5-bit groups to bytes, dropping incomplete trailing bits.
+fn to_bytes(groups: &[u8]) -> Vec<u8> {
+    let mut bytes = to_bytes_padded(groups);
+    bytes.truncate(groups.len() * 5 / 8);
+    bytes
+}
+
+/// Converts 5-bit groups to bytes, padding the last byte with zero bits.
+fn to_bytes_padded(groups: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity((groups.len() * 5).div_ceil(8));
+    let (mut acc, mut bits) = (0u32, 0);
+    for &group in groups {
+        acc = (acc << 5) | u32::from(group);
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((acc >> bits) as u8);
+        }
+    }
+    if bits > 0 {
+        bytes.push((acc << (8 - bits)) as u8);
+    }
+    bytes
+}
+
+/// Converts bytes to 5-bit groups, padding the last group with zero bits.
+fn to_groups(bytes: &[u8]) -> Vec<u8> {
+    let mut groups = Vec::with_capacity((bytes.len() * 8).div_ceil(5));
+    let (mut acc, mut bits) = (0u32, 0);
+    for &byte in bytes {
+        acc = (acc << 8) | u32::from(byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            groups.push(((acc >> bits) & 31) as u8);
+        }
+    }
+    if bits > 0 {
+        groups.push(((acc << (5 - bits)) & 31) as u8);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use secp256k1::SecretKey;
+
+    use super::*;
+
+    /// The key that signed the BOLT11 test vectors.
+    const SPEC_KEY: &str = "e126f68f7eafcc8b74f54d269fe206be715000f94dac067d1c04a8ca3b2db734";
+    const SPEC_NODE: &str = "03e7156ae33b0a208d0744199163177e909e80176e55d97a2f221ede0f934dd9ad";
+    const SPEC_HASH: &str = "0001020304050607080900010203040506070809000102030405060708090102";
+
+    const DONATION: &str = "lnbc1pvjluezsp5zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zygspp5qqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqypqdpl2pkx2ctnv5sxxmmwwd5kgetjypeh2ursdae8g6twvus8g6rfwvs8qun0dfjkxaq9qrsgq357wnc5r2ueh7ck6q93dj32dlqnls087fxdwk8qakdyafkq3yap9us6v52vjjsrvywa6rt52cm9r9zqt8r2t7mlcwspyetp5h2tztugp9lfyql";
+    const COFFEE: &str = "lnbc2500u1pvjluezsp5zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zygspp5qqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqypqdq5xysxxatsyp3k7enxv4jsxqzpu9qrsgquk0rl77nj30yxdy8j9vdx85fkpmdla2087ne0xh8nhedh8w27kyke0lp53ut353s06fv3qfegext0eh0ymjpf39tuven09sam30g4vgpfna3rh";
+    const NONSENSE: &str = "lnbc2500u1pvjluezsp5zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zygspp5qqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqypqdpquwpc4curk03c9wlrswe78q4eyqc7d8d0xqzpu9qrsgqhtjpauu9ur7fw2thcl4y9vfvh4m9wlfyz2gem29g5ghe2aak2pm3ps8fdhtceqsaagty2vph7utlgj48u0ged6a337aewvraedendscp573dxr";
+    const HASHED: &str = "lnbc20m1pvjluezsp5zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zygspp5qqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqypqhp58yjmdan79s6qqdhdzgynm4zwqd5d7xmw5fk98klysy043l2ahrqs9qrsgq7ea976txfraylvgzuxs8kgcw23ezlrszfnh8r6qtfpr6cxga50aj6txm9rxrydzd06dfeawfk6swupvz4erwnyutnjq7x39ymw6j38gp7ynn44";
+
+    fn groups(payment_request: &str) -> Vec<u8> {
+        let (_, data) = payment_request.rsplit_once('1').unwrap();
+        data.bytes().map(|c| CHARSET.iter().position(|&x| x == c).unwrap() as u8).collect()
+    }
+
+    /// Encodes `hrp` and `data` with a valid checksum.
+    fn encode(hrp: &str, data: &[u8]) -> String {
+        let values: Vec<u8> = hrp_expand(hrp).chain(data.iter().copied()).chain([0; 6]).collect();
+        let checksum = polymod(&values) ^ 1;
+        let checksum = (0..6).map(|i| ((checksum >> (5 * (5 - i))) & 31) as u8);
+        let data = data.iter().copied().chain(checksum);
+        format!("{hrp}1{}", data.map(|v| char::from(CHARSET[usize::from(v)])).collect::<String>())
+    }
+
+    /// Replaces the human-readable part of `payment_request`, keeping the checksum valid.
+    fn with_hrp(payment_request: &str, hrp: &str) -> String {
+        let data = groups(payment_request);
+        encode(hrp, &data[..data.len() - 6])
+    }
+
+    fn field(tag: u8, bytes: &[u8]) -> Vec<u8> {
+        let value = to_groups(bytes);
+        let tag = CHARSET.iter().position(|&c| c == tag).unwrap() as u8;
+        [tag, (value.len() >> 5) as u8, (value.len() & 31) as u8].into_iter().chain(value).collect()
+    }
+
+    /// Signs the timestamp and fields in `body` with `key`.
+    fn sign(hrp: &str, body: &[u8], key: &SecretKey) -> String {
+        let mut preimage = hrp.as_bytes().to_vec();
+        preimage.extend(to_bytes_padded(body));
+        let message = Message::from_digest(Sha256::digest(&preimage).into());
+        let signature = Secp256k1::new().sign_ecdsa_recoverable(&message, key);
+        let (recovery_id, signature) = signature.serialize_compact();
+        let mut signature = signature.to_vec();
+        signature.push(recovery_id.to_i32() as u8);
+        let data: Vec<u8> = body.iter().copied().chain(to_groups(&signature)).collect();
+        encode(hrp, &data)
+    }
+
+    fn spec_key() -> SecretKey {
+        SecretKey::from_slice(&hex::decode(SPEC_KEY).unwrap()).unwrap()
+    }
+
+    /// A body with the timestamp of the test vectors, a payment hash and a description.
+    fn body(extra: &[Vec<u8>]) -> Vec<u8> {
+        let mut body = groups(COFFEE)[..7].to_vec();
+        body.extend(field(b'p', &hex::decode(SPEC_HASH).unwrap()));
+        body.extend(field(b'd', b"test"));
+        body.extend(extra.iter().flatten());
+        body
+    }
+
+    fn reason(error: InvoiceError) -> String {
+        match error {
+            InvoiceError::Invalid(reason) => reason,
+            error => panic!("unexpected error {error:?}"),
+        }
+    }
+
+    #[test]
+    fn spec_vectors() {
+        for (payment_request, msat, description, description_hash) in [
+            (DONATION, None, "Please consider supporting this project", ""),
+            (COFFEE, Some(250_000_000), "1 cup coffee", ""),
+            (NONSENSE, Some(250_000_000), "ナンセンス 1杯", ""),
+            (
+                HASHED,
+                Some(2_000_000_000),
+                "",
+                "3925b6f67e2c340036ed12093dd44e0368df1b6ea26c53dbe4811f58fd5db8c1",
+            ),
+        ] {
+            let invoice = Invoice::decode(payment_request).unwrap();
+            assert_eq!(invoice.network(), Network::Bitcoin);
+            assert_eq!(invoice.destination().to_string(), SPEC_NODE);
+            assert_eq!(invoice.payment_hash().to_string(), SPEC_HASH);
+            assert_eq!(invoice.amount(), msat.map(MilliSats::from_msat));
+            let pay_req = invoice.pay_req();
+            assert_eq!(pay_req.timestamp, 1_496_314_658);
+            assert_eq!(pay_req.payment_addr[..], [0x11; 32]);
+            assert_eq!(pay_req.description, description);
+            assert_eq!(pay_req.description_hash, description_hash);
+            assert!(pay_req.features[&14].is_required);
+        }
+
+        let coffee = Invoice::decode(COFFEE).unwrap();
+        assert_eq!(coffee.pay_req().expiry, 60);
+        assert_eq!(coffee.pay_req().cltv_expiry, DEFAULT_CLTV_EXPIRY);
+        assert_eq!(Invoice::decode(DONATION).unwrap().pay_req().expiry, DEFAULT_EXPIRY);
+        assert_eq!(coffee.expires_at(), UNIX_EPOCH + Duration::from_secs(1_496_314_718));
+        assert!(matches!(coffee.validate(Network::Bitcoin), Err(InvoiceError::Expired)));
+        assert!(matches!(
+            coffee.validate(Network::Testnet),
+            Err(InvoiceError::WrongNetwork { .. })
+        ));
+    }
+
+    #[test]
+    fn case_and_prefix() {
+        let upper = format!("LIGHTNING:{}", COFFEE.to_ascii_uppercase());
+        assert_eq!(Invoice::decode(&upper).unwrap().payment_request(), COFFEE);
+
+        let mixed = format!("{}{}", &COFFEE[..10].to_ascii_uppercase(), &COFFEE[10..]);
+        assert_eq!(reason(Invoice::decode(&mixed).unwrap_err()), "mixed case");
+    }
+
+    #[test]
+    fn malformed() {
+        let mut flipped = COFFEE.to_owned().into_bytes();
+        flipped[40] = if flipped[40] == b'q' {
+            b'p'
+        } else {
+            b'q'
+        };
+        let flipped = String::from_utf8(flipped).unwrap();
+        assert_eq!(reason(Invoice::decode(&flipped).unwrap_err()), "invalid checksum");
+
+        assert_eq!(reason(Invoice::decode("lnbc2500u").unwrap_err()), "missing separator");
+        assert_eq!(reason(Invoice::decode("lnbc1qqqqqq").unwrap_err()), "too short");
+        assert_eq!(
+            reason(Invoice::decode(&COFFEE.replace('z', "b")).unwrap_err()),
+            "invalid character"
+        );
+        assert_eq!(
+            reason(Invoice::decode(&with_hrp(COFFEE, "lnxy2500u")).unwrap_err()),
+            "unknown currency prefix"
+        );
+        assert_eq!(
+            reason(Invoice::decode(&with_hrp(COFFEE, "lnbc2500x")).unwrap_err()),
+            "invalid amount"
+        );
+        assert_eq!(
+            reason(Invoice::decode(&with_hrp(COFFEE, "lnbc1p")).unwrap_err()),
+            "amount is not a whole number of millisatoshis"
+        );
+
+        let key = spec_key();
+        let without_hash =
+            sign("lnbc", &[&groups(COFFEE)[..7], &field(b'd', b"x")[..]].concat(), &key);
+        assert_eq!(reason(Invoice::decode(&without_hash).unwrap_err()), "missing payment hash");
+        let both = sign("lnbc", &body(&[field(b'h', &[0; 32])]), &key);
+        assert_eq!(
+            reason(Invoice::decode(&both).unwrap_err()),
+            "both description and description hash"
+        );
+        let truncated = sign("lnbc", &[&body(&[])[..], &[1, 0, 9]].concat(), &key);
+        assert_eq!(reason(Invoice::decode(&truncated).unwrap_err()), "truncated field");
+    }
+
+    #[test]
+    fn amounts() {
+        let amount = |hrp: &str| parse_hrp(hrp).map(|(_, msat)| msat).map_err(reason);
+        assert_eq!(amount("lnbc"), Ok(None));
+        assert_eq!(amount("lnbc1"), Ok(Some(100_000_000_000)));
+        assert_eq!(amount("lnbc10p"), Ok(Some(1)));
+        assert_eq!(amount("lntbs25n"), Ok(Some(2_500)));
+        assert_eq!(amount("lnbcrt3m"), Ok(Some(300_000_000)));
+        assert_eq!(amount("lnbc92233720368547758070p"), Ok(Some(i64::MAX as u64)));
+
+        let too_large = Err("amount too large".to_owned());
+        assert_eq!(amount("lnbc92233720368547758080p"), too_large);
+        assert_eq!(amount("lnbc100000000"), too_large);
+        assert_eq!(amount(&format!("lnbc{}", "9".repeat(29))), too_large);
+        assert_eq!(amount(&format!("lnbc{}m", "9".repeat(30))), too_large);
+        assert_eq!(amount(&format!("lnbc{}p", "9".repeat(60))), too_large);
+
+        // Checked before the signature, so it must fail without panicking.
+        let overflowing = with_hrp(COFFEE, &format!("lnbc{}", "9".repeat(40)));
+        assert_eq!(reason(Invoice::decode(&overflowing).unwrap_err()), "amount too large");
+    }
+
+    #[test]
+    fn signatures() {
+        let key = spec_key();
+        let other = SecretKey::from_slice(&[0x42; 32]).unwrap();
+        let other_key = PublicKey::from_secret_key(&Secp256k1::new(), &other);
+        let other_node = NodeId::try_from(other_key.serialize().as_slice()).unwrap();
+
+        // Without an `n` field the destination is recovered from the signature.
+        let signed = sign("lnbc", &body(&[]), &key);
+        assert_eq!(Invoice::decode(&signed).unwrap().destination().to_string(), SPEC_NODE);
+        let by_other = sign("lnbc", &body(&[]), &other);
+        assert_eq!(Invoice::decode(&by_other).unwrap().destination(), other_node);
+
+        // With one, the signature must be by that node.
+        let claimed = field(b'n', other_node.as_bytes());
+        let valid = sign("lnbc", &body(std::slice::from_ref(&claimed)), &other);
+        assert_eq!(Invoice::decode(&valid).unwrap().destination(), other_node);
+        let forged = sign("lnbc", &body(&[claimed]), &key);
+        assert!(matches!(Invoice::decode(&forged), Err(InvoiceError::InvalidSignature)));
+
+        // A changed amount invalidates the signature.
+        let tampered = with_hrp(&valid, "lnbc1m");
+        assert!(matches!(Invoice::decode(&tampered), Err(InvoiceError::InvalidSignature)));
+
+        // Recovery IDs above 3 are invalid.
+        let mut data = groups(COFFEE);
+        data.truncate(data.len() - 6);
+        let last = data.len() - 1;
+        data[last - 1] = 31;
+        let bad_recovery = encode("lnbc2500u", &data);
+        assert!(matches!(Invoice::decode(&bad_recovery), Err(InvoiceError::InvalidSignature)));
+    }
+}
@@ -1,15 +1,32 @@
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
 use std::path::{Path, PathBuf};
+use std::pin::Pin;
 use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Duration;
 
+use hyper::rt;
+use tonic::body::Body;
+use tonic::codec::CompressionEncoding;
+use tonic::codegen::{Service as TowerService, StdError};
+#[cfg(services)]
 use tonic::service::interceptor::InterceptedService;
-use tonic::transport::{Certificate, ClientTlsConfig, Endpoint, Uri};
+#[cfg(feature = "_tls-any")]
+use tonic::transport::{Certificate, ClientTlsConfig};
+use tonic::transport::{Channel, Endpoint, Uri};
 use zeroize::Zeroizing;
 
 use crate::error::{Error, Result};
+#[cfg(services)]
 use crate::protos::*;
+#[cfg(services)]
+use crate::service::{configure_client, MacaroonInterceptor};
+use crate::service::{MessageOptions, ServiceKind};
+use crate::transport::{BalanceStrategy, Node, Transport};
 
-type Service = InterceptedService<tonic::transport::Channel, MacaroonInterceptor>;
+#[cfg(services)]
+pub(crate) type Service = InterceptedService<Transport, MacaroonInterceptor>;
 
 /// Convenience type alias for lightning client.
 #[cfg(feature = "lightningrpc")]
@@ -84,6 +101,22 @@ pub type TapDevClient = tapdevrpc::tap_dev_client::TapDevClient<Service>;
 #[cfg(feature = "universerpc")]
 pub type UniversalClient = universerpc::universe_client::UniverseClient<Service>;
 
+type ConnectFuture =
+    Pin<Box<dyn Future<Output = std::result::Result<Channel, tonic::transport::Error>> + Send>>;
+
+/// A type-erased [`ClientBuilder::connector`], connecting right away or on first use.
+#[derive(Clone)]
+struct Connector {
+    connect: Arc<dyn Fn(Endpoint) -> ConnectFuture + Send + Sync>,
+    connect_lazy: Arc<dyn Fn(Endpoint) -> Channel + Send + Sync>,
+}
+
+impl std::fmt::Debug for Connector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Connector")
+    }
+}
+
 /// A builder for configuring and constructing a [`Client`] to connect to LND via gRPC.
 ///
 /// This builder allows you to specify connection details, authentication credentials (macaroon),
@@ -91,27 +124,38 @@ pub type UniversalClient = universerpc::universe_client::UniverseClient<Service>
 /// methods to set the desired options, then call [`build`] to create a [`Client`].
 ///
 /// # Example
-/// ```rust
+/// ```rust,no_run
+/// # use voltage_tonic_lnd::ClientBuilder;
+/// # async fn example() -> voltage_tonic_lnd::Result<()> {
 /// let client = ClientBuilder::new()
 ///     .address("https://localhost:10009")
 ///     .macaroon_path("~/.lnd/admin.macaroon")
 ///     .cert_path("~/.lnd/tls.cert")
 ///     .build()
 ///     .await?;
+/// # Ok(())
+/// # }
 /// ```
 ///
 /// You can also use in-memory credentials:
-/// ```rust
+/// ```rust,no_run
+/// # use voltage_tonic_lnd::ClientBuilder;
+/// # async fn example(hex_macaroon_string: &str, pem_cert_string: &str) -> voltage_tonic_lnd::Result<()> {
 /// let client = ClientBuilder::new()
 ///     .address("https://localhost:10009")
 ///     .macaroon_contents(hex_macaroon_string)
 ///     .cert_contents(pem_cert_string)
 ///     .build()
 ///     .await?;
+/// # Ok(())
+/// # }
 /// ```
 #[derive(Debug, Clone)]
 pub struct ClientBuilder {
-    address: Option<String>,
+    addresses: Vec<String>,
+    balance_strategy: Option<BalanceStrategy>,
+    #[cfg(feature = "staterpc")]
+    health_check_interval: Option<Duration>,
     macaroon_path: Option<PathBuf>,
     macaroon_contents: Option<Zeroizing<String>>,
     cert_path: Option<PathBuf>,
@@ -122,6 +166,12 @@ pub struct ClientBuilder {
     http2_keep_alive_interval: Option<Duration>,
     http2_keep_alive_timeout: Option<Duration>,
     http2_keep_alive_while_idle: Option<bool>,
+    connection_pool_size: Option<usize>,
+    dedicated_streaming_connection: Option<bool>,
+    connector: Option<Connector>,
+    services: Option<HashSet<ServiceKind>>,
+    message_options: MessageOptions,
+    service_options: HashMap<ServiceKind, MessageOptions>,
 }
 
 impl Default for ClientBuilder {
@@ -134,7 +184,10 @@ impl ClientBuilder {
     /// Creates a new [`ClientBuilder`] with no fields set.
     pub fn new() -> Self {
         Self {
-            address: None,
+            addresses: Vec::new(),
+            balance_strategy: None,
+            #[cfg(feature = "staterpc")]
+            health_check_interval: None,
             macaroon_path: None,
             macaroon_contents: None,
             cert_path: None,
@@ -145,6 +198,12 @@ impl ClientBuilder {
             http2_keep_alive_interval: None,
             http2_keep_alive_timeout: None,
             http2_keep_alive_while_idle: None,
+            connection_pool_size: None,
+            dedicated_streaming_connection: None,
+            connector: None,
+            services: None,
+            message_options: MessageOptions::new(),
+            service_options: HashMap::new(),
         }
     }
 
@@ -155,7 +214,50 @@ impl ClientBuilder {
     /// # Arguments
     /// * `address` - The gRPC endpoint of the LND node (e.g., "https://localhost:10009").
     pub fn address(mut self, address: impl ToString) -> Self {
-        self.address = Some(address.to_string());
+        self.addresses = vec![address.to_string()];
+        self
+    }
+
+    /// Sets several addresses of the same LND node, for example replicas reachable through
+    /// different networks, in order of preference.
+    ///
+    /// How calls are split between them, and which calls are pinned to one address, is
+    /// described on [`BalanceStrategy`]. The certificate and macaroon are used for every address.
+    /// [`build`](Self::build) succeeds as long as one of the addresses can be connected to; the
+    /// others start out down.
+    ///
+    /// # Arguments
+    /// * `addresses` - The gRPC endpoints of the node; each must begin with "https://".
+    pub fn addresses<I>(mut self, addresses: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.addresses = addresses.into_iter().map(|address| address.to_string()).collect();
+        self
+    }
+
+    /// Sets how calls are distributed over the addresses given to [`addresses`]. Defaults to
+    /// [`BalanceStrategy::Failover`].
+    ///
+    /// # Arguments
+    /// * `strategy` - The strategy to use.
+    pub fn balance_strategy(mut self, strategy: BalanceStrategy) -> Self {
+        self.balance_strategy = Some(strategy);
+        self
+    }
+
+    /// Polls the `State` service of every address at this interval and only sends calls to
+    /// addresses that report `SERVER_ACTIVE`. Without it, an address is only considered down
+    /// after a call to it fails at the transport level.
+    ///
+    /// The interval also bounds how long a single check may take.
+    ///
+    /// # Arguments
+    /// * `interval` - How often to check every address.
+    #[cfg(feature = "staterpc")]
+    pub fn health_check_interval(mut self, interval: Duration) -> Self {
+        self.health_check_interval = Some(interval);
         self
     }
 
@@ -264,81 +366,310 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets the number of HTTP/2 connections opened to the node. Calls are spread over them
+    /// round robin.
+    ///
+    /// All calls on one HTTP/2 connection share its TCP stream and flow-control window, so a
+    /// large response or a busy stream delays everything behind it. More connections reduce
+    /// that head-of-line blocking under load. Defaults to a single connection.
+    ///
+    /// # Arguments
+    /// * `size` - The number of connections. Values below 1 are treated as 1.
+    pub fn connection_pool_size(mut self, size: usize) -> Self {
+        self.connection_pool_size = Some(size.max(1));
+        self
+    }
+
+    /// If `true`, streaming RPCs (such as `SubscribeInvoices`, `SubscribeHtlcEvents` or
+    /// `HtlcInterceptor`) get an HTTP/2 connection of their own, separate from the connections
+    /// used by unary calls.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to open a dedicated streaming connection.
+    pub fn dedicated_streaming_connection(mut self, enabled: bool) -> Self {
+        self.dedicated_streaming_connection = Some(enabled);
+        self
+    }
+
+    /// Sets a custom connector used to open connections instead of TCP.
+    ///
+    /// The connector is called with the URI of each address and must return an I/O stream. This
+    /// allows tunneling over in-memory duplex streams in tests (wrapped in
+    /// `hyper_util::rt::TokioIo`), Unix sockets, SOCKS proxies and similar. TLS is still applied
+    /// on top of the stream for `https://` addresses; use an `http://` address to skip it.
+    ///
+    /// [`connect_timeout`] still applies, while [`tcp_keepalive`] has no effect.
+    ///
+    /// # Arguments
+    /// * `connector` - A [`tower::Service`](TowerService) from [`Uri`] to an I/O stream.
+    pub fn connector<C>(mut self, connector: C) -> Self
+    where
+        C: TowerService<Uri> + Clone + Send + Sync + 'static,
+        C::Response: rt::Read + rt::Write + Send + Unpin,
+        C::Future: Send,
+        StdError: From<C::Error> + Send,
+    {
+        let lazy = connector.clone();
+        self.connector = Some(Connector {
+            connect: Arc::new(move |endpoint: Endpoint| {
+                let connector = connector.clone();
+                Box::pin(async move { endpoint.connect_with_connector(connector).await })
+            }),
+            connect_lazy: Arc::new(move |endpoint: Endpoint| {
+                endpoint.connect_with_connector_lazy(lazy.clone())
+            }),
+        });
+        self
+    }
+
+    /// Limits the client to the given services. Accessors of other services return
+    /// [`Error::ServiceDisabled`].
+    ///
+    /// By default every service compiled into the build is enabled. Restricting them lets one
+    /// binary built with the `all` feature talk to LND-only, tapd-only and litd nodes; see
+    /// [`ServiceKind::LND`] and [`ServiceKind::TAPROOT_ASSETS`].
+    ///
+    /// # Arguments
+    /// * `services` - The services to enable.
+    pub fn services(mut self, services: impl IntoIterator<Item = ServiceKind>) -> Self {
+        self.services = Some(services.into_iter().collect());
+        self
+    }
+
+    /// Returns `true` if `service` is enabled.
+    #[cfg(services)]
+    fn is_enabled(&self, service: ServiceKind) -> bool {
+        self.services.as_ref().map_or(true, |services| services.contains(&service))
+    }
+
+    /// Sets the maximum size of a decoded (received) message for all services, in bytes.
+    ///
+    /// tonic defaults to 4 MiB, which large responses such as `DescribeGraph` on mainnet or
+    /// `ListPayments` with many payments exceed.
+    ///
+    /// # Arguments
+    /// * `limit` - The limit in bytes.
+    pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+        self.message_options = self.message_options.max_decoding_message_size(limit);
+        self
+    }
+
+    /// Sets the maximum size of an encoded (sent) message for all services, in bytes.
+    ///
+    /// # Arguments
+    /// * `limit` - The limit in bytes.
+    pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+        self.message_options = self.message_options.max_encoding_message_size(limit);
+        self
+    }
+
+    /// Compresses requests to all services with the given encoding.
+    ///
+    /// Requires the `gzip` or `zstd` feature.
+    ///
+    /// # Arguments
+    /// * `encoding` - The compression encoding to use.
+    pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+        self.message_options = self.message_options.send_compressed(encoding);
+        self
+    }
+
+    /// Accepts responses from all services compressed with the given encoding.
+    ///
+    /// Requires the `gzip` or `zstd` feature.
+    ///
+    /// # Arguments
+    /// * `encoding` - The compression encoding to accept.
+    pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+        self.message_options = self.message_options.accept_compressed(encoding);
+        self
+    }
+
+    /// Sets message size limits and compression for a single service, overriding the settings
+    /// applied to all services.
+    ///
+    /// # Arguments
+    /// * `service` - The service to configure.
+    /// * `options` - The options for that service.
+    pub fn service_options(mut self, service: ServiceKind, options: MessageOptions) -> Self {
+        self.service_options.insert(service, options);
+        self
+    }
+
+    /// Returns the options for `service`, merged with the options for all services.
+    #[cfg(services)]
+    fn options_for(&self, service: ServiceKind) -> MessageOptions {
+        self.service_options.get(&service).copied().unwrap_or_default().or(self.message_options)
+    }
+
     /// Finalizes the builder and attempts to connect to the LND node, returning a [`Client`].
     ///
     /// # Errors
     /// Returns an error if any required field is missing (such as address or macaroon),
-    /// or if the connection or credential loading fails.
+    /// or if none of the addresses can be connected to or credential loading fails.
     pub async fn build(self) -> Result<Client> {
-        let address = self.address.ok_or(Error::MissingAddress)?;
+        let (macaroon, cert) = self.credentials().await?;
+        let transport = do_connect(cert, &self).await?;
+        self.finish(transport, macaroon)
+    }
+
+    /// Like [`build`](Self::build), but keeps `permit` until the transport of the client is
+    /// dropped, so a [`Fleet`](crate::fleet::Fleet) can budget the connections it holds open.
+    #[cfg(feature = "fleet")]
+    pub(crate) async fn build_holding(
+        self,
+        permit: tokio::sync::OwnedSemaphorePermit,
+    ) -> Result<Client> {
+        let (macaroon, cert) = self.credentials().await?;
+        let transport = do_connect(cert, &self).await?.holding(permit);
+        self.finish(transport, macaroon)
+    }
 
-        let macaroon = if let Some(path) = self.macaroon_path {
+    /// Returns the number of connections [`build`](Self::build) opens: the pool of each address,
+    /// plus its dedicated streaming connection if enabled.
+    #[cfg(feature = "fleet")]
+    pub(crate) fn connections(&self) -> usize {
+        let per_address = self.connection_pool_size.unwrap_or(1)
+            + usize::from(self.dedicated_streaming_connection.unwrap_or(false));
+        self.addresses.len() * per_address
+    }
+
+    /// Starts the health checks of `transport` and creates the service clients on top of it.
+    fn finish(&self, transport: Transport, macaroon: Zeroizing<String>) -> Result<Client> {
+        #[cfg(feature = "staterpc")]
+        if let Some(interval) = self.health_check_interval {
+            transport.spawn_health_check(interval);
+        }
+
+        let uri = Uri::from_str(&self.addresses[0])?;
+        Ok(Client::with_transport(transport, macaroon, uri, self))
+    }
+
+    /// Finalizes the builder for LND's REST API instead of gRPC, returning a
+    /// [`RestClient`](crate::rest::RestClient).
+    ///
+    /// The address is the one of LND's REST listener, `https://<host>:8080` by default. Only the
+    /// first address is used. Of the connection settings, the timeouts and
+    /// [`tcp_keepalive`](Self::tcp_keepalive) apply; proxies are taken from the `HTTPS_PROXY`
+    /// and `ALL_PROXY` environment variables.
+    ///
+    /// Requires the `rest` feature.
+    ///
+    /// # Errors
+    /// Returns an error if any required field is missing (such as address or macaroon), or if
+    /// loading the credentials fails.
+    #[cfg(feature = "rest")]
+    pub async fn build_rest(self) -> Result<crate::rest::RestClient> {
+        let (macaroon, cert) = self.credentials().await?;
+        let mut http = reqwest::Client::builder();
+        if let Some(cert) = cert {
+            http = http.add_root_certificate(reqwest::Certificate::from_pem(&cert)?);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            http = http.connect_timeout(connect_timeout);
+        }
+        if let Some(tcp_keepalive) = self.tcp_keepalive {
+            http = http.tcp_keepalive(tcp_keepalive);
+        }
+        let base = reqwest::Url::parse(&self.addresses[0])
+            .map_err(|error| crate::ParseError::InvalidFormat(error.to_string()))?;
+        Ok(crate::rest::RestClient::new(
+            http.build()?,
+            base,
+            macaroon,
+            self.timeout,
+            self.services.clone(),
+        ))
+    }
+
+    /// Checks that an address is set and loads the macaroon and the certificate, if any.
+    async fn credentials(&self) -> Result<(Zeroizing<String>, Option<Vec<u8>>)> {
+        if self.addresses.is_empty() {
+            return Err(Error::MissingAddress);
+        }
+
+        let macaroon = if let Some(path) = &self.macaroon_path {
             load_macaroon(path).await?
         } else {
-            self.macaroon_contents.ok_or(Error::MissingMacaroon)?
+            self.macaroon_contents.clone().ok_or(Error::MissingMacaroon)?
         };
 
-        let cert = if let Some(path) = self.cert_path {
+        let cert = if let Some(path) = &self.cert_path {
             Some(load_file(path).await?)
         } else {
-            self.cert_contents.map(|contents| contents.as_bytes().to_vec())
+            self.cert_contents.as_ref().map(|contents| contents.as_bytes().to_vec())
         };
-
-        do_connect(
-            address,
-            cert.map(Certificate::from_pem),
-            macaroon,
-            self.timeout,
-            self.connect_timeout,
-            self.tcp_keepalive,
-            self.http2_keep_alive_interval,
-            self.http2_keep_alive_timeout,
-            self.http2_keep_alive_while_idle,
-        )
-        .await
+        Ok((macaroon, cert))
     }
 }
 /// The client returned by `connect` function
 ///
 /// This is a convenience type which you most likely want to use instead of raw client.
+///
+/// All service clients share the same connections, so the accessors such as
+/// [`lightning`](Self::lightning) hand out cheap clones and only need `&self`. A single
+/// `Client`, or an `Arc<Client>`, can therefore serve many concurrent tasks:
+///
+/// ```rust,no_run
+/// # #[cfg(feature = "lightningrpc")]
+/// # async fn example(client: voltage_tonic_lnd::Client) -> voltage_tonic_lnd::Result<()> {
+/// use std::sync::Arc;
+///
+/// use voltage_tonic_lnd::lnrpc::GetInfoRequest;
+///
+/// let client = Arc::new(client);
+/// let mut tasks = Vec::new();
+/// for _ in 0..8 {
+///     let client = client.clone();
+///     tasks.push(tokio::spawn(async move {
+///         let info = client.lightning()?.get_info(GetInfoRequest {}).await?;
+///         Ok::<_, voltage_tonic_lnd::Error>(info.into_inner())
+///     }));
+/// }
+/// for task in tasks {
+///     println!("{:?}", task.await.expect("task panicked")?);
+/// }
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Clone)]
 pub struct Client {
     #[cfg(feature = "lightningrpc")]
-    lightning: LightningClient,
+    lightning: Option<LightningClient>,
     #[cfg(feature = "walletrpc")]
-    wallet: WalletKitClient,
+    wallet: Option<WalletKitClient>,
     #[cfg(feature = "signrpc")]
-    signer: SignerClient,
+    signer: Option<SignerClient>,
     #[cfg(feature = "peersrpc")]
-    peers: PeersClient,
+    peers: Option<PeersClient>,
     #[cfg(feature = "versionrpc")]
-    version: VersionerClient,
+    version: Option<VersionerClient>,
     #[cfg(feature = "routerrpc")]
-    router: RouterClient,
+    router: Option<RouterClient>,
     #[cfg(feature = "invoicesrpc")]
-    invoices: InvoicesClient,
+    invoices: Option<InvoicesClient>,
     #[cfg(feature = "staterpc")]
-    state: StateClient,
+    state: Option<StateClient>,
     #[cfg(feature = "chainrpc")]
-    chain_notifier: ChainNotifierClient,
+    chain_notifier: Option<ChainNotifierClient>,
     #[cfg(feature = "chainrpc")]
-    chain_kit: ChainKitClient,
+    chain_kit: Option<ChainKitClient>,
     #[cfg(feature = "taprpc")]
-    taproot_assets: TaprootAssetsClient,
+    taproot_assets: Option<TaprootAssetsClient>,
     #[cfg(feature = "assetwalletrpc")]
-    asset_wallet: AssetWalletClient,
+    asset_wallet: Option<AssetWalletClient>,
     #[cfg(feature = "mintrpc")]
-    mint: MintClient,
+    mint: Option<MintClient>,
     #[cfg(feature = "priceoraclerpc")]
-    price_oracle: PriceOracleClient,
+    price_oracle: Option<PriceOracleClient>,
     #[cfg(feature = "rfqrpc")]
-    rfq: RfqClient,
+    rfq: Option<RfqClient>,
     #[cfg(feature = "tapchannelrpc")]
-    tap_channel: TapChannelClient,
+    tap_channel: Option<TapChannelClient>,
     #[cfg(feature = "tapdevrpc")]
-    tap_dev: TapDevClient,
+    tap_dev: Option<TapDevClient>,
     #[cfg(feature = "universerpc")]
-    universal: UniversalClient,
+    universal: Option<UniversalClient>,
 }
 
 impl Client {
@@ -347,250 +678,363 @@ impl Client {
         ClientBuilder::new()
     }
 
+    /// Creates a client on top of an existing [`Channel`].
+    ///
+    /// Use this to reuse a channel you already configured, for example one shared with other
+    /// gRPC clients, or built with [`Endpoint::connect_with_connector`]. Settings of
+    /// [`ClientBuilder`] such as timeouts, TLS or message limits are not applied; configure them
+    /// on the channel's [`Endpoint`] instead.
+    ///
+    /// The channel can also be wrapped in [`tower`](https://docs.rs/tower) layers that see every
+    /// call, for example the `Recorder` of the `testing` module.
+    ///
+    /// # Arguments
+    /// * `channel` - The channel to send all calls over, or a service wrapping one.
+    /// * `macaroon` - The macaroon as a hex-encoded string.
+    /// * `origin` - The origin (scheme and authority) set on every request, usually the address
+    ///   of the node.
+    pub fn from_channel<S>(channel: S, macaroon: impl ToString, origin: Uri) -> Self
+    where
+        S: TowerService<http::Request<Body>, Response = http::Response<Body>>
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+        S::Future: Send + 'static,
+        S::Error: Into<StdError>,
+    {
+        Self::with_transport(
+            Transport::from_service(channel),
+            Zeroizing::new(macaroon.to_string()),
+            origin,
+            &ClientBuilder::new(),
+        )
+    }
+
     /// Returns the lightning client.
+    ///
+    /// # Errors
+    /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+    /// [`ClientBuilder::services`].
     #[cfg(feature = "lightningrpc")]
-    pub fn lightning(&mut self) -> &mut LightningClient {
-        &mut self.lightning
+    pub fn lightning(&self) -> Result<LightningClient> {
+        self.lightning.clone().ok_or(Error::ServiceDisabled(ServiceKind::Lightning))
     }
 
     /// Returns a read-only lightning client.
     #[cfg(feature = "lightningrpc")]
-    pub fn lightning_read_only(self) -> LightningClient {
-        self.lightning
+    #[deprecated(since = "0.6.0", note = "Use `Client::lightning` instead, which takes `&self`.")]
+    pub fn lightning_read_only(self) -> Result<LightningClient> {
+        self.lightning.ok_or(Error::ServiceDisabled(ServiceKind::Lightning))
     }
 
     /// Returns the wallet client.
+    ///
+    /// # Errors
+    /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+    /// [`ClientBuilder::services`].
     #[cfg(feature = "walletrpc")]
-    pub fn wallet(&mut self) -> &mut WalletKitClient {
-        &mut self.wallet
+    pub fn wallet(&self) -> Result<WalletKitClient> {
+        self.wallet.clone().ok_or(Error::ServiceDisabled(ServiceKind::WalletKit))
     }
 
     /// Returns a read-only wallet client.
     #[cfg(feature = "walletrpc")]
-    pub fn wallet_read_only(self) -> WalletKitClient {
-        self.wallet
+    #[deprecated(since = "0.6.0", note = "Use `Client::wallet` instead, which takes `&self`.")]
+    pub fn wallet_read_only(self) -> Result<WalletKitClient> {
+        self.wallet.ok_or(Error::ServiceDisabled(ServiceKind::WalletKit))
     }
 
     /// Returns the signer client.
+    ///
+    /// # Errors
+    /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+    /// [`ClientBuilder::services`].
     #[cfg(feature = "signrpc")]
-    pub fn signer(&mut self) -> &mut SignerClient {
-        &mut self.signer
+    pub fn signer(&self) -> Result<SignerClient> {
+        self.signer.clone().ok_or(Error::ServiceDisabled(ServiceKind::Signer))
     }
 
     /// Returns a read-only signer client.
     #[cfg(feature = "signrpc")]
-    pub fn signer_read_only(self) -> SignerClient {
-        self.signer
+    #[deprecated(since = "0.6.0", note = "Use `Client::signer` instead, which takes `&self`.")]
+    pub fn signer_read_only(self) -> Result<SignerClient> {
+        self.signer.ok_or(Error::ServiceDisabled(ServiceKind::Signer))
     }
 
     /// Returns the versioner client.
+    ///
+    /// # Errors
+    /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+    /// [`ClientBuilder::services`].
     #[cfg(feature = "versionrpc")]
-    pub fn versioner(&mut self) -> &mut VersionerClient {
-        &mut self.version
+    pub fn versioner(&self) -> Result<VersionerClient> {
+        self.version.clone().ok_or(Error::ServiceDisabled(ServiceKind::Versioner))
     }
 
     /// Returns a read-only versioner client.
     #[cfg(feature = "versionrpc")]
-    pub fn versioner_read_only(self) -> VersionerClient {
-        self.version
+    #[deprecated(since = "0.6.0", note = "Use `Client::versioner` instead, which takes `&self`.")]
+    pub fn versioner_read_only(self) -> Result<VersionerClient> {
+        self.version.ok_or(Error::ServiceDisabled(ServiceKind::Versioner))
     }
 
     /// Returns the peers client.
+    ///
+    /// # Errors
+    /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+    /// [`ClientBuilder::services`].
     #[cfg(feature = "peersrpc")]
-    pub fn peers(&mut self) -> &mut PeersClient {
-        &mut self.peers
+    pub fn peers(&self) -> Result<PeersClient> {
+        self.peers.clone().ok_or(Error::ServiceDisabled(ServiceKind::Peers))
     }
 
     /// Returns a read-only peers client.
     #[cfg(feature = "peersrpc")]
-    pub fn peers_read_only(self) -> PeersClient {
-        self.peers
+    #[deprecated(since = "0.6.0", note = "Use `Client::peers` instead, which takes `&self`.")]
+    pub fn peers_read_only(self) -> Result<PeersClient> {
+        self.peers.ok_or(Error::ServiceDisabled(ServiceKind::Peers))
     }
 
     /// Returns the router client.
+    ///
+    /// # Errors
+    /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+    /// [`ClientBuilder::services`].
     #[cfg(feature = "routerrpc")]
-    pub fn router(&mut self) -> &mut RouterClient {
-        &mut self.router
+    pub fn router(&self) -> Result<RouterClient> {
+        self.router.clone().ok_or(Error::ServiceDisabled(ServiceKind::Router))
     }
 
     /// Returns a read-only router client.
     #[cfg(feature = "routerrpc")]
-    pub fn router_read_only(self) -> RouterClient {
-        self.router
+    #[deprecated(since = "0.6.0", note = "Use `Client::router` instead, which takes `&self`.")]
+    pub fn router_read_only(self) -> Result<RouterClient> {
+        self.router.ok_or(Error::ServiceDisabled(ServiceKind::Router))
     }
 
     /// Returns the invoices client.
+    ///
+    /// # Errors
+    /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+    /// [`ClientBuilder::services`].
     #[cfg(feature = "invoicesrpc")]
-    pub fn invoices(&mut self) -> &mut InvoicesClient {
-        &mut self.invoices
+    pub fn invoices(&self) -> Result<InvoicesClient> {
+        self.invoices.clone().ok_or(Error::ServiceDisabled(ServiceKind::Invoices))
     }
 
     /// Returns a read-only invoices client.
     #[cfg(feature = "invoicesrpc")]
-    pub fn invoices_read_only(self) -> InvoicesClient {
-        self.invoices
+    #[deprecated(since = "0.6.0", note = "Use `Client::invoices` instead, which takes `&self`.")]
+    pub fn invoices_read_only(self) -> Result<InvoicesClient> {
+        self.invoices.ok_or(Error::ServiceDisabled(ServiceKind::Invoices))
     }
 
     /// Returns the state service client.
+    ///
+    /// # Errors
+    /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+    /// [`ClientBuilder::services`].
     #[cfg(feature = "staterpc")]
-    pub fn state(&mut self) -> &mut StateClient {
-        &mut self.state
+    pub fn state(&self) -> Result<StateClient> {
+        self.state.clone().ok_or(Error::ServiceDisabled(ServiceKind::State))
     }
 
     /// Returns a read-only state service client.
     #[cfg(feature = "staterpc")]
-    pub fn state_read_only(self) -> StateClient {
-        self.state
+    #[deprecated(since = "0.6.0", note = "Use `Client::state` instead, which takes `&self`.")]
+    pub fn state_read_only(self) -> Result<StateClient> {
+        self.state.ok_or(Error::ServiceDisabled(ServiceKind::State))
     }
 
     /// Returns the chain notifier client.
+    ///
+    /// # Errors
+    /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+    /// [`ClientBuilder::services`].
     #[cfg(feature = "chainrpc")]
-    pub fn chain_notifier(&mut self) -> &mut ChainNotifierClient {
-        &mut self.chain_notifier
+    pub fn chain_notifier(&self) -> Result<ChainNotifierClient> {
+        self.chain_notifier.clone().ok_or(Error::ServiceDisabled(ServiceKind::ChainNotifier))
     }
 
     /// Returns a read-only chain notifier client.
     #[cfg(feature = "chainrpc")]
-    pub fn chain_notifier_read_only(self) -> ChainNotifierClient {
-        self.chain_notifier
+    #[deprecated(
+        since = "0.6.0",
+        note = "Use `Client::chain_notifier` instead, which takes `&self`."
+    )]
+    pub fn chain_notifier_read_only(self) -> Result<ChainNotifierClient> {
+        self.chain_notifier.ok_or(Error::ServiceDisabled(ServiceKind::ChainNotifier))
     }
 
     /// Returns the chain kit client.
+    ///
+    /// # Errors
+    /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+    /// [`ClientBuilder::services`].
     #[cfg(feature = "chainrpc")]
-    pub fn chain_kit(&mut self) -> &mut ChainKitClient {
-        &mut self.chain_kit
+    pub fn chain_kit(&self) -> Result<ChainKitClient> {
+        self.chain_kit.clone().ok_or(Error::ServiceDisabled(ServiceKind::ChainKit))
     }
 
     /// Returns a read-only chain kit client.
     #[cfg(feature = "chainrpc")]
-    pub fn chain_kit_read_only(self) -> ChainKitClient {
-        self.chain_kit
+    #[deprecated(since = "0.6.0", note = "Use `Client::chain_kit` instead, which takes `&self`.")]
+    pub fn chain_kit_read_only(self) -> Result<ChainKitClient> {
+        self.chain_kit.ok_or(Error::ServiceDisabled(ServiceKind::ChainKit))
     }
 
     /// Returns the taproot assets client.
+    ///
+    /// # Errors
+    /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+    /// [`ClientBuilder::services`].
     #[cfg(feature = "taprpc")]
-    pub fn taproot_assets(&mut self) -> &mut TaprootAssetsClient {
-        &mut self.taproot_assets
+    pub fn taproot_assets(&self) -> Result<TaprootAssetsClient> {
+        self.taproot_assets.clone().ok_or(Error::ServiceDisabled(ServiceKind::TaprootAssets))
     }
 
     /// Returns a read-only taproot assets client.
     #[cfg(feature = "taprpc")]
-    pub fn taproot_assets_read_only(self) -> TaprootAssetsClient {
-        self.taproot_assets
+    #[deprecated(
+        since = "0.6.0",
+        note = "Use `Client::taproot_assets` instead, which takes `&self`."
+    )]
+    pub fn taproot_assets_read_only(self) -> Result<TaprootAssetsClient> {
+        self.taproot_assets.ok_or(Error::ServiceDisabled(ServiceKind::TaprootAssets))
     }
 
     /// Returns the asset wallet client.
+    ///
+    /// # Errors
+    /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+    /// [`ClientBuilder::services`].
     #[cfg(feature = "assetwalletrpc")]
-    pub fn asset_wallet(&mut self) -> &mut AssetWalletClient {
-        &mut self.asset_wallet
+    pub fn asset_wallet(&self) -> Result<AssetWalletClient> {
+        self.asset_wallet.clone().ok_or(Error::ServiceDisabled(ServiceKind::AssetWallet))
     }
 
     /// Returns a read-only asset wallet client.
     #[cfg(feature = "assetwalletrpc")]
-    pub fn asset_wallet_read_only(self) -> AssetWalletClient {
-        self.asset_wallet
+    #[deprecated(
+        since = "0.6.0",
+        note = "Use `Client::asset_wallet` instead, which takes `&self`."
+    )]
+    pub fn asset_wallet_read_only(self) -> Result<AssetWalletClient> {
+        self.asset_wallet.ok_or(Error::ServiceDisabled(ServiceKind::AssetWallet))
     }
 
     /// Returns the mint client.
+    ///
+    /// # Errors
+    /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+    /// [`ClientBuilder::services`].
     #[cfg(feature = "mintrpc")]
-    pub fn mint(&mut self) -> &mut MintClient {
-        &mut self.mint
+    pub fn mint(&self) -> Result<MintClient> {
+        self.mint.clone().ok_or(Error::ServiceDisabled(ServiceKind::Mint))
     }
 
     /// Returns a read-only mint client.
     #[cfg(feature = "mintrpc")]
-    pub fn mint_read_only(self) -> MintClient {
-        self.mint
+    #[deprecated(since = "0.6.0", note = "Use `Client::mint` instead, which takes `&self`.")]
+    pub fn mint_read_only(self) -> Result<MintClient> {
+        self.mint.ok_or(Error::ServiceDisabled(ServiceKind::Mint))
     }
 
     /// Returns the price oracle client.
+    ///
+    /// # Errors
+    /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+    /// [`ClientBuilder::services`].
     #[cfg(feature = "priceoraclerpc")]
-    pub fn price_oracle(&mut self) -> &mut PriceOracleClient {
-        &mut self.price_oracle
+    pub fn price_oracle(&self) -> Result<PriceOracleClient> {
+        self.price_oracle.clone().ok_or(Error::ServiceDisabled(ServiceKind::PriceOracle))
     }
 
     /// Returns a read-only price oracle client.
     #[cfg(feature = "priceoraclerpc")]
-    pub fn price_oracle_read_only(self) -> PriceOracleClient {
-        self.price_oracle
+    #[deprecated(
+        since = "0.6.0",
+        note = "Use `Client::price_oracle` instead, which takes `&self`."
+    )]
+    pub fn price_oracle_read_only(self) -> Result<PriceOracleClient> {
+        self.price_oracle.ok_or(Error::ServiceDisabled(ServiceKind::PriceOracle))
     }
 
     /// Returns the RFQ client.
+    ///
+    /// # Errors
+    /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+    /// [`ClientBuilder::services`].
     #[cfg(feature = "rfqrpc")]
-    pub fn rfq(&mut self) -> &mut RfqClient {
-        &mut self.rfq
+    pub fn rfq(&self) -> Result<RfqClient> {
+        self.rfq.clone().ok_or(Error::ServiceDisabled(ServiceKind::Rfq))
     }
 
     /// Returns a read-only RFQ client.
     #[cfg(feature = "rfqrpc")]
-    pub fn rfq_read_only(self) -> RfqClient {
-        self.rfq
+    #[deprecated(since = "0.6.0", note = "Use `Client::rfq` instead, which takes `&self`.")]
+    pub fn rfq_read_only(self) -> Result<RfqClient> {
+        self.rfq.ok_or(Error::ServiceDisabled(ServiceKind::Rfq))
     }
 
     /// Returns the tap channel client.
+    ///
+    /// # Errors
+    /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+    /// [`ClientBuilder::services`].
     #[cfg(feature = "tapchannelrpc")]
-    pub fn tap_channel(&mut self) -> &mut TapChannelClient {
-        &mut self.tap_channel
+    pub fn tap_channel(&self) -> Result<TapChannelClient> {
+        self.tap_channel.clone().ok_or(Error::ServiceDisabled(ServiceKind::TapChannel))
     }
 
     /// Returns a read-only tap channel client.
     #[cfg(feature = "tapchannelrpc")]
-    pub fn tap_channel_read_only(self) -> TapChannelClient {
-        self.tap_channel
+    #[deprecated(since = "0.6.0", note = "Use `Client::tap_channel` instead, which takes `&self`.")]
+    pub fn tap_channel_read_only(self) -> Result<TapChannelClient> {
+        self.tap_channel.ok_or(Error::ServiceDisabled(ServiceKind::TapChannel))
     }
 
     /// Returns the tap dev client.
+    ///
+    /// # Errors
+    /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+    /// [`ClientBuilder::services`].
     #[cfg(feature = "tapdevrpc")]
-    pub fn tap_dev(&mut self) -> &mut TapDevClient {
-        &mut self.tap_dev
+    pub fn tap_dev(&self) -> Result<TapDevClient> {
+        self.tap_dev.clone().ok_or(Error::ServiceDisabled(ServiceKind::TapDev))
     }
 
     /// Returns a read-only tap dev client.
     #[cfg(feature = "tapdevrpc")]
-    pub fn tap_dev_read_only(self) -> TapDevClient {
-        self.tap_dev
+    #[deprecated(since = "0.6.0", note = "Use `Client::tap_dev` instead, which takes `&self`.")]
+    pub fn tap_dev_read_only(self) -> Result<TapDevClient> {
+        self.tap_dev.ok_or(Error::ServiceDisabled(ServiceKind::TapDev))
     }
 
     /// Returns the universal client.
+    ///
+    /// # Errors
+    /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+    /// [`ClientBuilder::services`].
     #[cfg(feature = "universerpc")]
-    pub fn universal(&mut self) -> &mut UniversalClient {
-        &mut self.universal
+    pub fn universal(&self) -> Result<UniversalClient> {
+        self.universal.clone().ok_or(Error::ServiceDisabled(ServiceKind::Universe))
     }
 
     /// Returns a read-only universal client.
     #[cfg(feature = "universerpc")]
-    pub fn universal_read_only(self) -> UniversalClient {
-        self.universal
-    }
-}
-
-/// Supplies requests with macaroon
-#[derive(Clone)]
-pub struct MacaroonInterceptor {
-    macaroon: Zeroizing<String>,
-}
-
-impl tonic::service::Interceptor for MacaroonInterceptor {
-    fn call(
-        &mut self,
-        mut request: tonic::Request<()>,
-    ) -> std::result::Result<tonic::Request<()>, tonic::Status> {
-        request.metadata_mut().insert(
-            "macaroon",
-            tonic::metadata::MetadataValue::from_str(&self.macaroon)
-                .expect("hex produced non-ascii"),
-        );
-        Ok(request)
+    #[deprecated(since = "0.6.0", note = "Use `Client::universal` instead, which takes `&self`.")]
+    pub fn universal_read_only(self) -> Result<UniversalClient> {
+        self.universal.ok_or(Error::ServiceDisabled(ServiceKind::Universe))
     }
 }
 
-async fn load_file(path: impl AsRef<Path> + Into<PathBuf>) -> std::io::Result<Vec<u8>> {
+async fn load_file(path: impl AsRef<Path>) -> std::io::Result<Vec<u8>> {
     tokio::fs::read(&path).await
 }
 
-async fn load_macaroon(
-    path: impl AsRef<Path> + Into<PathBuf>,
-) -> std::io::Result<Zeroizing<String>> {
+async fn load_macaroon(path: impl AsRef<Path>) -> std::io::Result<Zeroizing<String>> {
     let macaroon = load_file(path).await?;
 
     Ok(Zeroizing::new(hex::encode(macaroon)))
@@ -650,122 +1094,271 @@ pub async fn connect_from_memory_with_system_certs(
     Client::builder().address(address).macaroon_contents(macaroon).build().await
 }
 
-#[allow(clippy::too_many_arguments)]
-async fn do_connect(
-    address: String,
-    certs: Option<Certificate>,
-    macaroon: Zeroizing<String>,
-    timeout: Option<Duration>,
-    connect_timeout: Option<Duration>,
-    tcp_keepalive: Option<Duration>,
-    http2_keep_alive_interval: Option<Duration>,
-    http2_keep_alive_timeout: Option<Duration>,
-    http2_keep_alive_while_idle: Option<bool>,
-) -> Result<Client> {
-    let mut tls_config = ClientTlsConfig::new().with_enabled_roots();
-    if let Some(cert) = certs {
-        tls_config = tls_config.ca_certificate(cert);
-    }
-
-    let mut endpoint = Endpoint::from_shared(address.clone())?.tls_config(tls_config)?;
-    if let Some(timeout) = timeout {
+/// Creates the endpoint for one address, applying the connection settings of `builder`.
+fn endpoint(address: &str, builder: &ClientBuilder) -> Result<Endpoint> {
+    let mut endpoint = Endpoint::from_shared(address.to_owned())?;
+    if let Some(timeout) = builder.timeout {
         endpoint = endpoint.timeout(timeout);
     }
-    if let Some(connect_timeout) = connect_timeout {
+    if let Some(connect_timeout) = builder.connect_timeout {
         endpoint = endpoint.connect_timeout(connect_timeout);
     }
-    if let Some(tcp_keepalive) = tcp_keepalive {
+    if let Some(tcp_keepalive) = builder.tcp_keepalive {
         endpoint = endpoint.tcp_keepalive(Some(tcp_keepalive));
     }
-    if let Some(interval) = http2_keep_alive_interval {
+    if let Some(interval) = builder.http2_keep_alive_interval {
         endpoint = endpoint.http2_keep_alive_interval(interval);
     }
-    if let Some(timeout) = http2_keep_alive_timeout {
+    if let Some(timeout) = builder.http2_keep_alive_timeout {
         endpoint = endpoint.keep_alive_timeout(timeout);
     }
-    if let Some(enabled) = http2_keep_alive_while_idle {
+    if let Some(enabled) = builder.http2_keep_alive_while_idle {
         endpoint = endpoint.keep_alive_while_idle(enabled);
     }
+    Ok(endpoint)
+}
 
-    let channel = endpoint.connect().await?;
-    let channel = InterceptedService::new(
-        channel,
-        MacaroonInterceptor {
-            macaroon,
-        },
-    );
-
-    let uri = Uri::from_str(address.as_str())?;
-
-    let client = Client {
-        #[cfg(feature = "lightningrpc")]
-        lightning: lnrpc::lightning_client::LightningClient::with_origin(
-            channel.clone(),
-            uri.clone(),
-        ),
-        #[cfg(feature = "walletrpc")]
-        wallet: walletrpc::wallet_kit_client::WalletKitClient::with_origin(
-            channel.clone(),
-            uri.clone(),
-        ),
-        #[cfg(feature = "peersrpc")]
-        peers: peersrpc::peers_client::PeersClient::with_origin(channel.clone(), uri.clone()),
-        #[cfg(feature = "signrpc")]
-        signer: signrpc::signer_client::SignerClient::with_origin(channel.clone(), uri.clone()),
-        #[cfg(feature = "versionrpc")]
-        version: verrpc::versioner_client::VersionerClient::with_origin(
-            channel.clone(),
-            uri.clone(),
-        ),
-        #[cfg(feature = "routerrpc")]
-        router: routerrpc::router_client::RouterClient::with_origin(channel.clone(), uri.clone()),
-        #[cfg(feature = "invoicesrpc")]
-        invoices: invoicesrpc::invoices_client::InvoicesClient::with_origin(
-            channel.clone(),
-            uri.clone(),
-        ),
-        #[cfg(feature = "staterpc")]
-        state: lnrpc::state_client::StateClient::with_origin(channel.clone(), uri.clone()),
-        #[cfg(feature = "chainrpc")]
-        chain_notifier: chainrpc::chain_notifier_client::ChainNotifierClient::with_origin(
-            channel.clone(),
-            uri.clone(),
-        ),
-        #[cfg(feature = "chainrpc")]
-        chain_kit: chainrpc::chain_kit_client::ChainKitClient::with_origin(
-            channel.clone(),
-            uri.clone(),
-        ),
-        #[cfg(feature = "taprpc")]
-        taproot_assets: taprpc::taproot_assets_client::TaprootAssetsClient::with_origin(
-            channel.clone(),
-            uri.clone(),
-        ),
-        #[cfg(feature = "assetwalletrpc")]
-        asset_wallet: assetwalletrpc::asset_wallet_client::AssetWalletClient::with_origin(
-            channel.clone(),
-            uri.clone(),
-        ),
-        #[cfg(feature = "mintrpc")]
-        mint: mintrpc::mint_client::MintClient::with_origin(channel.clone(), uri.clone()),
-        #[cfg(feature = "priceoraclerpc")]
-        price_oracle: priceoraclerpc::price_oracle_client::PriceOracleClient::with_origin(
-            channel.clone(),
-            uri.clone(),
-        ),
-        #[cfg(feature = "rfqrpc")]
-        rfq: rfqrpc::rfq_client::RfqClient::with_origin(channel.clone(), uri.clone()),
-        #[cfg(feature = "tapchannelrpc")]
-        tap_channel:
-            tapchannelrpc::taproot_asset_channels_client::TaprootAssetChannelsClient::with_origin(
-                channel.clone(),
-                uri.clone(),
-            ),
-        #[cfg(feature = "tapdevrpc")]
-        tap_dev: tapdevrpc::tap_dev_client::TapDevClient::with_origin(channel.clone(), uri.clone()),
-        #[cfg(feature = "universerpc")]
-        universal: universerpc::universe_client::UniverseClient::with_origin(channel, uri),
+/// Opens the connections to every address of `builder`.
+async fn do_connect(cert: Option<Vec<u8>>, builder: &ClientBuilder) -> Result<Transport> {
+    #[cfg(feature = "_tls-any")]
+    let tls_config = {
+        let mut tls_config = ClientTlsConfig::new().with_enabled_roots();
+        if let Some(cert) = cert {
+            tls_config = tls_config.ca_certificate(Certificate::from_pem(cert));
+        }
+        tls_config
     };
+    // Without a TLS backend only plaintext endpoints and custom connectors can be used.
+    #[cfg(not(feature = "_tls-any"))]
+    if cert.is_some() {
+        return Err(Error::TlsDisabled);
+    }
+
+    // An address that can't be reached is marked down rather than failing the build, unless none
+    // can be, and its connections are opened on first use instead.
+    let count = builder.connection_pool_size.unwrap_or(1);
+    let dedicated = builder.dedicated_streaming_connection.unwrap_or(false);
+    let mut nodes = Vec::with_capacity(builder.addresses.len());
+    let mut reachable = false;
+    let mut first_error = None;
+    for address in &builder.addresses {
+        let endpoint = endpoint(address, builder)?;
+        #[cfg(feature = "_tls-any")]
+        let endpoint = endpoint.tls_config(tls_config.clone())?;
+        let connect = || async {
+            match &builder.connector {
+                Some(connector) => (connector.connect)(endpoint.clone()).await,
+                None => endpoint.connect().await,
+            }
+        };
+        let connect_lazy = || match &builder.connector {
+            Some(connector) => (connector.connect_lazy)(endpoint.clone()),
+            None => endpoint.connect_lazy(),
+        };
+        let mut channels = Vec::with_capacity(count + usize::from(dedicated));
+        let mut down = false;
+        for _ in 0..count + usize::from(dedicated) {
+            if !down {
+                match connect().await {
+                    Ok(channel) => {
+                        channels.push(channel);
+                        continue;
+                    }
+                    Err(error) => {
+                        down = true;
+                        first_error.get_or_insert(error);
+                    }
+                }
+            }
+            channels.push(connect_lazy());
+        }
+        let streaming = if dedicated {
+            channels.pop()
+        } else {
+            None
+        };
+        let node = Node::new(channels, streaming);
+        if down {
+            node.mark_down();
+        } else {
+            reachable = true;
+        }
+        nodes.push(node);
+    }
+    if let (false, Some(error)) = (reachable, first_error) {
+        return Err(error.into());
+    }
+
+    Ok(Transport::new(nodes, builder.balance_strategy.unwrap_or_default()))
+}
 
-    Ok(client)
+impl Client {
+    /// Creates the service clients on top of `transport`, applying the message options of
+    /// `builder`.
+    #[cfg_attr(not(services), allow(unused_variables))]
+    fn with_transport(
+        transport: Transport,
+        macaroon: Zeroizing<String>,
+        uri: Uri,
+        builder: &ClientBuilder,
+    ) -> Self {
+        #[cfg(services)]
+        let channel = InterceptedService::new(transport, MacaroonInterceptor::new(macaroon));
+
+        Client {
+            #[cfg(feature = "lightningrpc")]
+            lightning: builder.is_enabled(ServiceKind::Lightning).then(|| {
+                configure_client!(
+                    lnrpc::lightning_client::LightningClient::with_origin(channel.clone(), uri.clone()),
+                    builder.options_for(ServiceKind::Lightning)
+                )
+            }),
+            #[cfg(feature = "walletrpc")]
+            wallet: builder.is_enabled(ServiceKind::WalletKit).then(|| {
+                configure_client!(
+                    walletrpc::wallet_kit_client::WalletKitClient::with_origin(
+                        channel.clone(),
+                        uri.clone(),
+                    ),
+                    builder.options_for(ServiceKind::WalletKit)
+                )
+            }),
+            #[cfg(feature = "peersrpc")]
+            peers: builder.is_enabled(ServiceKind::Peers).then(|| {
+                configure_client!(
+                    peersrpc::peers_client::PeersClient::with_origin(channel.clone(), uri.clone()),
+                    builder.options_for(ServiceKind::Peers)
+                )
+            }),
+            #[cfg(feature = "signrpc")]
+            signer: builder.is_enabled(ServiceKind::Signer).then(|| {
+                configure_client!(
+                    signrpc::signer_client::SignerClient::with_origin(channel.clone(), uri.clone()),
+                    builder.options_for(ServiceKind::Signer)
+                )
+            }),
+            #[cfg(feature = "versionrpc")]
+            version: builder.is_enabled(ServiceKind::Versioner).then(|| {
+                configure_client!(
+                    verrpc::versioner_client::VersionerClient::with_origin(
+                        channel.clone(),
+                        uri.clone()
+                    ),
+                    builder.options_for(ServiceKind::Versioner)
+                )
+            }),
+            #[cfg(feature = "routerrpc")]
+            router: builder.is_enabled(ServiceKind::Router).then(|| {
+                configure_client!(
+                    routerrpc::router_client::RouterClient::with_origin(channel.clone(), uri.clone()),
+                    builder.options_for(ServiceKind::Router)
+                )
+            }),
+            #[cfg(feature = "invoicesrpc")]
+            invoices: builder.is_enabled(ServiceKind::Invoices).then(|| {
+                configure_client!(
+                    invoicesrpc::invoices_client::InvoicesClient::with_origin(
+                        channel.clone(),
+                        uri.clone(),
+                    ),
+                    builder.options_for(ServiceKind::Invoices)
+                )
+            }),
+            #[cfg(feature = "staterpc")]
+            state: builder.is_enabled(ServiceKind::State).then(|| {
+                configure_client!(
+                    lnrpc::state_client::StateClient::with_origin(channel.clone(), uri.clone()),
+                    builder.options_for(ServiceKind::State)
+                )
+            }),
+            #[cfg(feature = "chainrpc")]
+            chain_notifier: builder.is_enabled(ServiceKind::ChainNotifier).then(|| {
+                configure_client!(
+                    chainrpc::chain_notifier_client::ChainNotifierClient::with_origin(
+                        channel.clone(),
+                        uri.clone(),
+                    ),
+                    builder.options_for(ServiceKind::ChainNotifier)
+                )
+            }),
+            #[cfg(feature = "chainrpc")]
+            chain_kit: builder.is_enabled(ServiceKind::ChainKit).then(|| {
+                configure_client!(
+                    chainrpc::chain_kit_client::ChainKitClient::with_origin(
+                        channel.clone(),
+                        uri.clone()
+                    ),
+                    builder.options_for(ServiceKind::ChainKit)
+                )
+            }),
+            #[cfg(feature = "taprpc")]
+            taproot_assets: builder.is_enabled(ServiceKind::TaprootAssets).then(|| {
+                configure_client!(
+                    taprpc::taproot_assets_client::TaprootAssetsClient::with_origin(
+                        channel.clone(),
+                        uri.clone(),
+                    ),
+                    builder.options_for(ServiceKind::TaprootAssets)
+                )
+            }),
+            #[cfg(feature = "assetwalletrpc")]
+            asset_wallet: builder.is_enabled(ServiceKind::AssetWallet).then(|| {
+                configure_client!(
+                    assetwalletrpc::asset_wallet_client::AssetWalletClient::with_origin(
+                        channel.clone(),
+                        uri.clone(),
+                    ),
+                    builder.options_for(ServiceKind::AssetWallet)
+                )
+            }),
+            #[cfg(feature = "mintrpc")]
+            mint: builder.is_enabled(ServiceKind::Mint).then(|| {
+                configure_client!(
+                    mintrpc::mint_client::MintClient::with_origin(channel.clone(), uri.clone()),
+                    builder.options_for(ServiceKind::Mint)
+                )
+            }),
+            #[cfg(feature = "priceoraclerpc")]
+            price_oracle: builder.is_enabled(ServiceKind::PriceOracle).then(|| {
+                configure_client!(
+                    priceoraclerpc::price_oracle_client::PriceOracleClient::with_origin(
+                        channel.clone(),
+                        uri.clone(),
+                    ),
+                    builder.options_for(ServiceKind::PriceOracle)
+                )
+            }),
+            #[cfg(feature = "rfqrpc")]
+            rfq: builder.is_enabled(ServiceKind::Rfq).then(|| {
+                configure_client!(
+                    rfqrpc::rfq_client::RfqClient::with_origin(channel.clone(), uri.clone()),
+                    builder.options_for(ServiceKind::Rfq)
+                )
+            }),
+            #[cfg(feature = "tapchannelrpc")]
+            tap_channel: builder.is_enabled(ServiceKind::TapChannel).then(|| {
+                configure_client!(
+                    tapchannelrpc::taproot_asset_channels_client::TaprootAssetChannelsClient::with_origin(
+                        channel.clone(),
+                        uri.clone(),
+                    ),
+                    builder.options_for(ServiceKind::TapChannel)
+                )
+            }),
+            #[cfg(feature = "tapdevrpc")]
+            tap_dev: builder.is_enabled(ServiceKind::TapDev).then(|| {
+                configure_client!(
+                    tapdevrpc::tap_dev_client::TapDevClient::with_origin(channel.clone(), uri.clone()),
+                    builder.options_for(ServiceKind::TapDev)
+                )
+            }),
+            #[cfg(feature = "universerpc")]
+            universal: builder.is_enabled(ServiceKind::Universe).then(|| {
+                configure_client!(
+                    universerpc::universe_client::UniverseClient::with_origin(channel.clone(), uri.clone()),
+                    builder.options_for(ServiceKind::Universe)
+                )
+            }),
+        }
+    }
 }
@@ -1,15 +1,80 @@
+use crate::service::ServiceKind;
+
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("Filesystem error: {0}")]
     Filesystem(#[from] std::io::Error),
+    #[cfg(feature = "transport")]
     #[error("Tonic error: {0}")]
     Tonic(#[from] tonic::transport::Error),
+    #[cfg(feature = "transport")]
+    #[error("TLS is disabled, enable the ring or aws-lc feature")]
+    TlsDisabled,
     #[error("Invalid address: {0}")]
     InvalidAddress(#[from] http::uri::InvalidUri),
     #[error("Missing address")]
     MissingAddress,
     #[error("Missing macaroon")]
     MissingMacaroon,
+    #[error("RPC error: {0}")]
+    Status(#[from] tonic::Status),
+    #[error("Service disabled: {0}")]
+    ServiceDisabled(ServiceKind),
+    #[error("Unknown node: {0}")]
+    UnknownNode(String),
+    #[error("Parse error: {0}")]
+    Parse(#[from] ParseError),
+    #[cfg(feature = "bolt11")]
+    #[error("Invoice error: {0}")]
+    Invoice(#[from] InvoiceError),
+    #[cfg(feature = "rest")]
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+    #[cfg(feature = "blocking")]
+    #[error("Runtime error: {0}")]
+    Runtime(std::io::Error),
+    #[cfg(feature = "testing")]
+    #[error("Certificate error: {0}")]
+    Certificate(#[from] rcgen::Error),
+}
+
+/// An error parsing or validating one of the [`types`](crate::types).
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError {
+    #[error("Invalid length: expected {expected} bytes, got {actual}")]
+    InvalidLength {
+        expected: usize,
+        actual: usize,
+    },
+    #[error("Invalid hex: {0}")]
+    InvalidHex(#[from] hex::FromHexError),
+    #[error("Invalid format: {0}")]
+    InvalidFormat(String),
+    #[error("Out of range: {0}")]
+    OutOfRange(String),
+}
+
+/// An error decoding or validating a BOLT11 [`Invoice`](crate::bolt11::Invoice).
+#[cfg(feature = "bolt11")]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum InvoiceError {
+    #[error("Invalid invoice: {0}")]
+    Invalid(String),
+    #[error("Invalid invoice: {0}")]
+    Parse(#[from] ParseError),
+    #[error("Invalid invoice signature")]
+    InvalidSignature,
+    #[error("Invoice is for {actual}, expected {expected}")]
+    WrongNetwork {
+        expected: crate::bolt11::Network,
+        actual: crate::bolt11::Network,
+    },
+    #[error("Invoice expired")]
+    Expired,
+    #[error("Invoice has no amount and none was given")]
+    MissingAmount,
+    #[error("Invoice has an amount, another one can't be given")]
+    UnexpectedAmount,
 }
@@ -0,0 +1,382 @@
+//! Managing clients for many LND nodes at once.
+//!
+//! A [`Fleet`] holds one [`Client`] per node, keyed by a name of your choosing (for example the
+//! node pubkey). Clients are built lazily from a [`CredentialSource`] the first time a node is
+//! used, dead ones are evicted by [`Fleet::evict_unhealthy`], and the number of clients the fleet
+//! keeps can be capped with [`FleetBuilder::max_clients`]. To cap the connections open across all
+//! nodes, including those of clients callers still hold, use [`FleetBuilder::max_connections`].
+//!
+//! # Example
+//! ```rust,no_run
+//! use std::collections::HashMap;
+//!
+//! use voltage_tonic_lnd::fleet::Fleet;
+//! use voltage_tonic_lnd::lnrpc::GetInfoRequest;
+//! use voltage_tonic_lnd::Client;
+//!
+//! # async fn example() -> voltage_tonic_lnd::Result<()> {
+//! let mut nodes = HashMap::new();
+//! nodes.insert(
+//!     "alice".to_owned(),
+//!     Client::builder()
+//!         .address("https://alice.internal:10009")
+//!         .macaroon_path("/creds/alice/admin.macaroon"),
+//! );
+//! nodes.insert(
+//!     "bob".to_owned(),
+//!     Client::builder().address("https://bob.internal:10009").macaroon_path("/creds/bob/admin.macaroon"),
+//! );
+//!
+//! let fleet = Fleet::builder(nodes).max_clients(100).max_connections(200).build();
+//! let heights = fleet
+//!     .fan_out(|_node, client| async move {
+//!         let info = client.lightning()?.get_info(GetInfoRequest {}).await?;
+//!         Ok(info.into_inner().block_height)
+//!     })
+//!     .await?;
+//! for (node, height) in heights {
+//!     println!("{node}: {height:?}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::pin;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::sync::{Notify, OwnedSemaphorePermit, Semaphore};
+use tokio::task::JoinSet;
+
+use crate::error::{Error, Result};
+use crate::lnrpc::{GetStateRequest, WalletState};
+use crate::{Client, ClientBuilder};
+
+/// Provides the connection settings and credentials for the nodes of a [`Fleet`].
+pub trait CredentialSource: Send + Sync + 'static {
+    /// Returns the names of all nodes this source knows about. Used by [`Fleet::fan_out`].
+    fn nodes(&self) -> impl Future<Output = Result<Vec<String>>> + Send;
+
+    /// Returns a builder configured to connect to `node`.
+    ///
+    /// Returns [`Error::UnknownNode`] if the source has no credentials for `node`.
+    fn client_builder(&self, node: &str) -> impl Future<Output = Result<ClientBuilder>> + Send;
+}
+
+/// A fixed set of nodes and their builders.
+impl CredentialSource for HashMap<String, ClientBuilder> {
+    async fn nodes(&self) -> Result<Vec<String>> {
+        Ok(self.keys().cloned().collect())
+    }
+
+    async fn client_builder(&self, node: &str) -> Result<ClientBuilder> {
+        self.get(node).cloned().ok_or_else(|| Error::UnknownNode(node.to_owned()))
+    }
+}
+
+/// A builder for configuring and constructing a [`Fleet`].
+#[derive(Debug)]
+pub struct FleetBuilder<S> {
+    source: S,
+    max_clients: Option<usize>,
+    max_connections: Option<usize>,
+    health_check_timeout: Option<Duration>,
+}
+
+impl<S: CredentialSource> FleetBuilder<S> {
+    /// Caps the number of clients the fleet keeps. When a new client is needed and the fleet is
+    /// full, the least recently used client is dropped. [`Fleet::fan_out`] and
+    /// [`Fleet::fan_out_to`] also run at most this many calls at once, across all fan-outs of the
+    /// fleet.
+    ///
+    /// Clients handed out before they are evicted stay usable, and keep their connections open,
+    /// until they are dropped, and a client opens one or more connections per address (see
+    /// [`ClientBuilder::connection_pool_size`]). So this does not cap the connections open; see
+    /// [`max_connections`](Self::max_connections) for that.
+    ///
+    /// # Arguments
+    /// * `max` - The maximum number of clients. Values below 1 are treated as 1.
+    pub fn max_clients(mut self, max: usize) -> Self {
+        self.max_clients = Some(max.max(1));
+        self
+    }
+
+    /// Caps the number of connections open at once across all clients of the fleet, including
+    /// the clients that were evicted but are still held by callers.
+    ///
+    /// A client counts the connections it opens: its
+    /// [`connection_pool_size`](ClientBuilder::connection_pool_size), plus one if
+    /// [`dedicated_streaming_connection`](ClientBuilder::dedicated_streaming_connection) is
+    /// enabled, for each of its addresses. A client needing more than `max` connections counts
+    /// as `max`. When a new client doesn't fit, the least recently used clients are dropped from
+    /// the fleet until it does; if the connections are held by callers, [`Fleet::client`] waits
+    /// until enough of those clients are dropped. A task that holds a client while waiting for
+    /// another one can therefore wait forever once the budget is used up.
+    ///
+    /// # Arguments
+    /// * `max` - The maximum number of connections. Values below 1 are treated as 1.
+    pub fn max_connections(mut self, max: usize) -> Self {
+        self.max_connections = Some(max.max(1));
+        self
+    }
+
+    /// Sets how long [`Fleet::evict_unhealthy`] waits for a node to answer. Defaults to
+    /// 10 seconds.
+    ///
+    /// # Arguments
+    /// * `timeout` - The timeout for a single health check.
+    pub fn health_check_timeout(mut self, timeout: Duration) -> Self {
+        self.health_check_timeout = Some(timeout);
+        self
+    }
+
+    /// Creates the fleet. No connections are made until a node is used.
+    pub fn build(self) -> Fleet<S> {
+        Fleet {
+            inner: Arc::new(Inner {
+                source: self.source,
+                max_clients: self.max_clients,
+                connections: self.max_connections.map(|max| {
+                    let max = u32::try_from(max).unwrap_or(u32::MAX);
+                    (Arc::new(Semaphore::new(max as usize)), max)
+                }),
+                calls: Semaphore::new(self.max_clients.unwrap_or(Semaphore::MAX_PERMITS)),
+                health_check_timeout: self.health_check_timeout.unwrap_or(Duration::from_secs(10)),
+                clients: Mutex::new(HashMap::new()),
+                inserted: Notify::new(),
+            }),
+        }
+    }
+}
+
+/// A set of [`Client`]s for many LND nodes, built lazily from a [`CredentialSource`].
+///
+/// Cloning is cheap: clones share the same clients.
+pub struct Fleet<S> {
+    inner: Arc<Inner<S>>,
+}
+
+impl<S> Clone for Fleet<S> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+struct Inner<S> {
+    source: S,
+    max_clients: Option<usize>,
+    /// The connection budget and its size.
+    connections: Option<(Arc<Semaphore>, u32)>,
+    /// Limits the calls run by fan-outs at once.
+    calls: Semaphore,
+    health_check_timeout: Duration,
+    clients: Mutex<HashMap<String, Entry>>,
+    /// Notified whenever a client is added to `clients`.
+    inserted: Notify,
+}
+
+struct Entry {
+    client: Client,
+    last_used: Instant,
+}
+
+impl<S: CredentialSource> Fleet<S> {
+    /// Returns a builder for a fleet that takes node credentials from `source`.
+    pub fn builder(source: S) -> FleetBuilder<S> {
+        FleetBuilder {
+            source,
+            max_clients: None,
+            max_connections: None,
+            health_check_timeout: None,
+        }
+    }
+
+    /// Returns the client for `node`, connecting to it first if needed.
+    ///
+    /// With [`FleetBuilder::max_connections`], this waits until the connections of the new
+    /// client fit in the budget.
+    ///
+    /// # Errors
+    /// Returns an error if the credential source does not know `node` or the connection fails.
+    pub async fn client(&self, node: &str) -> Result<Client> {
+        if let Some(entry) = self.inner.clients.lock().expect("poisoned").get_mut(node) {
+            entry.last_used = Instant::now();
+            return Ok(entry.client.clone());
+        }
+
+        // Connect without holding the lock so other nodes aren't blocked on this one.
+        let builder = self.inner.source.client_builder(node).await?;
+        let client = match &self.inner.connections {
+            Some((budget, max)) => {
+                let count = u32::try_from(builder.connections()).unwrap_or(u32::MAX).clamp(1, *max);
+                let permit = self.reserve(budget, count).await;
+                builder.build_holding(permit).await?
+            }
+            None => builder.build().await?,
+        };
+
+        let mut clients = self.inner.clients.lock().expect("poisoned");
+        if let Some(entry) = clients.get_mut(node) {
+            // Another task connected to the same node in the meantime; keep its client.
+            entry.last_used = Instant::now();
+            return Ok(entry.client.clone());
+        }
+        if let Some(max) = self.inner.max_clients {
+            while clients.len() >= max && evict_oldest(&mut clients) {}
+        }
+        clients.insert(
+            node.to_owned(),
+            Entry {
+                client: client.clone(),
+                last_used: Instant::now(),
+            },
+        );
+        drop(clients);
+        self.inner.inserted.notify_waiters();
+        Ok(client)
+    }
+
+    /// Takes `count` connections from `budget`, dropping the least recently used clients of the
+    /// fleet until they are available.
+    async fn reserve(&self, budget: &Arc<Semaphore>, count: u32) -> OwnedSemaphorePermit {
+        loop {
+            // Listen for new clients before looking, so one added in between isn't missed.
+            let mut inserted = pin!(self.inner.inserted.notified());
+            inserted.as_mut().enable();
+
+            if let Ok(permit) = budget.clone().try_acquire_many_owned(count) {
+                return permit;
+            }
+            if evict_oldest(&mut self.inner.clients.lock().expect("poisoned")) {
+                continue;
+            }
+            // The connections belong to clients held by callers. Wait for those to be dropped, or
+            // for a client to be added to the fleet, which can then be dropped in turn.
+            tokio::select! {
+                permit = budget.clone().acquire_many_owned(count) => {
+                    return permit.expect("semaphore closed");
+                }
+                () = inserted => {}
+            }
+        }
+    }
+
+    /// Returns the names of the nodes that currently have a client.
+    pub fn connected_nodes(&self) -> Vec<String> {
+        self.inner.clients.lock().expect("poisoned").keys().cloned().collect()
+    }
+
+    /// Drops the client for `node`, if any. The next use of `node` connects again.
+    pub fn evict(&self, node: &str) {
+        self.inner.clients.lock().expect("poisoned").remove(node);
+    }
+
+    /// Checks every connected node through the `State` service and evicts the ones that don't
+    /// answer in time or aren't fully started (`SERVER_ACTIVE`).
+    ///
+    /// Nodes whose client has the `State` service disabled (see
+    /// [`ClientBuilder::services`]) are not checked and never evicted.
+    ///
+    /// Returns the names of the evicted nodes.
+    pub async fn evict_unhealthy(&self) -> Vec<String> {
+        let clients: Vec<_> = self
+            .inner
+            .clients
+            .lock()
+            .expect("poisoned")
+            .iter()
+            .map(|(name, entry)| (name.clone(), entry.client.clone()))
+            .collect();
+
+        let timeout = self.inner.health_check_timeout;
+        let mut checks = JoinSet::new();
+        for (name, client) in clients {
+            let Ok(mut state) = client.state() else {
+                continue;
+            };
+            checks.spawn(async move {
+                let response =
+                    tokio::time::timeout(timeout, state.get_state(GetStateRequest {})).await;
+                let healthy = matches!(
+                    response,
+                    Ok(Ok(response)) if response.get_ref().state() == WalletState::ServerActive
+                );
+                (name, healthy)
+            });
+        }
+
+        let mut evicted = Vec::new();
+        while let Some(result) = checks.join_next().await {
+            let (name, healthy) = result.expect("health check panicked");
+            if !healthy {
+                self.evict(&name);
+                evicted.push(name);
+            }
+        }
+        evicted
+    }
+
+    /// Runs `call` on every node of the credential source concurrently and collects the result
+    /// for each node. Clients are connected as needed; a node that fails to connect gets the
+    /// connection error as its result.
+    ///
+    /// # Errors
+    /// Returns an error only if the credential source fails to list its nodes.
+    pub async fn fan_out<F, Fut, T>(&self, call: F) -> Result<HashMap<String, Result<T>>>
+    where
+        F: Fn(String, Client) -> Fut + Clone + Send + 'static,
+        Fut: Future<Output = Result<T>> + Send + 'static,
+        T: Send + 'static,
+    {
+        let nodes = self.inner.source.nodes().await?;
+        Ok(self.fan_out_to(nodes, call).await)
+    }
+
+    /// Runs `call` on each of the given nodes concurrently and collects the result for each
+    /// node. See [`fan_out`](Self::fan_out).
+    pub async fn fan_out_to<I, F, Fut, T>(&self, nodes: I, call: F) -> HashMap<String, Result<T>>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+        F: Fn(String, Client) -> Fut + Clone + Send + 'static,
+        Fut: Future<Output = Result<T>> + Send + 'static,
+        T: Send + 'static,
+    {
+        let mut calls = JoinSet::new();
+        for node in nodes {
+            let node = node.into();
+            let fleet = self.clone();
+            let call = call.clone();
+            calls.spawn(async move {
+                let _permit = fleet.inner.calls.acquire().await.expect("semaphore closed");
+                let result = match fleet.client(&node).await {
+                    Ok(client) => call(node.clone(), client).await,
+                    Err(error) => Err(error),
+                };
+                (node, result)
+            });
+        }
+
+        let mut results = HashMap::new();
+        while let Some(result) = calls.join_next().await {
+            let (node, result) = result.expect("fan-out call panicked");
+            results.insert(node, result);
+        }
+        results
+    }
+}
+
+/// Drops the least recently used client. Returns `false` if there was none.
+fn evict_oldest(clients: &mut HashMap<String, Entry>) -> bool {
+    let Some(oldest) =
+        clients.iter().min_by_key(|(_, entry)| entry.last_used).map(|(name, _)| name.clone())
+    else {
+        return false;
+    };
+    clients.remove(&oldest);
+    true
+}
@@ -0,0 +1,2134 @@
+// This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundVirtualPsbtRequest {
+    /// Specify the type of coins that should be selected. Defaults to allowing both
+    /// script trees and BIP-086 compliant inputs.
+    #[prost(enumeration = "CoinSelectType", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::assetwalletrpc::CoinSelectType>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::assetwalletrpc::CoinSelectType>, _, _>"
+        )
+    )]
+    pub coin_select_type: i32,
+    #[prost(oneof = "fund_virtual_psbt_request::Template", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub template: ::core::option::Option<fund_virtual_psbt_request::Template>,
+}
+/// Nested message and enum types in `FundVirtualPsbtRequest`.
+pub mod fund_virtual_psbt_request {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Template {
+        /// Use an existing PSBT packet as the template for the funded PSBT.
+        #[prost(bytes, tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                rename = "psbt",
+                serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+                deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+            )
+        )]
+        Psbt(::prost::alloc::vec::Vec<u8>),
+        /// Use the asset outputs and optional asset inputs from this raw template.
+        #[prost(message, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "raw"))]
+        Raw(super::TxTemplate),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FundVirtualPsbtResponse {
+    /// The funded but not yet signed virtual PSBT packet.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub funded_psbt: ::prost::alloc::vec::Vec<u8>,
+    /// The index of the added change output or -1 if no change was left over.
+    #[prost(int32, tag = "2")]
+    pub change_output_index: i32,
+    /// The list of passive virtual transactions that are anchored in the same BTC
+    /// level anchor transaction inputs as the funded "active" asset above. These
+    /// assets can be ignored when using the AnchorVirtualPsbts RPC, since they are
+    /// retrieved, signed and committed automatically in that method. But the
+    /// passive assets have to be included in the CommitVirtualPsbts RPC which is
+    /// used when custom BTC level anchor transactions are created.
+    /// The main difference to the "active" asset above is that the passive assets
+    /// will not get their own entry in the transfer table of the database, since
+    /// they are just carried along and not directly affected by the direct user
+    /// action.
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub passive_asset_psbts: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TxTemplate {
+    /// An optional list of inputs to use. Every input must be an asset UTXO known
+    /// to the wallet. The sum of all inputs must be greater than or equal to the
+    /// sum of all outputs.
+    ///
+    /// If no inputs are specified, asset coin selection will be performed instead
+    /// and inputs of sufficient value will be added to the resulting PSBT.
+    #[prost(message, repeated, tag = "1")]
+    pub inputs: ::prost::alloc::vec::Vec<PrevId>,
+    /// A map of all Taproot Asset addresses mapped to the anchor transaction's
+    /// output index that should be sent to.
+    #[prost(map = "string, uint64", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Map<crate::serde_helpers::Int64>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Map<crate::serde_helpers::Int64>, _, _>"
+        )
+    )]
+    pub recipients: ::std::collections::HashMap<::prost::alloc::string::String, u64>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PrevId {
+    /// The bitcoin anchor output on chain that contains the input asset.
+    #[prost(message, optional, tag = "1")]
+    pub outpoint: ::core::option::Option<super::taprpc::OutPoint>,
+    /// The asset ID of the previous asset tree.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub id: ::prost::alloc::vec::Vec<u8>,
+    /// The tweaked Taproot output key committing to the possible spending
+    /// conditions of the asset.
+    #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub script_key: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SignVirtualPsbtRequest {
+    /// The PSBT of the virtual transaction that should be signed. The PSBT must
+    /// contain all required inputs, outputs, UTXO data and custom fields required
+    /// to identify the signing key.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub funded_psbt: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SignVirtualPsbtResponse {
+    /// The signed virtual transaction in PSBT format.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub signed_psbt: ::prost::alloc::vec::Vec<u8>,
+    /// The indices of signed inputs.
+    #[prost(uint32, repeated, tag = "2")]
+    pub signed_inputs: ::prost::alloc::vec::Vec<u32>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AnchorVirtualPsbtsRequest {
+    /// The list of virtual transactions that should be merged and committed to in
+    /// the BTC level anchor transaction.
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub virtual_psbts: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CommitVirtualPsbtsRequest {
+    /// The list of virtual transactions that should be mapped to the given BTC
+    /// level anchor transaction template. The virtual transactions are expected to
+    /// be signed (or use ASSET_VERSION_V1 with segregated witness to allow for
+    /// signing after committing) and ready to be committed to the anchor
+    /// transaction.
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub virtual_psbts: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// The list of passive virtual transactions that are anchored in the same BTC
+    /// level anchor transaction inputs as the "active" assets above. These can be
+    /// obtained by calling FundVirtualPsbt and using the passive assets returned.
+    /// The virtual transactions are expected to be signed (or use ASSET_VERSION_V1
+    /// with segregated witness to allow for signing after committing) and ready to
+    /// be committed to the anchor transaction.
+    /// The main difference to the "active" assets above is that the passive assets
+    /// will not get their own entry in the transfer table of the database, since
+    /// they are just carried along and not directly affected by the direct user
+    /// action.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub passive_asset_psbts: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// The template of the BTC level anchor transaction that the virtual
+    /// transactions should be mapped to. The template is expected to already
+    /// contain all asset related inputs and outputs corresponding to the virtual
+    /// transactions given above. This can be achieved by using
+    /// tapfreighter.PrepareAnchoringTemplate for example.
+    #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub anchor_psbt: ::prost::alloc::vec::Vec<u8>,
+    /// The custom lock ID used to identify the lock lease for UTXOs that serve as
+    /// inputs in the BTC-level anchor transaction. If left empty, LND's default
+    /// lock ID will be used.
+    #[prost(bytes = "vec", tag = "8")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub custom_lock_id: ::prost::alloc::vec::Vec<u8>,
+    /// If set, the UTXOs used as inputs in the BTC-level anchor transaction will be
+    /// locked for the specified number of seconds. If unset, LND's default lock
+    /// expiration of 10 minutes will be applied.
+    #[prost(uint64, tag = "9")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub lock_expiration_seconds: u64,
+    /// If set, the psbt funding step will be skipped. This is useful if the intent
+    /// is to create a zero-fee transaction.
+    #[prost(bool, tag = "10")]
+    pub skip_funding: bool,
+    #[prost(oneof = "commit_virtual_psbts_request::AnchorChangeOutput", tags = "4, 5")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub anchor_change_output: ::core::option::Option<
+        commit_virtual_psbts_request::AnchorChangeOutput,
+    >,
+    #[prost(oneof = "commit_virtual_psbts_request::Fees", tags = "6, 7")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub fees: ::core::option::Option<commit_virtual_psbts_request::Fees>,
+}
+/// Nested message and enum types in `CommitVirtualPsbtsRequest`.
+pub mod commit_virtual_psbts_request {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum AnchorChangeOutput {
+        /// Use the existing output within the anchor PSBT with the specified
+        /// index as the change output. Any leftover change will be added to the
+        /// already specified amount of that output. To add a new change output to
+        /// the PSBT, set the "add" field below instead.
+        #[prost(int32, tag = "4")]
+        #[cfg_attr(feature = "serde", serde(rename = "existing_output_index"))]
+        ExistingOutputIndex(i32),
+        /// Add a new P2TR change output to the PSBT if required.
+        #[prost(bool, tag = "5")]
+        #[cfg_attr(feature = "serde", serde(rename = "add"))]
+        Add(bool),
+    }
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Fees {
+        /// The target number of blocks that the transaction should be confirmed in.
+        #[prost(uint32, tag = "6")]
+        #[cfg_attr(feature = "serde", serde(rename = "target_conf"))]
+        TargetConf(u32),
+        /// The fee rate, expressed in sat/vbyte, that should be used to fund the
+        /// BTC level anchor transaction.
+        #[prost(uint64, tag = "7")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                rename = "sat_per_vbyte",
+                serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+                deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+            )
+        )]
+        SatPerVbyte(u64),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommitVirtualPsbtsResponse {
+    /// The funded BTC level anchor transaction with all outputs updated to commit
+    /// to the virtual transactions given. The transaction is ready to be signed,
+    /// unless some of the asset inputs don't belong to this daemon, in which case
+    /// the anchor input derivation info must be added to those inputs first.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub anchor_psbt: ::prost::alloc::vec::Vec<u8>,
+    /// The updated virtual transactions that now contain the state transition
+    /// proofs for being committed to the BTC level anchor transaction above. If the
+    /// assets in the virtual transaction outputs are ASSET_VERSION_V1 and not yet
+    /// signed, then the proofs need to be updated to include the witness before
+    /// they become fully valid.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub virtual_psbts: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// The updated passive virtual transactions that were committed to the same BTC
+    /// level anchor transaction as the "active" virtual transactions given. If the
+    /// assets in the virtual transaction outputs are ASSET_VERSION_V1 and not yet
+    /// signed, then the proofs need to be updated to include the witness before
+    /// they become fully valid.
+    #[prost(bytes = "vec", repeated, tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub passive_asset_psbts: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// The index of the (added) change output or -1 if no change was left over.
+    #[prost(int32, tag = "5")]
+    pub change_output_index: i32,
+    /// The list of UTXO lock leases that were acquired for the inputs in the funded
+    /// PSBT packet from lnd. Only inputs added to the PSBT by this RPC are locked,
+    /// inputs that were already present in the PSBT are not locked.
+    #[prost(message, repeated, tag = "6")]
+    pub lnd_locked_utxos: ::prost::alloc::vec::Vec<super::taprpc::OutPoint>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PublishAndLogRequest {
+    /// The funded BTC level anchor transaction with all outputs updated to commit
+    /// to the virtual transactions given. The transaction is ready to be signed,
+    /// unless some of the asset inputs don't belong to this daemon, in which case
+    /// the anchor input derivation info must be added to those inputs first.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub anchor_psbt: ::prost::alloc::vec::Vec<u8>,
+    /// The updated virtual transactions that contain the state transition proofs
+    /// of being committed to the BTC level anchor transaction above.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub virtual_psbts: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// The updated passive virtual transactions that contain the state transition
+    /// proofs of being committed to the BTC level anchor transaction above.
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub passive_asset_psbts: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// The index of the (added) change output or -1 if no change was left over.
+    #[prost(int32, tag = "4")]
+    pub change_output_index: i32,
+    /// The list of UTXO lock leases that were acquired for the inputs in the funded
+    /// PSBT packet from lnd. Only inputs added to the PSBT by this RPC are locked,
+    /// inputs that were already present in the PSBT are not locked.
+    #[prost(message, repeated, tag = "5")]
+    pub lnd_locked_utxos: ::prost::alloc::vec::Vec<super::taprpc::OutPoint>,
+    /// If set, the anchor transaction will not be broadcast to the network. This
+    /// is useful when an external system handles broadcasting, such as in custom
+    /// transaction packaging workflows.
+    #[prost(bool, tag = "6")]
+    pub skip_anchor_tx_broadcast: bool,
+    /// An optional short label for the transfer. This label can be used to track
+    /// the progress of the transfer via the logs or an event subscription.
+    /// Multiple transfers can share the same label.
+    #[prost(string, tag = "7")]
+    pub label: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct NextInternalKeyRequest {
+    #[prost(uint32, tag = "1")]
+    pub key_family: u32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct NextInternalKeyResponse {
+    #[prost(message, optional, tag = "1")]
+    pub internal_key: ::core::option::Option<super::taprpc::KeyDescriptor>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct NextScriptKeyRequest {
+    #[prost(uint32, tag = "1")]
+    pub key_family: u32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct NextScriptKeyResponse {
+    #[prost(message, optional, tag = "1")]
+    pub script_key: ::core::option::Option<super::taprpc::ScriptKey>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QueryInternalKeyRequest {
+    /// The internal key to look for. This can either be the 32-byte x-only raw
+    /// internal key or the 33-byte raw internal key with the parity byte.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub internal_key: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QueryInternalKeyResponse {
+    #[prost(message, optional, tag = "1")]
+    pub internal_key: ::core::option::Option<super::taprpc::KeyDescriptor>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QueryScriptKeyRequest {
+    /// The tweaked script key to look for. This can either be the 32-byte
+    /// x-only tweaked script key or the 33-byte tweaked script key with the
+    /// parity byte.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub tweaked_script_key: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QueryScriptKeyResponse {
+    #[prost(message, optional, tag = "1")]
+    pub script_key: ::core::option::Option<super::taprpc::ScriptKey>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ProveAssetOwnershipRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub script_key: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub outpoint: ::core::option::Option<super::taprpc::OutPoint>,
+    /// An optional 32-byte challenge that may be used to bind the generated
+    /// proof. This challenge needs to be also presented on the
+    /// VerifyAssetOwnership RPC in order to check the proof against it.
+    #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub challenge: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ProveAssetOwnershipResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub proof_with_witness: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct VerifyAssetOwnershipRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub proof_with_witness: ::prost::alloc::vec::Vec<u8>,
+    /// An optional 32-byte challenge that may be used to check the ownership
+    /// proof against. This challenge must match the one that the prover used
+    /// on the ProveAssetOwnership RPC.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub challenge: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct VerifyAssetOwnershipResponse {
+    #[prost(bool, tag = "1")]
+    pub valid_proof: bool,
+    /// The outpoint the proof commits to.
+    #[prost(message, optional, tag = "2")]
+    pub outpoint: ::core::option::Option<super::taprpc::OutPoint>,
+    /// The outpoint in the human-readable form "hash:index".
+    #[prost(string, tag = "3")]
+    pub outpoint_str: ::prost::alloc::string::String,
+    /// The block hash the output is part of.
+    #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub block_hash: ::prost::alloc::vec::Vec<u8>,
+    /// The block hash as hexadecimal string of the byte-reversed hash.
+    #[prost(string, tag = "5")]
+    pub block_hash_str: ::prost::alloc::string::String,
+    /// The block height of the block the output is part of.
+    #[prost(uint32, tag = "6")]
+    pub block_height: u32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RemoveUtxoLeaseRequest {
+    /// The outpoint of the UTXO to remove the lease for.
+    #[prost(message, optional, tag = "1")]
+    pub outpoint: ::core::option::Option<super::taprpc::OutPoint>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RemoveUtxoLeaseResponse {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeclareScriptKeyRequest {
+    #[prost(message, optional, tag = "1")]
+    pub script_key: ::core::option::Option<super::taprpc::ScriptKey>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeclareScriptKeyResponse {
+    #[prost(message, optional, tag = "1")]
+    pub script_key: ::core::option::Option<super::taprpc::ScriptKey>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CoinSelectType {
+    /// Use the default coin selection type, which currently allows script keys and
+    /// key spend paths.
+    #[cfg_attr(feature = "serde", serde(rename = "COIN_SELECT_DEFAULT"))]
+    CoinSelectDefault = 0,
+    /// Explicitly only select inputs that are known to be BIP-086 compliant (have
+    /// a key-spend path only and no script tree).
+    #[cfg_attr(feature = "serde", serde(rename = "COIN_SELECT_BIP86_ONLY"))]
+    CoinSelectBip86Only = 1,
+    /// Allow the selection of inputs that have a script tree spend path as well as
+    /// a key spend path.
+    #[cfg_attr(feature = "serde", serde(rename = "COIN_SELECT_SCRIPT_TREES_ALLOWED"))]
+    CoinSelectScriptTreesAllowed = 2,
+}
+impl CoinSelectType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::CoinSelectDefault => "COIN_SELECT_DEFAULT",
+            Self::CoinSelectBip86Only => "COIN_SELECT_BIP86_ONLY",
+            Self::CoinSelectScriptTreesAllowed => "COIN_SELECT_SCRIPT_TREES_ALLOWED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "COIN_SELECT_DEFAULT" => Some(Self::CoinSelectDefault),
+            "COIN_SELECT_BIP86_ONLY" => Some(Self::CoinSelectBip86Only),
+            "COIN_SELECT_SCRIPT_TREES_ALLOWED" => {
+                Some(Self::CoinSelectScriptTreesAllowed)
+            }
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod asset_wallet_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct AssetWalletClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "transport")]
+    impl AssetWalletClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> AssetWalletClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> AssetWalletClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            AssetWalletClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// FundVirtualPsbt selects inputs from the available asset commitments to fund
+        /// a virtual transaction matching the template.
+        pub async fn fund_virtual_psbt(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FundVirtualPsbtRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FundVirtualPsbtResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/assetwalletrpc.AssetWallet/FundVirtualPsbt",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("assetwalletrpc.AssetWallet", "FundVirtualPsbt"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// SignVirtualPsbt signs the inputs of a virtual transaction and prepares the
+        /// commitments of the inputs and outputs.
+        pub async fn sign_virtual_psbt(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SignVirtualPsbtRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SignVirtualPsbtResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/assetwalletrpc.AssetWallet/SignVirtualPsbt",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("assetwalletrpc.AssetWallet", "SignVirtualPsbt"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// AnchorVirtualPsbts merges and then commits multiple virtual transactions in
+        /// a single BTC level anchor transaction. This RPC should be used if the BTC
+        /// level anchor transaction of the assets to be spent are encumbered by a
+        /// normal key and don't require any special spending conditions. For any custom
+        /// spending conditions on the BTC level, the two RPCs CommitVirtualPsbts and
+        /// PublishAndLogTransfer should be used instead (which in combination do the
+        /// same as this RPC but allow for more flexibility).
+        pub async fn anchor_virtual_psbts(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AnchorVirtualPsbtsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::taprpc::SendAssetResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/assetwalletrpc.AssetWallet/AnchorVirtualPsbts",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("assetwalletrpc.AssetWallet", "AnchorVirtualPsbts"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// CommitVirtualPsbts creates the output commitments and proofs for the given
+        /// virtual transactions by committing them to the BTC level anchor transaction.
+        /// In addition, the BTC level anchor transaction is funded and prepared up to
+        /// the point where it is ready to be signed.
+        pub async fn commit_virtual_psbts(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CommitVirtualPsbtsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CommitVirtualPsbtsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/assetwalletrpc.AssetWallet/CommitVirtualPsbts",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("assetwalletrpc.AssetWallet", "CommitVirtualPsbts"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// PublishAndLogTransfer accepts a fully committed and signed anchor
+        /// transaction and publishes it to the Bitcoin network. It also logs the
+        /// transfer of the given active and passive assets in the database and ships
+        /// any outgoing proofs to the counterparties.
+        pub async fn publish_and_log_transfer(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PublishAndLogRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::taprpc::SendAssetResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/assetwalletrpc.AssetWallet/PublishAndLogTransfer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "assetwalletrpc.AssetWallet",
+                        "PublishAndLogTransfer",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// NextInternalKey derives the next internal key for the given key family and
+        /// stores it as an internal key in the database to make sure it is identified
+        /// as a local key later on when importing proofs. While an internal key can
+        /// also be used as the internal key of a script key, it is recommended to use
+        /// the NextScriptKey RPC instead, to make sure the tweaked Taproot output key
+        /// is also recognized as a local key.
+        pub async fn next_internal_key(
+            &mut self,
+            request: impl tonic::IntoRequest<super::NextInternalKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::NextInternalKeyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/assetwalletrpc.AssetWallet/NextInternalKey",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("assetwalletrpc.AssetWallet", "NextInternalKey"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// NextScriptKey derives the next script key (and its corresponding internal
+        /// key) and stores them both in the database to make sure they are identified
+        /// as local keys later on when importing proofs.
+        pub async fn next_script_key(
+            &mut self,
+            request: impl tonic::IntoRequest<super::NextScriptKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::NextScriptKeyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/assetwalletrpc.AssetWallet/NextScriptKey",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("assetwalletrpc.AssetWallet", "NextScriptKey"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// QueryInternalKey returns the key descriptor for the given internal key.
+        pub async fn query_internal_key(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryInternalKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryInternalKeyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/assetwalletrpc.AssetWallet/QueryInternalKey",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("assetwalletrpc.AssetWallet", "QueryInternalKey"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// QueryScriptKey returns the full script key descriptor for the given tweaked
+        /// script key.
+        pub async fn query_script_key(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryScriptKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryScriptKeyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/assetwalletrpc.AssetWallet/QueryScriptKey",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("assetwalletrpc.AssetWallet", "QueryScriptKey"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `proofs proveownership`
+        /// ProveAssetOwnership creates an ownership proof embedded in an asset
+        /// transition proof. That ownership proof is a signed virtual transaction
+        /// spending the asset with a valid witness to prove the prover owns the keys
+        /// that can spend the asset.
+        pub async fn prove_asset_ownership(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProveAssetOwnershipRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProveAssetOwnershipResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/assetwalletrpc.AssetWallet/ProveAssetOwnership",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("assetwalletrpc.AssetWallet", "ProveAssetOwnership"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `proofs verifyownership`
+        /// VerifyAssetOwnership verifies the asset ownership proof embedded in the
+        /// given transition proof of an asset and returns true if the proof is valid.
+        pub async fn verify_asset_ownership(
+            &mut self,
+            request: impl tonic::IntoRequest<super::VerifyAssetOwnershipRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::VerifyAssetOwnershipResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/assetwalletrpc.AssetWallet/VerifyAssetOwnership",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("assetwalletrpc.AssetWallet", "VerifyAssetOwnership"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// RemoveUTXOLease removes the lease/lock/reservation of the given managed
+        /// UTXO.
+        pub async fn remove_utxo_lease(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RemoveUtxoLeaseRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveUtxoLeaseResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/assetwalletrpc.AssetWallet/RemoveUTXOLease",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("assetwalletrpc.AssetWallet", "RemoveUTXOLease"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// DeclareScriptKey declares a new script key to the wallet. This is useful
+        /// when the script key contains scripts, which would mean it wouldn't be
+        /// recognized by the wallet automatically. Declaring a script key will make any
+        /// assets sent to the script key be recognized as being local assets.
+        pub async fn declare_script_key(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeclareScriptKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeclareScriptKeyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/assetwalletrpc.AssetWallet/DeclareScriptKey",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("assetwalletrpc.AssetWallet", "DeclareScriptKey"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod asset_wallet_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with AssetWalletServer.
+    #[async_trait]
+    pub trait AssetWallet: std::marker::Send + std::marker::Sync + 'static {
+        /// FundVirtualPsbt selects inputs from the available asset commitments to fund
+        /// a virtual transaction matching the template.
+        async fn fund_virtual_psbt(
+            &self,
+            request: tonic::Request<super::FundVirtualPsbtRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FundVirtualPsbtResponse>,
+            tonic::Status,
+        >;
+        /// SignVirtualPsbt signs the inputs of a virtual transaction and prepares the
+        /// commitments of the inputs and outputs.
+        async fn sign_virtual_psbt(
+            &self,
+            request: tonic::Request<super::SignVirtualPsbtRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SignVirtualPsbtResponse>,
+            tonic::Status,
+        >;
+        /// AnchorVirtualPsbts merges and then commits multiple virtual transactions in
+        /// a single BTC level anchor transaction. This RPC should be used if the BTC
+        /// level anchor transaction of the assets to be spent are encumbered by a
+        /// normal key and don't require any special spending conditions. For any custom
+        /// spending conditions on the BTC level, the two RPCs CommitVirtualPsbts and
+        /// PublishAndLogTransfer should be used instead (which in combination do the
+        /// same as this RPC but allow for more flexibility).
+        async fn anchor_virtual_psbts(
+            &self,
+            request: tonic::Request<super::AnchorVirtualPsbtsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::taprpc::SendAssetResponse>,
+            tonic::Status,
+        >;
+        /// CommitVirtualPsbts creates the output commitments and proofs for the given
+        /// virtual transactions by committing them to the BTC level anchor transaction.
+        /// In addition, the BTC level anchor transaction is funded and prepared up to
+        /// the point where it is ready to be signed.
+        async fn commit_virtual_psbts(
+            &self,
+            request: tonic::Request<super::CommitVirtualPsbtsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CommitVirtualPsbtsResponse>,
+            tonic::Status,
+        >;
+        /// PublishAndLogTransfer accepts a fully committed and signed anchor
+        /// transaction and publishes it to the Bitcoin network. It also logs the
+        /// transfer of the given active and passive assets in the database and ships
+        /// any outgoing proofs to the counterparties.
+        async fn publish_and_log_transfer(
+            &self,
+            request: tonic::Request<super::PublishAndLogRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::taprpc::SendAssetResponse>,
+            tonic::Status,
+        >;
+        /// NextInternalKey derives the next internal key for the given key family and
+        /// stores it as an internal key in the database to make sure it is identified
+        /// as a local key later on when importing proofs. While an internal key can
+        /// also be used as the internal key of a script key, it is recommended to use
+        /// the NextScriptKey RPC instead, to make sure the tweaked Taproot output key
+        /// is also recognized as a local key.
+        async fn next_internal_key(
+            &self,
+            request: tonic::Request<super::NextInternalKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::NextInternalKeyResponse>,
+            tonic::Status,
+        >;
+        /// NextScriptKey derives the next script key (and its corresponding internal
+        /// key) and stores them both in the database to make sure they are identified
+        /// as local keys later on when importing proofs.
+        async fn next_script_key(
+            &self,
+            request: tonic::Request<super::NextScriptKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::NextScriptKeyResponse>,
+            tonic::Status,
+        >;
+        /// QueryInternalKey returns the key descriptor for the given internal key.
+        async fn query_internal_key(
+            &self,
+            request: tonic::Request<super::QueryInternalKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryInternalKeyResponse>,
+            tonic::Status,
+        >;
+        /// QueryScriptKey returns the full script key descriptor for the given tweaked
+        /// script key.
+        async fn query_script_key(
+            &self,
+            request: tonic::Request<super::QueryScriptKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryScriptKeyResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `proofs proveownership`
+        /// ProveAssetOwnership creates an ownership proof embedded in an asset
+        /// transition proof. That ownership proof is a signed virtual transaction
+        /// spending the asset with a valid witness to prove the prover owns the keys
+        /// that can spend the asset.
+        async fn prove_asset_ownership(
+            &self,
+            request: tonic::Request<super::ProveAssetOwnershipRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProveAssetOwnershipResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `proofs verifyownership`
+        /// VerifyAssetOwnership verifies the asset ownership proof embedded in the
+        /// given transition proof of an asset and returns true if the proof is valid.
+        async fn verify_asset_ownership(
+            &self,
+            request: tonic::Request<super::VerifyAssetOwnershipRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::VerifyAssetOwnershipResponse>,
+            tonic::Status,
+        >;
+        /// RemoveUTXOLease removes the lease/lock/reservation of the given managed
+        /// UTXO.
+        async fn remove_utxo_lease(
+            &self,
+            request: tonic::Request<super::RemoveUtxoLeaseRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveUtxoLeaseResponse>,
+            tonic::Status,
+        >;
+        /// DeclareScriptKey declares a new script key to the wallet. This is useful
+        /// when the script key contains scripts, which would mean it wouldn't be
+        /// recognized by the wallet automatically. Declaring a script key will make any
+        /// assets sent to the script key be recognized as being local assets.
+        async fn declare_script_key(
+            &self,
+            request: tonic::Request<super::DeclareScriptKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeclareScriptKeyResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct AssetWalletServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> AssetWalletServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for AssetWalletServer<T>
+    where
+        T: AssetWallet,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/assetwalletrpc.AssetWallet/FundVirtualPsbt" => {
+                    #[allow(non_camel_case_types)]
+                    struct FundVirtualPsbtSvc<T: AssetWallet>(pub Arc<T>);
+                    impl<
+                        T: AssetWallet,
+                    > tonic::server::UnaryService<super::FundVirtualPsbtRequest>
+                    for FundVirtualPsbtSvc<T> {
+                        type Response = super::FundVirtualPsbtResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FundVirtualPsbtRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AssetWallet>::fund_virtual_psbt(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FundVirtualPsbtSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/assetwalletrpc.AssetWallet/SignVirtualPsbt" => {
+                    #[allow(non_camel_case_types)]
+                    struct SignVirtualPsbtSvc<T: AssetWallet>(pub Arc<T>);
+                    impl<
+                        T: AssetWallet,
+                    > tonic::server::UnaryService<super::SignVirtualPsbtRequest>
+                    for SignVirtualPsbtSvc<T> {
+                        type Response = super::SignVirtualPsbtResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SignVirtualPsbtRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AssetWallet>::sign_virtual_psbt(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SignVirtualPsbtSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/assetwalletrpc.AssetWallet/AnchorVirtualPsbts" => {
+                    #[allow(non_camel_case_types)]
+                    struct AnchorVirtualPsbtsSvc<T: AssetWallet>(pub Arc<T>);
+                    impl<
+                        T: AssetWallet,
+                    > tonic::server::UnaryService<super::AnchorVirtualPsbtsRequest>
+                    for AnchorVirtualPsbtsSvc<T> {
+                        type Response = super::super::taprpc::SendAssetResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AnchorVirtualPsbtsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AssetWallet>::anchor_virtual_psbts(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AnchorVirtualPsbtsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/assetwalletrpc.AssetWallet/CommitVirtualPsbts" => {
+                    #[allow(non_camel_case_types)]
+                    struct CommitVirtualPsbtsSvc<T: AssetWallet>(pub Arc<T>);
+                    impl<
+                        T: AssetWallet,
+                    > tonic::server::UnaryService<super::CommitVirtualPsbtsRequest>
+                    for CommitVirtualPsbtsSvc<T> {
+                        type Response = super::CommitVirtualPsbtsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CommitVirtualPsbtsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AssetWallet>::commit_virtual_psbts(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CommitVirtualPsbtsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/assetwalletrpc.AssetWallet/PublishAndLogTransfer" => {
+                    #[allow(non_camel_case_types)]
+                    struct PublishAndLogTransferSvc<T: AssetWallet>(pub Arc<T>);
+                    impl<
+                        T: AssetWallet,
+                    > tonic::server::UnaryService<super::PublishAndLogRequest>
+                    for PublishAndLogTransferSvc<T> {
+                        type Response = super::super::taprpc::SendAssetResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PublishAndLogRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AssetWallet>::publish_and_log_transfer(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PublishAndLogTransferSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/assetwalletrpc.AssetWallet/NextInternalKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct NextInternalKeySvc<T: AssetWallet>(pub Arc<T>);
+                    impl<
+                        T: AssetWallet,
+                    > tonic::server::UnaryService<super::NextInternalKeyRequest>
+                    for NextInternalKeySvc<T> {
+                        type Response = super::NextInternalKeyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::NextInternalKeyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AssetWallet>::next_internal_key(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = NextInternalKeySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/assetwalletrpc.AssetWallet/NextScriptKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct NextScriptKeySvc<T: AssetWallet>(pub Arc<T>);
+                    impl<
+                        T: AssetWallet,
+                    > tonic::server::UnaryService<super::NextScriptKeyRequest>
+                    for NextScriptKeySvc<T> {
+                        type Response = super::NextScriptKeyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::NextScriptKeyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AssetWallet>::next_script_key(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = NextScriptKeySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/assetwalletrpc.AssetWallet/QueryInternalKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryInternalKeySvc<T: AssetWallet>(pub Arc<T>);
+                    impl<
+                        T: AssetWallet,
+                    > tonic::server::UnaryService<super::QueryInternalKeyRequest>
+                    for QueryInternalKeySvc<T> {
+                        type Response = super::QueryInternalKeyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryInternalKeyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AssetWallet>::query_internal_key(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryInternalKeySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/assetwalletrpc.AssetWallet/QueryScriptKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryScriptKeySvc<T: AssetWallet>(pub Arc<T>);
+                    impl<
+                        T: AssetWallet,
+                    > tonic::server::UnaryService<super::QueryScriptKeyRequest>
+                    for QueryScriptKeySvc<T> {
+                        type Response = super::QueryScriptKeyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryScriptKeyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AssetWallet>::query_script_key(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryScriptKeySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/assetwalletrpc.AssetWallet/ProveAssetOwnership" => {
+                    #[allow(non_camel_case_types)]
+                    struct ProveAssetOwnershipSvc<T: AssetWallet>(pub Arc<T>);
+                    impl<
+                        T: AssetWallet,
+                    > tonic::server::UnaryService<super::ProveAssetOwnershipRequest>
+                    for ProveAssetOwnershipSvc<T> {
+                        type Response = super::ProveAssetOwnershipResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProveAssetOwnershipRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AssetWallet>::prove_asset_ownership(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ProveAssetOwnershipSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/assetwalletrpc.AssetWallet/VerifyAssetOwnership" => {
+                    #[allow(non_camel_case_types)]
+                    struct VerifyAssetOwnershipSvc<T: AssetWallet>(pub Arc<T>);
+                    impl<
+                        T: AssetWallet,
+                    > tonic::server::UnaryService<super::VerifyAssetOwnershipRequest>
+                    for VerifyAssetOwnershipSvc<T> {
+                        type Response = super::VerifyAssetOwnershipResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::VerifyAssetOwnershipRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AssetWallet>::verify_asset_ownership(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = VerifyAssetOwnershipSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/assetwalletrpc.AssetWallet/RemoveUTXOLease" => {
+                    #[allow(non_camel_case_types)]
+                    struct RemoveUTXOLeaseSvc<T: AssetWallet>(pub Arc<T>);
+                    impl<
+                        T: AssetWallet,
+                    > tonic::server::UnaryService<super::RemoveUtxoLeaseRequest>
+                    for RemoveUTXOLeaseSvc<T> {
+                        type Response = super::RemoveUtxoLeaseResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RemoveUtxoLeaseRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AssetWallet>::remove_utxo_lease(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RemoveUTXOLeaseSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/assetwalletrpc.AssetWallet/DeclareScriptKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeclareScriptKeySvc<T: AssetWallet>(pub Arc<T>);
+                    impl<
+                        T: AssetWallet,
+                    > tonic::server::UnaryService<super::DeclareScriptKeyRequest>
+                    for DeclareScriptKeySvc<T> {
+                        type Response = super::DeclareScriptKeyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeclareScriptKeyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AssetWallet>::declare_script_key(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeclareScriptKeySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for AssetWalletServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "assetwalletrpc.AssetWallet";
+    impl<T> tonic::server::NamedService for AssetWalletServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+impl crate::assetwalletrpc::FundVirtualPsbtRequest {
+    /// Returns `coin_select_type`, or an error for values unknown to this version of the protos.
+    pub fn try_coin_select_type(&self) -> ::core::result::Result<crate::assetwalletrpc::CoinSelectType, ::prost::UnknownEnumValue> {
+        crate::assetwalletrpc::CoinSelectType::try_from(self.coin_select_type)
+    }
+}
+/// Generated blocking client implementations.
+#[cfg(feature = "blocking")]
+pub mod asset_wallet_blocking_client {
+    #![allow(clippy::wildcard_imports)]
+    use tonic::codegen::*;
+    use super::asset_wallet_client::AssetWalletClient;
+    /// A [`AssetWalletClient`] that blocks the current thread on every call, driving the
+    /// requests on its runtime.
+    #[derive(Debug, Clone)]
+    pub struct AssetWalletBlockingClient<T> {
+        inner: AssetWalletClient<T>,
+        runtime: std::sync::Arc<crate::blocking::Runtime>,
+    }
+    impl<T> AssetWalletBlockingClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        /// Wraps `inner`, running its calls on `runtime`.
+        pub fn new(inner: AssetWalletClient<T>, runtime: std::sync::Arc<crate::blocking::Runtime>) -> Self {
+            Self { inner, runtime }
+        }
+        /// Returns the async client.
+        pub fn into_inner(self) -> AssetWalletClient<T> {
+            self.inner
+        }
+        /// Blocking version of [`AssetWalletClient::fund_virtual_psbt`].
+        pub fn fund_virtual_psbt(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::assetwalletrpc::FundVirtualPsbtRequest>,
+        ) -> std::result::Result<tonic::Response<crate::assetwalletrpc::FundVirtualPsbtResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.fund_virtual_psbt(request))
+        }
+        /// Blocking version of [`AssetWalletClient::sign_virtual_psbt`].
+        pub fn sign_virtual_psbt(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::assetwalletrpc::SignVirtualPsbtRequest>,
+        ) -> std::result::Result<tonic::Response<crate::assetwalletrpc::SignVirtualPsbtResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.sign_virtual_psbt(request))
+        }
+        /// Blocking version of [`AssetWalletClient::anchor_virtual_psbts`].
+        pub fn anchor_virtual_psbts(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::assetwalletrpc::AnchorVirtualPsbtsRequest>,
+        ) -> std::result::Result<tonic::Response<crate::taprpc::SendAssetResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.anchor_virtual_psbts(request))
+        }
+        /// Blocking version of [`AssetWalletClient::commit_virtual_psbts`].
+        pub fn commit_virtual_psbts(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::assetwalletrpc::CommitVirtualPsbtsRequest>,
+        ) -> std::result::Result<tonic::Response<crate::assetwalletrpc::CommitVirtualPsbtsResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.commit_virtual_psbts(request))
+        }
+        /// Blocking version of [`AssetWalletClient::publish_and_log_transfer`].
+        pub fn publish_and_log_transfer(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::assetwalletrpc::PublishAndLogRequest>,
+        ) -> std::result::Result<tonic::Response<crate::taprpc::SendAssetResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.publish_and_log_transfer(request))
+        }
+        /// Blocking version of [`AssetWalletClient::next_internal_key`].
+        pub fn next_internal_key(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::assetwalletrpc::NextInternalKeyRequest>,
+        ) -> std::result::Result<tonic::Response<crate::assetwalletrpc::NextInternalKeyResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.next_internal_key(request))
+        }
+        /// Blocking version of [`AssetWalletClient::next_script_key`].
+        pub fn next_script_key(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::assetwalletrpc::NextScriptKeyRequest>,
+        ) -> std::result::Result<tonic::Response<crate::assetwalletrpc::NextScriptKeyResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.next_script_key(request))
+        }
+        /// Blocking version of [`AssetWalletClient::query_internal_key`].
+        pub fn query_internal_key(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::assetwalletrpc::QueryInternalKeyRequest>,
+        ) -> std::result::Result<tonic::Response<crate::assetwalletrpc::QueryInternalKeyResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.query_internal_key(request))
+        }
+        /// Blocking version of [`AssetWalletClient::query_script_key`].
+        pub fn query_script_key(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::assetwalletrpc::QueryScriptKeyRequest>,
+        ) -> std::result::Result<tonic::Response<crate::assetwalletrpc::QueryScriptKeyResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.query_script_key(request))
+        }
+        /// Blocking version of [`AssetWalletClient::prove_asset_ownership`].
+        pub fn prove_asset_ownership(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::assetwalletrpc::ProveAssetOwnershipRequest>,
+        ) -> std::result::Result<tonic::Response<crate::assetwalletrpc::ProveAssetOwnershipResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.prove_asset_ownership(request))
+        }
+        /// Blocking version of [`AssetWalletClient::verify_asset_ownership`].
+        pub fn verify_asset_ownership(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::assetwalletrpc::VerifyAssetOwnershipRequest>,
+        ) -> std::result::Result<tonic::Response<crate::assetwalletrpc::VerifyAssetOwnershipResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.verify_asset_ownership(request))
+        }
+        /// Blocking version of [`AssetWalletClient::remove_utxo_lease`].
+        pub fn remove_utxo_lease(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::assetwalletrpc::RemoveUtxoLeaseRequest>,
+        ) -> std::result::Result<tonic::Response<crate::assetwalletrpc::RemoveUtxoLeaseResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.remove_utxo_lease(request))
+        }
+        /// Blocking version of [`AssetWalletClient::declare_script_key`].
+        pub fn declare_script_key(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::assetwalletrpc::DeclareScriptKeyRequest>,
+        ) -> std::result::Result<tonic::Response<crate::assetwalletrpc::DeclareScriptKeyResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.declare_script_key(request))
+        }
+    }
+}
@@ -0,0 +1,1562 @@
+// This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetBlockRequest {
+    /// The hash of the requested block.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub block_hash: ::prost::alloc::vec::Vec<u8>,
+}
+/// TODO(ffranr): The neutrino GetBlock response includes many
+/// additional helpful fields. Consider adding them here also.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetBlockResponse {
+    /// The raw bytes of the requested block.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub raw_block: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetBlockHeaderRequest {
+    /// The hash of the block with the requested header.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub block_hash: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetBlockHeaderResponse {
+    /// The header of the block with the requested hash.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub raw_block_header: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetBestBlockRequest {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetBestBlockResponse {
+    /// The hash of the best block.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub block_hash: ::prost::alloc::vec::Vec<u8>,
+    /// The height of the best block.
+    #[prost(int32, tag = "2")]
+    pub block_height: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetBlockHashRequest {
+    /// Block height of the target best chain block.
+    #[prost(int64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub block_height: i64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetBlockHashResponse {
+    /// The hash of the best block at the specified height.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub block_hash: ::prost::alloc::vec::Vec<u8>,
+}
+/// Generated client implementations.
+pub mod chain_kit_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// ChainKit is a service that can be used to get information from the
+    /// chain backend.
+    #[derive(Debug, Clone)]
+    pub struct ChainKitClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "transport")]
+    impl ChainKitClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ChainKitClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> ChainKitClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            ChainKitClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// lncli: `chain getblock`
+        /// GetBlock returns a block given the corresponding block hash.
+        pub async fn get_block(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/chainrpc.ChainKit/GetBlock",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("chainrpc.ChainKit", "GetBlock"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `chain getblockheader`
+        /// GetBlockHeader returns a block header with a particular block hash.
+        pub async fn get_block_header(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockHeaderRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeaderResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/chainrpc.ChainKit/GetBlockHeader",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("chainrpc.ChainKit", "GetBlockHeader"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `chain getbestblock`
+        /// GetBestBlock returns the block hash and current height from the valid
+        /// most-work chain.
+        pub async fn get_best_block(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBestBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBestBlockResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/chainrpc.ChainKit/GetBestBlock",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("chainrpc.ChainKit", "GetBestBlock"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `chain getblockhash`
+        /// GetBlockHash returns the hash of the block in the best blockchain
+        /// at the given height.
+        pub async fn get_block_hash(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockHashRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHashResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/chainrpc.ChainKit/GetBlockHash",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("chainrpc.ChainKit", "GetBlockHash"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod chain_kit_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ChainKitServer.
+    #[async_trait]
+    pub trait ChainKit: std::marker::Send + std::marker::Sync + 'static {
+        /// lncli: `chain getblock`
+        /// GetBlock returns a block given the corresponding block hash.
+        async fn get_block(
+            &self,
+            request: tonic::Request<super::GetBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `chain getblockheader`
+        /// GetBlockHeader returns a block header with a particular block hash.
+        async fn get_block_header(
+            &self,
+            request: tonic::Request<super::GetBlockHeaderRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeaderResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `chain getbestblock`
+        /// GetBestBlock returns the block hash and current height from the valid
+        /// most-work chain.
+        async fn get_best_block(
+            &self,
+            request: tonic::Request<super::GetBestBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBestBlockResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `chain getblockhash`
+        /// GetBlockHash returns the hash of the block in the best blockchain
+        /// at the given height.
+        async fn get_block_hash(
+            &self,
+            request: tonic::Request<super::GetBlockHashRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHashResponse>,
+            tonic::Status,
+        >;
+    }
+    /// ChainKit is a service that can be used to get information from the
+    /// chain backend.
+    #[derive(Debug)]
+    pub struct ChainKitServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> ChainKitServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for ChainKitServer<T>
+    where
+        T: ChainKit,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/chainrpc.ChainKit/GetBlock" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockSvc<T: ChainKit>(pub Arc<T>);
+                    impl<T: ChainKit> tonic::server::UnaryService<super::GetBlockRequest>
+                    for GetBlockSvc<T> {
+                        type Response = super::GetBlockResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ChainKit>::get_block(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/chainrpc.ChainKit/GetBlockHeader" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockHeaderSvc<T: ChainKit>(pub Arc<T>);
+                    impl<
+                        T: ChainKit,
+                    > tonic::server::UnaryService<super::GetBlockHeaderRequest>
+                    for GetBlockHeaderSvc<T> {
+                        type Response = super::GetBlockHeaderResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlockHeaderRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ChainKit>::get_block_header(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockHeaderSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/chainrpc.ChainKit/GetBestBlock" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBestBlockSvc<T: ChainKit>(pub Arc<T>);
+                    impl<
+                        T: ChainKit,
+                    > tonic::server::UnaryService<super::GetBestBlockRequest>
+                    for GetBestBlockSvc<T> {
+                        type Response = super::GetBestBlockResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBestBlockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ChainKit>::get_best_block(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBestBlockSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/chainrpc.ChainKit/GetBlockHash" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockHashSvc<T: ChainKit>(pub Arc<T>);
+                    impl<
+                        T: ChainKit,
+                    > tonic::server::UnaryService<super::GetBlockHashRequest>
+                    for GetBlockHashSvc<T> {
+                        type Response = super::GetBlockHashResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlockHashRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ChainKit>::get_block_hash(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockHashSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for ChainKitServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "chainrpc.ChainKit";
+    impl<T> tonic::server::NamedService for ChainKitServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ConfRequest {
+    /// The transaction hash for which we should request a confirmation notification
+    /// for. If set to a hash of all zeros, then the confirmation notification will
+    /// be requested for the script instead.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub txid: ::prost::alloc::vec::Vec<u8>,
+    /// An output script within a transaction with the hash above which will be used
+    /// by light clients to match block filters. If the transaction hash is set to a
+    /// hash of all zeros, then a confirmation notification will be requested for
+    /// this script instead.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub script: ::prost::alloc::vec::Vec<u8>,
+    /// The number of desired confirmations the transaction/output script should
+    /// reach before dispatching a confirmation notification.
+    #[prost(uint32, tag = "3")]
+    pub num_confs: u32,
+    /// The earliest height in the chain for which the transaction/output script
+    /// could have been included in a block. This should in most cases be set to the
+    /// broadcast height of the transaction/output script.
+    #[prost(uint32, tag = "4")]
+    pub height_hint: u32,
+    /// If true, then the block that mines the specified txid/script will be
+    /// included in eventual the notification event.
+    #[prost(bool, tag = "5")]
+    pub include_block: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ConfDetails {
+    /// The raw bytes of the confirmed transaction.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub raw_tx: ::prost::alloc::vec::Vec<u8>,
+    /// The hash of the block in which the confirmed transaction was included in.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub block_hash: ::prost::alloc::vec::Vec<u8>,
+    /// The height of the block in which the confirmed transaction was included
+    /// in.
+    #[prost(uint32, tag = "3")]
+    pub block_height: u32,
+    /// The index of the confirmed transaction within the block.
+    #[prost(uint32, tag = "4")]
+    pub tx_index: u32,
+    /// The raw bytes of the block that mined the transaction. Only included if
+    /// include_block was set in the request.
+    #[prost(bytes = "vec", tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub raw_block: ::prost::alloc::vec::Vec<u8>,
+}
+/// TODO(wilmer): need to know how the client will use this first.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Reorg {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ConfEvent {
+    #[prost(oneof = "conf_event::Event", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub event: ::core::option::Option<conf_event::Event>,
+}
+/// Nested message and enum types in `ConfEvent`.
+pub mod conf_event {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Event {
+        /// An event that includes the confirmation details of the request
+        /// (txid/ouput script).
+        #[prost(message, tag = "1")]
+        #[cfg_attr(feature = "serde", serde(rename = "conf"))]
+        Conf(super::ConfDetails),
+        /// An event send when the transaction of the request is reorged out of the
+        /// chain.
+        #[prost(message, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "reorg"))]
+        Reorg(super::Reorg),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Outpoint {
+    /// The hash of the transaction.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    /// The index of the output within the transaction.
+    #[prost(uint32, tag = "2")]
+    pub index: u32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SpendRequest {
+    /// The outpoint for which we should request a spend notification for. If set to
+    /// a zero outpoint, then the spend notification will be requested for the
+    /// script instead. A zero or nil outpoint is not supported for Taproot spends
+    /// because the output script cannot reliably be computed from the witness alone
+    /// and the spent output script is not always available in the rescan context.
+    /// So an outpoint must *always* be specified when registering a spend
+    /// notification for a Taproot output.
+    #[prost(message, optional, tag = "1")]
+    pub outpoint: ::core::option::Option<Outpoint>,
+    /// The output script for the outpoint above. This will be used by light clients
+    /// to match block filters. If the outpoint is set to a zero outpoint, then a
+    /// spend notification will be requested for this script instead.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub script: ::prost::alloc::vec::Vec<u8>,
+    /// The earliest height in the chain for which the outpoint/output script could
+    /// have been spent. This should in most cases be set to the broadcast height of
+    /// the outpoint/output script.
+    #[prost(uint32, tag = "3")]
+    pub height_hint: u32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SpendDetails {
+    /// The outpoint was that spent.
+    #[prost(message, optional, tag = "1")]
+    pub spending_outpoint: ::core::option::Option<Outpoint>,
+    /// The raw bytes of the spending transaction.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub raw_spending_tx: ::prost::alloc::vec::Vec<u8>,
+    /// The hash of the spending transaction.
+    #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub spending_tx_hash: ::prost::alloc::vec::Vec<u8>,
+    /// The input of the spending transaction that fulfilled the spend request.
+    #[prost(uint32, tag = "4")]
+    pub spending_input_index: u32,
+    /// The height at which the spending transaction was included in a block.
+    #[prost(uint32, tag = "5")]
+    pub spending_height: u32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SpendEvent {
+    #[prost(oneof = "spend_event::Event", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub event: ::core::option::Option<spend_event::Event>,
+}
+/// Nested message and enum types in `SpendEvent`.
+pub mod spend_event {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Event {
+        /// An event that includes the details of the spending transaction of the
+        /// request (outpoint/output script).
+        #[prost(message, tag = "1")]
+        #[cfg_attr(feature = "serde", serde(rename = "spend"))]
+        Spend(super::SpendDetails),
+        /// An event sent when the spending transaction of the request was
+        /// reorged out of the chain.
+        #[prost(message, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "reorg"))]
+        Reorg(super::Reorg),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BlockEpoch {
+    /// The hash of the block.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    /// The height of the block.
+    #[prost(uint32, tag = "2")]
+    pub height: u32,
+}
+/// Generated client implementations.
+pub mod chain_notifier_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// ChainNotifier is a service that can be used to get information about the
+    /// chain backend by registering notifiers for chain events.
+    #[derive(Debug, Clone)]
+    pub struct ChainNotifierClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "transport")]
+    impl ChainNotifierClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ChainNotifierClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> ChainNotifierClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            ChainNotifierClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// RegisterConfirmationsNtfn is a synchronous response-streaming RPC that
+        /// registers an intent for a client to be notified once a confirmation request
+        /// has reached its required number of confirmations on-chain.
+        ///
+        /// A confirmation request must have a valid output script. It is also possible
+        /// to give a transaction ID. If the transaction ID is not set, a notification
+        /// is sent once the output script confirms. If the transaction ID is also set,
+        /// a notification is sent once the output script confirms in the given
+        /// transaction.
+        pub async fn register_confirmations_ntfn(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ConfRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ConfEvent>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/chainrpc.ChainNotifier/RegisterConfirmationsNtfn",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "chainrpc.ChainNotifier",
+                        "RegisterConfirmationsNtfn",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// RegisterSpendNtfn is a synchronous response-streaming RPC that registers an
+        /// intent for a client to be notification once a spend request has been spent
+        /// by a transaction that has confirmed on-chain.
+        ///
+        /// A client can specify whether the spend request should be for a particular
+        /// outpoint  or for an output script by specifying a zero outpoint.
+        pub async fn register_spend_ntfn(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SpendRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::SpendEvent>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/chainrpc.ChainNotifier/RegisterSpendNtfn",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("chainrpc.ChainNotifier", "RegisterSpendNtfn"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// RegisterBlockEpochNtfn is a synchronous response-streaming RPC that
+        /// registers an intent for a client to be notified of blocks in the chain. The
+        /// stream will return a hash and height tuple of a block for each new/stale
+        /// block in the chain. It is the client's responsibility to determine whether
+        /// the tuple returned is for a new or stale block in the chain.
+        ///
+        /// A client can also request a historical backlog of blocks from a particular
+        /// point. This allows clients to be idempotent by ensuring that they do not
+        /// missing processing a single block within the chain.
+        pub async fn register_block_epoch_ntfn(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BlockEpoch>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::BlockEpoch>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/chainrpc.ChainNotifier/RegisterBlockEpochNtfn",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("chainrpc.ChainNotifier", "RegisterBlockEpochNtfn"),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod chain_notifier_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ChainNotifierServer.
+    #[async_trait]
+    pub trait ChainNotifier: std::marker::Send + std::marker::Sync + 'static {
+        /// Server streaming response type for the RegisterConfirmationsNtfn method.
+        type RegisterConfirmationsNtfnStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ConfEvent, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// RegisterConfirmationsNtfn is a synchronous response-streaming RPC that
+        /// registers an intent for a client to be notified once a confirmation request
+        /// has reached its required number of confirmations on-chain.
+        ///
+        /// A confirmation request must have a valid output script. It is also possible
+        /// to give a transaction ID. If the transaction ID is not set, a notification
+        /// is sent once the output script confirms. If the transaction ID is also set,
+        /// a notification is sent once the output script confirms in the given
+        /// transaction.
+        async fn register_confirmations_ntfn(
+            &self,
+            request: tonic::Request<super::ConfRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::RegisterConfirmationsNtfnStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the RegisterSpendNtfn method.
+        type RegisterSpendNtfnStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::SpendEvent, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// RegisterSpendNtfn is a synchronous response-streaming RPC that registers an
+        /// intent for a client to be notification once a spend request has been spent
+        /// by a transaction that has confirmed on-chain.
+        ///
+        /// A client can specify whether the spend request should be for a particular
+        /// outpoint  or for an output script by specifying a zero outpoint.
+        async fn register_spend_ntfn(
+            &self,
+            request: tonic::Request<super::SpendRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::RegisterSpendNtfnStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the RegisterBlockEpochNtfn method.
+        type RegisterBlockEpochNtfnStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::BlockEpoch, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// RegisterBlockEpochNtfn is a synchronous response-streaming RPC that
+        /// registers an intent for a client to be notified of blocks in the chain. The
+        /// stream will return a hash and height tuple of a block for each new/stale
+        /// block in the chain. It is the client's responsibility to determine whether
+        /// the tuple returned is for a new or stale block in the chain.
+        ///
+        /// A client can also request a historical backlog of blocks from a particular
+        /// point. This allows clients to be idempotent by ensuring that they do not
+        /// missing processing a single block within the chain.
+        async fn register_block_epoch_ntfn(
+            &self,
+            request: tonic::Request<super::BlockEpoch>,
+        ) -> std::result::Result<
+            tonic::Response<Self::RegisterBlockEpochNtfnStream>,
+            tonic::Status,
+        >;
+    }
+    /// ChainNotifier is a service that can be used to get information about the
+    /// chain backend by registering notifiers for chain events.
+    #[derive(Debug)]
+    pub struct ChainNotifierServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> ChainNotifierServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for ChainNotifierServer<T>
+    where
+        T: ChainNotifier,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/chainrpc.ChainNotifier/RegisterConfirmationsNtfn" => {
+                    #[allow(non_camel_case_types)]
+                    struct RegisterConfirmationsNtfnSvc<T: ChainNotifier>(pub Arc<T>);
+                    impl<
+                        T: ChainNotifier,
+                    > tonic::server::ServerStreamingService<super::ConfRequest>
+                    for RegisterConfirmationsNtfnSvc<T> {
+                        type Response = super::ConfEvent;
+                        type ResponseStream = T::RegisterConfirmationsNtfnStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ConfRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ChainNotifier>::register_confirmations_ntfn(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RegisterConfirmationsNtfnSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/chainrpc.ChainNotifier/RegisterSpendNtfn" => {
+                    #[allow(non_camel_case_types)]
+                    struct RegisterSpendNtfnSvc<T: ChainNotifier>(pub Arc<T>);
+                    impl<
+                        T: ChainNotifier,
+                    > tonic::server::ServerStreamingService<super::SpendRequest>
+                    for RegisterSpendNtfnSvc<T> {
+                        type Response = super::SpendEvent;
+                        type ResponseStream = T::RegisterSpendNtfnStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SpendRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ChainNotifier>::register_spend_ntfn(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RegisterSpendNtfnSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/chainrpc.ChainNotifier/RegisterBlockEpochNtfn" => {
+                    #[allow(non_camel_case_types)]
+                    struct RegisterBlockEpochNtfnSvc<T: ChainNotifier>(pub Arc<T>);
+                    impl<
+                        T: ChainNotifier,
+                    > tonic::server::ServerStreamingService<super::BlockEpoch>
+                    for RegisterBlockEpochNtfnSvc<T> {
+                        type Response = super::BlockEpoch;
+                        type ResponseStream = T::RegisterBlockEpochNtfnStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BlockEpoch>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ChainNotifier>::register_block_epoch_ntfn(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RegisterBlockEpochNtfnSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for ChainNotifierServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "chainrpc.ChainNotifier";
+    impl<T> tonic::server::NamedService for ChainNotifierServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Generated blocking client implementations.
+#[cfg(feature = "blocking")]
+pub mod chain_kit_blocking_client {
+    #![allow(clippy::wildcard_imports)]
+    use tonic::codegen::*;
+    use super::chain_kit_client::ChainKitClient;
+    /// A [`ChainKitClient`] that blocks the current thread on every call, driving the
+    /// requests on its runtime.
+    #[derive(Debug, Clone)]
+    pub struct ChainKitBlockingClient<T> {
+        inner: ChainKitClient<T>,
+        runtime: std::sync::Arc<crate::blocking::Runtime>,
+    }
+    impl<T> ChainKitBlockingClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        /// Wraps `inner`, running its calls on `runtime`.
+        pub fn new(inner: ChainKitClient<T>, runtime: std::sync::Arc<crate::blocking::Runtime>) -> Self {
+            Self { inner, runtime }
+        }
+        /// Returns the async client.
+        pub fn into_inner(self) -> ChainKitClient<T> {
+            self.inner
+        }
+        /// Blocking version of [`ChainKitClient::get_block`].
+        pub fn get_block(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::chainrpc::GetBlockRequest>,
+        ) -> std::result::Result<tonic::Response<crate::chainrpc::GetBlockResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.get_block(request))
+        }
+        /// Blocking version of [`ChainKitClient::get_block_header`].
+        pub fn get_block_header(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::chainrpc::GetBlockHeaderRequest>,
+        ) -> std::result::Result<tonic::Response<crate::chainrpc::GetBlockHeaderResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.get_block_header(request))
+        }
+        /// Blocking version of [`ChainKitClient::get_best_block`].
+        pub fn get_best_block(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::chainrpc::GetBestBlockRequest>,
+        ) -> std::result::Result<tonic::Response<crate::chainrpc::GetBestBlockResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.get_best_block(request))
+        }
+        /// Blocking version of [`ChainKitClient::get_block_hash`].
+        pub fn get_block_hash(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::chainrpc::GetBlockHashRequest>,
+        ) -> std::result::Result<tonic::Response<crate::chainrpc::GetBlockHashResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.get_block_hash(request))
+        }
+    }
+}
+/// Generated blocking client implementations.
+#[cfg(feature = "blocking")]
+pub mod chain_notifier_blocking_client {
+    #![allow(clippy::wildcard_imports)]
+    use tonic::codegen::*;
+    use super::chain_notifier_client::ChainNotifierClient;
+    /// A [`ChainNotifierClient`] that blocks the current thread on every call, driving the
+    /// requests on its runtime.
+    #[derive(Debug, Clone)]
+    pub struct ChainNotifierBlockingClient<T> {
+        inner: ChainNotifierClient<T>,
+        runtime: std::sync::Arc<crate::blocking::Runtime>,
+    }
+    impl<T> ChainNotifierBlockingClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        /// Wraps `inner`, running its calls on `runtime`.
+        pub fn new(inner: ChainNotifierClient<T>, runtime: std::sync::Arc<crate::blocking::Runtime>) -> Self {
+            Self { inner, runtime }
+        }
+        /// Returns the async client.
+        pub fn into_inner(self) -> ChainNotifierClient<T> {
+            self.inner
+        }
+        /// Blocking version of [`ChainNotifierClient::register_confirmations_ntfn`].
+        pub fn register_confirmations_ntfn(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::chainrpc::ConfRequest>,
+        ) -> std::result::Result<tonic::Response<crate::blocking::Streaming<crate::chainrpc::ConfEvent>>, tonic::Status> {
+            let response = self.runtime.block_on(self.inner.register_confirmations_ntfn(request))?;
+            Ok(response.map(|stream| crate::blocking::Streaming::new(stream, self.runtime.clone())))
+        }
+        /// Blocking version of [`ChainNotifierClient::register_spend_ntfn`].
+        pub fn register_spend_ntfn(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::chainrpc::SpendRequest>,
+        ) -> std::result::Result<tonic::Response<crate::blocking::Streaming<crate::chainrpc::SpendEvent>>, tonic::Status> {
+            let response = self.runtime.block_on(self.inner.register_spend_ntfn(request))?;
+            Ok(response.map(|stream| crate::blocking::Streaming::new(stream, self.runtime.clone())))
+        }
+        /// Blocking version of [`ChainNotifierClient::register_block_epoch_ntfn`].
+        pub fn register_block_epoch_ntfn(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::chainrpc::BlockEpoch>,
+        ) -> std::result::Result<tonic::Response<crate::blocking::Streaming<crate::chainrpc::BlockEpoch>>, tonic::Status> {
+            let response = self.runtime.block_on(self.inner.register_block_epoch_ntfn(request))?;
+            Ok(response.map(|stream| crate::blocking::Streaming::new(stream, self.runtime.clone())))
+        }
+    }
+}
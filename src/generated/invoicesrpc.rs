@@ -0,0 +1,1195 @@
+// This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CancelInvoiceMsg {
+    /// Hash corresponding to the (hold) invoice to cancel. When using
+    /// REST, this field must be encoded as base64.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub payment_hash: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CancelInvoiceResp {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddHoldInvoiceRequest {
+    /// An optional memo to attach along with the invoice. Used for record keeping
+    /// purposes for the invoice's creator, and will also be set in the description
+    /// field of the encoded payment request if the description_hash field is not
+    /// being used.
+    #[prost(string, tag = "1")]
+    pub memo: ::prost::alloc::string::String,
+    /// The hash of the preimage
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    /// The value of this invoice in satoshis
+    ///
+    /// The fields value and value_msat are mutually exclusive.
+    #[prost(int64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub value: i64,
+    /// The value of this invoice in millisatoshis
+    ///
+    /// The fields value and value_msat are mutually exclusive.
+    #[prost(int64, tag = "10")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub value_msat: i64,
+    /// Hash (SHA-256) of a description of the payment. Used if the description of
+    /// payment (memo) is too long to naturally fit within the description field
+    /// of an encoded payment request.
+    #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub description_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Payment request expiry time in seconds. Default is 86400 (24 hours).
+    #[prost(int64, tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub expiry: i64,
+    /// Fallback on-chain address.
+    #[prost(string, tag = "6")]
+    pub fallback_addr: ::prost::alloc::string::String,
+    /// Delta to use for the time-lock of the CLTV extended to the final hop.
+    #[prost(uint64, tag = "7")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub cltv_expiry: u64,
+    /// Route hints that can each be individually used to assist in reaching the
+    /// invoice's destination.
+    #[prost(message, repeated, tag = "8")]
+    pub route_hints: ::prost::alloc::vec::Vec<super::lnrpc::RouteHint>,
+    /// Whether this invoice should include routing hints for private channels.
+    #[prost(bool, tag = "9")]
+    pub private: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AddHoldInvoiceResp {
+    /// A bare-bones invoice for a payment within the Lightning Network. With the
+    /// details of the invoice, the sender has all the data necessary to send a
+    /// payment to the recipient.
+    #[prost(string, tag = "1")]
+    pub payment_request: ::prost::alloc::string::String,
+    /// The "add" index of this invoice. Each newly created invoice will increment
+    /// this index making it monotonically increasing. Callers to the
+    /// SubscribeInvoices call can use this to instantly get notified of all added
+    /// invoices with an add_index greater than this one.
+    #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub add_index: u64,
+    /// The payment address of the generated invoice. This is also called
+    /// the payment secret in specifications (e.g. BOLT 11). This value should
+    /// be used in all payments for this invoice as we require it for end to end
+    /// security.
+    #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub payment_addr: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SettleInvoiceMsg {
+    /// Externally discovered pre-image that should be used to settle the hold
+    /// invoice.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub preimage: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SettleInvoiceResp {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SubscribeSingleInvoiceRequest {
+    /// Hash corresponding to the (hold) invoice to subscribe to. When using
+    /// REST, this field must be encoded as base64url.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub r_hash: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct LookupInvoiceMsg {
+    #[prost(enumeration = "LookupModifier", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::invoicesrpc::LookupModifier>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::invoicesrpc::LookupModifier>, _, _>"
+        )
+    )]
+    pub lookup_modifier: i32,
+    #[prost(oneof = "lookup_invoice_msg::InvoiceRef", tags = "1, 2, 3")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub invoice_ref: ::core::option::Option<lookup_invoice_msg::InvoiceRef>,
+}
+/// Nested message and enum types in `LookupInvoiceMsg`.
+pub mod lookup_invoice_msg {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum InvoiceRef {
+        /// When using REST, this field must be encoded as base64.
+        #[prost(bytes, tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                rename = "payment_hash",
+                serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+                deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+            )
+        )]
+        PaymentHash(::prost::alloc::vec::Vec<u8>),
+        #[prost(bytes, tag = "2")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                rename = "payment_addr",
+                serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+                deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+            )
+        )]
+        PaymentAddr(::prost::alloc::vec::Vec<u8>),
+        #[prost(bytes, tag = "3")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                rename = "set_id",
+                serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+                deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+            )
+        )]
+        SetId(::prost::alloc::vec::Vec<u8>),
+    }
+}
+/// CircuitKey is a unique identifier for an HTLC.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CircuitKey {
+    /// The id of the channel that the is part of this circuit.
+    #[prost(uint64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub chan_id: u64,
+    /// The index of the incoming htlc in the incoming channel.
+    #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub htlc_id: u64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HtlcModifyRequest {
+    /// The invoice the intercepted HTLC is attempting to settle. The HTLCs in
+    /// the invoice are only HTLCs that have already been accepted or settled,
+    /// not including the current intercepted HTLC.
+    #[prost(message, optional, tag = "1")]
+    pub invoice: ::core::option::Option<super::lnrpc::Invoice>,
+    /// The unique identifier of the HTLC of this intercepted HTLC.
+    #[prost(message, optional, tag = "2")]
+    pub exit_htlc_circuit_key: ::core::option::Option<CircuitKey>,
+    /// The amount in milli-satoshi that the exit HTLC is attempting to pay.
+    #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub exit_htlc_amt: u64,
+    /// The absolute expiry height of the exit HTLC.
+    #[prost(uint32, tag = "4")]
+    pub exit_htlc_expiry: u32,
+    /// The current block height.
+    #[prost(uint32, tag = "5")]
+    pub current_height: u32,
+    /// The wire message custom records of the exit HTLC.
+    #[prost(map = "uint64, bytes", tag = "6")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Map<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Map<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub exit_htlc_wire_custom_records: ::std::collections::HashMap<
+        u64,
+        ::prost::alloc::vec::Vec<u8>,
+    >,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct HtlcModifyResponse {
+    /// The circuit key of the HTLC that the client wants to modify.
+    #[prost(message, optional, tag = "1")]
+    pub circuit_key: ::core::option::Option<CircuitKey>,
+    /// The modified amount in milli-satoshi that the exit HTLC is paying. This
+    /// value can be different from the actual on-chain HTLC amount, in case the
+    /// HTLC carries other valuable items, as can be the case with custom channel
+    /// types.
+    #[prost(uint64, optional, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Optional<crate::serde_helpers::Int64>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Optional<crate::serde_helpers::Int64>, _, _>"
+        )
+    )]
+    pub amt_paid: ::core::option::Option<u64>,
+    /// This flag indicates whether the HTLCs associated with the invoices should
+    /// be cancelled. The interceptor client may set this field if some
+    /// unexpected behavior is encountered. Setting this will ignore the amt_paid
+    /// field.
+    #[prost(bool, tag = "3")]
+    pub cancel_set: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum LookupModifier {
+    /// The default look up modifier, no look up behavior is changed.
+    #[cfg_attr(feature = "serde", serde(rename = "DEFAULT"))]
+    Default = 0,
+    /// Indicates that when a look up is done based on a set_id, then only that set
+    /// of HTLCs related to that set ID should be returned.
+    #[cfg_attr(feature = "serde", serde(rename = "HTLC_SET_ONLY"))]
+    HtlcSetOnly = 1,
+    /// Indicates that when a look up is done using a payment_addr, then no HTLCs
+    /// related to the payment_addr should be returned. This is useful when one
+    /// wants to be able to obtain the set of associated setIDs with a given
+    /// invoice, then look up the sub-invoices "projected" by that set ID.
+    #[cfg_attr(feature = "serde", serde(rename = "HTLC_SET_BLANK"))]
+    HtlcSetBlank = 2,
+}
+impl LookupModifier {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Default => "DEFAULT",
+            Self::HtlcSetOnly => "HTLC_SET_ONLY",
+            Self::HtlcSetBlank => "HTLC_SET_BLANK",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DEFAULT" => Some(Self::Default),
+            "HTLC_SET_ONLY" => Some(Self::HtlcSetOnly),
+            "HTLC_SET_BLANK" => Some(Self::HtlcSetBlank),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod invoices_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Invoices is a service that can be used to create, accept, settle and cancel
+    /// invoices.
+    #[derive(Debug, Clone)]
+    pub struct InvoicesClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "transport")]
+    impl InvoicesClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> InvoicesClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InvoicesClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            InvoicesClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// SubscribeSingleInvoice returns a uni-directional stream (server -> client)
+        /// to notify the client of state transitions of the specified invoice.
+        /// Initially the current invoice state is always sent out.
+        pub async fn subscribe_single_invoice(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubscribeSingleInvoiceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::super::lnrpc::Invoice>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/invoicesrpc.Invoices/SubscribeSingleInvoice",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("invoicesrpc.Invoices", "SubscribeSingleInvoice"),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// lncli: `cancelinvoice`
+        /// CancelInvoice cancels a currently open invoice. If the invoice is already
+        /// canceled, this call will succeed. If the invoice is already settled, it will
+        /// fail.
+        pub async fn cancel_invoice(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CancelInvoiceMsg>,
+        ) -> std::result::Result<
+            tonic::Response<super::CancelInvoiceResp>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/invoicesrpc.Invoices/CancelInvoice",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("invoicesrpc.Invoices", "CancelInvoice"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `addholdinvoice`
+        /// AddHoldInvoice creates a hold invoice. It ties the invoice to the hash
+        /// supplied in the request.
+        pub async fn add_hold_invoice(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddHoldInvoiceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddHoldInvoiceResp>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/invoicesrpc.Invoices/AddHoldInvoice",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("invoicesrpc.Invoices", "AddHoldInvoice"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `settleinvoice`
+        /// SettleInvoice settles an accepted invoice. If the invoice is already
+        /// settled, this call will succeed.
+        pub async fn settle_invoice(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SettleInvoiceMsg>,
+        ) -> std::result::Result<
+            tonic::Response<super::SettleInvoiceResp>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/invoicesrpc.Invoices/SettleInvoice",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("invoicesrpc.Invoices", "SettleInvoice"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// LookupInvoiceV2 attempts to look up at invoice. An invoice can be referenced
+        /// using either its payment hash, payment address, or set ID.
+        pub async fn lookup_invoice_v2(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LookupInvoiceMsg>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::lnrpc::Invoice>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/invoicesrpc.Invoices/LookupInvoiceV2",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("invoicesrpc.Invoices", "LookupInvoiceV2"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// HtlcModifier is a bidirectional streaming RPC that allows a client to
+        /// intercept and modify the HTLCs that attempt to settle the given invoice. The
+        /// server will send HTLCs of invoices to the client and the client can modify
+        /// some aspects of the HTLC in order to pass the invoice acceptance tests.
+        pub async fn htlc_modifier(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<
+                Message = super::HtlcModifyResponse,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::HtlcModifyRequest>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/invoicesrpc.Invoices/HtlcModifier",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("invoicesrpc.Invoices", "HtlcModifier"));
+            self.inner.streaming(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod invoices_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with InvoicesServer.
+    #[async_trait]
+    pub trait Invoices: std::marker::Send + std::marker::Sync + 'static {
+        /// Server streaming response type for the SubscribeSingleInvoice method.
+        type SubscribeSingleInvoiceStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::super::lnrpc::Invoice, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// SubscribeSingleInvoice returns a uni-directional stream (server -> client)
+        /// to notify the client of state transitions of the specified invoice.
+        /// Initially the current invoice state is always sent out.
+        async fn subscribe_single_invoice(
+            &self,
+            request: tonic::Request<super::SubscribeSingleInvoiceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::SubscribeSingleInvoiceStream>,
+            tonic::Status,
+        >;
+        /// lncli: `cancelinvoice`
+        /// CancelInvoice cancels a currently open invoice. If the invoice is already
+        /// canceled, this call will succeed. If the invoice is already settled, it will
+        /// fail.
+        async fn cancel_invoice(
+            &self,
+            request: tonic::Request<super::CancelInvoiceMsg>,
+        ) -> std::result::Result<
+            tonic::Response<super::CancelInvoiceResp>,
+            tonic::Status,
+        >;
+        /// lncli: `addholdinvoice`
+        /// AddHoldInvoice creates a hold invoice. It ties the invoice to the hash
+        /// supplied in the request.
+        async fn add_hold_invoice(
+            &self,
+            request: tonic::Request<super::AddHoldInvoiceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddHoldInvoiceResp>,
+            tonic::Status,
+        >;
+        /// lncli: `settleinvoice`
+        /// SettleInvoice settles an accepted invoice. If the invoice is already
+        /// settled, this call will succeed.
+        async fn settle_invoice(
+            &self,
+            request: tonic::Request<super::SettleInvoiceMsg>,
+        ) -> std::result::Result<
+            tonic::Response<super::SettleInvoiceResp>,
+            tonic::Status,
+        >;
+        /// LookupInvoiceV2 attempts to look up at invoice. An invoice can be referenced
+        /// using either its payment hash, payment address, or set ID.
+        async fn lookup_invoice_v2(
+            &self,
+            request: tonic::Request<super::LookupInvoiceMsg>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::lnrpc::Invoice>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the HtlcModifier method.
+        type HtlcModifierStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::HtlcModifyRequest, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// HtlcModifier is a bidirectional streaming RPC that allows a client to
+        /// intercept and modify the HTLCs that attempt to settle the given invoice. The
+        /// server will send HTLCs of invoices to the client and the client can modify
+        /// some aspects of the HTLC in order to pass the invoice acceptance tests.
+        async fn htlc_modifier(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::HtlcModifyResponse>>,
+        ) -> std::result::Result<
+            tonic::Response<Self::HtlcModifierStream>,
+            tonic::Status,
+        >;
+    }
+    /// Invoices is a service that can be used to create, accept, settle and cancel
+    /// invoices.
+    #[derive(Debug)]
+    pub struct InvoicesServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> InvoicesServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for InvoicesServer<T>
+    where
+        T: Invoices,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/invoicesrpc.Invoices/SubscribeSingleInvoice" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeSingleInvoiceSvc<T: Invoices>(pub Arc<T>);
+                    impl<
+                        T: Invoices,
+                    > tonic::server::ServerStreamingService<
+                        super::SubscribeSingleInvoiceRequest,
+                    > for SubscribeSingleInvoiceSvc<T> {
+                        type Response = super::super::lnrpc::Invoice;
+                        type ResponseStream = T::SubscribeSingleInvoiceStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubscribeSingleInvoiceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Invoices>::subscribe_single_invoice(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SubscribeSingleInvoiceSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/invoicesrpc.Invoices/CancelInvoice" => {
+                    #[allow(non_camel_case_types)]
+                    struct CancelInvoiceSvc<T: Invoices>(pub Arc<T>);
+                    impl<
+                        T: Invoices,
+                    > tonic::server::UnaryService<super::CancelInvoiceMsg>
+                    for CancelInvoiceSvc<T> {
+                        type Response = super::CancelInvoiceResp;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CancelInvoiceMsg>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Invoices>::cancel_invoice(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CancelInvoiceSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/invoicesrpc.Invoices/AddHoldInvoice" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddHoldInvoiceSvc<T: Invoices>(pub Arc<T>);
+                    impl<
+                        T: Invoices,
+                    > tonic::server::UnaryService<super::AddHoldInvoiceRequest>
+                    for AddHoldInvoiceSvc<T> {
+                        type Response = super::AddHoldInvoiceResp;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddHoldInvoiceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Invoices>::add_hold_invoice(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AddHoldInvoiceSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/invoicesrpc.Invoices/SettleInvoice" => {
+                    #[allow(non_camel_case_types)]
+                    struct SettleInvoiceSvc<T: Invoices>(pub Arc<T>);
+                    impl<
+                        T: Invoices,
+                    > tonic::server::UnaryService<super::SettleInvoiceMsg>
+                    for SettleInvoiceSvc<T> {
+                        type Response = super::SettleInvoiceResp;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SettleInvoiceMsg>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Invoices>::settle_invoice(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SettleInvoiceSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/invoicesrpc.Invoices/LookupInvoiceV2" => {
+                    #[allow(non_camel_case_types)]
+                    struct LookupInvoiceV2Svc<T: Invoices>(pub Arc<T>);
+                    impl<
+                        T: Invoices,
+                    > tonic::server::UnaryService<super::LookupInvoiceMsg>
+                    for LookupInvoiceV2Svc<T> {
+                        type Response = super::super::lnrpc::Invoice;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LookupInvoiceMsg>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Invoices>::lookup_invoice_v2(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LookupInvoiceV2Svc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/invoicesrpc.Invoices/HtlcModifier" => {
+                    #[allow(non_camel_case_types)]
+                    struct HtlcModifierSvc<T: Invoices>(pub Arc<T>);
+                    impl<
+                        T: Invoices,
+                    > tonic::server::StreamingService<super::HtlcModifyResponse>
+                    for HtlcModifierSvc<T> {
+                        type Response = super::HtlcModifyRequest;
+                        type ResponseStream = T::HtlcModifierStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::HtlcModifyResponse>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Invoices>::htlc_modifier(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = HtlcModifierSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for InvoicesServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "invoicesrpc.Invoices";
+    impl<T> tonic::server::NamedService for InvoicesServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+impl crate::invoicesrpc::LookupInvoiceMsg {
+    /// Returns `lookup_modifier`, or an error for values unknown to this version of the protos.
+    pub fn try_lookup_modifier(&self) -> ::core::result::Result<crate::invoicesrpc::LookupModifier, ::prost::UnknownEnumValue> {
+        crate::invoicesrpc::LookupModifier::try_from(self.lookup_modifier)
+    }
+}
+/// Generated blocking client implementations.
+#[cfg(feature = "blocking")]
+pub mod invoices_blocking_client {
+    #![allow(clippy::wildcard_imports)]
+    use tonic::codegen::*;
+    use super::invoices_client::InvoicesClient;
+    /// A [`InvoicesClient`] that blocks the current thread on every call, driving the
+    /// requests on its runtime.
+    #[derive(Debug, Clone)]
+    pub struct InvoicesBlockingClient<T> {
+        inner: InvoicesClient<T>,
+        runtime: std::sync::Arc<crate::blocking::Runtime>,
+    }
+    impl<T> InvoicesBlockingClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        /// Wraps `inner`, running its calls on `runtime`.
+        pub fn new(inner: InvoicesClient<T>, runtime: std::sync::Arc<crate::blocking::Runtime>) -> Self {
+            Self { inner, runtime }
+        }
+        /// Returns the async client.
+        pub fn into_inner(self) -> InvoicesClient<T> {
+            self.inner
+        }
+        /// Blocking version of [`InvoicesClient::subscribe_single_invoice`].
+        pub fn subscribe_single_invoice(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::invoicesrpc::SubscribeSingleInvoiceRequest>,
+        ) -> std::result::Result<tonic::Response<crate::blocking::Streaming<crate::lnrpc::Invoice>>, tonic::Status> {
+            let response = self.runtime.block_on(self.inner.subscribe_single_invoice(request))?;
+            Ok(response.map(|stream| crate::blocking::Streaming::new(stream, self.runtime.clone())))
+        }
+        /// Blocking version of [`InvoicesClient::cancel_invoice`].
+        pub fn cancel_invoice(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::invoicesrpc::CancelInvoiceMsg>,
+        ) -> std::result::Result<tonic::Response<crate::invoicesrpc::CancelInvoiceResp>, tonic::Status> {
+            self.runtime.block_on(self.inner.cancel_invoice(request))
+        }
+        /// Blocking version of [`InvoicesClient::add_hold_invoice`].
+        pub fn add_hold_invoice(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::invoicesrpc::AddHoldInvoiceRequest>,
+        ) -> std::result::Result<tonic::Response<crate::invoicesrpc::AddHoldInvoiceResp>, tonic::Status> {
+            self.runtime.block_on(self.inner.add_hold_invoice(request))
+        }
+        /// Blocking version of [`InvoicesClient::settle_invoice`].
+        pub fn settle_invoice(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::invoicesrpc::SettleInvoiceMsg>,
+        ) -> std::result::Result<tonic::Response<crate::invoicesrpc::SettleInvoiceResp>, tonic::Status> {
+            self.runtime.block_on(self.inner.settle_invoice(request))
+        }
+        /// Blocking version of [`InvoicesClient::lookup_invoice_v2`].
+        pub fn lookup_invoice_v2(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::invoicesrpc::LookupInvoiceMsg>,
+        ) -> std::result::Result<tonic::Response<crate::lnrpc::Invoice>, tonic::Status> {
+            self.runtime.block_on(self.inner.lookup_invoice_v2(request))
+        }
+        /// Blocking version of [`InvoicesClient::htlc_modifier`].
+        pub fn htlc_modifier(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = crate::invoicesrpc::HtlcModifyResponse>,
+        ) -> std::result::Result<tonic::Response<crate::blocking::Streaming<crate::invoicesrpc::HtlcModifyRequest>>, tonic::Status> {
+            let response = self.runtime.block_on(self.inner.htlc_modifier(request))?;
+            Ok(response.map(|stream| crate::blocking::Streaming::new(stream, self.runtime.clone())))
+        }
+    }
+}
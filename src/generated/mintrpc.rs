@@ -0,0 +1,1510 @@
+// This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PendingAsset {
+    /// The version of asset to mint.
+    #[prost(enumeration = "super::taprpc::AssetVersion", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::taprpc::AssetVersion>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::taprpc::AssetVersion>, _, _>"
+        )
+    )]
+    pub asset_version: i32,
+    /// The type of the asset to be created.
+    #[prost(enumeration = "super::taprpc::AssetType", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::taprpc::AssetType>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::taprpc::AssetType>, _, _>"
+        )
+    )]
+    pub asset_type: i32,
+    /// The name, or "tag" of the asset. This will affect the final asset ID.
+    #[prost(string, tag = "3")]
+    pub name: ::prost::alloc::string::String,
+    /// A blob that resents metadata related to the asset. This will affect the
+    /// final asset ID.
+    #[prost(message, optional, tag = "4")]
+    pub asset_meta: ::core::option::Option<super::taprpc::AssetMeta>,
+    /// The total amount of units of the new asset that should be created. If the
+    /// AssetType is Collectible, then this field cannot be set.
+    #[prost(uint64, tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub amount: u64,
+    /// If true, then the asset will be created with a new group key, which allows
+    /// for future asset issuance.
+    #[prost(bool, tag = "6")]
+    pub new_grouped_asset: bool,
+    /// The specific existing group key this asset should be minted with.
+    #[prost(bytes = "vec", tag = "7")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub group_key: ::prost::alloc::vec::Vec<u8>,
+    /// The name of the asset in the batch that will anchor a new asset group.
+    /// This asset will be minted with the same group key as the anchor asset.
+    #[prost(string, tag = "8")]
+    pub group_anchor: ::prost::alloc::string::String,
+    /// The optional key that will be used as the internal key for an asset group
+    /// created with this asset.
+    #[prost(message, optional, tag = "9")]
+    pub group_internal_key: ::core::option::Option<super::taprpc::KeyDescriptor>,
+    /// The optional root of a tapscript tree that will be used when constructing a
+    /// new asset group key. This enables future issuance authorized with a script
+    /// witness.
+    #[prost(bytes = "vec", tag = "10")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub group_tapscript_root: ::prost::alloc::vec::Vec<u8>,
+    /// The optional script key to use for the new asset. If no script key is given,
+    /// a BIP-86 key will be derived from the underlying wallet.
+    #[prost(message, optional, tag = "11")]
+    pub script_key: ::core::option::Option<super::taprpc::ScriptKey>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct UnsealedAsset {
+    /// The pending asset with an unsealed asset group.
+    #[prost(message, optional, tag = "1")]
+    pub asset: ::core::option::Option<PendingAsset>,
+    /// The group key request for the asset.
+    #[prost(message, optional, tag = "2")]
+    pub group_key_request: ::core::option::Option<super::taprpc::GroupKeyRequest>,
+    /// The group virtual transaction for the asset.
+    #[prost(message, optional, tag = "3")]
+    pub group_virtual_tx: ::core::option::Option<super::taprpc::GroupVirtualTx>,
+    /// The byte serialized PSBT equivalent of the group virtual transaction.
+    #[prost(string, tag = "4")]
+    pub group_virtual_psbt: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MintAsset {
+    /// The version of asset to mint.
+    #[prost(enumeration = "super::taprpc::AssetVersion", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::taprpc::AssetVersion>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::taprpc::AssetVersion>, _, _>"
+        )
+    )]
+    pub asset_version: i32,
+    /// The type of the asset to be created.
+    #[prost(enumeration = "super::taprpc::AssetType", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::taprpc::AssetType>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::taprpc::AssetType>, _, _>"
+        )
+    )]
+    pub asset_type: i32,
+    /// The name, or "tag" of the asset. This will affect the final asset ID.
+    #[prost(string, tag = "3")]
+    pub name: ::prost::alloc::string::String,
+    /// A blob that resents metadata related to the asset. This will affect the
+    /// final asset ID.
+    #[prost(message, optional, tag = "4")]
+    pub asset_meta: ::core::option::Option<super::taprpc::AssetMeta>,
+    /// The total amount of units of the new asset that should be created. If the
+    /// AssetType is Collectible, then this field cannot be set.
+    #[prost(uint64, tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub amount: u64,
+    /// If true, then the asset will be created with a group key, which allows for
+    /// future asset issuance.
+    #[prost(bool, tag = "6")]
+    pub new_grouped_asset: bool,
+    /// If true, then a group key or group anchor can be set to mint this asset into
+    /// an existing asset group.
+    #[prost(bool, tag = "7")]
+    pub grouped_asset: bool,
+    /// The specific existing group key this asset should be minted with.
+    #[prost(bytes = "vec", tag = "8")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub group_key: ::prost::alloc::vec::Vec<u8>,
+    /// The name of the asset in the batch that will anchor a new asset group.
+    /// This asset will be minted with the same group key as the anchor asset.
+    #[prost(string, tag = "9")]
+    pub group_anchor: ::prost::alloc::string::String,
+    /// The optional key that will be used as the internal key for an asset group
+    /// created with this asset.
+    ///
+    /// If this field is set then external_group_key must be unset, and vice versa.
+    #[prost(message, optional, tag = "10")]
+    pub group_internal_key: ::core::option::Option<super::taprpc::KeyDescriptor>,
+    /// An optional root of a Tapscript tree used when constructing a new asset
+    /// group key. This allows for future asset issuance authorized using a
+    /// script witness.
+    ///
+    /// If an external group key is provided, the V1 scheme for group key script
+    /// construction will be used, which supports PSBT signing. In this scheme,
+    /// the user-supplied Tapscript root is extended by two levels of Tapscript
+    /// siblings that commit to the group anchor's asset ID. As a result, the
+    /// provided Tapscript root becomes a branch within a larger Tapscript tree,
+    /// and the final Tapscript root will differ from the one specified here.
+    #[prost(bytes = "vec", tag = "11")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub group_tapscript_root: ::prost::alloc::vec::Vec<u8>,
+    /// The optional script key to use for the new asset. If no script key is given,
+    /// a BIP-86 key will be derived from the underlying wallet.
+    #[prost(message, optional, tag = "12")]
+    pub script_key: ::core::option::Option<super::taprpc::ScriptKey>,
+    /// Decimal display dictates the number of decimal places to shift the amount to
+    /// the left converting from Taproot Asset integer representation to a
+    /// UX-recognizable fractional quantity.
+    ///
+    /// For example, if the decimal_display value is 2 and there's 100 of those
+    /// assets, then a wallet would display the amount as "1.00". This field is
+    /// intended as information for wallets that display balances and has no impact
+    /// on the behavior of the daemon or any other part of the protocol. This value
+    /// is encoded in the MetaData field as a JSON field, therefore it is only
+    /// compatible with assets that have a JSON MetaData field.
+    #[prost(uint32, tag = "13")]
+    pub decimal_display: u32,
+    /// The external group key is an optional field that allows specifying an
+    /// external signing key for the group virtual transaction during minting.
+    /// This key enables signing operations to be performed externally, outside
+    /// the daemon.
+    ///
+    /// If this field is set then group_internal_key must be unset, and vice versa.
+    #[prost(message, optional, tag = "14")]
+    pub external_group_key: ::core::option::Option<super::taprpc::ExternalKey>,
+    /// Enabling this flag allows the creation of universe commitments for a new
+    /// asset group.
+    ///
+    /// Universe commitments are minter-controlled, on-chain attestations that
+    /// anchor and verify the state of an asset group.
+    ///
+    /// This flag imposes restrictions on the minting process by limiting the batch
+    /// to assets within the same universe, meaning they share the same group key.
+    /// This option is applicable only for the creation of a new asset group
+    /// (i.e., the first minting tranche of an asset group) and ensures that the
+    /// batch is limited to a single asset group.
+    #[prost(bool, tag = "15")]
+    pub universe_commitments: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MintAssetRequest {
+    /// The asset to be minted.
+    #[prost(message, optional, tag = "1")]
+    pub asset: ::core::option::Option<MintAsset>,
+    /// If true, then the assets currently in the batch won't be returned in the
+    /// response. This is mainly to avoid a lot of data being transmitted and
+    /// possibly printed on the command line in the case of a very large batch.
+    #[prost(bool, tag = "2")]
+    pub short_response: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MintAssetResponse {
+    /// The pending batch the asset was added to.
+    #[prost(message, optional, tag = "1")]
+    pub pending_batch: ::core::option::Option<MintingBatch>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MintingBatch {
+    /// A public key serialized in compressed format that can be used to uniquely
+    /// identify a pending minting batch. Responses that share the same key will be
+    /// batched into the same minting transaction.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub batch_key: ::prost::alloc::vec::Vec<u8>,
+    /// The transaction ID of the batch. Only populated if the batch has been
+    /// committed.
+    #[prost(string, tag = "2")]
+    pub batch_txid: ::prost::alloc::string::String,
+    /// The state of the batch.
+    #[prost(enumeration = "BatchState", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::mintrpc::BatchState>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::mintrpc::BatchState>, _, _>"
+        )
+    )]
+    pub state: i32,
+    /// The assets that are part of the batch.
+    #[prost(message, repeated, tag = "4")]
+    pub assets: ::prost::alloc::vec::Vec<PendingAsset>,
+    /// The time the batch was created as a Unix timestamp (in seconds).
+    #[prost(int64, tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub created_at: i64,
+    /// The current height of the block chain at the time of the batch creation.
+    #[prost(uint32, tag = "6")]
+    pub height_hint: u32,
+    /// The genesis transaction as a PSBT packet. Only populated if the batch has
+    /// been committed.
+    #[prost(bytes = "vec", tag = "7")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub batch_psbt: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VerboseBatch {
+    /// The minting batch, without any assets.
+    #[prost(message, optional, tag = "1")]
+    pub batch: ::core::option::Option<MintingBatch>,
+    /// The assets that are part of the batch.
+    #[prost(message, repeated, tag = "2")]
+    pub unsealed_assets: ::prost::alloc::vec::Vec<UnsealedAsset>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundBatchRequest {
+    /// If true, then the assets currently in the batch won't be returned in the
+    /// response. This is mainly to avoid a lot of data being transmitted and
+    /// possibly printed on the command line in the case of a very large batch.
+    #[prost(bool, tag = "1")]
+    pub short_response: bool,
+    /// The optional fee rate to use for the minting transaction, in sat/kw.
+    #[prost(uint32, tag = "2")]
+    pub fee_rate: u32,
+    /// The optional tapscript sibling that will be used when deriving the genesis
+    /// output for the batch. This sibling is a tapscript tree, which allows the
+    /// minter to encumber future transfers of assets in the batch with Tapscript.
+    #[prost(oneof = "fund_batch_request::BatchSibling", tags = "3, 4")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub batch_sibling: ::core::option::Option<fund_batch_request::BatchSibling>,
+}
+/// Nested message and enum types in `FundBatchRequest`.
+pub mod fund_batch_request {
+    /// The optional tapscript sibling that will be used when deriving the genesis
+    /// output for the batch. This sibling is a tapscript tree, which allows the
+    /// minter to encumber future transfers of assets in the batch with Tapscript.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum BatchSibling {
+        /// An ordered list of TapLeafs, which will be used to construct a
+        /// Tapscript tree.
+        #[prost(message, tag = "3")]
+        #[cfg_attr(feature = "serde", serde(rename = "full_tree"))]
+        FullTree(super::super::taprpc::TapscriptFullTree),
+        /// A TapBranch that represents a Tapscript tree managed externally.
+        #[prost(message, tag = "4")]
+        #[cfg_attr(feature = "serde", serde(rename = "branch"))]
+        Branch(super::super::taprpc::TapBranch),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundBatchResponse {
+    /// The funded batch.
+    #[prost(message, optional, tag = "1")]
+    pub batch: ::core::option::Option<VerboseBatch>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SealBatchRequest {
+    /// If true, then the assets currently in the batch won't be returned in the
+    /// response. This is mainly to avoid a lot of data being transmitted and
+    /// possibly printed on the command line in the case of a very large batch.
+    #[prost(bool, tag = "1")]
+    pub short_response: bool,
+    /// The assetID, witness pairs that authorize asset membership in a group.
+    /// This field should not be used in conjunction with
+    /// `signed_group_virtual_psbts`; use one or the other.
+    #[prost(message, repeated, tag = "2")]
+    pub group_witnesses: ::prost::alloc::vec::Vec<super::taprpc::GroupWitness>,
+    /// The base64 encoded signed group virtual PSBTs.
+    /// This field should not be used in conjunction with `group_witnesses`;
+    /// use one or the other.
+    #[prost(string, repeated, tag = "3")]
+    pub signed_group_virtual_psbts: ::prost::alloc::vec::Vec<
+        ::prost::alloc::string::String,
+    >,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SealBatchResponse {
+    /// The sealed batch.
+    #[prost(message, optional, tag = "1")]
+    pub batch: ::core::option::Option<MintingBatch>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FinalizeBatchRequest {
+    /// If true, then the assets currently in the batch won't be returned in the
+    /// response. This is mainly to avoid a lot of data being transmitted and
+    /// possibly printed on the command line in the case of a very large batch.
+    #[prost(bool, tag = "1")]
+    pub short_response: bool,
+    /// The optional fee rate to use for the minting transaction, in sat/kw.
+    #[prost(uint32, tag = "2")]
+    pub fee_rate: u32,
+    /// The optional tapscript sibling that will be used when deriving the genesis
+    /// output for the batch. This sibling is a tapscript tree, which allows the
+    /// minter to encumber future transfers of assets in the batch with Tapscript.
+    #[prost(oneof = "finalize_batch_request::BatchSibling", tags = "3, 4")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub batch_sibling: ::core::option::Option<finalize_batch_request::BatchSibling>,
+}
+/// Nested message and enum types in `FinalizeBatchRequest`.
+pub mod finalize_batch_request {
+    /// The optional tapscript sibling that will be used when deriving the genesis
+    /// output for the batch. This sibling is a tapscript tree, which allows the
+    /// minter to encumber future transfers of assets in the batch with Tapscript.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum BatchSibling {
+        /// An ordered list of TapLeafs, which will be used to construct a
+        /// Tapscript tree.
+        #[prost(message, tag = "3")]
+        #[cfg_attr(feature = "serde", serde(rename = "full_tree"))]
+        FullTree(super::super::taprpc::TapscriptFullTree),
+        /// A TapBranch that represents a Tapscript tree managed externally.
+        #[prost(message, tag = "4")]
+        #[cfg_attr(feature = "serde", serde(rename = "branch"))]
+        Branch(super::super::taprpc::TapBranch),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FinalizeBatchResponse {
+    /// The finalized batch.
+    #[prost(message, optional, tag = "1")]
+    pub batch: ::core::option::Option<MintingBatch>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CancelBatchRequest {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CancelBatchResponse {
+    /// The internal public key of the batch.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub batch_key: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListBatchRequest {
+    /// If true, pending asset group details will be included for any funded,
+    /// non-empty pending batch. Unfunded or empty batches will be excluded.
+    #[prost(bool, tag = "3")]
+    pub verbose: bool,
+    /// The optional batch key of the batch to list.
+    #[prost(oneof = "list_batch_request::Filter", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub filter: ::core::option::Option<list_batch_request::Filter>,
+}
+/// Nested message and enum types in `ListBatchRequest`.
+pub mod list_batch_request {
+    /// The optional batch key of the batch to list.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Filter {
+        /// The optional batch key of the batch to list, specified as raw bytes
+        /// (gRPC only).
+        #[prost(bytes, tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                rename = "batch_key",
+                serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+                deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+            )
+        )]
+        BatchKey(::prost::alloc::vec::Vec<u8>),
+        /// The optional batch key of the batch to list, specified as a hex
+        /// encoded string (use this for REST).
+        #[prost(string, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "batch_key_str"))]
+        BatchKeyStr(::prost::alloc::string::String),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListBatchResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub batches: ::prost::alloc::vec::Vec<VerboseBatch>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SubscribeMintEventsRequest {
+    /// If true, then the assets currently in the batch won't be returned in the
+    /// event's batch. This is mainly to avoid a lot of data being transmitted and
+    /// possibly printed on the command line in the case of a very large batch.
+    #[prost(bool, tag = "1")]
+    pub short_response: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MintEvent {
+    /// Execute timestamp (Unix timestamp in microseconds).
+    #[prost(int64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub timestamp: i64,
+    /// The last state of the batch that was successfully executed. If error
+    /// below is set, then the batch_state is the state that lead to the error
+    /// during its execution.
+    #[prost(enumeration = "BatchState", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::mintrpc::BatchState>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::mintrpc::BatchState>, _, _>"
+        )
+    )]
+    pub batch_state: i32,
+    /// The batch that the event is for.
+    #[prost(message, optional, tag = "3")]
+    pub batch: ::core::option::Option<MintingBatch>,
+    /// An optional error, indicating that executing the batch_state failed.
+    #[prost(string, tag = "4")]
+    pub error: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum BatchState {
+    #[cfg_attr(feature = "serde", serde(rename = "BATCH_STATE_UNKNOWN"))]
+    Unknown = 0,
+    #[cfg_attr(feature = "serde", serde(rename = "BATCH_STATE_PENDING"))]
+    Pending = 1,
+    #[cfg_attr(feature = "serde", serde(rename = "BATCH_STATE_FROZEN"))]
+    Frozen = 2,
+    #[cfg_attr(feature = "serde", serde(rename = "BATCH_STATE_COMMITTED"))]
+    Committed = 3,
+    #[cfg_attr(feature = "serde", serde(rename = "BATCH_STATE_BROADCAST"))]
+    Broadcast = 4,
+    #[cfg_attr(feature = "serde", serde(rename = "BATCH_STATE_CONFIRMED"))]
+    Confirmed = 5,
+    #[cfg_attr(feature = "serde", serde(rename = "BATCH_STATE_FINALIZED"))]
+    Finalized = 6,
+    #[cfg_attr(feature = "serde", serde(rename = "BATCH_STATE_SEEDLING_CANCELLED"))]
+    SeedlingCancelled = 7,
+    #[cfg_attr(feature = "serde", serde(rename = "BATCH_STATE_SPROUT_CANCELLED"))]
+    SproutCancelled = 8,
+}
+impl BatchState {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "BATCH_STATE_UNKNOWN",
+            Self::Pending => "BATCH_STATE_PENDING",
+            Self::Frozen => "BATCH_STATE_FROZEN",
+            Self::Committed => "BATCH_STATE_COMMITTED",
+            Self::Broadcast => "BATCH_STATE_BROADCAST",
+            Self::Confirmed => "BATCH_STATE_CONFIRMED",
+            Self::Finalized => "BATCH_STATE_FINALIZED",
+            Self::SeedlingCancelled => "BATCH_STATE_SEEDLING_CANCELLED",
+            Self::SproutCancelled => "BATCH_STATE_SPROUT_CANCELLED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BATCH_STATE_UNKNOWN" => Some(Self::Unknown),
+            "BATCH_STATE_PENDING" => Some(Self::Pending),
+            "BATCH_STATE_FROZEN" => Some(Self::Frozen),
+            "BATCH_STATE_COMMITTED" => Some(Self::Committed),
+            "BATCH_STATE_BROADCAST" => Some(Self::Broadcast),
+            "BATCH_STATE_CONFIRMED" => Some(Self::Confirmed),
+            "BATCH_STATE_FINALIZED" => Some(Self::Finalized),
+            "BATCH_STATE_SEEDLING_CANCELLED" => Some(Self::SeedlingCancelled),
+            "BATCH_STATE_SPROUT_CANCELLED" => Some(Self::SproutCancelled),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod mint_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct MintClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "transport")]
+    impl MintClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> MintClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> MintClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            MintClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// tapcli: `assets mint`
+        /// MintAsset will attempt to mint the set of assets (async by default to
+        /// ensure proper batching) specified in the request. The pending batch is
+        /// returned that shows the other pending assets that are part of the next
+        /// batch. This call will block until the operation succeeds (asset is staged
+        /// in the batch) or fails.
+        pub async fn mint_asset(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MintAssetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MintAssetResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/mintrpc.Mint/MintAsset");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("mintrpc.Mint", "MintAsset"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli `assets mint fund`
+        /// FundBatch will attempt to fund the current pending batch with a genesis
+        /// input, or create a new funded batch if no batch exists yet. This RPC is only
+        /// needed if a custom witness is needed to finalize the batch. Otherwise,
+        /// FinalizeBatch can be called directly.
+        pub async fn fund_batch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FundBatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FundBatchResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/mintrpc.Mint/FundBatch");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("mintrpc.Mint", "FundBatch"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli `assets mint seal`
+        /// SealBatch will attempt to seal the current pending batch by creating and
+        /// validating asset group witness for all assets in the batch. If a witness
+        /// is not provided, a signature will be derived to serve as the witness. This
+        /// RPC is only needed if any assets in the batch have a custom asset group key
+        /// that require an external signer. Otherwise, FinalizeBatch can be called
+        /// directly.
+        pub async fn seal_batch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SealBatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SealBatchResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/mintrpc.Mint/SealBatch");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("mintrpc.Mint", "SealBatch"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `assets mint finalize`
+        /// FinalizeBatch will attempt to finalize the current pending batch.
+        pub async fn finalize_batch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FinalizeBatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FinalizeBatchResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/mintrpc.Mint/FinalizeBatch",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("mintrpc.Mint", "FinalizeBatch"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `assets mint cancel`
+        /// CancelBatch will attempt to cancel the current pending batch.
+        pub async fn cancel_batch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CancelBatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CancelBatchResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/mintrpc.Mint/CancelBatch");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("mintrpc.Mint", "CancelBatch"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `assets mint batches`
+        /// ListBatches lists the set of batches submitted to the daemon, including
+        /// pending and cancelled batches.
+        pub async fn list_batches(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListBatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListBatchResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/mintrpc.Mint/ListBatches");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("mintrpc.Mint", "ListBatches"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `events mint`
+        /// SubscribeMintEvents allows a caller to subscribe to mint events for asset
+        /// creation batches.
+        pub async fn subscribe_mint_events(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubscribeMintEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::MintEvent>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/mintrpc.Mint/SubscribeMintEvents",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("mintrpc.Mint", "SubscribeMintEvents"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod mint_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MintServer.
+    #[async_trait]
+    pub trait Mint: std::marker::Send + std::marker::Sync + 'static {
+        /// tapcli: `assets mint`
+        /// MintAsset will attempt to mint the set of assets (async by default to
+        /// ensure proper batching) specified in the request. The pending batch is
+        /// returned that shows the other pending assets that are part of the next
+        /// batch. This call will block until the operation succeeds (asset is staged
+        /// in the batch) or fails.
+        async fn mint_asset(
+            &self,
+            request: tonic::Request<super::MintAssetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MintAssetResponse>,
+            tonic::Status,
+        >;
+        /// tapcli `assets mint fund`
+        /// FundBatch will attempt to fund the current pending batch with a genesis
+        /// input, or create a new funded batch if no batch exists yet. This RPC is only
+        /// needed if a custom witness is needed to finalize the batch. Otherwise,
+        /// FinalizeBatch can be called directly.
+        async fn fund_batch(
+            &self,
+            request: tonic::Request<super::FundBatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FundBatchResponse>,
+            tonic::Status,
+        >;
+        /// tapcli `assets mint seal`
+        /// SealBatch will attempt to seal the current pending batch by creating and
+        /// validating asset group witness for all assets in the batch. If a witness
+        /// is not provided, a signature will be derived to serve as the witness. This
+        /// RPC is only needed if any assets in the batch have a custom asset group key
+        /// that require an external signer. Otherwise, FinalizeBatch can be called
+        /// directly.
+        async fn seal_batch(
+            &self,
+            request: tonic::Request<super::SealBatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SealBatchResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `assets mint finalize`
+        /// FinalizeBatch will attempt to finalize the current pending batch.
+        async fn finalize_batch(
+            &self,
+            request: tonic::Request<super::FinalizeBatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FinalizeBatchResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `assets mint cancel`
+        /// CancelBatch will attempt to cancel the current pending batch.
+        async fn cancel_batch(
+            &self,
+            request: tonic::Request<super::CancelBatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CancelBatchResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `assets mint batches`
+        /// ListBatches lists the set of batches submitted to the daemon, including
+        /// pending and cancelled batches.
+        async fn list_batches(
+            &self,
+            request: tonic::Request<super::ListBatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListBatchResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the SubscribeMintEvents method.
+        type SubscribeMintEventsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::MintEvent, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// tapcli: `events mint`
+        /// SubscribeMintEvents allows a caller to subscribe to mint events for asset
+        /// creation batches.
+        async fn subscribe_mint_events(
+            &self,
+            request: tonic::Request<super::SubscribeMintEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::SubscribeMintEventsStream>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct MintServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> MintServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for MintServer<T>
+    where
+        T: Mint,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/mintrpc.Mint/MintAsset" => {
+                    #[allow(non_camel_case_types)]
+                    struct MintAssetSvc<T: Mint>(pub Arc<T>);
+                    impl<T: Mint> tonic::server::UnaryService<super::MintAssetRequest>
+                    for MintAssetSvc<T> {
+                        type Response = super::MintAssetResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MintAssetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Mint>::mint_asset(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = MintAssetSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/mintrpc.Mint/FundBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct FundBatchSvc<T: Mint>(pub Arc<T>);
+                    impl<T: Mint> tonic::server::UnaryService<super::FundBatchRequest>
+                    for FundBatchSvc<T> {
+                        type Response = super::FundBatchResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FundBatchRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Mint>::fund_batch(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FundBatchSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/mintrpc.Mint/SealBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct SealBatchSvc<T: Mint>(pub Arc<T>);
+                    impl<T: Mint> tonic::server::UnaryService<super::SealBatchRequest>
+                    for SealBatchSvc<T> {
+                        type Response = super::SealBatchResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SealBatchRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Mint>::seal_batch(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SealBatchSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/mintrpc.Mint/FinalizeBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct FinalizeBatchSvc<T: Mint>(pub Arc<T>);
+                    impl<
+                        T: Mint,
+                    > tonic::server::UnaryService<super::FinalizeBatchRequest>
+                    for FinalizeBatchSvc<T> {
+                        type Response = super::FinalizeBatchResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FinalizeBatchRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Mint>::finalize_batch(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FinalizeBatchSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/mintrpc.Mint/CancelBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct CancelBatchSvc<T: Mint>(pub Arc<T>);
+                    impl<T: Mint> tonic::server::UnaryService<super::CancelBatchRequest>
+                    for CancelBatchSvc<T> {
+                        type Response = super::CancelBatchResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CancelBatchRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Mint>::cancel_batch(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CancelBatchSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/mintrpc.Mint/ListBatches" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBatchesSvc<T: Mint>(pub Arc<T>);
+                    impl<T: Mint> tonic::server::UnaryService<super::ListBatchRequest>
+                    for ListBatchesSvc<T> {
+                        type Response = super::ListBatchResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListBatchRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Mint>::list_batches(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListBatchesSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/mintrpc.Mint/SubscribeMintEvents" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeMintEventsSvc<T: Mint>(pub Arc<T>);
+                    impl<
+                        T: Mint,
+                    > tonic::server::ServerStreamingService<
+                        super::SubscribeMintEventsRequest,
+                    > for SubscribeMintEventsSvc<T> {
+                        type Response = super::MintEvent;
+                        type ResponseStream = T::SubscribeMintEventsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubscribeMintEventsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Mint>::subscribe_mint_events(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SubscribeMintEventsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for MintServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "mintrpc.Mint";
+    impl<T> tonic::server::NamedService for MintServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+impl crate::mintrpc::PendingAsset {
+    /// Returns `asset_version`, or an error for values unknown to this version of the protos.
+    pub fn try_asset_version(&self) -> ::core::result::Result<crate::taprpc::AssetVersion, ::prost::UnknownEnumValue> {
+        crate::taprpc::AssetVersion::try_from(self.asset_version)
+    }
+    /// Returns `asset_type`, or an error for values unknown to this version of the protos.
+    pub fn try_asset_type(&self) -> ::core::result::Result<crate::taprpc::AssetType, ::prost::UnknownEnumValue> {
+        crate::taprpc::AssetType::try_from(self.asset_type)
+    }
+}
+impl crate::mintrpc::MintAsset {
+    /// Returns `asset_version`, or an error for values unknown to this version of the protos.
+    pub fn try_asset_version(&self) -> ::core::result::Result<crate::taprpc::AssetVersion, ::prost::UnknownEnumValue> {
+        crate::taprpc::AssetVersion::try_from(self.asset_version)
+    }
+    /// Returns `asset_type`, or an error for values unknown to this version of the protos.
+    pub fn try_asset_type(&self) -> ::core::result::Result<crate::taprpc::AssetType, ::prost::UnknownEnumValue> {
+        crate::taprpc::AssetType::try_from(self.asset_type)
+    }
+}
+impl crate::mintrpc::MintingBatch {
+    /// Returns `state`, or an error for values unknown to this version of the protos.
+    pub fn try_state(&self) -> ::core::result::Result<crate::mintrpc::BatchState, ::prost::UnknownEnumValue> {
+        crate::mintrpc::BatchState::try_from(self.state)
+    }
+}
+impl crate::mintrpc::MintEvent {
+    /// Returns `batch_state`, or an error for values unknown to this version of the protos.
+    pub fn try_batch_state(&self) -> ::core::result::Result<crate::mintrpc::BatchState, ::prost::UnknownEnumValue> {
+        crate::mintrpc::BatchState::try_from(self.batch_state)
+    }
+}
+/// Generated blocking client implementations.
+#[cfg(feature = "blocking")]
+pub mod mint_blocking_client {
+    #![allow(clippy::wildcard_imports)]
+    use tonic::codegen::*;
+    use super::mint_client::MintClient;
+    /// A [`MintClient`] that blocks the current thread on every call, driving the
+    /// requests on its runtime.
+    #[derive(Debug, Clone)]
+    pub struct MintBlockingClient<T> {
+        inner: MintClient<T>,
+        runtime: std::sync::Arc<crate::blocking::Runtime>,
+    }
+    impl<T> MintBlockingClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        /// Wraps `inner`, running its calls on `runtime`.
+        pub fn new(inner: MintClient<T>, runtime: std::sync::Arc<crate::blocking::Runtime>) -> Self {
+            Self { inner, runtime }
+        }
+        /// Returns the async client.
+        pub fn into_inner(self) -> MintClient<T> {
+            self.inner
+        }
+        /// Blocking version of [`MintClient::mint_asset`].
+        pub fn mint_asset(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::mintrpc::MintAssetRequest>,
+        ) -> std::result::Result<tonic::Response<crate::mintrpc::MintAssetResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.mint_asset(request))
+        }
+        /// Blocking version of [`MintClient::fund_batch`].
+        pub fn fund_batch(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::mintrpc::FundBatchRequest>,
+        ) -> std::result::Result<tonic::Response<crate::mintrpc::FundBatchResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.fund_batch(request))
+        }
+        /// Blocking version of [`MintClient::seal_batch`].
+        pub fn seal_batch(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::mintrpc::SealBatchRequest>,
+        ) -> std::result::Result<tonic::Response<crate::mintrpc::SealBatchResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.seal_batch(request))
+        }
+        /// Blocking version of [`MintClient::finalize_batch`].
+        pub fn finalize_batch(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::mintrpc::FinalizeBatchRequest>,
+        ) -> std::result::Result<tonic::Response<crate::mintrpc::FinalizeBatchResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.finalize_batch(request))
+        }
+        /// Blocking version of [`MintClient::cancel_batch`].
+        pub fn cancel_batch(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::mintrpc::CancelBatchRequest>,
+        ) -> std::result::Result<tonic::Response<crate::mintrpc::CancelBatchResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.cancel_batch(request))
+        }
+        /// Blocking version of [`MintClient::list_batches`].
+        pub fn list_batches(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::mintrpc::ListBatchRequest>,
+        ) -> std::result::Result<tonic::Response<crate::mintrpc::ListBatchResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.list_batches(request))
+        }
+        /// Blocking version of [`MintClient::subscribe_mint_events`].
+        pub fn subscribe_mint_events(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::mintrpc::SubscribeMintEventsRequest>,
+        ) -> std::result::Result<tonic::Response<crate::blocking::Streaming<crate::mintrpc::MintEvent>>, tonic::Status> {
+            let response = self.runtime.block_on(self.inner.subscribe_mint_events(request))?;
+            Ok(response.map(|stream| crate::blocking::Streaming::new(stream, self.runtime.clone())))
+        }
+    }
+}
@@ -0,0 +1,517 @@
+// This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct UpdateAddressAction {
+    /// Determines the kind of action.
+    #[prost(enumeration = "UpdateAction", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::peersrpc::UpdateAction>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::peersrpc::UpdateAction>, _, _>"
+        )
+    )]
+    pub action: i32,
+    /// The address used to apply the update action.
+    #[prost(string, tag = "2")]
+    pub address: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct UpdateFeatureAction {
+    /// Determines the kind of action.
+    #[prost(enumeration = "UpdateAction", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::peersrpc::UpdateAction>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::peersrpc::UpdateAction>, _, _>"
+        )
+    )]
+    pub action: i32,
+    /// The feature bit used to apply the update action.
+    #[prost(enumeration = "super::lnrpc::FeatureBit", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::lnrpc::FeatureBit>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::lnrpc::FeatureBit>, _, _>"
+        )
+    )]
+    pub feature_bit: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodeAnnouncementUpdateRequest {
+    /// Set of changes for the features that the node supports.
+    #[prost(message, repeated, tag = "1")]
+    pub feature_updates: ::prost::alloc::vec::Vec<UpdateFeatureAction>,
+    /// Color is the node's color in hex code format.
+    #[prost(string, tag = "2")]
+    pub color: ::prost::alloc::string::String,
+    /// Alias or nick name of the node.
+    #[prost(string, tag = "3")]
+    pub alias: ::prost::alloc::string::String,
+    /// Set of changes for the node's known addresses.
+    #[prost(message, repeated, tag = "4")]
+    pub address_updates: ::prost::alloc::vec::Vec<UpdateAddressAction>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodeAnnouncementUpdateResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub ops: ::prost::alloc::vec::Vec<super::lnrpc::Op>,
+}
+/// UpdateAction is used to determine the kind of action we are referring to.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum UpdateAction {
+    /// ADD indicates this is an "insertion" kind of action.
+    #[cfg_attr(feature = "serde", serde(rename = "ADD"))]
+    Add = 0,
+    /// REMOVE indicates this is a "deletion" kind of action.
+    #[cfg_attr(feature = "serde", serde(rename = "REMOVE"))]
+    Remove = 1,
+}
+impl UpdateAction {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Add => "ADD",
+            Self::Remove => "REMOVE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ADD" => Some(Self::Add),
+            "REMOVE" => Some(Self::Remove),
+            _ => None,
+        }
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum FeatureSet {
+    /// SET_INIT identifies features that should be sent in an Init message to
+    /// a remote peer.
+    #[cfg_attr(feature = "serde", serde(rename = "SET_INIT"))]
+    SetInit = 0,
+    /// SET_LEGACY_GLOBAL identifies features that should be set in the legacy
+    /// GlobalFeatures field of an Init message, which maintains backwards
+    /// compatibility with nodes that haven't implemented flat features.
+    #[cfg_attr(feature = "serde", serde(rename = "SET_LEGACY_GLOBAL"))]
+    SetLegacyGlobal = 1,
+    /// SET_NODE_ANN identifies features that should be advertised on node
+    /// announcements.
+    #[cfg_attr(feature = "serde", serde(rename = "SET_NODE_ANN"))]
+    SetNodeAnn = 2,
+    /// SET_INVOICE identifies features that should be advertised on invoices
+    /// generated by the daemon.
+    #[cfg_attr(feature = "serde", serde(rename = "SET_INVOICE"))]
+    SetInvoice = 3,
+    /// SET_INVOICE_AMP identifies the features that should be advertised on
+    /// AMP invoices generated by the daemon.
+    #[cfg_attr(feature = "serde", serde(rename = "SET_INVOICE_AMP"))]
+    SetInvoiceAmp = 4,
+}
+impl FeatureSet {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::SetInit => "SET_INIT",
+            Self::SetLegacyGlobal => "SET_LEGACY_GLOBAL",
+            Self::SetNodeAnn => "SET_NODE_ANN",
+            Self::SetInvoice => "SET_INVOICE",
+            Self::SetInvoiceAmp => "SET_INVOICE_AMP",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SET_INIT" => Some(Self::SetInit),
+            "SET_LEGACY_GLOBAL" => Some(Self::SetLegacyGlobal),
+            "SET_NODE_ANN" => Some(Self::SetNodeAnn),
+            "SET_INVOICE" => Some(Self::SetInvoice),
+            "SET_INVOICE_AMP" => Some(Self::SetInvoiceAmp),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod peers_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Peers is a service that can be used to get information and interact
+    /// with the other nodes of the network.
+    #[derive(Debug, Clone)]
+    pub struct PeersClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "transport")]
+    impl PeersClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> PeersClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> PeersClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            PeersClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// lncli: peers updatenodeannouncement
+        /// UpdateNodeAnnouncement allows the caller to update the node parameters
+        /// and broadcasts a new version of the node announcement to its peers.
+        pub async fn update_node_announcement(
+            &mut self,
+            request: impl tonic::IntoRequest<super::NodeAnnouncementUpdateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::NodeAnnouncementUpdateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/peersrpc.Peers/UpdateNodeAnnouncement",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("peersrpc.Peers", "UpdateNodeAnnouncement"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod peers_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with PeersServer.
+    #[async_trait]
+    pub trait Peers: std::marker::Send + std::marker::Sync + 'static {
+        /// lncli: peers updatenodeannouncement
+        /// UpdateNodeAnnouncement allows the caller to update the node parameters
+        /// and broadcasts a new version of the node announcement to its peers.
+        async fn update_node_announcement(
+            &self,
+            request: tonic::Request<super::NodeAnnouncementUpdateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::NodeAnnouncementUpdateResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Peers is a service that can be used to get information and interact
+    /// with the other nodes of the network.
+    #[derive(Debug)]
+    pub struct PeersServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> PeersServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for PeersServer<T>
+    where
+        T: Peers,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/peersrpc.Peers/UpdateNodeAnnouncement" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateNodeAnnouncementSvc<T: Peers>(pub Arc<T>);
+                    impl<
+                        T: Peers,
+                    > tonic::server::UnaryService<super::NodeAnnouncementUpdateRequest>
+                    for UpdateNodeAnnouncementSvc<T> {
+                        type Response = super::NodeAnnouncementUpdateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::NodeAnnouncementUpdateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Peers>::update_node_announcement(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UpdateNodeAnnouncementSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for PeersServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "peersrpc.Peers";
+    impl<T> tonic::server::NamedService for PeersServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+impl crate::peersrpc::UpdateAddressAction {
+    /// Returns `action`, or an error for values unknown to this version of the protos.
+    pub fn try_action(&self) -> ::core::result::Result<crate::peersrpc::UpdateAction, ::prost::UnknownEnumValue> {
+        crate::peersrpc::UpdateAction::try_from(self.action)
+    }
+}
+impl crate::peersrpc::UpdateFeatureAction {
+    /// Returns `action`, or an error for values unknown to this version of the protos.
+    pub fn try_action(&self) -> ::core::result::Result<crate::peersrpc::UpdateAction, ::prost::UnknownEnumValue> {
+        crate::peersrpc::UpdateAction::try_from(self.action)
+    }
+    /// Returns `feature_bit`, or an error for values unknown to this version of the protos.
+    pub fn try_feature_bit(&self) -> ::core::result::Result<crate::lnrpc::FeatureBit, ::prost::UnknownEnumValue> {
+        crate::lnrpc::FeatureBit::try_from(self.feature_bit)
+    }
+}
+/// Generated blocking client implementations.
+#[cfg(feature = "blocking")]
+pub mod peers_blocking_client {
+    #![allow(clippy::wildcard_imports)]
+    use tonic::codegen::*;
+    use super::peers_client::PeersClient;
+    /// A [`PeersClient`] that blocks the current thread on every call, driving the
+    /// requests on its runtime.
+    #[derive(Debug, Clone)]
+    pub struct PeersBlockingClient<T> {
+        inner: PeersClient<T>,
+        runtime: std::sync::Arc<crate::blocking::Runtime>,
+    }
+    impl<T> PeersBlockingClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        /// Wraps `inner`, running its calls on `runtime`.
+        pub fn new(inner: PeersClient<T>, runtime: std::sync::Arc<crate::blocking::Runtime>) -> Self {
+            Self { inner, runtime }
+        }
+        /// Returns the async client.
+        pub fn into_inner(self) -> PeersClient<T> {
+            self.inner
+        }
+        /// Blocking version of [`PeersClient::update_node_announcement`].
+        pub fn update_node_announcement(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::peersrpc::NodeAnnouncementUpdateRequest>,
+        ) -> std::result::Result<tonic::Response<crate::peersrpc::NodeAnnouncementUpdateResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.update_node_announcement(request))
+        }
+    }
+}
@@ -0,0 +1,609 @@
+// This file is @generated by prost-build.
+/// FixedPoint is a scaled integer representation of a fractional number.
+///
+/// This type consists of two integer fields: a coefficient and a scale.
+/// Using this format enables precise and consistent representation of fractional
+/// numbers while avoiding floating-point data types, which are prone to
+/// precision errors.
+///
+/// The relationship between the fractional representation and its fixed-point
+/// representation is expressed as:
+///
+/// ```text,
+/// V = F_c / (10^F_s)
+/// ```
+///
+/// where:
+///
+/// * `V` is the fractional value.
+///
+/// * `F_c` is the coefficient component of the fixed-point representation. It is
+///   the scaled-up fractional value represented as an integer.
+///
+/// * `F_s` is the scale component. It is an integer specifying how
+///   many decimal places `F_c` should be divided by to obtain the fractional
+///   representation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FixedPoint {
+    /// The coefficient is the fractional value scaled-up as an integer. This
+    /// integer is represented as a string as it may be too large to fit in a
+    /// uint64.
+    #[prost(string, tag = "1")]
+    pub coefficient: ::prost::alloc::string::String,
+    /// The scale is the component that determines how many decimal places
+    /// the coefficient should be divided by to obtain the fractional value.
+    #[prost(uint32, tag = "2")]
+    pub scale: u32,
+}
+/// AssetRates represents the exchange rates for subject and payment assets
+/// relative to BTC, expressed as fixed-point numbers. It includes the rates
+/// for both assets and an expiration timestamp indicating when the rates
+/// are no longer valid.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AssetRates {
+    /// subjectAssetRate is the number of subject asset units per BTC represented
+    /// as a fixed-point number. This field is also commonly referred to as the
+    /// subject asset to BTC (conversion) rate. When the subject asset is BTC,
+    /// this field should be set to 100 billion, as one BTC is equivalent to 100
+    /// billion msats.
+    #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(rename = "subjectAssetRate"))]
+    pub subject_asset_rate: ::core::option::Option<FixedPoint>,
+    /// paymentAssetRate is the number of payment asset units per BTC represented
+    /// as a fixed-point number. This field is also commonly referred to as the
+    /// payment asset to BTC (conversion) rate. When the payment asset is BTC,
+    /// this field should be set to 100 billion, as one BTC is equivalent to 100
+    /// billion msats.
+    #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(rename = "paymentAssetRate"))]
+    pub payment_asset_rate: ::core::option::Option<FixedPoint>,
+    /// expiry_timestamp is the Unix timestamp in seconds after which the asset
+    /// rates are no longer valid.
+    #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub expiry_timestamp: u64,
+}
+/// AssetSpecifier is a union type for specifying an asset by either its asset ID
+/// or group key.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AssetSpecifier {
+    #[prost(oneof = "asset_specifier::Id", tags = "1, 2, 3, 4")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub id: ::core::option::Option<asset_specifier::Id>,
+}
+/// Nested message and enum types in `AssetSpecifier`.
+pub mod asset_specifier {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Id {
+        /// The 32-byte asset ID specified as raw bytes (gRPC only).
+        #[prost(bytes, tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                rename = "asset_id",
+                serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+                deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+            )
+        )]
+        AssetId(::prost::alloc::vec::Vec<u8>),
+        /// The 32-byte asset ID encoded as a hex string (use this for REST).
+        #[prost(string, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "asset_id_str"))]
+        AssetIdStr(::prost::alloc::string::String),
+        /// The 32-byte asset group key specified as raw bytes (gRPC only).
+        #[prost(bytes, tag = "3")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                rename = "group_key",
+                serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+                deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+            )
+        )]
+        GroupKey(::prost::alloc::vec::Vec<u8>),
+        /// The 32-byte asset group key encoded as hex string (use this for
+        /// REST).
+        #[prost(string, tag = "4")]
+        #[cfg_attr(feature = "serde", serde(rename = "group_key_str"))]
+        GroupKeyStr(::prost::alloc::string::String),
+    }
+}
+/// QueryAssetRatesRequest specifies the parameters for querying asset exchange
+/// rates in a transaction. It includes the transaction type, details about the
+/// subject and payment assets, and an optional hint for expected asset rates.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QueryAssetRatesRequest {
+    /// transaction_type indicates whether the transaction is a purchase or a
+    /// sale.
+    #[prost(enumeration = "TransactionType", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::priceoraclerpc::TransactionType>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::priceoraclerpc::TransactionType>, _, _>"
+        )
+    )]
+    pub transaction_type: i32,
+    /// subject_asset is the asset to be priced for purchase or sale.
+    #[prost(message, optional, tag = "2")]
+    pub subject_asset: ::core::option::Option<AssetSpecifier>,
+    /// subject_asset_max_amount is the maximum amount of the subject asset that
+    /// could be involved in the transaction.
+    #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub subject_asset_max_amount: u64,
+    /// payment_asset is the asset used for purchasing or receiving from a sale.
+    ///
+    /// NOTE: An asset ID of all zeros indicates that the payment asset is BTC.
+    /// In this case, the asset rate will be given as milli-satoshi per asset
+    /// unit
+    #[prost(message, optional, tag = "4")]
+    pub payment_asset: ::core::option::Option<AssetSpecifier>,
+    /// payment_asset_max_amount is the maximum amount of the payment asset that
+    /// could be involved in the transaction. This field is optional. If set to
+    /// zero, it is considered unset.
+    #[prost(uint64, tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub payment_asset_max_amount: u64,
+    /// asset_rates_hint is an optional suggestion of asset rates for the
+    /// transaction, intended to provide guidance on expected pricing.
+    #[prost(message, optional, tag = "6")]
+    pub asset_rates_hint: ::core::option::Option<AssetRates>,
+}
+/// QueryAssetRatesOkResponse is the successful response to a
+/// QueryAssetRates call.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QueryAssetRatesOkResponse {
+    /// asset_rates is the asset exchange rates for the transaction.
+    #[prost(message, optional, tag = "1")]
+    pub asset_rates: ::core::option::Option<AssetRates>,
+}
+/// QueryAssetRatesErrResponse is the error response to a QueryAssetRates call.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QueryAssetRatesErrResponse {
+    /// error is the error message.
+    #[prost(string, tag = "1")]
+    pub message: ::prost::alloc::string::String,
+    /// code is the error code.
+    #[prost(uint32, tag = "2")]
+    pub code: u32,
+}
+/// QueryAssetRatesResponse is the response from a QueryAssetRates RPC call.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QueryAssetRatesResponse {
+    #[prost(oneof = "query_asset_rates_response::Result", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub result: ::core::option::Option<query_asset_rates_response::Result>,
+}
+/// Nested message and enum types in `QueryAssetRatesResponse`.
+pub mod query_asset_rates_response {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Result {
+        /// ok is the successful response to the query.
+        #[prost(message, tag = "1")]
+        #[cfg_attr(feature = "serde", serde(rename = "ok"))]
+        Ok(super::QueryAssetRatesOkResponse),
+        /// error is the error response to the query.
+        #[prost(message, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "error"))]
+        Error(super::QueryAssetRatesErrResponse),
+    }
+}
+/// TransactionType is an enum representing the type of transaction.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TransactionType {
+    /// PURCHASE indicates a purchase transaction.
+    #[cfg_attr(feature = "serde", serde(rename = "PURCHASE"))]
+    Purchase = 0,
+    /// SALE indicates a sale transaction.
+    #[cfg_attr(feature = "serde", serde(rename = "SALE"))]
+    Sale = 1,
+}
+impl TransactionType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Purchase => "PURCHASE",
+            Self::Sale => "SALE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PURCHASE" => Some(Self::Purchase),
+            "SALE" => Some(Self::Sale),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod price_oracle_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct PriceOracleClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "transport")]
+    impl PriceOracleClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> PriceOracleClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> PriceOracleClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            PriceOracleClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// QueryAssetRates retrieves the exchange rate between a tap asset and BTC for
+        /// a specified transaction type, subject asset, and payment asset. The asset
+        /// rate represents the number of tap asset units per BTC.
+        pub async fn query_asset_rates(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryAssetRatesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryAssetRatesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/priceoraclerpc.PriceOracle/QueryAssetRates",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("priceoraclerpc.PriceOracle", "QueryAssetRates"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod price_oracle_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with PriceOracleServer.
+    #[async_trait]
+    pub trait PriceOracle: std::marker::Send + std::marker::Sync + 'static {
+        /// QueryAssetRates retrieves the exchange rate between a tap asset and BTC for
+        /// a specified transaction type, subject asset, and payment asset. The asset
+        /// rate represents the number of tap asset units per BTC.
+        async fn query_asset_rates(
+            &self,
+            request: tonic::Request<super::QueryAssetRatesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryAssetRatesResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct PriceOracleServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> PriceOracleServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for PriceOracleServer<T>
+    where
+        T: PriceOracle,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/priceoraclerpc.PriceOracle/QueryAssetRates" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryAssetRatesSvc<T: PriceOracle>(pub Arc<T>);
+                    impl<
+                        T: PriceOracle,
+                    > tonic::server::UnaryService<super::QueryAssetRatesRequest>
+                    for QueryAssetRatesSvc<T> {
+                        type Response = super::QueryAssetRatesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryAssetRatesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PriceOracle>::query_asset_rates(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryAssetRatesSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for PriceOracleServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "priceoraclerpc.PriceOracle";
+    impl<T> tonic::server::NamedService for PriceOracleServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+impl crate::priceoraclerpc::QueryAssetRatesRequest {
+    /// Returns `transaction_type`, or an error for values unknown to this version of the protos.
+    pub fn try_transaction_type(&self) -> ::core::result::Result<crate::priceoraclerpc::TransactionType, ::prost::UnknownEnumValue> {
+        crate::priceoraclerpc::TransactionType::try_from(self.transaction_type)
+    }
+}
+/// Generated blocking client implementations.
+#[cfg(feature = "blocking")]
+pub mod price_oracle_blocking_client {
+    #![allow(clippy::wildcard_imports)]
+    use tonic::codegen::*;
+    use super::price_oracle_client::PriceOracleClient;
+    /// A [`PriceOracleClient`] that blocks the current thread on every call, driving the
+    /// requests on its runtime.
+    #[derive(Debug, Clone)]
+    pub struct PriceOracleBlockingClient<T> {
+        inner: PriceOracleClient<T>,
+        runtime: std::sync::Arc<crate::blocking::Runtime>,
+    }
+    impl<T> PriceOracleBlockingClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        /// Wraps `inner`, running its calls on `runtime`.
+        pub fn new(inner: PriceOracleClient<T>, runtime: std::sync::Arc<crate::blocking::Runtime>) -> Self {
+            Self { inner, runtime }
+        }
+        /// Returns the async client.
+        pub fn into_inner(self) -> PriceOracleClient<T> {
+            self.inner
+        }
+        /// Blocking version of [`PriceOracleClient::query_asset_rates`].
+        pub fn query_asset_rates(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::priceoraclerpc::QueryAssetRatesRequest>,
+        ) -> std::result::Result<tonic::Response<crate::priceoraclerpc::QueryAssetRatesResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.query_asset_rates(request))
+        }
+    }
+}
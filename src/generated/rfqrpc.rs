@@ -0,0 +1,1485 @@
+// This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AssetSpecifier {
+    #[prost(oneof = "asset_specifier::Id", tags = "1, 2, 3, 4")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub id: ::core::option::Option<asset_specifier::Id>,
+}
+/// Nested message and enum types in `AssetSpecifier`.
+pub mod asset_specifier {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Id {
+        /// The 32-byte asset ID specified as raw bytes (gRPC only).
+        #[prost(bytes, tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                rename = "asset_id",
+                serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+                deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+            )
+        )]
+        AssetId(::prost::alloc::vec::Vec<u8>),
+        /// The 32-byte asset ID encoded as a hex string (use this for REST).
+        #[prost(string, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "asset_id_str"))]
+        AssetIdStr(::prost::alloc::string::String),
+        /// The 32-byte asset group key specified as raw bytes (gRPC only).
+        #[prost(bytes, tag = "3")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                rename = "group_key",
+                serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+                deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+            )
+        )]
+        GroupKey(::prost::alloc::vec::Vec<u8>),
+        /// The 32-byte asset group key encoded as hex string (use this for
+        /// REST).
+        #[prost(string, tag = "4")]
+        #[cfg_attr(feature = "serde", serde(rename = "group_key_str"))]
+        GroupKeyStr(::prost::alloc::string::String),
+    }
+}
+/// FixedPoint is a scaled integer representation of a fractional number.
+///
+/// This type consists of two integer fields: a coefficient and a scale.
+/// Using this format enables precise and consistent representation of fractional
+/// numbers while avoiding floating-point data types, which are prone to
+/// precision errors.
+///
+/// The relationship between the fractional representation and its fixed-point
+/// representation is expressed as:
+///
+/// ```text,
+/// V = F_c / (10^F_s)
+/// ```
+///
+/// where:
+///
+/// * `V` is the fractional value.
+///
+/// * `F_c` is the coefficient component of the fixed-point representation. It is
+///   the scaled-up fractional value represented as an integer.
+///
+/// * `F_s` is the scale component. It is an integer specifying how
+///   many decimal places `F_c` should be divided by to obtain the fractional
+///   representation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FixedPoint {
+    /// The coefficient is the fractional value scaled-up as an integer. This
+    /// integer is represented as a string as it may be too large to fit in a
+    /// uint64.
+    #[prost(string, tag = "1")]
+    pub coefficient: ::prost::alloc::string::String,
+    /// The scale is the component that determines how many decimal places
+    /// the coefficient should be divided by to obtain the fractional value.
+    #[prost(uint32, tag = "2")]
+    pub scale: u32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AddAssetBuyOrderRequest {
+    /// asset_specifier is the subject asset.
+    #[prost(message, optional, tag = "1")]
+    pub asset_specifier: ::core::option::Option<AssetSpecifier>,
+    /// The maximum amount of the asset that the provider must be willing to
+    /// offer.
+    #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub asset_max_amt: u64,
+    /// The unix timestamp in seconds after which the order is no longer valid.
+    #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub expiry: u64,
+    /// peer_pub_key is an optional field for specifying the public key of the
+    /// intended recipient peer for the order.
+    #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub peer_pub_key: ::prost::alloc::vec::Vec<u8>,
+    /// timeout_seconds is the number of seconds to wait for the peer to respond
+    /// with an accepted quote (or a rejection).
+    #[prost(uint32, tag = "5")]
+    pub timeout_seconds: u32,
+    /// If set, the check if a channel with the given asset exists with the peer
+    /// will be skipped. An active channel with the peer is still required for
+    /// the RFQ negotiation to work. This flag shouldn't be set outside of test
+    /// scenarios.
+    #[prost(bool, tag = "6")]
+    pub skip_asset_channel_check: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AddAssetBuyOrderResponse {
+    #[prost(oneof = "add_asset_buy_order_response::Response", tags = "1, 2, 3")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub response: ::core::option::Option<add_asset_buy_order_response::Response>,
+}
+/// Nested message and enum types in `AddAssetBuyOrderResponse`.
+pub mod add_asset_buy_order_response {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Response {
+        /// accepted_quote holds the quote received from the peer as a response
+        /// to our quote request.
+        #[prost(message, tag = "1")]
+        #[cfg_attr(feature = "serde", serde(rename = "accepted_quote"))]
+        AcceptedQuote(super::PeerAcceptedBuyQuote),
+        /// invalid_quote is returned if the quote response received from the
+        /// peer was invalid or insufficient.
+        #[prost(message, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "invalid_quote"))]
+        InvalidQuote(super::InvalidQuoteResponse),
+        /// rejected_quote is returned if the quote request was rejected by the
+        /// peer.
+        #[prost(message, tag = "3")]
+        #[cfg_attr(feature = "serde", serde(rename = "rejected_quote"))]
+        RejectedQuote(super::RejectedQuoteResponse),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AddAssetSellOrderRequest {
+    /// asset_specifier is the subject asset.
+    #[prost(message, optional, tag = "1")]
+    pub asset_specifier: ::core::option::Option<AssetSpecifier>,
+    /// The maximum msat amount that the responding peer must agree to pay
+    /// (units: millisats).
+    #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub payment_max_amt: u64,
+    /// The unix timestamp in seconds after which the order is no longer valid.
+    #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub expiry: u64,
+    /// peer_pub_key is an optional field for specifying the public key of the
+    /// intended recipient peer for the order.
+    #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub peer_pub_key: ::prost::alloc::vec::Vec<u8>,
+    /// timeout_seconds is the number of seconds to wait for the peer to respond
+    /// with an accepted quote (or a rejection).
+    #[prost(uint32, tag = "5")]
+    pub timeout_seconds: u32,
+    /// If set, the check if a channel with the given asset exists with the peer
+    /// will be skipped. An active channel with the peer is still required for
+    /// the RFQ negotiation to work. This flag shouldn't be set outside of test
+    /// scenarios.
+    #[prost(bool, tag = "6")]
+    pub skip_asset_channel_check: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AddAssetSellOrderResponse {
+    #[prost(oneof = "add_asset_sell_order_response::Response", tags = "1, 2, 3")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub response: ::core::option::Option<add_asset_sell_order_response::Response>,
+}
+/// Nested message and enum types in `AddAssetSellOrderResponse`.
+pub mod add_asset_sell_order_response {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Response {
+        /// accepted_quote holds the quote received from the peer as a response
+        /// to our quote request.
+        #[prost(message, tag = "1")]
+        #[cfg_attr(feature = "serde", serde(rename = "accepted_quote"))]
+        AcceptedQuote(super::PeerAcceptedSellQuote),
+        /// invalid_quote is returned if the quote response received from the
+        /// peer was invalid or insufficient.
+        #[prost(message, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "invalid_quote"))]
+        InvalidQuote(super::InvalidQuoteResponse),
+        /// rejected_quote is returned if the quote request was rejected by the
+        /// peer.
+        #[prost(message, tag = "3")]
+        #[cfg_attr(feature = "serde", serde(rename = "rejected_quote"))]
+        RejectedQuote(super::RejectedQuoteResponse),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AddAssetSellOfferRequest {
+    /// asset_specifier is the subject asset.
+    #[prost(message, optional, tag = "1")]
+    pub asset_specifier: ::core::option::Option<AssetSpecifier>,
+    /// max_units is the maximum amount of the asset to sell.
+    #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub max_units: u64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AddAssetSellOfferResponse {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AddAssetBuyOfferRequest {
+    /// asset_specifier is the subject asset.
+    #[prost(message, optional, tag = "1")]
+    pub asset_specifier: ::core::option::Option<AssetSpecifier>,
+    /// max_units is the maximum amount of the asset to buy.
+    #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub max_units: u64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AddAssetBuyOfferResponse {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QueryPeerAcceptedQuotesRequest {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PeerAcceptedBuyQuote {
+    /// Quote counterparty peer.
+    #[prost(string, tag = "1")]
+    pub peer: ::prost::alloc::string::String,
+    /// The unique identifier of the quote request.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub id: ::prost::alloc::vec::Vec<u8>,
+    /// The short channel ID of the channel over which the payment for the quote
+    /// should be made.
+    #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub scid: u64,
+    /// The maximum exchange amount denoted in the subject asset. This includes
+    /// the user-configured maximum routing fees, so the actual payment amount
+    /// will be less than this. This just defines the maximum volume that the
+    /// edge node has accepted to divest with the given rate.
+    #[prost(uint64, tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub asset_max_amount: u64,
+    /// ask_asset_rate is the asset to BTC conversion rate represented as a
+    /// fixed-point number.
+    #[prost(message, optional, tag = "5")]
+    pub ask_asset_rate: ::core::option::Option<FixedPoint>,
+    /// The unix timestamp in seconds after which the quote is no longer valid.
+    #[prost(uint64, tag = "6")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub expiry: u64,
+    /// The smallest amount of asset units that can be transported within a
+    /// single HTLC over the Lightning Network with the given rate. This is the
+    /// asset unit equivalent of 354 satoshis, which is the minimum amount for an
+    /// HTLC to be above the dust limit.
+    #[prost(uint64, tag = "7")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub min_transportable_units: u64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PeerAcceptedSellQuote {
+    /// Quote counterparty peer.
+    #[prost(string, tag = "1")]
+    pub peer: ::prost::alloc::string::String,
+    /// The unique identifier of the quote request.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub id: ::prost::alloc::vec::Vec<u8>,
+    /// scid is the short channel ID of the channel over which the payment for
+    /// the quote should be made.
+    #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub scid: u64,
+    /// asset_amount is the amount of the subject asset.
+    #[prost(uint64, tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub asset_amount: u64,
+    /// bid_asset_rate is the asset to BTC conversion rate represented as a
+    /// fixed-point number.
+    #[prost(message, optional, tag = "5")]
+    pub bid_asset_rate: ::core::option::Option<FixedPoint>,
+    /// The unix timestamp in seconds after which the quote is no longer valid.
+    #[prost(uint64, tag = "6")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub expiry: u64,
+    /// The minimum amount of milli-satoshis that need to be sent out in order to
+    /// transport a single asset unit over the Lightning Network with the given
+    /// rate. This is the base amount of 354,000 milli-satoshi (the minimum
+    /// amount for a non-dust HTLC) plus the equivalent of one asset unit in
+    /// milli-satoshis.
+    #[prost(uint64, tag = "7")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub min_transportable_msat: u64,
+}
+/// InvalidQuoteResponse is a message that is returned when a quote response is
+/// invalid or insufficient.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct InvalidQuoteResponse {
+    /// status is the status of the quote response.
+    #[prost(enumeration = "QuoteRespStatus", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::rfqrpc::QuoteRespStatus>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::rfqrpc::QuoteRespStatus>, _, _>"
+        )
+    )]
+    pub status: i32,
+    /// peer is the quote counterparty peer.
+    #[prost(string, tag = "2")]
+    pub peer: ::prost::alloc::string::String,
+    /// id is the unique identifier of the quote request.
+    #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub id: ::prost::alloc::vec::Vec<u8>,
+}
+/// RejectedQuoteResponse is a message that is returned when a quote request is
+/// rejected by the peer.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RejectedQuoteResponse {
+    /// peer is the quote counterparty peer.
+    #[prost(string, tag = "1")]
+    pub peer: ::prost::alloc::string::String,
+    /// id is the unique identifier of the quote request.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub id: ::prost::alloc::vec::Vec<u8>,
+    /// error_message is a human-readable error message.
+    #[prost(string, tag = "3")]
+    pub error_message: ::prost::alloc::string::String,
+    /// error_code is a machine-readable error code.
+    #[prost(uint32, tag = "4")]
+    pub error_code: u32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryPeerAcceptedQuotesResponse {
+    /// buy_quotes is a list of asset buy quotes which were requested by our
+    /// node and have been accepted by our peers.
+    #[prost(message, repeated, tag = "1")]
+    pub buy_quotes: ::prost::alloc::vec::Vec<PeerAcceptedBuyQuote>,
+    /// sell_quotes is a list of asset sell quotes which were requested by our
+    /// node and have been accepted by our peers.
+    #[prost(message, repeated, tag = "2")]
+    pub sell_quotes: ::prost::alloc::vec::Vec<PeerAcceptedSellQuote>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SubscribeRfqEventNtfnsRequest {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PeerAcceptedBuyQuoteEvent {
+    /// Unix timestamp in microseconds.
+    #[prost(uint64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub timestamp: u64,
+    /// The asset buy quote that was accepted by out peer.
+    #[prost(message, optional, tag = "2")]
+    pub peer_accepted_buy_quote: ::core::option::Option<PeerAcceptedBuyQuote>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PeerAcceptedSellQuoteEvent {
+    /// Unix timestamp in microseconds.
+    #[prost(uint64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub timestamp: u64,
+    /// The asset sell quote that was accepted by out peer.
+    #[prost(message, optional, tag = "2")]
+    pub peer_accepted_sell_quote: ::core::option::Option<PeerAcceptedSellQuote>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AcceptHtlcEvent {
+    /// Unix timestamp in microseconds.
+    #[prost(uint64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub timestamp: u64,
+    /// scid is the short channel ID of the channel over which the payment for
+    /// the quote is made.
+    #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub scid: u64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RfqEvent {
+    #[prost(oneof = "rfq_event::Event", tags = "1, 2, 3")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub event: ::core::option::Option<rfq_event::Event>,
+}
+/// Nested message and enum types in `RfqEvent`.
+pub mod rfq_event {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Event {
+        /// peer_accepted_buy_quote is an event that is emitted when a peer
+        /// accepted (incoming) asset buy quote message is received.
+        #[prost(message, tag = "1")]
+        #[cfg_attr(feature = "serde", serde(rename = "peer_accepted_buy_quote"))]
+        PeerAcceptedBuyQuote(super::PeerAcceptedBuyQuoteEvent),
+        /// peer_accepted_sell_offer is an event that is emitted when a peer
+        /// accepted (incoming) asset sell quote message is received.
+        #[prost(message, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "peer_accepted_sell_quote"))]
+        PeerAcceptedSellQuote(super::PeerAcceptedSellQuoteEvent),
+        /// accept_htlc is an event that is sent when a HTLC is accepted by the
+        /// RFQ service.
+        #[prost(message, tag = "3")]
+        #[cfg_attr(feature = "serde", serde(rename = "accept_htlc"))]
+        AcceptHtlc(super::AcceptHtlcEvent),
+    }
+}
+/// QuoteRespStatus is an enum that represents the status of a quote response.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum QuoteRespStatus {
+    /// INVALID_ASSET_RATES indicates that at least one asset rate in the
+    /// quote response is invalid.
+    #[cfg_attr(feature = "serde", serde(rename = "INVALID_ASSET_RATES"))]
+    InvalidAssetRates = 0,
+    /// INVALID_EXPIRY indicates that the expiry in the quote response is
+    /// invalid.
+    #[cfg_attr(feature = "serde", serde(rename = "INVALID_EXPIRY"))]
+    InvalidExpiry = 1,
+    /// PRICE_ORACLE_QUERY_ERR indicates that an error occurred when querying the
+    /// price oracle whilst evaluating the quote response.
+    #[cfg_attr(feature = "serde", serde(rename = "PRICE_ORACLE_QUERY_ERR"))]
+    PriceOracleQueryErr = 2,
+}
+impl QuoteRespStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::InvalidAssetRates => "INVALID_ASSET_RATES",
+            Self::InvalidExpiry => "INVALID_EXPIRY",
+            Self::PriceOracleQueryErr => "PRICE_ORACLE_QUERY_ERR",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "INVALID_ASSET_RATES" => Some(Self::InvalidAssetRates),
+            "INVALID_EXPIRY" => Some(Self::InvalidExpiry),
+            "PRICE_ORACLE_QUERY_ERR" => Some(Self::PriceOracleQueryErr),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod rfq_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct RfqClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "transport")]
+    impl RfqClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> RfqClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> RfqClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            RfqClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// tapcli: `rfq buyorder`
+        /// AddAssetBuyOrder is used to add a buy order for a specific asset. If a buy
+        /// order already exists for the asset, it will be updated.
+        ///
+        /// A buy order instructs the RFQ (Request For Quote) system to request a quote
+        /// from a peer for the acquisition of an asset.
+        ///
+        /// The normal use of a buy order is as follows:
+        ///
+        /// 1. Alice, operating a wallet node, wants to receive a Tap asset as payment
+        ///   by issuing a Lightning invoice.
+        /// 1. Alice has an asset channel established with Bob's edge node.
+        /// 1. Before issuing the invoice, Alice needs to agree on an exchange rate with
+        ///   Bob, who will facilitate the asset transfer.
+        /// 1. To obtain the best exchange rate, Alice creates a buy order specifying
+        ///   the desired asset.
+        /// 1. Alice's RFQ subsystem processes the buy order and sends buy requests to
+        ///   relevant peers to find the best rate. In this example, Bob is the only
+        ///   available peer.
+        /// 1. Once Bob provides a satisfactory quote, Alice accepts it.
+        /// 1. Alice issues the Lightning invoice, which Charlie will pay.
+        /// 1. Instead of paying Alice directly, Charlie pays Bob.
+        /// 1. Bob then forwards the agreed amount of the Tap asset to Alice over their
+        ///   asset channel.
+        pub async fn add_asset_buy_order(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddAssetBuyOrderRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddAssetBuyOrderResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rfqrpc.Rfq/AddAssetBuyOrder",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("rfqrpc.Rfq", "AddAssetBuyOrder"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `rfq sellorder`
+        /// AddAssetSellOrder is used to add a sell order for a specific asset. If a
+        /// sell order already exists for the asset, it will be updated.
+        pub async fn add_asset_sell_order(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddAssetSellOrderRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddAssetSellOrderResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rfqrpc.Rfq/AddAssetSellOrder",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("rfqrpc.Rfq", "AddAssetSellOrder"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `rfq selloffer`
+        /// AddAssetSellOffer is used to add a sell offer for a specific asset. If a
+        /// sell offer already exists for the asset, it will be updated.
+        pub async fn add_asset_sell_offer(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddAssetSellOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddAssetSellOfferResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rfqrpc.Rfq/AddAssetSellOffer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("rfqrpc.Rfq", "AddAssetSellOffer"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `rfq buyoffer`
+        /// AddAssetBuyOffer is used to add a buy offer for a specific asset. If a
+        /// buy offer already exists for the asset, it will be updated.
+        ///
+        /// A buy offer is used by the node to selectively accept or reject incoming
+        /// asset sell quote requests before price is considered.
+        pub async fn add_asset_buy_offer(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddAssetBuyOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddAssetBuyOfferResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rfqrpc.Rfq/AddAssetBuyOffer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("rfqrpc.Rfq", "AddAssetBuyOffer"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `rfq acceptedquotes`
+        /// QueryPeerAcceptedQuotes is used to query for quotes that were requested by
+        /// our node and have been accepted our peers.
+        pub async fn query_peer_accepted_quotes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryPeerAcceptedQuotesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryPeerAcceptedQuotesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rfqrpc.Rfq/QueryPeerAcceptedQuotes",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("rfqrpc.Rfq", "QueryPeerAcceptedQuotes"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// SubscribeRfqEventNtfns is used to subscribe to RFQ events.
+        pub async fn subscribe_rfq_event_ntfns(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubscribeRfqEventNtfnsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::RfqEvent>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rfqrpc.Rfq/SubscribeRfqEventNtfns",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("rfqrpc.Rfq", "SubscribeRfqEventNtfns"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod rfq_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with RfqServer.
+    #[async_trait]
+    pub trait Rfq: std::marker::Send + std::marker::Sync + 'static {
+        /// tapcli: `rfq buyorder`
+        /// AddAssetBuyOrder is used to add a buy order for a specific asset. If a buy
+        /// order already exists for the asset, it will be updated.
+        ///
+        /// A buy order instructs the RFQ (Request For Quote) system to request a quote
+        /// from a peer for the acquisition of an asset.
+        ///
+        /// The normal use of a buy order is as follows:
+        ///
+        /// 1. Alice, operating a wallet node, wants to receive a Tap asset as payment
+        ///   by issuing a Lightning invoice.
+        /// 1. Alice has an asset channel established with Bob's edge node.
+        /// 1. Before issuing the invoice, Alice needs to agree on an exchange rate with
+        ///   Bob, who will facilitate the asset transfer.
+        /// 1. To obtain the best exchange rate, Alice creates a buy order specifying
+        ///   the desired asset.
+        /// 1. Alice's RFQ subsystem processes the buy order and sends buy requests to
+        ///   relevant peers to find the best rate. In this example, Bob is the only
+        ///   available peer.
+        /// 1. Once Bob provides a satisfactory quote, Alice accepts it.
+        /// 1. Alice issues the Lightning invoice, which Charlie will pay.
+        /// 1. Instead of paying Alice directly, Charlie pays Bob.
+        /// 1. Bob then forwards the agreed amount of the Tap asset to Alice over their
+        ///   asset channel.
+        async fn add_asset_buy_order(
+            &self,
+            request: tonic::Request<super::AddAssetBuyOrderRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddAssetBuyOrderResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `rfq sellorder`
+        /// AddAssetSellOrder is used to add a sell order for a specific asset. If a
+        /// sell order already exists for the asset, it will be updated.
+        async fn add_asset_sell_order(
+            &self,
+            request: tonic::Request<super::AddAssetSellOrderRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddAssetSellOrderResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `rfq selloffer`
+        /// AddAssetSellOffer is used to add a sell offer for a specific asset. If a
+        /// sell offer already exists for the asset, it will be updated.
+        async fn add_asset_sell_offer(
+            &self,
+            request: tonic::Request<super::AddAssetSellOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddAssetSellOfferResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `rfq buyoffer`
+        /// AddAssetBuyOffer is used to add a buy offer for a specific asset. If a
+        /// buy offer already exists for the asset, it will be updated.
+        ///
+        /// A buy offer is used by the node to selectively accept or reject incoming
+        /// asset sell quote requests before price is considered.
+        async fn add_asset_buy_offer(
+            &self,
+            request: tonic::Request<super::AddAssetBuyOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddAssetBuyOfferResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `rfq acceptedquotes`
+        /// QueryPeerAcceptedQuotes is used to query for quotes that were requested by
+        /// our node and have been accepted our peers.
+        async fn query_peer_accepted_quotes(
+            &self,
+            request: tonic::Request<super::QueryPeerAcceptedQuotesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryPeerAcceptedQuotesResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the SubscribeRfqEventNtfns method.
+        type SubscribeRfqEventNtfnsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::RfqEvent, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// SubscribeRfqEventNtfns is used to subscribe to RFQ events.
+        async fn subscribe_rfq_event_ntfns(
+            &self,
+            request: tonic::Request<super::SubscribeRfqEventNtfnsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::SubscribeRfqEventNtfnsStream>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct RfqServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> RfqServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for RfqServer<T>
+    where
+        T: Rfq,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/rfqrpc.Rfq/AddAssetBuyOrder" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddAssetBuyOrderSvc<T: Rfq>(pub Arc<T>);
+                    impl<
+                        T: Rfq,
+                    > tonic::server::UnaryService<super::AddAssetBuyOrderRequest>
+                    for AddAssetBuyOrderSvc<T> {
+                        type Response = super::AddAssetBuyOrderResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddAssetBuyOrderRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Rfq>::add_asset_buy_order(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AddAssetBuyOrderSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rfqrpc.Rfq/AddAssetSellOrder" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddAssetSellOrderSvc<T: Rfq>(pub Arc<T>);
+                    impl<
+                        T: Rfq,
+                    > tonic::server::UnaryService<super::AddAssetSellOrderRequest>
+                    for AddAssetSellOrderSvc<T> {
+                        type Response = super::AddAssetSellOrderResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddAssetSellOrderRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Rfq>::add_asset_sell_order(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AddAssetSellOrderSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rfqrpc.Rfq/AddAssetSellOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddAssetSellOfferSvc<T: Rfq>(pub Arc<T>);
+                    impl<
+                        T: Rfq,
+                    > tonic::server::UnaryService<super::AddAssetSellOfferRequest>
+                    for AddAssetSellOfferSvc<T> {
+                        type Response = super::AddAssetSellOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddAssetSellOfferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Rfq>::add_asset_sell_offer(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AddAssetSellOfferSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rfqrpc.Rfq/AddAssetBuyOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddAssetBuyOfferSvc<T: Rfq>(pub Arc<T>);
+                    impl<
+                        T: Rfq,
+                    > tonic::server::UnaryService<super::AddAssetBuyOfferRequest>
+                    for AddAssetBuyOfferSvc<T> {
+                        type Response = super::AddAssetBuyOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddAssetBuyOfferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Rfq>::add_asset_buy_offer(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AddAssetBuyOfferSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rfqrpc.Rfq/QueryPeerAcceptedQuotes" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryPeerAcceptedQuotesSvc<T: Rfq>(pub Arc<T>);
+                    impl<
+                        T: Rfq,
+                    > tonic::server::UnaryService<super::QueryPeerAcceptedQuotesRequest>
+                    for QueryPeerAcceptedQuotesSvc<T> {
+                        type Response = super::QueryPeerAcceptedQuotesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryPeerAcceptedQuotesRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Rfq>::query_peer_accepted_quotes(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryPeerAcceptedQuotesSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rfqrpc.Rfq/SubscribeRfqEventNtfns" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeRfqEventNtfnsSvc<T: Rfq>(pub Arc<T>);
+                    impl<
+                        T: Rfq,
+                    > tonic::server::ServerStreamingService<
+                        super::SubscribeRfqEventNtfnsRequest,
+                    > for SubscribeRfqEventNtfnsSvc<T> {
+                        type Response = super::RfqEvent;
+                        type ResponseStream = T::SubscribeRfqEventNtfnsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubscribeRfqEventNtfnsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Rfq>::subscribe_rfq_event_ntfns(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SubscribeRfqEventNtfnsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for RfqServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "rfqrpc.Rfq";
+    impl<T> tonic::server::NamedService for RfqServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+impl crate::rfqrpc::InvalidQuoteResponse {
+    /// Returns `status`, or an error for values unknown to this version of the protos.
+    pub fn try_status(&self) -> ::core::result::Result<crate::rfqrpc::QuoteRespStatus, ::prost::UnknownEnumValue> {
+        crate::rfqrpc::QuoteRespStatus::try_from(self.status)
+    }
+}
+/// Generated blocking client implementations.
+#[cfg(feature = "blocking")]
+pub mod rfq_blocking_client {
+    #![allow(clippy::wildcard_imports)]
+    use tonic::codegen::*;
+    use super::rfq_client::RfqClient;
+    /// A [`RfqClient`] that blocks the current thread on every call, driving the
+    /// requests on its runtime.
+    #[derive(Debug, Clone)]
+    pub struct RfqBlockingClient<T> {
+        inner: RfqClient<T>,
+        runtime: std::sync::Arc<crate::blocking::Runtime>,
+    }
+    impl<T> RfqBlockingClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        /// Wraps `inner`, running its calls on `runtime`.
+        pub fn new(inner: RfqClient<T>, runtime: std::sync::Arc<crate::blocking::Runtime>) -> Self {
+            Self { inner, runtime }
+        }
+        /// Returns the async client.
+        pub fn into_inner(self) -> RfqClient<T> {
+            self.inner
+        }
+        /// Blocking version of [`RfqClient::add_asset_buy_order`].
+        pub fn add_asset_buy_order(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::rfqrpc::AddAssetBuyOrderRequest>,
+        ) -> std::result::Result<tonic::Response<crate::rfqrpc::AddAssetBuyOrderResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.add_asset_buy_order(request))
+        }
+        /// Blocking version of [`RfqClient::add_asset_sell_order`].
+        pub fn add_asset_sell_order(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::rfqrpc::AddAssetSellOrderRequest>,
+        ) -> std::result::Result<tonic::Response<crate::rfqrpc::AddAssetSellOrderResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.add_asset_sell_order(request))
+        }
+        /// Blocking version of [`RfqClient::add_asset_sell_offer`].
+        pub fn add_asset_sell_offer(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::rfqrpc::AddAssetSellOfferRequest>,
+        ) -> std::result::Result<tonic::Response<crate::rfqrpc::AddAssetSellOfferResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.add_asset_sell_offer(request))
+        }
+        /// Blocking version of [`RfqClient::add_asset_buy_offer`].
+        pub fn add_asset_buy_offer(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::rfqrpc::AddAssetBuyOfferRequest>,
+        ) -> std::result::Result<tonic::Response<crate::rfqrpc::AddAssetBuyOfferResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.add_asset_buy_offer(request))
+        }
+        /// Blocking version of [`RfqClient::query_peer_accepted_quotes`].
+        pub fn query_peer_accepted_quotes(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::rfqrpc::QueryPeerAcceptedQuotesRequest>,
+        ) -> std::result::Result<tonic::Response<crate::rfqrpc::QueryPeerAcceptedQuotesResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.query_peer_accepted_quotes(request))
+        }
+        /// Blocking version of [`RfqClient::subscribe_rfq_event_ntfns`].
+        pub fn subscribe_rfq_event_ntfns(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::rfqrpc::SubscribeRfqEventNtfnsRequest>,
+        ) -> std::result::Result<tonic::Response<crate::blocking::Streaming<crate::rfqrpc::RfqEvent>>, tonic::Status> {
+            let response = self.runtime.block_on(self.inner.subscribe_rfq_event_ntfns(request))?;
+            Ok(response.map(|stream| crate::blocking::Streaming::new(stream, self.runtime.clone())))
+        }
+    }
+}
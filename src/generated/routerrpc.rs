@@ -0,0 +1,3840 @@
+// This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SendPaymentRequest {
+    /// The identity pubkey of the payment recipient
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub dest: ::prost::alloc::vec::Vec<u8>,
+    /// Number of satoshis to send.
+    ///
+    /// The fields amt and amt_msat are mutually exclusive.
+    #[prost(int64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub amt: i64,
+    /// The hash to use within the payment's HTLC
+    #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub payment_hash: ::prost::alloc::vec::Vec<u8>,
+    /// The CLTV delta from the current height that should be used to set the
+    /// timelock for the final hop.
+    #[prost(int32, tag = "4")]
+    pub final_cltv_delta: i32,
+    /// A bare-bones invoice for a payment within the Lightning Network.  With the
+    /// details of the invoice, the sender has all the data necessary to send a
+    /// payment to the recipient. The amount in the payment request may be zero. In
+    /// that case it is required to set the amt field as well. If no payment request
+    /// is specified, the following fields are required: dest, amt and payment_hash.
+    #[prost(string, tag = "5")]
+    pub payment_request: ::prost::alloc::string::String,
+    /// An optional limit, expressed in seconds, on the time to wait before
+    /// attempting the first HTLC. Once HTLCs are in flight, the payment will
+    /// not be aborted until the HTLCs are either settled or failed. If the field
+    /// is not set or is explicitly set to zero, the default value of 60 seconds
+    /// will be applied.
+    #[prost(int32, tag = "6")]
+    pub timeout_seconds: i32,
+    /// The maximum number of satoshis that will be paid as a fee of the payment.
+    /// If this field is left to the default value of 0, only zero-fee routes will
+    /// be considered. This usually means single hop routes connecting directly to
+    /// the destination. To send the payment without a fee limit, use max int here.
+    ///
+    /// The fields fee_limit_sat and fee_limit_msat are mutually exclusive.
+    #[prost(int64, tag = "7")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub fee_limit_sat: i64,
+    /// An optional maximum total time lock for the route. This should not
+    /// exceed lnd's `--max-cltv-expiry` setting. If zero, then the value of
+    /// `--max-cltv-expiry` is enforced.
+    #[prost(int32, tag = "9")]
+    pub cltv_limit: i32,
+    /// Optional route hints to reach the destination through private channels.
+    #[prost(message, repeated, tag = "10")]
+    pub route_hints: ::prost::alloc::vec::Vec<super::lnrpc::RouteHint>,
+    /// An optional field that can be used to pass an arbitrary set of TLV records
+    /// to a peer which understands the new records. This can be used to pass
+    /// application specific data during the payment attempt. Record types are
+    /// required to be in the custom range >= 65536. When using REST, the values
+    /// must be encoded as base64.
+    #[prost(map = "uint64, bytes", tag = "11")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Map<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Map<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub dest_custom_records: ::std::collections::HashMap<
+        u64,
+        ::prost::alloc::vec::Vec<u8>,
+    >,
+    /// Number of millisatoshis to send.
+    ///
+    /// The fields amt and amt_msat are mutually exclusive.
+    #[prost(int64, tag = "12")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub amt_msat: i64,
+    /// The maximum number of millisatoshis that will be paid as a fee of the
+    /// payment. If this field is left to the default value of 0, only zero-fee
+    /// routes will be considered. This usually means single hop routes connecting
+    /// directly to the destination. To send the payment without a fee limit, use
+    /// max int here.
+    ///
+    /// The fields fee_limit_sat and fee_limit_msat are mutually exclusive.
+    #[prost(int64, tag = "13")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub fee_limit_msat: i64,
+    /// The pubkey of the last hop of the route. If empty, any hop may be used.
+    #[prost(bytes = "vec", tag = "14")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub last_hop_pubkey: ::prost::alloc::vec::Vec<u8>,
+    /// If set, circular payments to self are permitted.
+    #[prost(bool, tag = "15")]
+    pub allow_self_payment: bool,
+    /// Features assumed to be supported by the final node. All transitive feature
+    /// dependencies must also be set properly. For a given feature bit pair, either
+    /// optional or remote may be set, but not both. If this field is nil or empty,
+    /// the router will try to load destination features from the graph as a
+    /// fallback.
+    #[prost(enumeration = "super::lnrpc::FeatureBit", repeated, tag = "16")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Enum<crate::lnrpc::FeatureBit>>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Enum<crate::lnrpc::FeatureBit>>, _, _>"
+        )
+    )]
+    pub dest_features: ::prost::alloc::vec::Vec<i32>,
+    /// The maximum number of partial payments that may be use to complete the full
+    /// amount.
+    #[prost(uint32, tag = "17")]
+    pub max_parts: u32,
+    /// If set, only the final payment update is streamed back. Intermediate updates
+    /// that show which htlcs are still in flight are suppressed.
+    #[prost(bool, tag = "18")]
+    pub no_inflight_updates: bool,
+    /// The channel ids of the channels are allowed for the first hop. If empty,
+    /// any channel may be used.
+    #[prost(uint64, repeated, tag = "19")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Int64>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Int64>, _, _>"
+        )
+    )]
+    pub outgoing_chan_ids: ::prost::alloc::vec::Vec<u64>,
+    /// An optional payment addr to be included within the last hop of the route.
+    /// This is also called payment secret in specifications (e.g. BOLT 11).
+    #[prost(bytes = "vec", tag = "20")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub payment_addr: ::prost::alloc::vec::Vec<u8>,
+    /// The largest payment split that should be attempted when making a payment if
+    /// splitting is necessary. Setting this value will effectively cause lnd to
+    /// split more aggressively, vs only when it thinks it needs to. Note that this
+    /// value is in milli-satoshis.
+    #[prost(uint64, tag = "21")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub max_shard_size_msat: u64,
+    /// If set, an AMP-payment will be attempted.
+    #[prost(bool, tag = "22")]
+    pub amp: bool,
+    /// The time preference for this payment. Set to -1 to optimize for fees
+    /// only, to 1 to optimize for reliability only or a value inbetween for a mix.
+    #[prost(double, tag = "23")]
+    pub time_pref: f64,
+    /// If set, the payment loop can be interrupted by manually canceling the
+    /// payment context, even before the payment timeout is reached. Note that the
+    /// payment may still succeed after cancellation, as in-flight attempts can
+    /// still settle afterwards. Canceling will only prevent further attempts from
+    /// being sent.
+    #[prost(bool, tag = "24")]
+    pub cancelable: bool,
+    /// An optional field that can be used to pass an arbitrary set of TLV records
+    /// to the first hop peer of this payment. This can be used to pass application
+    /// specific data during the payment attempt. Record types are required to be in
+    /// the custom range >= 65536. When using REST, the values must be encoded as
+    /// base64.
+    #[prost(map = "uint64, bytes", tag = "25")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Map<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Map<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub first_hop_custom_records: ::std::collections::HashMap<
+        u64,
+        ::prost::alloc::vec::Vec<u8>,
+    >,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TrackPaymentRequest {
+    /// The hash of the payment to look up.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub payment_hash: ::prost::alloc::vec::Vec<u8>,
+    /// If set, only the final payment update is streamed back. Intermediate updates
+    /// that show which htlcs are still in flight are suppressed.
+    #[prost(bool, tag = "2")]
+    pub no_inflight_updates: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TrackPaymentsRequest {
+    /// If set, only the final payment updates are streamed back. Intermediate
+    /// updates that show which htlcs are still in flight are suppressed.
+    #[prost(bool, tag = "1")]
+    pub no_inflight_updates: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RouteFeeRequest {
+    /// The destination one wishes to obtain a routing fee quote to. If set, this
+    /// parameter requires the amt_sat parameter also to be set. This parameter
+    /// combination triggers a graph based routing fee estimation as opposed to a
+    /// payment probe based estimate in case a payment request is provided. The
+    /// graph based estimation is an algorithm that is executed on the in memory
+    /// graph. Hence its runtime is significantly shorter than a payment probe
+    /// estimation that sends out actual payments to the network.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub dest: ::prost::alloc::vec::Vec<u8>,
+    /// The amount one wishes to send to the target destination. It is only to be
+    /// used in combination with the dest parameter.
+    #[prost(int64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub amt_sat: i64,
+    /// A payment request of the target node that the route fee request is intended
+    /// for. Its parameters are input to probe payments that estimate routing fees.
+    /// The timeout parameter can be specified to set a maximum time on the probing
+    /// attempt. Cannot be used in combination with dest and amt_sat.
+    #[prost(string, tag = "3")]
+    pub payment_request: ::prost::alloc::string::String,
+    /// A user preference of how long a probe payment should maximally be allowed to
+    /// take, denoted in seconds. The probing payment loop is aborted if this
+    /// timeout is reached. Note that the probing process itself can take longer
+    /// than the timeout if the HTLC becomes delayed or stuck. Canceling the context
+    /// of this call will not cancel the payment loop, the duration is only
+    /// controlled by the timeout parameter.
+    #[prost(uint32, tag = "4")]
+    pub timeout: u32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RouteFeeResponse {
+    /// A lower bound of the estimated fee to the target destination within the
+    /// network, expressed in milli-satoshis.
+    #[prost(int64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub routing_fee_msat: i64,
+    /// An estimate of the worst case time delay that can occur. Note that callers
+    /// will still need to factor in the final CLTV delta of the last hop into this
+    /// value.
+    #[prost(int64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub time_lock_delay: i64,
+    /// An indication whether a probing payment succeeded or whether and why it
+    /// failed. FAILURE_REASON_NONE indicates success.
+    #[prost(enumeration = "super::lnrpc::PaymentFailureReason", tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::lnrpc::PaymentFailureReason>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::lnrpc::PaymentFailureReason>, _, _>"
+        )
+    )]
+    pub failure_reason: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SendToRouteRequest {
+    /// The payment hash to use for the HTLC.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub payment_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Route that should be used to attempt to complete the payment.
+    #[prost(message, optional, tag = "2")]
+    pub route: ::core::option::Option<super::lnrpc::Route>,
+    /// Whether the payment should be marked as failed when a temporary error is
+    /// returned from the given route. Set it to true so the payment won't be
+    /// failed unless a terminal error is occurred, such as payment timeout, no
+    /// routes, incorrect payment details, or insufficient funds.
+    #[prost(bool, tag = "3")]
+    pub skip_temp_err: bool,
+    /// An optional field that can be used to pass an arbitrary set of TLV records
+    /// to the first hop peer of this payment. This can be used to pass application
+    /// specific data during the payment attempt. Record types are required to be in
+    /// the custom range >= 65536. When using REST, the values must be encoded as
+    /// base64.
+    #[prost(map = "uint64, bytes", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Map<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Map<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub first_hop_custom_records: ::std::collections::HashMap<
+        u64,
+        ::prost::alloc::vec::Vec<u8>,
+    >,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ResetMissionControlRequest {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ResetMissionControlResponse {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QueryMissionControlRequest {}
+/// QueryMissionControlResponse contains mission control state.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryMissionControlResponse {
+    /// Node pair-level mission control state.
+    #[prost(message, repeated, tag = "2")]
+    pub pairs: ::prost::alloc::vec::Vec<PairHistory>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct XImportMissionControlRequest {
+    /// Node pair-level mission control state to be imported.
+    #[prost(message, repeated, tag = "1")]
+    pub pairs: ::prost::alloc::vec::Vec<PairHistory>,
+    /// Whether to force override MC pair history. Note that even with force
+    /// override the failure pair is imported before the success pair and both
+    /// still clamp existing failure/success amounts.
+    #[prost(bool, tag = "2")]
+    pub force: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct XImportMissionControlResponse {}
+/// PairHistory contains the mission control state for a particular node pair.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PairHistory {
+    /// The source node pubkey of the pair.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub node_from: ::prost::alloc::vec::Vec<u8>,
+    /// The destination node pubkey of the pair.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub node_to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "7")]
+    pub history: ::core::option::Option<PairData>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PairData {
+    /// Time of last failure.
+    #[prost(int64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub fail_time: i64,
+    /// Lowest amount that failed to forward rounded to whole sats. This may be
+    /// set to zero if the failure is independent of amount.
+    #[prost(int64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub fail_amt_sat: i64,
+    /// Lowest amount that failed to forward in millisats. This may be
+    /// set to zero if the failure is independent of amount.
+    #[prost(int64, tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub fail_amt_msat: i64,
+    /// Time of last success.
+    #[prost(int64, tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub success_time: i64,
+    /// Highest amount that we could successfully forward rounded to whole sats.
+    #[prost(int64, tag = "6")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub success_amt_sat: i64,
+    /// Highest amount that we could successfully forward in millisats.
+    #[prost(int64, tag = "7")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub success_amt_msat: i64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetMissionControlConfigRequest {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetMissionControlConfigResponse {
+    /// Mission control's currently active config.
+    #[prost(message, optional, tag = "1")]
+    pub config: ::core::option::Option<MissionControlConfig>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SetMissionControlConfigRequest {
+    /// The config to set for mission control. Note that all values *must* be set,
+    /// because the full config will be applied.
+    #[prost(message, optional, tag = "1")]
+    pub config: ::core::option::Option<MissionControlConfig>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetMissionControlConfigResponse {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct MissionControlConfig {
+    /// Deprecated, use AprioriParameters. The amount of time mission control will
+    /// take to restore a penalized node or channel back to 50% success probability,
+    /// expressed in seconds. Setting this value to a higher value will penalize
+    /// failures for longer, making mission control less likely to route through
+    /// nodes and channels that we have previously recorded failures for.
+    #[deprecated]
+    #[prost(uint64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub half_life_seconds: u64,
+    /// Deprecated, use AprioriParameters. The probability of success mission
+    /// control should assign to hop in a route where it has no other information
+    /// available. Higher values will make mission control more willing to try hops
+    /// that we have no information about, lower values will discourage trying these
+    /// hops.
+    #[deprecated]
+    #[prost(float, tag = "2")]
+    pub hop_probability: f32,
+    /// Deprecated, use AprioriParameters. The importance that mission control
+    /// should place on historical results, expressed as a value in \[0;1\]. Setting
+    /// this value to 1 will ignore all historical payments and just use the hop
+    /// probability to assess the probability of success for each hop. A zero value
+    /// ignores hop probability completely and relies entirely on historical
+    /// results, unless none are available.
+    #[deprecated]
+    #[prost(float, tag = "3")]
+    pub weight: f32,
+    /// The maximum number of payment results that mission control will store.
+    #[prost(uint32, tag = "4")]
+    pub maximum_payment_results: u32,
+    /// The minimum time that must have passed since the previously recorded failure
+    /// before we raise the failure amount.
+    #[prost(uint64, tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub minimum_failure_relax_interval: u64,
+    /// ProbabilityModel defines which probability estimator should be used in
+    /// pathfinding. Note that the bimodal estimator is experimental.
+    #[prost(enumeration = "mission_control_config::ProbabilityModel", tag = "6")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::routerrpc::mission_control_config::ProbabilityModel>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::routerrpc::mission_control_config::ProbabilityModel>, _, _>"
+        )
+    )]
+    pub model: i32,
+    /// EstimatorConfig is populated dependent on the estimator type.
+    #[prost(oneof = "mission_control_config::EstimatorConfig", tags = "7, 8")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub estimator_config: ::core::option::Option<
+        mission_control_config::EstimatorConfig,
+    >,
+}
+/// Nested message and enum types in `MissionControlConfig`.
+pub mod mission_control_config {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum ProbabilityModel {
+        #[cfg_attr(feature = "serde", serde(rename = "APRIORI"))]
+        Apriori = 0,
+        #[cfg_attr(feature = "serde", serde(rename = "BIMODAL"))]
+        Bimodal = 1,
+    }
+    impl ProbabilityModel {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Apriori => "APRIORI",
+                Self::Bimodal => "BIMODAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "APRIORI" => Some(Self::Apriori),
+                "BIMODAL" => Some(Self::Bimodal),
+                _ => None,
+            }
+        }
+    }
+    /// EstimatorConfig is populated dependent on the estimator type.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, Copy, PartialEq, ::prost::Oneof)]
+    pub enum EstimatorConfig {
+        #[prost(message, tag = "7")]
+        #[cfg_attr(feature = "serde", serde(rename = "apriori"))]
+        Apriori(super::AprioriParameters),
+        #[prost(message, tag = "8")]
+        #[cfg_attr(feature = "serde", serde(rename = "bimodal"))]
+        Bimodal(super::BimodalParameters),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct BimodalParameters {
+    /// NodeWeight defines how strongly other previous forwardings on channels of a
+    /// router should be taken into account when computing a channel's probability
+    /// to route. The allowed values are in the range \[0, 1\], where a value of 0
+    /// means that only direct information about a channel is taken into account.
+    #[prost(double, tag = "1")]
+    pub node_weight: f64,
+    /// ScaleMsat describes the scale over which channels statistically have some
+    /// liquidity left. The value determines how quickly the bimodal distribution
+    /// drops off from the edges of a channel. A larger value (compared to typical
+    /// channel capacities) means that the drop off is slow and that channel
+    /// balances are distributed more uniformly. A small value leads to the
+    /// assumption of very unbalanced channels.
+    #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub scale_msat: u64,
+    /// DecayTime describes the information decay of knowledge about previous
+    /// successes and failures in channels. The smaller the decay time, the quicker
+    /// we forget about past forwardings.
+    #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub decay_time: u64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AprioriParameters {
+    /// The amount of time mission control will take to restore a penalized node
+    /// or channel back to 50% success probability, expressed in seconds. Setting
+    /// this value to a higher value will penalize failures for longer, making
+    /// mission control less likely to route through nodes and channels that we
+    /// have previously recorded failures for.
+    #[prost(uint64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub half_life_seconds: u64,
+    /// The probability of success mission control should assign to hop in a route
+    /// where it has no other information available. Higher values will make mission
+    /// control more willing to try hops that we have no information about, lower
+    /// values will discourage trying these hops.
+    #[prost(double, tag = "2")]
+    pub hop_probability: f64,
+    /// The importance that mission control should place on historical results,
+    /// expressed as a value in \[0;1\]. Setting this value to 1 will ignore all
+    /// historical payments and just use the hop probability to assess the
+    /// probability of success for each hop. A zero value ignores hop probability
+    /// completely and relies entirely on historical results, unless none are
+    /// available.
+    #[prost(double, tag = "3")]
+    pub weight: f64,
+    /// The fraction of a channel's capacity that we consider to have liquidity. For
+    /// amounts that come close to or exceed the fraction, an additional penalty is
+    /// applied. A value of 1.0 disables the capacity factor. Allowed values are in
+    /// \[0.75, 1.0\].
+    #[prost(double, tag = "4")]
+    pub capacity_fraction: f64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QueryProbabilityRequest {
+    /// The source node pubkey of the pair.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub from_node: ::prost::alloc::vec::Vec<u8>,
+    /// The destination node pubkey of the pair.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub to_node: ::prost::alloc::vec::Vec<u8>,
+    /// The amount for which to calculate a probability.
+    #[prost(int64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub amt_msat: i64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct QueryProbabilityResponse {
+    /// The success probability for the requested pair.
+    #[prost(double, tag = "1")]
+    pub probability: f64,
+    /// The historical data for the requested pair.
+    #[prost(message, optional, tag = "2")]
+    pub history: ::core::option::Option<PairData>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BuildRouteRequest {
+    /// The amount to send expressed in msat. If set to zero, the minimum routable
+    /// amount is used.
+    #[prost(int64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub amt_msat: i64,
+    /// CLTV delta from the current height that should be used for the timelock
+    /// of the final hop
+    #[prost(int32, tag = "2")]
+    pub final_cltv_delta: i32,
+    /// The channel id of the channel that must be taken to the first hop. If zero,
+    /// any channel may be used.
+    #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub outgoing_chan_id: u64,
+    /// A list of hops that defines the route. This does not include the source hop
+    /// pubkey.
+    #[prost(bytes = "vec", repeated, tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub hop_pubkeys: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// An optional payment addr to be included within the last hop of the route.
+    /// This is also called payment secret in specifications (e.g. BOLT 11).
+    #[prost(bytes = "vec", tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub payment_addr: ::prost::alloc::vec::Vec<u8>,
+    /// An optional field that can be used to pass an arbitrary set of TLV records
+    /// to the first hop peer of this payment. This can be used to pass application
+    /// specific data during the payment attempt. Record types are required to be in
+    /// the custom range >= 65536. When using REST, the values must be encoded as
+    /// base64.
+    #[prost(map = "uint64, bytes", tag = "6")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Map<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Map<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub first_hop_custom_records: ::std::collections::HashMap<
+        u64,
+        ::prost::alloc::vec::Vec<u8>,
+    >,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BuildRouteResponse {
+    /// Fully specified route that can be used to execute the payment.
+    #[prost(message, optional, tag = "1")]
+    pub route: ::core::option::Option<super::lnrpc::Route>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SubscribeHtlcEventsRequest {}
+/// HtlcEvent contains the htlc event that was processed. These are served on a
+/// best-effort basis; events are not persisted, delivery is not guaranteed
+/// (in the event of a crash in the switch, forward events may be lost) and
+/// some events may be replayed upon restart. Events consumed from this package
+/// should be de-duplicated by the htlc's unique combination of incoming and
+/// outgoing channel id and htlc id. \[EXPERIMENTAL\]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct HtlcEvent {
+    /// The short channel id that the incoming htlc arrived at our node on. This
+    /// value is zero for sends.
+    #[prost(uint64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub incoming_channel_id: u64,
+    /// The short channel id that the outgoing htlc left our node on. This value
+    /// is zero for receives.
+    #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub outgoing_channel_id: u64,
+    /// Incoming id is the index of the incoming htlc in the incoming channel.
+    /// This value is zero for sends.
+    #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub incoming_htlc_id: u64,
+    /// Outgoing id is the index of the outgoing htlc in the outgoing channel.
+    /// This value is zero for receives.
+    #[prost(uint64, tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub outgoing_htlc_id: u64,
+    /// The time in unix nanoseconds that the event occurred.
+    #[prost(uint64, tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub timestamp_ns: u64,
+    /// The event type indicates whether the htlc was part of a send, receive or
+    /// forward.
+    #[prost(enumeration = "htlc_event::EventType", tag = "6")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::routerrpc::htlc_event::EventType>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::routerrpc::htlc_event::EventType>, _, _>"
+        )
+    )]
+    pub event_type: i32,
+    #[prost(oneof = "htlc_event::Event", tags = "7, 8, 9, 10, 11, 12")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub event: ::core::option::Option<htlc_event::Event>,
+}
+/// Nested message and enum types in `HtlcEvent`.
+pub mod htlc_event {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum EventType {
+        #[cfg_attr(feature = "serde", serde(rename = "UNKNOWN"))]
+        Unknown = 0,
+        #[cfg_attr(feature = "serde", serde(rename = "SEND"))]
+        Send = 1,
+        #[cfg_attr(feature = "serde", serde(rename = "RECEIVE"))]
+        Receive = 2,
+        #[cfg_attr(feature = "serde", serde(rename = "FORWARD"))]
+        Forward = 3,
+    }
+    impl EventType {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unknown => "UNKNOWN",
+                Self::Send => "SEND",
+                Self::Receive => "RECEIVE",
+                Self::Forward => "FORWARD",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "UNKNOWN" => Some(Self::Unknown),
+                "SEND" => Some(Self::Send),
+                "RECEIVE" => Some(Self::Receive),
+                "FORWARD" => Some(Self::Forward),
+                _ => None,
+            }
+        }
+    }
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag = "7")]
+        #[cfg_attr(feature = "serde", serde(rename = "forward_event"))]
+        ForwardEvent(super::ForwardEvent),
+        #[prost(message, tag = "8")]
+        #[cfg_attr(feature = "serde", serde(rename = "forward_fail_event"))]
+        ForwardFailEvent(super::ForwardFailEvent),
+        #[prost(message, tag = "9")]
+        #[cfg_attr(feature = "serde", serde(rename = "settle_event"))]
+        SettleEvent(super::SettleEvent),
+        #[prost(message, tag = "10")]
+        #[cfg_attr(feature = "serde", serde(rename = "link_fail_event"))]
+        LinkFailEvent(super::LinkFailEvent),
+        #[prost(message, tag = "11")]
+        #[cfg_attr(feature = "serde", serde(rename = "subscribed_event"))]
+        SubscribedEvent(super::SubscribedEvent),
+        #[prost(message, tag = "12")]
+        #[cfg_attr(feature = "serde", serde(rename = "final_htlc_event"))]
+        FinalHtlcEvent(super::FinalHtlcEvent),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct HtlcInfo {
+    /// The timelock on the incoming htlc.
+    #[prost(uint32, tag = "1")]
+    pub incoming_timelock: u32,
+    /// The timelock on the outgoing htlc.
+    #[prost(uint32, tag = "2")]
+    pub outgoing_timelock: u32,
+    /// The amount of the incoming htlc.
+    #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub incoming_amt_msat: u64,
+    /// The amount of the outgoing htlc.
+    #[prost(uint64, tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub outgoing_amt_msat: u64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ForwardEvent {
+    /// Info contains details about the htlc that was forwarded.
+    #[prost(message, optional, tag = "1")]
+    pub info: ::core::option::Option<HtlcInfo>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ForwardFailEvent {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SettleEvent {
+    /// The revealed preimage.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub preimage: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FinalHtlcEvent {
+    #[prost(bool, tag = "1")]
+    pub settled: bool,
+    #[prost(bool, tag = "2")]
+    pub offchain: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SubscribedEvent {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct LinkFailEvent {
+    /// Info contains details about the htlc that we failed.
+    #[prost(message, optional, tag = "1")]
+    pub info: ::core::option::Option<HtlcInfo>,
+    /// FailureCode is the BOLT error code for the failure.
+    #[prost(enumeration = "super::lnrpc::failure::FailureCode", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::lnrpc::failure::FailureCode>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::lnrpc::failure::FailureCode>, _, _>"
+        )
+    )]
+    pub wire_failure: i32,
+    /// FailureDetail provides additional information about the reason for the
+    /// failure. This detail enriches the information provided by the wire message
+    /// and may be 'no detail' if the wire message requires no additional metadata.
+    #[prost(enumeration = "FailureDetail", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::routerrpc::FailureDetail>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::routerrpc::FailureDetail>, _, _>"
+        )
+    )]
+    pub failure_detail: i32,
+    /// A string representation of the link failure.
+    #[prost(string, tag = "4")]
+    pub failure_string: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CircuitKey {
+    /// / The id of the channel that the is part of this circuit.
+    #[prost(uint64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub chan_id: u64,
+    /// / The index of the incoming htlc in the incoming channel.
+    #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub htlc_id: u64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ForwardHtlcInterceptRequest {
+    /// The key of this forwarded htlc. It defines the incoming channel id and
+    /// the index in this channel.
+    ///
+    /// Interceptor clients should handle requests for the same circuit key
+    /// idempotently. Requests may be replayed after reconnect, and an htlc that was
+    /// previously offered off-chain may be offered again after it moves on-chain.
+    #[prost(message, optional, tag = "1")]
+    pub incoming_circuit_key: ::core::option::Option<CircuitKey>,
+    /// The incoming htlc amount.
+    #[prost(uint64, tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub incoming_amount_msat: u64,
+    /// The incoming htlc expiry.
+    #[prost(uint32, tag = "6")]
+    pub incoming_expiry: u32,
+    /// The htlc payment hash. This value is not guaranteed to be unique per
+    /// request.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub payment_hash: ::prost::alloc::vec::Vec<u8>,
+    /// The requested outgoing channel id for this forwarded htlc. Because of
+    /// non-strict forwarding, this isn't necessarily the channel over which the
+    /// packet will be forwarded eventually. A different channel to the same peer
+    /// may be selected as well.
+    #[prost(uint64, tag = "7")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub outgoing_requested_chan_id: u64,
+    /// The outgoing htlc amount.
+    #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub outgoing_amount_msat: u64,
+    /// The outgoing htlc expiry.
+    #[prost(uint32, tag = "4")]
+    pub outgoing_expiry: u32,
+    /// Any custom records that were present in the payload.
+    #[prost(map = "uint64, bytes", tag = "8")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Map<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Map<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub custom_records: ::std::collections::HashMap<u64, ::prost::alloc::vec::Vec<u8>>,
+    /// The onion blob for the next hop
+    #[prost(bytes = "vec", tag = "9")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub onion_blob: ::prost::alloc::vec::Vec<u8>,
+    /// The block height at which this htlc will be auto-failed to prevent the
+    /// channel from force-closing. For on-chain htlcs, this field is the
+    /// settlement deadline instead and no automatic fail-back is attempted.
+    #[prost(int32, tag = "10")]
+    pub auto_fail_height: i32,
+    /// The custom records of the peer's incoming p2p wire message.
+    #[prost(map = "uint64, bytes", tag = "11")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Map<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Map<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub in_wire_custom_records: ::std::collections::HashMap<
+        u64,
+        ::prost::alloc::vec::Vec<u8>,
+    >,
+}
+/// *
+///
+/// ForwardHtlcInterceptResponse enables the caller to resolve a previously hold
+/// forward. The caller can choose either to:
+///
+/// * `Resume`: Execute the default behavior (usually forward).
+/// * `ResumeModified`: Execute the default behavior (usually forward) with HTLC
+///   field modifications.
+/// * `Reject`: Fail the htlc backwards.
+/// * `Settle`: Settle this htlc with a given preimage.
+///
+/// Once the incoming channel has force-closed and the HTLC is being resolved
+/// on-chain (see auto_fail_height), only `Settle` has any effect. The HTLC can no
+/// longer be resumed or failed back off-chain, so `Resume`, `ResumeModified`, and
+/// `Fail` return a stream-terminating error. The HTLC stays held until it is
+/// settled with a preimage, the on-chain resolver completes, or it expires
+/// on-chain. Clients should reconnect to receive any held HTLCs that remain
+/// unresolved.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ForwardHtlcInterceptResponse {
+    /// *
+    ///
+    /// The key of this forwarded htlc. It defines the incoming channel id and
+    /// the index in this channel.
+    #[prost(message, optional, tag = "1")]
+    pub incoming_circuit_key: ::core::option::Option<CircuitKey>,
+    /// The resolve action for this intercepted htlc.
+    #[prost(enumeration = "ResolveHoldForwardAction", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::routerrpc::ResolveHoldForwardAction>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::routerrpc::ResolveHoldForwardAction>, _, _>"
+        )
+    )]
+    pub action: i32,
+    /// The preimage in case the resolve action is Settle.
+    #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub preimage: ::prost::alloc::vec::Vec<u8>,
+    /// Encrypted failure message in case the resolve action is Fail.
+    ///
+    /// If failure_message is specified, the failure_code field must be set
+    /// to zero.
+    #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub failure_message: ::prost::alloc::vec::Vec<u8>,
+    /// Return the specified failure code in case the resolve action is Fail. The
+    /// message data fields are populated automatically.
+    ///
+    /// If a non-zero failure_code is specified, failure_message must not be set.
+    ///
+    /// For backwards-compatibility reasons, TEMPORARY_CHANNEL_FAILURE is the
+    /// default value for this field.
+    #[prost(enumeration = "super::lnrpc::failure::FailureCode", tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::lnrpc::failure::FailureCode>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::lnrpc::failure::FailureCode>, _, _>"
+        )
+    )]
+    pub failure_code: i32,
+    /// The amount that was set on the p2p wire message of the incoming HTLC.
+    /// This field is ignored if the action is not RESUME_MODIFIED or the amount
+    /// is zero.
+    #[prost(uint64, tag = "6")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub in_amount_msat: u64,
+    /// The amount to set on the p2p wire message of the resumed HTLC. This field
+    /// is ignored if the action is not RESUME_MODIFIED or the amount is zero.
+    #[prost(uint64, tag = "7")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub out_amount_msat: u64,
+    /// Any custom records that should be set on the p2p wire message message of
+    /// the resumed HTLC. This field is ignored if the action is not
+    /// RESUME_MODIFIED.
+    ///
+    /// This map will merge with the existing set of custom records (if any),
+    /// replacing any conflicting types. Note that there currently is no support
+    /// for deleting existing custom records (they can only be replaced).
+    #[prost(map = "uint64, bytes", tag = "8")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Map<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Map<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub out_wire_custom_records: ::std::collections::HashMap<
+        u64,
+        ::prost::alloc::vec::Vec<u8>,
+    >,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct UpdateChanStatusRequest {
+    #[prost(message, optional, tag = "1")]
+    pub chan_point: ::core::option::Option<super::lnrpc::ChannelPoint>,
+    #[prost(enumeration = "ChanStatusAction", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::routerrpc::ChanStatusAction>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::routerrpc::ChanStatusAction>, _, _>"
+        )
+    )]
+    pub action: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct UpdateChanStatusResponse {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddAliasesRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub alias_maps: ::prost::alloc::vec::Vec<super::lnrpc::AliasMap>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddAliasesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub alias_maps: ::prost::alloc::vec::Vec<super::lnrpc::AliasMap>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteAliasesRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub alias_maps: ::prost::alloc::vec::Vec<super::lnrpc::AliasMap>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteAliasesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub alias_maps: ::prost::alloc::vec::Vec<super::lnrpc::AliasMap>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FindBaseAliasRequest {
+    /// The alias we want to look up the base scid for.
+    #[prost(uint64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub alias: u64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FindBaseAliasResponse {
+    /// The base scid that resulted from the base scid look up.
+    #[prost(uint64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub base: u64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeleteForwardingHistoryRequest {
+    /// Specify the cutoff time for deletion using one of the following options.
+    /// Events with a timestamp at or before the cutoff are deleted.
+    #[prost(oneof = "delete_forwarding_history_request::TimeSpec", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub time_spec: ::core::option::Option<delete_forwarding_history_request::TimeSpec>,
+}
+/// Nested message and enum types in `DeleteForwardingHistoryRequest`.
+pub mod delete_forwarding_history_request {
+    /// Specify the cutoff time for deletion using one of the following options.
+    /// Events with a timestamp at or before the cutoff are deleted.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum TimeSpec {
+        /// Absolute Unix timestamp (seconds). Events at or before this time
+        /// are deleted.
+        #[prost(uint64, tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                rename = "delete_before_time",
+                serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+                deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+            )
+        )]
+        DeleteBeforeTime(u64),
+        /// Relative duration string indicating how far back to delete, e.g.
+        /// "-30d" deletes events at or before 30 days ago.
+        /// Standard Go: "-24h", "-1.5h"
+        /// Custom units: "-1d", "-1w", "-1M", "-1y"
+        /// Supported: ns, us/µs, ms, s, m, h, d (days), w (weeks),
+        /// M (months=30.44d), y (years=365.25d).
+        /// Use negative values to specify time in the past.
+        #[prost(string, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "delete_before_duration"))]
+        DeleteBeforeDuration(::prost::alloc::string::String),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeleteForwardingHistoryResponse {
+    /// Number of forwarding events deleted.
+    #[prost(uint64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub events_deleted: u64,
+    /// Total fees earned from deleted events (in millisatoshis).
+    /// This is the sum of (amt_in - amt_out) for all deleted events, which
+    /// can be used for accounting purposes.
+    #[prost(int64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub total_fee_msat: i64,
+    /// Status message.
+    #[prost(string, tag = "3")]
+    pub status: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum FailureDetail {
+    #[cfg_attr(feature = "serde", serde(rename = "UNKNOWN"))]
+    Unknown = 0,
+    #[cfg_attr(feature = "serde", serde(rename = "NO_DETAIL"))]
+    NoDetail = 1,
+    #[cfg_attr(feature = "serde", serde(rename = "ONION_DECODE"))]
+    OnionDecode = 2,
+    #[cfg_attr(feature = "serde", serde(rename = "LINK_NOT_ELIGIBLE"))]
+    LinkNotEligible = 3,
+    #[cfg_attr(feature = "serde", serde(rename = "ON_CHAIN_TIMEOUT"))]
+    OnChainTimeout = 4,
+    #[cfg_attr(feature = "serde", serde(rename = "HTLC_EXCEEDS_MAX"))]
+    HtlcExceedsMax = 5,
+    #[cfg_attr(feature = "serde", serde(rename = "INSUFFICIENT_BALANCE"))]
+    InsufficientBalance = 6,
+    #[cfg_attr(feature = "serde", serde(rename = "INCOMPLETE_FORWARD"))]
+    IncompleteForward = 7,
+    #[cfg_attr(feature = "serde", serde(rename = "HTLC_ADD_FAILED"))]
+    HtlcAddFailed = 8,
+    #[cfg_attr(feature = "serde", serde(rename = "FORWARDS_DISABLED"))]
+    ForwardsDisabled = 9,
+    #[cfg_attr(feature = "serde", serde(rename = "INVOICE_CANCELED"))]
+    InvoiceCanceled = 10,
+    #[cfg_attr(feature = "serde", serde(rename = "INVOICE_UNDERPAID"))]
+    InvoiceUnderpaid = 11,
+    #[cfg_attr(feature = "serde", serde(rename = "INVOICE_EXPIRY_TOO_SOON"))]
+    InvoiceExpiryTooSoon = 12,
+    #[cfg_attr(feature = "serde", serde(rename = "INVOICE_NOT_OPEN"))]
+    InvoiceNotOpen = 13,
+    #[cfg_attr(feature = "serde", serde(rename = "MPP_INVOICE_TIMEOUT"))]
+    MppInvoiceTimeout = 14,
+    #[cfg_attr(feature = "serde", serde(rename = "ADDRESS_MISMATCH"))]
+    AddressMismatch = 15,
+    #[cfg_attr(feature = "serde", serde(rename = "SET_TOTAL_MISMATCH"))]
+    SetTotalMismatch = 16,
+    #[cfg_attr(feature = "serde", serde(rename = "SET_TOTAL_TOO_LOW"))]
+    SetTotalTooLow = 17,
+    #[cfg_attr(feature = "serde", serde(rename = "SET_OVERPAID"))]
+    SetOverpaid = 18,
+    #[cfg_attr(feature = "serde", serde(rename = "UNKNOWN_INVOICE"))]
+    UnknownInvoice = 19,
+    #[cfg_attr(feature = "serde", serde(rename = "INVALID_KEYSEND"))]
+    InvalidKeysend = 20,
+    #[cfg_attr(feature = "serde", serde(rename = "MPP_IN_PROGRESS"))]
+    MppInProgress = 21,
+    #[cfg_attr(feature = "serde", serde(rename = "CIRCULAR_ROUTE"))]
+    CircularRoute = 22,
+    #[cfg_attr(feature = "serde", serde(rename = "INVOICE_ALREADY_SETTLED"))]
+    InvoiceAlreadySettled = 23,
+    #[cfg_attr(feature = "serde", serde(rename = "HTLC_INVOICE_TYPE_MISMATCH"))]
+    HtlcInvoiceTypeMismatch = 24,
+    #[cfg_attr(feature = "serde", serde(rename = "AMP_ERROR"))]
+    AmpError = 25,
+    #[cfg_attr(feature = "serde", serde(rename = "AMP_RECONSTRUCTION"))]
+    AmpReconstruction = 26,
+    #[cfg_attr(feature = "serde", serde(rename = "EXTERNAL_VALIDATION_FAILED"))]
+    ExternalValidationFailed = 27,
+}
+impl FailureDetail {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "UNKNOWN",
+            Self::NoDetail => "NO_DETAIL",
+            Self::OnionDecode => "ONION_DECODE",
+            Self::LinkNotEligible => "LINK_NOT_ELIGIBLE",
+            Self::OnChainTimeout => "ON_CHAIN_TIMEOUT",
+            Self::HtlcExceedsMax => "HTLC_EXCEEDS_MAX",
+            Self::InsufficientBalance => "INSUFFICIENT_BALANCE",
+            Self::IncompleteForward => "INCOMPLETE_FORWARD",
+            Self::HtlcAddFailed => "HTLC_ADD_FAILED",
+            Self::ForwardsDisabled => "FORWARDS_DISABLED",
+            Self::InvoiceCanceled => "INVOICE_CANCELED",
+            Self::InvoiceUnderpaid => "INVOICE_UNDERPAID",
+            Self::InvoiceExpiryTooSoon => "INVOICE_EXPIRY_TOO_SOON",
+            Self::InvoiceNotOpen => "INVOICE_NOT_OPEN",
+            Self::MppInvoiceTimeout => "MPP_INVOICE_TIMEOUT",
+            Self::AddressMismatch => "ADDRESS_MISMATCH",
+            Self::SetTotalMismatch => "SET_TOTAL_MISMATCH",
+            Self::SetTotalTooLow => "SET_TOTAL_TOO_LOW",
+            Self::SetOverpaid => "SET_OVERPAID",
+            Self::UnknownInvoice => "UNKNOWN_INVOICE",
+            Self::InvalidKeysend => "INVALID_KEYSEND",
+            Self::MppInProgress => "MPP_IN_PROGRESS",
+            Self::CircularRoute => "CIRCULAR_ROUTE",
+            Self::InvoiceAlreadySettled => "INVOICE_ALREADY_SETTLED",
+            Self::HtlcInvoiceTypeMismatch => "HTLC_INVOICE_TYPE_MISMATCH",
+            Self::AmpError => "AMP_ERROR",
+            Self::AmpReconstruction => "AMP_RECONSTRUCTION",
+            Self::ExternalValidationFailed => "EXTERNAL_VALIDATION_FAILED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "UNKNOWN" => Some(Self::Unknown),
+            "NO_DETAIL" => Some(Self::NoDetail),
+            "ONION_DECODE" => Some(Self::OnionDecode),
+            "LINK_NOT_ELIGIBLE" => Some(Self::LinkNotEligible),
+            "ON_CHAIN_TIMEOUT" => Some(Self::OnChainTimeout),
+            "HTLC_EXCEEDS_MAX" => Some(Self::HtlcExceedsMax),
+            "INSUFFICIENT_BALANCE" => Some(Self::InsufficientBalance),
+            "INCOMPLETE_FORWARD" => Some(Self::IncompleteForward),
+            "HTLC_ADD_FAILED" => Some(Self::HtlcAddFailed),
+            "FORWARDS_DISABLED" => Some(Self::ForwardsDisabled),
+            "INVOICE_CANCELED" => Some(Self::InvoiceCanceled),
+            "INVOICE_UNDERPAID" => Some(Self::InvoiceUnderpaid),
+            "INVOICE_EXPIRY_TOO_SOON" => Some(Self::InvoiceExpiryTooSoon),
+            "INVOICE_NOT_OPEN" => Some(Self::InvoiceNotOpen),
+            "MPP_INVOICE_TIMEOUT" => Some(Self::MppInvoiceTimeout),
+            "ADDRESS_MISMATCH" => Some(Self::AddressMismatch),
+            "SET_TOTAL_MISMATCH" => Some(Self::SetTotalMismatch),
+            "SET_TOTAL_TOO_LOW" => Some(Self::SetTotalTooLow),
+            "SET_OVERPAID" => Some(Self::SetOverpaid),
+            "UNKNOWN_INVOICE" => Some(Self::UnknownInvoice),
+            "INVALID_KEYSEND" => Some(Self::InvalidKeysend),
+            "MPP_IN_PROGRESS" => Some(Self::MppInProgress),
+            "CIRCULAR_ROUTE" => Some(Self::CircularRoute),
+            "INVOICE_ALREADY_SETTLED" => Some(Self::InvoiceAlreadySettled),
+            "HTLC_INVOICE_TYPE_MISMATCH" => Some(Self::HtlcInvoiceTypeMismatch),
+            "AMP_ERROR" => Some(Self::AmpError),
+            "AMP_RECONSTRUCTION" => Some(Self::AmpReconstruction),
+            "EXTERNAL_VALIDATION_FAILED" => Some(Self::ExternalValidationFailed),
+            _ => None,
+        }
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ResolveHoldForwardAction {
+    /// SETTLE is an action that is used to settle an HTLC instead of forwarding
+    /// it.
+    #[cfg_attr(feature = "serde", serde(rename = "SETTLE"))]
+    Settle = 0,
+    /// FAIL is an action that is used to fail an HTLC backwards.
+    #[cfg_attr(feature = "serde", serde(rename = "FAIL"))]
+    Fail = 1,
+    /// RESUME is an action that is used to resume a forward HTLC.
+    #[cfg_attr(feature = "serde", serde(rename = "RESUME"))]
+    Resume = 2,
+    /// RESUME_MODIFIED is an action that is used to resume a hold forward HTLC
+    /// with modifications specified during interception.
+    #[cfg_attr(feature = "serde", serde(rename = "RESUME_MODIFIED"))]
+    ResumeModified = 3,
+}
+impl ResolveHoldForwardAction {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Settle => "SETTLE",
+            Self::Fail => "FAIL",
+            Self::Resume => "RESUME",
+            Self::ResumeModified => "RESUME_MODIFIED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SETTLE" => Some(Self::Settle),
+            "FAIL" => Some(Self::Fail),
+            "RESUME" => Some(Self::Resume),
+            "RESUME_MODIFIED" => Some(Self::ResumeModified),
+            _ => None,
+        }
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ChanStatusAction {
+    #[cfg_attr(feature = "serde", serde(rename = "ENABLE"))]
+    Enable = 0,
+    #[cfg_attr(feature = "serde", serde(rename = "DISABLE"))]
+    Disable = 1,
+    #[cfg_attr(feature = "serde", serde(rename = "AUTO"))]
+    Auto = 2,
+}
+impl ChanStatusAction {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Enable => "ENABLE",
+            Self::Disable => "DISABLE",
+            Self::Auto => "AUTO",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ENABLE" => Some(Self::Enable),
+            "DISABLE" => Some(Self::Disable),
+            "AUTO" => Some(Self::Auto),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod router_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Router is a service that offers advanced interaction with the router
+    /// subsystem of the daemon.
+    #[derive(Debug, Clone)]
+    pub struct RouterClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "transport")]
+    impl RouterClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> RouterClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> RouterClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            RouterClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// lncli: `sendpayment`
+        /// SendPaymentV2 attempts to route a payment described by the passed
+        /// PaymentRequest to the final destination. The call returns a stream of
+        /// payment updates. When using this RPC, make sure to set a fee limit, as the
+        /// default routing fee limit is 0 sats. Without a non-zero fee limit only
+        /// routes without fees will be attempted which often fails with
+        /// FAILURE_REASON_NO_ROUTE.
+        pub async fn send_payment_v2(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SendPaymentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::super::lnrpc::Payment>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/SendPaymentV2",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "SendPaymentV2"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// lncli: `trackpayment`
+        /// TrackPaymentV2 returns an update stream for the payment identified by the
+        /// payment hash.
+        pub async fn track_payment_v2(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TrackPaymentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::super::lnrpc::Payment>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/TrackPaymentV2",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "TrackPaymentV2"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// TrackPayments returns an update stream for every payment that is not in a
+        /// terminal state. Note that if payments are in-flight while starting a new
+        /// subscription, the start of the payment stream could produce out-of-order
+        /// and/or duplicate events. In order to get updates for every in-flight
+        /// payment attempt make sure to subscribe to this method before initiating any
+        /// payments.
+        pub async fn track_payments(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TrackPaymentsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::super::lnrpc::Payment>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/TrackPayments",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "TrackPayments"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// lncli: `estimateroutefee`
+        /// EstimateRouteFee allows callers to obtain a lower bound w.r.t how much it
+        /// may cost to send an HTLC to the target end destination.
+        pub async fn estimate_route_fee(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RouteFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RouteFeeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/EstimateRouteFee",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "EstimateRouteFee"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `sendtoroute`
+        /// SendToRouteV2 attempts to make a payment via the specified route. This
+        /// method differs from SendPayment in that it allows users to specify a full
+        /// route manually. This can be used for things like rebalancing, and atomic
+        /// swaps.
+        pub async fn send_to_route_v2(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SendToRouteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::lnrpc::HtlcAttempt>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/SendToRouteV2",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "SendToRouteV2"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `resetmc`
+        /// ResetMissionControl clears all mission control state and starts with a clean
+        /// slate.
+        pub async fn reset_mission_control(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ResetMissionControlRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ResetMissionControlResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/ResetMissionControl",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "ResetMissionControl"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `querymc`
+        /// QueryMissionControl exposes the internal mission control state to callers.
+        /// It is a development feature.
+        pub async fn query_mission_control(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryMissionControlRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryMissionControlResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/QueryMissionControl",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "QueryMissionControl"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `importmc`
+        /// XImportMissionControl is an experimental API that imports the state provided
+        /// to the internal mission control's state, using all results which are more
+        /// recent than our existing values. These values will only be imported
+        /// in-memory, and will not be persisted across restarts.
+        pub async fn x_import_mission_control(
+            &mut self,
+            request: impl tonic::IntoRequest<super::XImportMissionControlRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::XImportMissionControlResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/XImportMissionControl",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "XImportMissionControl"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `getmccfg`
+        /// GetMissionControlConfig returns mission control's current config.
+        pub async fn get_mission_control_config(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetMissionControlConfigRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetMissionControlConfigResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/GetMissionControlConfig",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "GetMissionControlConfig"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `setmccfg`
+        /// SetMissionControlConfig will set mission control's config, if the config
+        /// provided is valid.
+        pub async fn set_mission_control_config(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetMissionControlConfigRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetMissionControlConfigResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/SetMissionControlConfig",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "SetMissionControlConfig"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `queryprob`
+        /// Deprecated. QueryProbability returns the current success probability
+        /// estimate for a given node pair and amount. The call returns a zero success
+        /// probability if no channel is available or if the amount violates min/max
+        /// HTLC constraints.
+        pub async fn query_probability(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryProbabilityRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryProbabilityResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/QueryProbability",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "QueryProbability"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `buildroute`
+        /// BuildRoute builds a fully specified route based on a list of hop public
+        /// keys. It retrieves the relevant channel policies from the graph in order to
+        /// calculate the correct fees and time locks.
+        /// Note that LND will use its default final_cltv_delta if no value is supplied.
+        /// Make sure to add the correct final_cltv_delta depending on the invoice
+        /// restriction. Moreover the caller has to make sure to provide the
+        /// payment_addr if the route is paying an invoice which signaled it.
+        pub async fn build_route(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BuildRouteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BuildRouteResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/BuildRoute",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "BuildRoute"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// SubscribeHtlcEvents creates a uni-directional stream from the server to
+        /// the client which delivers a stream of htlc events.
+        pub async fn subscribe_htlc_events(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubscribeHtlcEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::HtlcEvent>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/SubscribeHtlcEvents",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "SubscribeHtlcEvents"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// *
+        ///
+        /// HtlcInterceptor dispatches a bi-directional streaming RPC in which
+        /// Forwarded HTLC requests are sent to the client and the client responds with
+        /// a boolean that tells LND if this htlc should be intercepted.
+        /// In case of interception, the htlc can be either settled, cancelled or
+        /// resumed later by using the ResolveHoldForward endpoint.
+        pub async fn htlc_interceptor(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<
+                Message = super::ForwardHtlcInterceptResponse,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ForwardHtlcInterceptRequest>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/HtlcInterceptor",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "HtlcInterceptor"));
+            self.inner.streaming(req, path, codec).await
+        }
+        /// lncli: `updatechanstatus`
+        /// UpdateChanStatus attempts to manually set the state of a channel
+        /// (enabled, disabled, or auto). A manual "disable" request will cause the
+        /// channel to stay disabled until a subsequent manual request of either
+        /// "enable" or "auto".
+        pub async fn update_chan_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateChanStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateChanStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/UpdateChanStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "UpdateChanStatus"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// XAddLocalChanAliases is an experimental API that creates a set of new
+        /// channel SCID alias mappings. The final total set of aliases in the manager
+        /// after the add operation is returned. This is only a locally stored alias,
+        /// and will not be communicated to the channel peer via any message. Therefore,
+        /// routing over such an alias will only work if the peer also calls this same
+        /// RPC on their end. If an alias already exists, an error is returned
+        pub async fn x_add_local_chan_aliases(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddAliasesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddAliasesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/XAddLocalChanAliases",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "XAddLocalChanAliases"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// XDeleteLocalChanAliases is an experimental API that deletes a set of alias
+        /// mappings. The final total set of aliases in the manager after the delete
+        /// operation is returned. The deletion will not be communicated to the channel
+        /// peer via any message.
+        pub async fn x_delete_local_chan_aliases(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteAliasesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteAliasesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/XDeleteLocalChanAliases",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "XDeleteLocalChanAliases"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// XFindBaseLocalChanAlias is an experimental API that looks up the base scid
+        /// for a local chan alias that was registered during the current runtime.
+        pub async fn x_find_base_local_chan_alias(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindBaseAliasRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FindBaseAliasResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/XFindBaseLocalChanAlias",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "XFindBaseLocalChanAlias"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `deletefwdhistory`
+        /// DeleteForwardingHistory allows the caller to delete forwarding history
+        /// events with a timestamp at or before a specified time. This is useful
+        /// for implementing data retention policies for privacy purposes. The call
+        /// deletes events in batches and returns statistics including the total number
+        /// of events deleted and the aggregate fees earned from those events. The
+        /// deletion is performed in a transaction-safe manner with configurable batch
+        /// sizes to avoid holding large database locks.
+        pub async fn delete_forwarding_history(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteForwardingHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteForwardingHistoryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/routerrpc.Router/DeleteForwardingHistory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("routerrpc.Router", "DeleteForwardingHistory"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod router_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with RouterServer.
+    #[async_trait]
+    pub trait Router: std::marker::Send + std::marker::Sync + 'static {
+        /// Server streaming response type for the SendPaymentV2 method.
+        type SendPaymentV2Stream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::super::lnrpc::Payment, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// lncli: `sendpayment`
+        /// SendPaymentV2 attempts to route a payment described by the passed
+        /// PaymentRequest to the final destination. The call returns a stream of
+        /// payment updates. When using this RPC, make sure to set a fee limit, as the
+        /// default routing fee limit is 0 sats. Without a non-zero fee limit only
+        /// routes without fees will be attempted which often fails with
+        /// FAILURE_REASON_NO_ROUTE.
+        async fn send_payment_v2(
+            &self,
+            request: tonic::Request<super::SendPaymentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::SendPaymentV2Stream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the TrackPaymentV2 method.
+        type TrackPaymentV2Stream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::super::lnrpc::Payment, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// lncli: `trackpayment`
+        /// TrackPaymentV2 returns an update stream for the payment identified by the
+        /// payment hash.
+        async fn track_payment_v2(
+            &self,
+            request: tonic::Request<super::TrackPaymentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::TrackPaymentV2Stream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the TrackPayments method.
+        type TrackPaymentsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::super::lnrpc::Payment, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// TrackPayments returns an update stream for every payment that is not in a
+        /// terminal state. Note that if payments are in-flight while starting a new
+        /// subscription, the start of the payment stream could produce out-of-order
+        /// and/or duplicate events. In order to get updates for every in-flight
+        /// payment attempt make sure to subscribe to this method before initiating any
+        /// payments.
+        async fn track_payments(
+            &self,
+            request: tonic::Request<super::TrackPaymentsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::TrackPaymentsStream>,
+            tonic::Status,
+        >;
+        /// lncli: `estimateroutefee`
+        /// EstimateRouteFee allows callers to obtain a lower bound w.r.t how much it
+        /// may cost to send an HTLC to the target end destination.
+        async fn estimate_route_fee(
+            &self,
+            request: tonic::Request<super::RouteFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RouteFeeResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `sendtoroute`
+        /// SendToRouteV2 attempts to make a payment via the specified route. This
+        /// method differs from SendPayment in that it allows users to specify a full
+        /// route manually. This can be used for things like rebalancing, and atomic
+        /// swaps.
+        async fn send_to_route_v2(
+            &self,
+            request: tonic::Request<super::SendToRouteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::lnrpc::HtlcAttempt>,
+            tonic::Status,
+        >;
+        /// lncli: `resetmc`
+        /// ResetMissionControl clears all mission control state and starts with a clean
+        /// slate.
+        async fn reset_mission_control(
+            &self,
+            request: tonic::Request<super::ResetMissionControlRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ResetMissionControlResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `querymc`
+        /// QueryMissionControl exposes the internal mission control state to callers.
+        /// It is a development feature.
+        async fn query_mission_control(
+            &self,
+            request: tonic::Request<super::QueryMissionControlRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryMissionControlResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `importmc`
+        /// XImportMissionControl is an experimental API that imports the state provided
+        /// to the internal mission control's state, using all results which are more
+        /// recent than our existing values. These values will only be imported
+        /// in-memory, and will not be persisted across restarts.
+        async fn x_import_mission_control(
+            &self,
+            request: tonic::Request<super::XImportMissionControlRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::XImportMissionControlResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `getmccfg`
+        /// GetMissionControlConfig returns mission control's current config.
+        async fn get_mission_control_config(
+            &self,
+            request: tonic::Request<super::GetMissionControlConfigRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetMissionControlConfigResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `setmccfg`
+        /// SetMissionControlConfig will set mission control's config, if the config
+        /// provided is valid.
+        async fn set_mission_control_config(
+            &self,
+            request: tonic::Request<super::SetMissionControlConfigRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetMissionControlConfigResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `queryprob`
+        /// Deprecated. QueryProbability returns the current success probability
+        /// estimate for a given node pair and amount. The call returns a zero success
+        /// probability if no channel is available or if the amount violates min/max
+        /// HTLC constraints.
+        async fn query_probability(
+            &self,
+            request: tonic::Request<super::QueryProbabilityRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryProbabilityResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `buildroute`
+        /// BuildRoute builds a fully specified route based on a list of hop public
+        /// keys. It retrieves the relevant channel policies from the graph in order to
+        /// calculate the correct fees and time locks.
+        /// Note that LND will use its default final_cltv_delta if no value is supplied.
+        /// Make sure to add the correct final_cltv_delta depending on the invoice
+        /// restriction. Moreover the caller has to make sure to provide the
+        /// payment_addr if the route is paying an invoice which signaled it.
+        async fn build_route(
+            &self,
+            request: tonic::Request<super::BuildRouteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BuildRouteResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the SubscribeHtlcEvents method.
+        type SubscribeHtlcEventsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::HtlcEvent, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// SubscribeHtlcEvents creates a uni-directional stream from the server to
+        /// the client which delivers a stream of htlc events.
+        async fn subscribe_htlc_events(
+            &self,
+            request: tonic::Request<super::SubscribeHtlcEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::SubscribeHtlcEventsStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the HtlcInterceptor method.
+        type HtlcInterceptorStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::ForwardHtlcInterceptRequest,
+                    tonic::Status,
+                >,
+            >
+            + std::marker::Send
+            + 'static;
+        /// *
+        ///
+        /// HtlcInterceptor dispatches a bi-directional streaming RPC in which
+        /// Forwarded HTLC requests are sent to the client and the client responds with
+        /// a boolean that tells LND if this htlc should be intercepted.
+        /// In case of interception, the htlc can be either settled, cancelled or
+        /// resumed later by using the ResolveHoldForward endpoint.
+        async fn htlc_interceptor(
+            &self,
+            request: tonic::Request<
+                tonic::Streaming<super::ForwardHtlcInterceptResponse>,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<Self::HtlcInterceptorStream>,
+            tonic::Status,
+        >;
+        /// lncli: `updatechanstatus`
+        /// UpdateChanStatus attempts to manually set the state of a channel
+        /// (enabled, disabled, or auto). A manual "disable" request will cause the
+        /// channel to stay disabled until a subsequent manual request of either
+        /// "enable" or "auto".
+        async fn update_chan_status(
+            &self,
+            request: tonic::Request<super::UpdateChanStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateChanStatusResponse>,
+            tonic::Status,
+        >;
+        /// XAddLocalChanAliases is an experimental API that creates a set of new
+        /// channel SCID alias mappings. The final total set of aliases in the manager
+        /// after the add operation is returned. This is only a locally stored alias,
+        /// and will not be communicated to the channel peer via any message. Therefore,
+        /// routing over such an alias will only work if the peer also calls this same
+        /// RPC on their end. If an alias already exists, an error is returned
+        async fn x_add_local_chan_aliases(
+            &self,
+            request: tonic::Request<super::AddAliasesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddAliasesResponse>,
+            tonic::Status,
+        >;
+        /// XDeleteLocalChanAliases is an experimental API that deletes a set of alias
+        /// mappings. The final total set of aliases in the manager after the delete
+        /// operation is returned. The deletion will not be communicated to the channel
+        /// peer via any message.
+        async fn x_delete_local_chan_aliases(
+            &self,
+            request: tonic::Request<super::DeleteAliasesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteAliasesResponse>,
+            tonic::Status,
+        >;
+        /// XFindBaseLocalChanAlias is an experimental API that looks up the base scid
+        /// for a local chan alias that was registered during the current runtime.
+        async fn x_find_base_local_chan_alias(
+            &self,
+            request: tonic::Request<super::FindBaseAliasRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FindBaseAliasResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `deletefwdhistory`
+        /// DeleteForwardingHistory allows the caller to delete forwarding history
+        /// events with a timestamp at or before a specified time. This is useful
+        /// for implementing data retention policies for privacy purposes. The call
+        /// deletes events in batches and returns statistics including the total number
+        /// of events deleted and the aggregate fees earned from those events. The
+        /// deletion is performed in a transaction-safe manner with configurable batch
+        /// sizes to avoid holding large database locks.
+        async fn delete_forwarding_history(
+            &self,
+            request: tonic::Request<super::DeleteForwardingHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteForwardingHistoryResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Router is a service that offers advanced interaction with the router
+    /// subsystem of the daemon.
+    #[derive(Debug)]
+    pub struct RouterServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> RouterServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for RouterServer<T>
+    where
+        T: Router,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/routerrpc.Router/SendPaymentV2" => {
+                    #[allow(non_camel_case_types)]
+                    struct SendPaymentV2Svc<T: Router>(pub Arc<T>);
+                    impl<
+                        T: Router,
+                    > tonic::server::ServerStreamingService<super::SendPaymentRequest>
+                    for SendPaymentV2Svc<T> {
+                        type Response = super::super::lnrpc::Payment;
+                        type ResponseStream = T::SendPaymentV2Stream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SendPaymentRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::send_payment_v2(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SendPaymentV2Svc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/routerrpc.Router/TrackPaymentV2" => {
+                    #[allow(non_camel_case_types)]
+                    struct TrackPaymentV2Svc<T: Router>(pub Arc<T>);
+                    impl<
+                        T: Router,
+                    > tonic::server::ServerStreamingService<super::TrackPaymentRequest>
+                    for TrackPaymentV2Svc<T> {
+                        type Response = super::super::lnrpc::Payment;
+                        type ResponseStream = T::TrackPaymentV2Stream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TrackPaymentRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::track_payment_v2(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TrackPaymentV2Svc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/routerrpc.Router/TrackPayments" => {
+                    #[allow(non_camel_case_types)]
+                    struct TrackPaymentsSvc<T: Router>(pub Arc<T>);
+                    impl<
+                        T: Router,
+                    > tonic::server::ServerStreamingService<super::TrackPaymentsRequest>
+                    for TrackPaymentsSvc<T> {
+                        type Response = super::super::lnrpc::Payment;
+                        type ResponseStream = T::TrackPaymentsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TrackPaymentsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::track_payments(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TrackPaymentsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/routerrpc.Router/EstimateRouteFee" => {
+                    #[allow(non_camel_case_types)]
+                    struct EstimateRouteFeeSvc<T: Router>(pub Arc<T>);
+                    impl<T: Router> tonic::server::UnaryService<super::RouteFeeRequest>
+                    for EstimateRouteFeeSvc<T> {
+                        type Response = super::RouteFeeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RouteFeeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::estimate_route_fee(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EstimateRouteFeeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/routerrpc.Router/SendToRouteV2" => {
+                    #[allow(non_camel_case_types)]
+                    struct SendToRouteV2Svc<T: Router>(pub Arc<T>);
+                    impl<
+                        T: Router,
+                    > tonic::server::UnaryService<super::SendToRouteRequest>
+                    for SendToRouteV2Svc<T> {
+                        type Response = super::super::lnrpc::HtlcAttempt;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SendToRouteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::send_to_route_v2(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SendToRouteV2Svc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/routerrpc.Router/ResetMissionControl" => {
+                    #[allow(non_camel_case_types)]
+                    struct ResetMissionControlSvc<T: Router>(pub Arc<T>);
+                    impl<
+                        T: Router,
+                    > tonic::server::UnaryService<super::ResetMissionControlRequest>
+                    for ResetMissionControlSvc<T> {
+                        type Response = super::ResetMissionControlResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ResetMissionControlRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::reset_mission_control(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ResetMissionControlSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/routerrpc.Router/QueryMissionControl" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryMissionControlSvc<T: Router>(pub Arc<T>);
+                    impl<
+                        T: Router,
+                    > tonic::server::UnaryService<super::QueryMissionControlRequest>
+                    for QueryMissionControlSvc<T> {
+                        type Response = super::QueryMissionControlResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryMissionControlRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::query_mission_control(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryMissionControlSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/routerrpc.Router/XImportMissionControl" => {
+                    #[allow(non_camel_case_types)]
+                    struct XImportMissionControlSvc<T: Router>(pub Arc<T>);
+                    impl<
+                        T: Router,
+                    > tonic::server::UnaryService<super::XImportMissionControlRequest>
+                    for XImportMissionControlSvc<T> {
+                        type Response = super::XImportMissionControlResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::XImportMissionControlRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::x_import_mission_control(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = XImportMissionControlSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/routerrpc.Router/GetMissionControlConfig" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetMissionControlConfigSvc<T: Router>(pub Arc<T>);
+                    impl<
+                        T: Router,
+                    > tonic::server::UnaryService<super::GetMissionControlConfigRequest>
+                    for GetMissionControlConfigSvc<T> {
+                        type Response = super::GetMissionControlConfigResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetMissionControlConfigRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::get_mission_control_config(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetMissionControlConfigSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/routerrpc.Router/SetMissionControlConfig" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetMissionControlConfigSvc<T: Router>(pub Arc<T>);
+                    impl<
+                        T: Router,
+                    > tonic::server::UnaryService<super::SetMissionControlConfigRequest>
+                    for SetMissionControlConfigSvc<T> {
+                        type Response = super::SetMissionControlConfigResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::SetMissionControlConfigRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::set_mission_control_config(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetMissionControlConfigSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/routerrpc.Router/QueryProbability" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryProbabilitySvc<T: Router>(pub Arc<T>);
+                    impl<
+                        T: Router,
+                    > tonic::server::UnaryService<super::QueryProbabilityRequest>
+                    for QueryProbabilitySvc<T> {
+                        type Response = super::QueryProbabilityResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryProbabilityRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::query_probability(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryProbabilitySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/routerrpc.Router/BuildRoute" => {
+                    #[allow(non_camel_case_types)]
+                    struct BuildRouteSvc<T: Router>(pub Arc<T>);
+                    impl<T: Router> tonic::server::UnaryService<super::BuildRouteRequest>
+                    for BuildRouteSvc<T> {
+                        type Response = super::BuildRouteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BuildRouteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::build_route(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BuildRouteSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/routerrpc.Router/SubscribeHtlcEvents" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeHtlcEventsSvc<T: Router>(pub Arc<T>);
+                    impl<
+                        T: Router,
+                    > tonic::server::ServerStreamingService<
+                        super::SubscribeHtlcEventsRequest,
+                    > for SubscribeHtlcEventsSvc<T> {
+                        type Response = super::HtlcEvent;
+                        type ResponseStream = T::SubscribeHtlcEventsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubscribeHtlcEventsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::subscribe_htlc_events(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SubscribeHtlcEventsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/routerrpc.Router/HtlcInterceptor" => {
+                    #[allow(non_camel_case_types)]
+                    struct HtlcInterceptorSvc<T: Router>(pub Arc<T>);
+                    impl<
+                        T: Router,
+                    > tonic::server::StreamingService<
+                        super::ForwardHtlcInterceptResponse,
+                    > for HtlcInterceptorSvc<T> {
+                        type Response = super::ForwardHtlcInterceptRequest;
+                        type ResponseStream = T::HtlcInterceptorStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::ForwardHtlcInterceptResponse>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::htlc_interceptor(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = HtlcInterceptorSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/routerrpc.Router/UpdateChanStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateChanStatusSvc<T: Router>(pub Arc<T>);
+                    impl<
+                        T: Router,
+                    > tonic::server::UnaryService<super::UpdateChanStatusRequest>
+                    for UpdateChanStatusSvc<T> {
+                        type Response = super::UpdateChanStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateChanStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::update_chan_status(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UpdateChanStatusSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/routerrpc.Router/XAddLocalChanAliases" => {
+                    #[allow(non_camel_case_types)]
+                    struct XAddLocalChanAliasesSvc<T: Router>(pub Arc<T>);
+                    impl<T: Router> tonic::server::UnaryService<super::AddAliasesRequest>
+                    for XAddLocalChanAliasesSvc<T> {
+                        type Response = super::AddAliasesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddAliasesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::x_add_local_chan_aliases(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = XAddLocalChanAliasesSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/routerrpc.Router/XDeleteLocalChanAliases" => {
+                    #[allow(non_camel_case_types)]
+                    struct XDeleteLocalChanAliasesSvc<T: Router>(pub Arc<T>);
+                    impl<
+                        T: Router,
+                    > tonic::server::UnaryService<super::DeleteAliasesRequest>
+                    for XDeleteLocalChanAliasesSvc<T> {
+                        type Response = super::DeleteAliasesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteAliasesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::x_delete_local_chan_aliases(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = XDeleteLocalChanAliasesSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/routerrpc.Router/XFindBaseLocalChanAlias" => {
+                    #[allow(non_camel_case_types)]
+                    struct XFindBaseLocalChanAliasSvc<T: Router>(pub Arc<T>);
+                    impl<
+                        T: Router,
+                    > tonic::server::UnaryService<super::FindBaseAliasRequest>
+                    for XFindBaseLocalChanAliasSvc<T> {
+                        type Response = super::FindBaseAliasResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindBaseAliasRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::x_find_base_local_chan_alias(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = XFindBaseLocalChanAliasSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/routerrpc.Router/DeleteForwardingHistory" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteForwardingHistorySvc<T: Router>(pub Arc<T>);
+                    impl<
+                        T: Router,
+                    > tonic::server::UnaryService<super::DeleteForwardingHistoryRequest>
+                    for DeleteForwardingHistorySvc<T> {
+                        type Response = super::DeleteForwardingHistoryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::DeleteForwardingHistoryRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Router>::delete_forwarding_history(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeleteForwardingHistorySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for RouterServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "routerrpc.Router";
+    impl<T> tonic::server::NamedService for RouterServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+impl crate::routerrpc::SendPaymentRequest {
+    /// Returns the values of `dest_features`, or an error for values unknown to this version of the protos.
+    pub fn try_dest_features(&self) -> impl ::core::iter::Iterator<Item = ::core::result::Result<crate::lnrpc::FeatureBit, ::prost::UnknownEnumValue>> + '_ {
+        self.dest_features.iter().map(|&value| crate::lnrpc::FeatureBit::try_from(value))
+    }
+}
+impl crate::routerrpc::RouteFeeResponse {
+    /// Returns `failure_reason`, or an error for values unknown to this version of the protos.
+    pub fn try_failure_reason(&self) -> ::core::result::Result<crate::lnrpc::PaymentFailureReason, ::prost::UnknownEnumValue> {
+        crate::lnrpc::PaymentFailureReason::try_from(self.failure_reason)
+    }
+}
+impl crate::routerrpc::MissionControlConfig {
+    /// Returns `model`, or an error for values unknown to this version of the protos.
+    pub fn try_model(&self) -> ::core::result::Result<crate::routerrpc::mission_control_config::ProbabilityModel, ::prost::UnknownEnumValue> {
+        crate::routerrpc::mission_control_config::ProbabilityModel::try_from(self.model)
+    }
+}
+impl crate::routerrpc::HtlcEvent {
+    /// Returns `event_type`, or an error for values unknown to this version of the protos.
+    pub fn try_event_type(&self) -> ::core::result::Result<crate::routerrpc::htlc_event::EventType, ::prost::UnknownEnumValue> {
+        crate::routerrpc::htlc_event::EventType::try_from(self.event_type)
+    }
+}
+impl crate::routerrpc::LinkFailEvent {
+    /// Returns `wire_failure`, or an error for values unknown to this version of the protos.
+    pub fn try_wire_failure(&self) -> ::core::result::Result<crate::lnrpc::failure::FailureCode, ::prost::UnknownEnumValue> {
+        crate::lnrpc::failure::FailureCode::try_from(self.wire_failure)
+    }
+    /// Returns `failure_detail`, or an error for values unknown to this version of the protos.
+    pub fn try_failure_detail(&self) -> ::core::result::Result<crate::routerrpc::FailureDetail, ::prost::UnknownEnumValue> {
+        crate::routerrpc::FailureDetail::try_from(self.failure_detail)
+    }
+}
+impl crate::routerrpc::ForwardHtlcInterceptResponse {
+    /// Returns `action`, or an error for values unknown to this version of the protos.
+    pub fn try_action(&self) -> ::core::result::Result<crate::routerrpc::ResolveHoldForwardAction, ::prost::UnknownEnumValue> {
+        crate::routerrpc::ResolveHoldForwardAction::try_from(self.action)
+    }
+    /// Returns `failure_code`, or an error for values unknown to this version of the protos.
+    pub fn try_failure_code(&self) -> ::core::result::Result<crate::lnrpc::failure::FailureCode, ::prost::UnknownEnumValue> {
+        crate::lnrpc::failure::FailureCode::try_from(self.failure_code)
+    }
+}
+impl crate::routerrpc::UpdateChanStatusRequest {
+    /// Returns `action`, or an error for values unknown to this version of the protos.
+    pub fn try_action(&self) -> ::core::result::Result<crate::routerrpc::ChanStatusAction, ::prost::UnknownEnumValue> {
+        crate::routerrpc::ChanStatusAction::try_from(self.action)
+    }
+}
+/// Generated blocking client implementations.
+#[cfg(feature = "blocking")]
+pub mod router_blocking_client {
+    #![allow(clippy::wildcard_imports)]
+    use tonic::codegen::*;
+    use super::router_client::RouterClient;
+    /// A [`RouterClient`] that blocks the current thread on every call, driving the
+    /// requests on its runtime.
+    #[derive(Debug, Clone)]
+    pub struct RouterBlockingClient<T> {
+        inner: RouterClient<T>,
+        runtime: std::sync::Arc<crate::blocking::Runtime>,
+    }
+    impl<T> RouterBlockingClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        /// Wraps `inner`, running its calls on `runtime`.
+        pub fn new(inner: RouterClient<T>, runtime: std::sync::Arc<crate::blocking::Runtime>) -> Self {
+            Self { inner, runtime }
+        }
+        /// Returns the async client.
+        pub fn into_inner(self) -> RouterClient<T> {
+            self.inner
+        }
+        /// Blocking version of [`RouterClient::send_payment_v2`].
+        pub fn send_payment_v2(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::routerrpc::SendPaymentRequest>,
+        ) -> std::result::Result<tonic::Response<crate::blocking::Streaming<crate::lnrpc::Payment>>, tonic::Status> {
+            let response = self.runtime.block_on(self.inner.send_payment_v2(request))?;
+            Ok(response.map(|stream| crate::blocking::Streaming::new(stream, self.runtime.clone())))
+        }
+        /// Blocking version of [`RouterClient::track_payment_v2`].
+        pub fn track_payment_v2(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::routerrpc::TrackPaymentRequest>,
+        ) -> std::result::Result<tonic::Response<crate::blocking::Streaming<crate::lnrpc::Payment>>, tonic::Status> {
+            let response = self.runtime.block_on(self.inner.track_payment_v2(request))?;
+            Ok(response.map(|stream| crate::blocking::Streaming::new(stream, self.runtime.clone())))
+        }
+        /// Blocking version of [`RouterClient::track_payments`].
+        pub fn track_payments(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::routerrpc::TrackPaymentsRequest>,
+        ) -> std::result::Result<tonic::Response<crate::blocking::Streaming<crate::lnrpc::Payment>>, tonic::Status> {
+            let response = self.runtime.block_on(self.inner.track_payments(request))?;
+            Ok(response.map(|stream| crate::blocking::Streaming::new(stream, self.runtime.clone())))
+        }
+        /// Blocking version of [`RouterClient::estimate_route_fee`].
+        pub fn estimate_route_fee(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::routerrpc::RouteFeeRequest>,
+        ) -> std::result::Result<tonic::Response<crate::routerrpc::RouteFeeResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.estimate_route_fee(request))
+        }
+        /// Blocking version of [`RouterClient::send_to_route_v2`].
+        pub fn send_to_route_v2(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::routerrpc::SendToRouteRequest>,
+        ) -> std::result::Result<tonic::Response<crate::lnrpc::HtlcAttempt>, tonic::Status> {
+            self.runtime.block_on(self.inner.send_to_route_v2(request))
+        }
+        /// Blocking version of [`RouterClient::reset_mission_control`].
+        pub fn reset_mission_control(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::routerrpc::ResetMissionControlRequest>,
+        ) -> std::result::Result<tonic::Response<crate::routerrpc::ResetMissionControlResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.reset_mission_control(request))
+        }
+        /// Blocking version of [`RouterClient::query_mission_control`].
+        pub fn query_mission_control(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::routerrpc::QueryMissionControlRequest>,
+        ) -> std::result::Result<tonic::Response<crate::routerrpc::QueryMissionControlResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.query_mission_control(request))
+        }
+        /// Blocking version of [`RouterClient::x_import_mission_control`].
+        pub fn x_import_mission_control(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::routerrpc::XImportMissionControlRequest>,
+        ) -> std::result::Result<tonic::Response<crate::routerrpc::XImportMissionControlResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.x_import_mission_control(request))
+        }
+        /// Blocking version of [`RouterClient::get_mission_control_config`].
+        pub fn get_mission_control_config(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::routerrpc::GetMissionControlConfigRequest>,
+        ) -> std::result::Result<tonic::Response<crate::routerrpc::GetMissionControlConfigResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.get_mission_control_config(request))
+        }
+        /// Blocking version of [`RouterClient::set_mission_control_config`].
+        pub fn set_mission_control_config(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::routerrpc::SetMissionControlConfigRequest>,
+        ) -> std::result::Result<tonic::Response<crate::routerrpc::SetMissionControlConfigResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.set_mission_control_config(request))
+        }
+        /// Blocking version of [`RouterClient::query_probability`].
+        pub fn query_probability(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::routerrpc::QueryProbabilityRequest>,
+        ) -> std::result::Result<tonic::Response<crate::routerrpc::QueryProbabilityResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.query_probability(request))
+        }
+        /// Blocking version of [`RouterClient::build_route`].
+        pub fn build_route(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::routerrpc::BuildRouteRequest>,
+        ) -> std::result::Result<tonic::Response<crate::routerrpc::BuildRouteResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.build_route(request))
+        }
+        /// Blocking version of [`RouterClient::subscribe_htlc_events`].
+        pub fn subscribe_htlc_events(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::routerrpc::SubscribeHtlcEventsRequest>,
+        ) -> std::result::Result<tonic::Response<crate::blocking::Streaming<crate::routerrpc::HtlcEvent>>, tonic::Status> {
+            let response = self.runtime.block_on(self.inner.subscribe_htlc_events(request))?;
+            Ok(response.map(|stream| crate::blocking::Streaming::new(stream, self.runtime.clone())))
+        }
+        /// Blocking version of [`RouterClient::htlc_interceptor`].
+        pub fn htlc_interceptor(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = crate::routerrpc::ForwardHtlcInterceptResponse>,
+        ) -> std::result::Result<tonic::Response<crate::blocking::Streaming<crate::routerrpc::ForwardHtlcInterceptRequest>>, tonic::Status> {
+            let response = self.runtime.block_on(self.inner.htlc_interceptor(request))?;
+            Ok(response.map(|stream| crate::blocking::Streaming::new(stream, self.runtime.clone())))
+        }
+        /// Blocking version of [`RouterClient::update_chan_status`].
+        pub fn update_chan_status(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::routerrpc::UpdateChanStatusRequest>,
+        ) -> std::result::Result<tonic::Response<crate::routerrpc::UpdateChanStatusResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.update_chan_status(request))
+        }
+        /// Blocking version of [`RouterClient::x_add_local_chan_aliases`].
+        pub fn x_add_local_chan_aliases(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::routerrpc::AddAliasesRequest>,
+        ) -> std::result::Result<tonic::Response<crate::routerrpc::AddAliasesResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.x_add_local_chan_aliases(request))
+        }
+        /// Blocking version of [`RouterClient::x_delete_local_chan_aliases`].
+        pub fn x_delete_local_chan_aliases(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::routerrpc::DeleteAliasesRequest>,
+        ) -> std::result::Result<tonic::Response<crate::routerrpc::DeleteAliasesResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.x_delete_local_chan_aliases(request))
+        }
+        /// Blocking version of [`RouterClient::x_find_base_local_chan_alias`].
+        pub fn x_find_base_local_chan_alias(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::routerrpc::FindBaseAliasRequest>,
+        ) -> std::result::Result<tonic::Response<crate::routerrpc::FindBaseAliasResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.x_find_base_local_chan_alias(request))
+        }
+        /// Blocking version of [`RouterClient::delete_forwarding_history`].
+        pub fn delete_forwarding_history(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::routerrpc::DeleteForwardingHistoryRequest>,
+        ) -> std::result::Result<tonic::Response<crate::routerrpc::DeleteForwardingHistoryResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.delete_forwarding_history(request))
+        }
+    }
+}
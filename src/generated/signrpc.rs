@@ -0,0 +1,2528 @@
+// This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct KeyLocator {
+    /// The family of key being identified.
+    #[prost(int32, tag = "1")]
+    pub key_family: i32,
+    /// The precise index of the key being identified.
+    #[prost(int32, tag = "2")]
+    pub key_index: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct KeyDescriptor {
+    /// The raw bytes of the public key in the key pair being identified. Either
+    /// this or the KeyLocator must be specified.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub raw_key_bytes: ::prost::alloc::vec::Vec<u8>,
+    /// The key locator that identifies which private key to use for signing.
+    /// Either this or the raw bytes of the target public key must be specified.
+    #[prost(message, optional, tag = "2")]
+    pub key_loc: ::core::option::Option<KeyLocator>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TxOut {
+    /// The value of the output being spent.
+    #[prost(int64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub value: i64,
+    /// The script of the output being spent.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub pk_script: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SignDescriptor {
+    /// A descriptor that precisely describes *which* key to use for signing. This
+    /// may provide the raw public key directly, or require the Signer to re-derive
+    /// the key according to the populated derivation path.
+    ///
+    /// Note that if the key descriptor was obtained through walletrpc.DeriveKey,
+    /// then the key locator MUST always be provided, since the derived keys are not
+    /// persisted unlike with DeriveNextKey.
+    #[prost(message, optional, tag = "1")]
+    pub key_desc: ::core::option::Option<KeyDescriptor>,
+    /// A scalar value that will be added to the private key corresponding to the
+    /// above public key to obtain the private key to be used to sign this input.
+    /// This value is typically derived via the following computation:
+    ///
+    /// derivedKey = privkey + sha256(perCommitmentPoint || pubKey) mod N
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub single_tweak: ::prost::alloc::vec::Vec<u8>,
+    /// A private key that will be used in combination with its corresponding
+    /// private key to derive the private key that is to be used to sign the target
+    /// input. Within the Lightning protocol, this value is typically the
+    /// commitment secret from a previously revoked commitment transaction. This
+    /// value is in combination with two hash values, and the original private key
+    /// to derive the private key to be used when signing.
+    ///
+    /// k = (privKey*sha256(pubKey || tweakPub) +
+    /// tweakPriv*sha256(tweakPub || pubKey)) mod N
+    #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub double_tweak: ::prost::alloc::vec::Vec<u8>,
+    /// The 32 byte input to the taproot tweak derivation that is used to derive
+    /// the output key from an internal key: outputKey = internalKey +
+    /// tagged_hash("tapTweak", internalKey || tapTweak).
+    ///
+    /// When doing a BIP 86 spend, this field can be an empty byte slice.
+    ///
+    /// When doing a normal key path spend, with the output key committing to an
+    /// actual script root, then this field should be: the tapscript root hash.
+    #[prost(bytes = "vec", tag = "10")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub tap_tweak: ::prost::alloc::vec::Vec<u8>,
+    /// The full script required to properly redeem the output. This field will
+    /// only be populated if a p2tr, p2wsh or a p2sh output is being signed. If a
+    /// taproot script path spend is being attempted, then this should be the raw
+    /// leaf script.
+    #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub witness_script: ::prost::alloc::vec::Vec<u8>,
+    /// A description of the output being spent. The value and script MUST be
+    /// provided.
+    #[prost(message, optional, tag = "5")]
+    pub output: ::core::option::Option<TxOut>,
+    /// The target sighash type that should be used when generating the final
+    /// sighash, and signature.
+    #[prost(uint32, tag = "7")]
+    pub sighash: u32,
+    /// The target input within the transaction that should be signed.
+    #[prost(int32, tag = "8")]
+    pub input_index: i32,
+    /// The sign method specifies how the input should be signed. Depending on the
+    /// method, either the tap_tweak, witness_script or both need to be specified.
+    /// Defaults to SegWit v0 signing to be backward compatible with older RPC
+    /// clients.
+    #[prost(enumeration = "SignMethod", tag = "9")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::signrpc::SignMethod>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::signrpc::SignMethod>, _, _>"
+        )
+    )]
+    pub sign_method: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignReq {
+    /// The raw bytes of the transaction to be signed.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub raw_tx_bytes: ::prost::alloc::vec::Vec<u8>,
+    /// A set of sign descriptors, for each input to be signed.
+    #[prost(message, repeated, tag = "2")]
+    pub sign_descs: ::prost::alloc::vec::Vec<SignDescriptor>,
+    /// The full list of UTXO information for each of the inputs being spent. This
+    /// is required when spending one or more taproot (SegWit v1) outputs.
+    #[prost(message, repeated, tag = "3")]
+    pub prev_outputs: ::prost::alloc::vec::Vec<TxOut>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SignResp {
+    /// A set of signatures realized in a fixed 64-byte format ordered in ascending
+    /// input order.
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub raw_sigs: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct InputScript {
+    /// The serializes witness stack for the specified input.
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub witness: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// The optional sig script for the specified witness that will only be set if
+    /// the input specified is a nested p2sh witness program.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub sig_script: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InputScriptResp {
+    /// The set of fully valid input scripts requested.
+    #[prost(message, repeated, tag = "1")]
+    pub input_scripts: ::prost::alloc::vec::Vec<InputScript>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SignMessageReq {
+    /// The message to be signed. When using REST, this field must be encoded as
+    /// base64.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub msg: ::prost::alloc::vec::Vec<u8>,
+    /// The key locator that identifies which key to use for signing.
+    #[prost(message, optional, tag = "2")]
+    pub key_loc: ::core::option::Option<KeyLocator>,
+    /// Double-SHA256 hash instead of just the default single round.
+    #[prost(bool, tag = "3")]
+    pub double_hash: bool,
+    /// Use the compact (pubkey recoverable) format instead of the raw lnwire
+    /// format. This option cannot be used with Schnorr signatures.
+    #[prost(bool, tag = "4")]
+    pub compact_sig: bool,
+    /// Use Schnorr signature. This option cannot be used with compact format.
+    #[prost(bool, tag = "5")]
+    pub schnorr_sig: bool,
+    /// The optional Taproot tweak bytes to apply to the private key before creating
+    /// a Schnorr signature. The private key is tweaked as described in BIP-341:
+    /// privKey + h_tapTweak(internalKey || tapTweak)
+    #[prost(bytes = "vec", tag = "6")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub schnorr_sig_tap_tweak: ::prost::alloc::vec::Vec<u8>,
+    /// An optional tag that can be provided when taking a tagged hash of a
+    /// message. This option can only be used when schnorr_sig is true.
+    #[prost(bytes = "vec", tag = "7")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub tag: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SignMessageResp {
+    /// The signature for the given message in the fixed-size LN wire format.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct VerifyMessageReq {
+    /// The message over which the signature is to be verified. When using
+    /// REST, this field must be encoded as base64.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub msg: ::prost::alloc::vec::Vec<u8>,
+    /// The fixed-size LN wire encoded signature to be verified over the given
+    /// message. When using REST, this field must be encoded as base64.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+    /// The public key the signature has to be valid for. When using REST, this
+    /// field must be encoded as base64. If the is_schnorr_sig option is true, then
+    /// the public key is expected to be in the 32-byte x-only serialization
+    /// according to BIP-340.
+    #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub pubkey: ::prost::alloc::vec::Vec<u8>,
+    /// Specifies if the signature is a Schnorr signature.
+    #[prost(bool, tag = "4")]
+    pub is_schnorr_sig: bool,
+    /// An optional tag that can be provided when taking a tagged hash of a
+    /// message. This option can only be used when is_schnorr_sig is true.
+    #[prost(bytes = "vec", tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub tag: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct VerifyMessageResp {
+    /// Whether the signature was valid over the given message.
+    #[prost(bool, tag = "1")]
+    pub valid: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SharedKeyRequest {
+    /// The ephemeral public key to use for the DH key derivation.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub ephemeral_pubkey: ::prost::alloc::vec::Vec<u8>,
+    /// Deprecated. The optional key locator of the local key that should be used.
+    /// If this parameter is not set then the node's identity private key will be
+    /// used.
+    #[deprecated]
+    #[prost(message, optional, tag = "2")]
+    pub key_loc: ::core::option::Option<KeyLocator>,
+    /// A key descriptor describes the key used for performing ECDH. Either a key
+    /// locator or a raw public key is expected, if neither is supplied, defaults to
+    /// the node's identity private key.
+    #[prost(message, optional, tag = "3")]
+    pub key_desc: ::core::option::Option<KeyDescriptor>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SharedKeyResponse {
+    /// The shared public key, hashed with sha256.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub shared_key: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TweakDesc {
+    /// Tweak is the 32-byte value that will modify the public key.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub tweak: ::prost::alloc::vec::Vec<u8>,
+    /// Specifies if the target key should be converted to an x-only public key
+    /// before tweaking. If true, then the public key will be mapped to an x-only
+    /// key before the tweaking operation is applied.
+    #[prost(bool, tag = "2")]
+    pub is_x_only: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TaprootTweakDesc {
+    /// The root hash of the tapscript tree if a script path is committed to. If
+    /// the MuSig2 key put on chain doesn't also commit to a script path (BIP-0086
+    /// key spend only), then this needs to be empty and the key_spend_only field
+    /// below must be set to true. This is required because gRPC cannot
+    /// differentiate between a zero-size byte slice and a nil byte slice (both
+    /// would be serialized the same way). So the extra boolean is required.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub script_root: ::prost::alloc::vec::Vec<u8>,
+    /// Indicates that the above script_root is expected to be empty because this
+    /// is a BIP-0086 key spend only commitment where only the internal key is
+    /// committed to instead of also including a script root hash.
+    #[prost(bool, tag = "2")]
+    pub key_spend_only: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MuSig2CombineKeysRequest {
+    /// A list of all public keys (serialized in 32-byte x-only format for v0.4.0
+    /// and 33-byte compressed format for v1.0.0rc2!) participating in the signing
+    /// session. The list will always be sorted lexicographically internally. This
+    /// must include the local key which is described by the above key_loc.
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub all_signer_pubkeys: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// A series of optional generic tweaks to be applied to the aggregated
+    /// public key.
+    #[prost(message, repeated, tag = "2")]
+    pub tweaks: ::prost::alloc::vec::Vec<TweakDesc>,
+    /// An optional taproot specific tweak that must be specified if the MuSig2
+    /// combined key will be used as the main taproot key of a taproot output
+    /// on-chain.
+    #[prost(message, optional, tag = "3")]
+    pub taproot_tweak: ::core::option::Option<TaprootTweakDesc>,
+    /// The mandatory version of the MuSig2 BIP draft to use. This is necessary to
+    /// differentiate between the changes that were made to the BIP while this
+    /// experimental RPC was already released. Some of those changes affect how the
+    /// combined key and nonces are created.
+    #[prost(enumeration = "MuSig2Version", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::signrpc::MuSig2Version>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::signrpc::MuSig2Version>, _, _>"
+        )
+    )]
+    pub version: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MuSig2CombineKeysResponse {
+    /// The combined public key (in the 32-byte x-only format) with all tweaks
+    /// applied to it. If a taproot tweak is specified, this corresponds to the
+    /// taproot key that can be put into the on-chain output.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub combined_key: ::prost::alloc::vec::Vec<u8>,
+    /// The raw combined public key (in the 32-byte x-only format) before any tweaks
+    /// are applied to it. If a taproot tweak is specified, this corresponds to the
+    /// internal key that needs to be put into the witness if the script spend path
+    /// is used.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub taproot_internal_key: ::prost::alloc::vec::Vec<u8>,
+    /// The version of the MuSig2 BIP that was used to combine the keys.
+    #[prost(enumeration = "MuSig2Version", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::signrpc::MuSig2Version>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::signrpc::MuSig2Version>, _, _>"
+        )
+    )]
+    pub version: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MuSig2SessionRequest {
+    /// The key locator that identifies which key to use for signing.
+    #[prost(message, optional, tag = "1")]
+    pub key_loc: ::core::option::Option<KeyLocator>,
+    /// A list of all public keys (serialized in 32-byte x-only format for v0.4.0
+    /// and 33-byte compressed format for v1.0.0rc2!) participating in the signing
+    /// session. The list will always be sorted lexicographically internally. This
+    /// must include the local key which is described by the above key_loc.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub all_signer_pubkeys: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// An optional list of all public nonces of other signing participants that
+    /// might already be known.
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub other_signer_public_nonces: ::prost::alloc::vec::Vec<
+        ::prost::alloc::vec::Vec<u8>,
+    >,
+    /// A series of optional generic tweaks to be applied to the aggregated
+    /// public key.
+    #[prost(message, repeated, tag = "4")]
+    pub tweaks: ::prost::alloc::vec::Vec<TweakDesc>,
+    /// An optional taproot specific tweak that must be specified if the MuSig2
+    /// combined key will be used as the main taproot key of a taproot output
+    /// on-chain.
+    #[prost(message, optional, tag = "5")]
+    pub taproot_tweak: ::core::option::Option<TaprootTweakDesc>,
+    /// The mandatory version of the MuSig2 BIP draft to use. This is necessary to
+    /// differentiate between the changes that were made to the BIP while this
+    /// experimental RPC was already released. Some of those changes affect how the
+    /// combined key and nonces are created.
+    #[prost(enumeration = "MuSig2Version", tag = "6")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::signrpc::MuSig2Version>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::signrpc::MuSig2Version>, _, _>"
+        )
+    )]
+    pub version: i32,
+    /// A set of pre generated secret local nonces to use in the musig2 session.
+    /// This field is optional. This can be useful for protocols that need to send
+    /// nonces ahead of time before the set of signer keys are known. This value
+    /// MUST be 97 bytes and be the concatenation of two CSPRNG generated 32 byte
+    /// values and local public key used for signing as specified in the key_loc
+    /// field.
+    #[prost(bytes = "vec", tag = "7")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub pregenerated_local_nonce: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MuSig2SessionResponse {
+    /// The unique ID that represents this signing session. A session can be used
+    /// for producing a signature a single time. If the signing fails for any
+    /// reason, a new session with the same participants needs to be created.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub session_id: ::prost::alloc::vec::Vec<u8>,
+    /// The combined public key (in the 32-byte x-only format) with all tweaks
+    /// applied to it. If a taproot tweak is specified, this corresponds to the
+    /// taproot key that can be put into the on-chain output.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub combined_key: ::prost::alloc::vec::Vec<u8>,
+    /// The raw combined public key (in the 32-byte x-only format) before any tweaks
+    /// are applied to it. If a taproot tweak is specified, this corresponds to the
+    /// internal key that needs to be put into the witness if the script spend path
+    /// is used.
+    #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub taproot_internal_key: ::prost::alloc::vec::Vec<u8>,
+    /// The two public nonces the local signer uses, combined into a single value
+    /// of 66 bytes. Can be split into the two 33-byte points to get the individual
+    /// nonces.
+    #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub local_public_nonces: ::prost::alloc::vec::Vec<u8>,
+    /// Indicates whether all nonces required to start the signing process are known
+    /// now.
+    #[prost(bool, tag = "5")]
+    pub have_all_nonces: bool,
+    /// The version of the MuSig2 BIP that was used to create the session.
+    #[prost(enumeration = "MuSig2Version", tag = "6")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::signrpc::MuSig2Version>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::signrpc::MuSig2Version>, _, _>"
+        )
+    )]
+    pub version: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MuSig2RegisterNoncesRequest {
+    /// The unique ID of the signing session those nonces should be registered with.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub session_id: ::prost::alloc::vec::Vec<u8>,
+    /// A list of all public nonces of other signing participants that should be
+    /// registered.
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub other_signer_public_nonces: ::prost::alloc::vec::Vec<
+        ::prost::alloc::vec::Vec<u8>,
+    >,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MuSig2RegisterNoncesResponse {
+    /// Indicates whether all nonces required to start the signing process are known
+    /// now.
+    #[prost(bool, tag = "1")]
+    pub have_all_nonces: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MuSig2RegisterCombinedNonceRequest {
+    /// The unique ID of the signing session the combined nonce should be registered
+    /// with.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub session_id: ::prost::alloc::vec::Vec<u8>,
+    /// The 66-byte combined public nonce that was aggregated externally. This is a
+    /// concatenation of two 33-byte compressed public keys (R1 || R2).
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub combined_public_nonce: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MuSig2RegisterCombinedNonceResponse {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MuSig2GetCombinedNonceRequest {
+    /// The unique ID of the signing session to get the combined nonce for.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub session_id: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MuSig2GetCombinedNonceResponse {
+    /// The 66-byte combined public nonce. This is a concatenation of two 33-byte
+    /// compressed public keys (R1 || R2).
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub combined_public_nonce: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MuSig2SignRequest {
+    /// The unique ID of the signing session to use for signing.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub session_id: ::prost::alloc::vec::Vec<u8>,
+    /// The 32-byte SHA256 digest of the message to sign.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub message_digest: ::prost::alloc::vec::Vec<u8>,
+    /// Cleanup indicates that after signing, the session state can be cleaned up,
+    /// since another participant is going to be responsible for combining the
+    /// partial signatures.
+    #[prost(bool, tag = "3")]
+    pub cleanup: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MuSig2SignResponse {
+    /// The partial signature created by the local signer.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub local_partial_signature: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MuSig2CombineSigRequest {
+    /// The unique ID of the signing session to combine the signatures for.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub session_id: ::prost::alloc::vec::Vec<u8>,
+    /// The list of all other participants' partial signatures to add to the current
+    /// session.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Repeated<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub other_partial_signatures: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MuSig2CombineSigResponse {
+    /// Indicates whether all partial signatures required to create a final, full
+    /// signature are known yet. If this is true, then the final_signature field is
+    /// set, otherwise it is empty.
+    #[prost(bool, tag = "1")]
+    pub have_all_signatures: bool,
+    /// The final, full signature that is valid for the combined public key.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub final_signature: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MuSig2CleanupRequest {
+    /// The unique ID of the signing session that should be removed/cleaned up.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub session_id: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MuSig2CleanupResponse {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SignMethod {
+    /// Specifies that a SegWit v0 (p2wkh, np2wkh, p2wsh) input script should be
+    /// signed.
+    #[cfg_attr(feature = "serde", serde(rename = "SIGN_METHOD_WITNESS_V0"))]
+    WitnessV0 = 0,
+    /// Specifies that a SegWit v1 (p2tr) input should be signed by using the
+    /// BIP0086 method (commit to internal key only).
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "SIGN_METHOD_TAPROOT_KEY_SPEND_BIP0086")
+    )]
+    TaprootKeySpendBip0086 = 1,
+    /// Specifies that a SegWit v1 (p2tr) input should be signed by using a given
+    /// taproot hash to commit to in addition to the internal key.
+    #[cfg_attr(feature = "serde", serde(rename = "SIGN_METHOD_TAPROOT_KEY_SPEND"))]
+    TaprootKeySpend = 2,
+    /// Specifies that a SegWit v1 (p2tr) input should be spent using the script
+    /// path and that a specific leaf script should be signed for.
+    #[cfg_attr(feature = "serde", serde(rename = "SIGN_METHOD_TAPROOT_SCRIPT_SPEND"))]
+    TaprootScriptSpend = 3,
+}
+impl SignMethod {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::WitnessV0 => "SIGN_METHOD_WITNESS_V0",
+            Self::TaprootKeySpendBip0086 => "SIGN_METHOD_TAPROOT_KEY_SPEND_BIP0086",
+            Self::TaprootKeySpend => "SIGN_METHOD_TAPROOT_KEY_SPEND",
+            Self::TaprootScriptSpend => "SIGN_METHOD_TAPROOT_SCRIPT_SPEND",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SIGN_METHOD_WITNESS_V0" => Some(Self::WitnessV0),
+            "SIGN_METHOD_TAPROOT_KEY_SPEND_BIP0086" => Some(Self::TaprootKeySpendBip0086),
+            "SIGN_METHOD_TAPROOT_KEY_SPEND" => Some(Self::TaprootKeySpend),
+            "SIGN_METHOD_TAPROOT_SCRIPT_SPEND" => Some(Self::TaprootScriptSpend),
+            _ => None,
+        }
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum MuSig2Version {
+    /// The default value on the RPC is zero for enums so we need to represent an
+    /// invalid/undefined version by default to make sure clients upgrade their
+    /// software to set the version explicitly.
+    #[cfg_attr(feature = "serde", serde(rename = "MUSIG2_VERSION_UNDEFINED"))]
+    Musig2VersionUndefined = 0,
+    /// The version of MuSig2 that lnd 0.15.x shipped with, which corresponds to the
+    /// version v0.4.0 of the MuSig2 BIP draft.
+    #[cfg_attr(feature = "serde", serde(rename = "MUSIG2_VERSION_V040"))]
+    Musig2VersionV040 = 1,
+    /// The current version of MuSig2 which corresponds to the version v1.0.0rc2 of
+    /// the MuSig2 BIP draft.
+    #[cfg_attr(feature = "serde", serde(rename = "MUSIG2_VERSION_V100RC2"))]
+    Musig2VersionV100rc2 = 2,
+}
+impl MuSig2Version {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Musig2VersionUndefined => "MUSIG2_VERSION_UNDEFINED",
+            Self::Musig2VersionV040 => "MUSIG2_VERSION_V040",
+            Self::Musig2VersionV100rc2 => "MUSIG2_VERSION_V100RC2",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "MUSIG2_VERSION_UNDEFINED" => Some(Self::Musig2VersionUndefined),
+            "MUSIG2_VERSION_V040" => Some(Self::Musig2VersionV040),
+            "MUSIG2_VERSION_V100RC2" => Some(Self::Musig2VersionV100rc2),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod signer_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Signer is a service that gives access to the signing functionality of the
+    /// daemon's wallet.
+    #[derive(Debug, Clone)]
+    pub struct SignerClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "transport")]
+    impl SignerClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> SignerClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> SignerClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            SignerClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// SignOutputRaw is a method that can be used to generated a signature for a
+        /// set of inputs/outputs to a transaction. Each request specifies details
+        /// concerning how the outputs should be signed, which keys they should be
+        /// signed with, and also any optional tweaks. The return value is a fixed
+        /// 64-byte signature (the same format as we use on the wire in Lightning).
+        ///
+        /// If we are  unable to sign using the specified keys, then an error will be
+        /// returned.
+        pub async fn sign_output_raw(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SignReq>,
+        ) -> std::result::Result<tonic::Response<super::SignResp>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/signrpc.Signer/SignOutputRaw",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("signrpc.Signer", "SignOutputRaw"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// ComputeInputScript generates a complete InputIndex for the passed
+        /// transaction with the signature as defined within the passed SignDescriptor.
+        /// This method should be capable of generating the proper input script for both
+        /// regular p2wkh/p2tr outputs and p2wkh outputs nested within a regular p2sh
+        /// output.
+        ///
+        /// Note that when using this method to sign inputs belonging to the wallet,
+        /// the only items of the SignDescriptor that need to be populated are pkScript
+        /// in the TxOut field, the value in that same field, and finally the input
+        /// index.
+        pub async fn compute_input_script(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SignReq>,
+        ) -> std::result::Result<
+            tonic::Response<super::InputScriptResp>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/signrpc.Signer/ComputeInputScript",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("signrpc.Signer", "ComputeInputScript"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// SignMessage signs a message with the key specified in the key locator. The
+        /// returned signature is fixed-size LN wire format encoded.
+        ///
+        /// The main difference to SignMessage in the main RPC is that a specific key is
+        /// used to sign the message instead of the node identity private key.
+        pub async fn sign_message(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SignMessageReq>,
+        ) -> std::result::Result<
+            tonic::Response<super::SignMessageResp>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/signrpc.Signer/SignMessage",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("signrpc.Signer", "SignMessage"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// VerifyMessage verifies a signature over a message using the public key
+        /// provided. The signature must be fixed-size LN wire format encoded.
+        ///
+        /// The main difference to VerifyMessage in the main RPC is that the public key
+        /// used to sign the message does not have to be a node known to the network.
+        pub async fn verify_message(
+            &mut self,
+            request: impl tonic::IntoRequest<super::VerifyMessageReq>,
+        ) -> std::result::Result<
+            tonic::Response<super::VerifyMessageResp>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/signrpc.Signer/VerifyMessage",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("signrpc.Signer", "VerifyMessage"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// DeriveSharedKey returns a shared secret key by performing Diffie-Hellman key
+        /// derivation between the ephemeral public key in the request and the node's
+        /// key specified in the key_desc parameter. Either a key locator or a raw
+        /// public key is expected in the key_desc, if neither is supplied, defaults to
+        /// the node's identity private key:
+        /// P_shared = privKeyNode * ephemeralPubkey
+        /// The resulting shared public key is serialized in the compressed format and
+        /// hashed with sha256, resulting in the final key length of 256bit.
+        pub async fn derive_shared_key(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SharedKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SharedKeyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/signrpc.Signer/DeriveSharedKey",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("signrpc.Signer", "DeriveSharedKey"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// MuSig2CombineKeys (experimental!) is a stateless helper RPC that can be used
+        /// to calculate the combined MuSig2 public key from a list of all participating
+        /// signers' public keys. This RPC is completely stateless and deterministic and
+        /// does not create any signing session. It can be used to determine the Taproot
+        /// public key that should be put in an on-chain output once all public keys are
+        /// known. A signing session is only needed later when that output should be
+        /// *spent* again.
+        ///
+        /// NOTE: The MuSig2 BIP is not final yet and therefore this API must be
+        /// considered to be HIGHLY EXPERIMENTAL and subject to change in upcoming
+        /// releases. Backward compatibility is not guaranteed!
+        pub async fn mu_sig2_combine_keys(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MuSig2CombineKeysRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MuSig2CombineKeysResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/signrpc.Signer/MuSig2CombineKeys",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("signrpc.Signer", "MuSig2CombineKeys"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// MuSig2CreateSession (experimental!) creates a new MuSig2 signing session
+        /// using the local key identified by the key locator. The complete list of all
+        /// public keys of all signing parties must be provided, including the public
+        /// key of the local signing key. If nonces of other parties are already known,
+        /// they can be submitted as well to reduce the number of RPC calls necessary
+        /// later on.
+        ///
+        /// NOTE: The MuSig2 BIP is not final yet and therefore this API must be
+        /// considered to be HIGHLY EXPERIMENTAL and subject to change in upcoming
+        /// releases. Backward compatibility is not guaranteed!
+        pub async fn mu_sig2_create_session(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MuSig2SessionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MuSig2SessionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/signrpc.Signer/MuSig2CreateSession",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("signrpc.Signer", "MuSig2CreateSession"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// MuSig2RegisterNonces (experimental!) registers one or more public nonces of
+        /// other signing participants for a session identified by its ID. This RPC can
+        /// be called multiple times until all nonces are registered.
+        ///
+        /// NOTE: The MuSig2 BIP is not final yet and therefore this API must be
+        /// considered to be HIGHLY EXPERIMENTAL and subject to change in upcoming
+        /// releases. Backward compatibility is not guaranteed!
+        pub async fn mu_sig2_register_nonces(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MuSig2RegisterNoncesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MuSig2RegisterNoncesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/signrpc.Signer/MuSig2RegisterNonces",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("signrpc.Signer", "MuSig2RegisterNonces"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// MuSig2RegisterCombinedNonce (experimental!) registers a pre-aggregated
+        /// combined nonce for a signing session. This is an alternative to
+        /// MuSig2RegisterNonces and is used when a coordinator has already aggregated
+        /// all individual nonces and wants to distribute the combined nonce to
+        /// participants.
+        ///
+        /// NOTE: This method is mutually exclusive with MuSig2RegisterNonces for the
+        /// same session. The MuSig2 BIP is not final yet and therefore this API must
+        /// be considered to be HIGHLY EXPERIMENTAL and subject to change in upcoming
+        /// releases. Backward compatibility is not guaranteed!
+        pub async fn mu_sig2_register_combined_nonce(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MuSig2RegisterCombinedNonceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MuSig2RegisterCombinedNonceResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/signrpc.Signer/MuSig2RegisterCombinedNonce",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("signrpc.Signer", "MuSig2RegisterCombinedNonce"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// MuSig2GetCombinedNonce (experimental!) retrieves the combined nonce for a
+        /// signing session. This will be available after either all individual nonces
+        /// have been registered via MuSig2RegisterNonces, or a combined nonce has been
+        /// registered via MuSig2RegisterCombinedNonce.
+        ///
+        /// NOTE: The MuSig2 BIP is not final yet and therefore this API must be
+        /// considered to be HIGHLY EXPERIMENTAL and subject to change in upcoming
+        /// releases. Backward compatibility is not guaranteed!
+        pub async fn mu_sig2_get_combined_nonce(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MuSig2GetCombinedNonceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MuSig2GetCombinedNonceResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/signrpc.Signer/MuSig2GetCombinedNonce",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("signrpc.Signer", "MuSig2GetCombinedNonce"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// MuSig2Sign (experimental!) creates a partial signature using the local
+        /// signing key that was specified when the session was created. This can only
+        /// be called when all public nonces of all participants are known and have been
+        /// registered with the session. If this node isn't responsible for combining
+        /// all the partial signatures, then the cleanup flag should be set, indicating
+        /// that the session can be removed from memory once the signature was produced.
+        ///
+        /// NOTE: The MuSig2 BIP is not final yet and therefore this API must be
+        /// considered to be HIGHLY EXPERIMENTAL and subject to change in upcoming
+        /// releases. Backward compatibility is not guaranteed!
+        pub async fn mu_sig2_sign(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MuSig2SignRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MuSig2SignResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/signrpc.Signer/MuSig2Sign",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("signrpc.Signer", "MuSig2Sign"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// MuSig2CombineSig (experimental!) combines the given partial signature(s)
+        /// with the local one, if it already exists. Once a partial signature of all
+        /// participants is registered, the final signature will be combined and
+        /// returned.
+        ///
+        /// NOTE: The MuSig2 BIP is not final yet and therefore this API must be
+        /// considered to be HIGHLY EXPERIMENTAL and subject to change in upcoming
+        /// releases. Backward compatibility is not guaranteed!
+        pub async fn mu_sig2_combine_sig(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MuSig2CombineSigRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MuSig2CombineSigResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/signrpc.Signer/MuSig2CombineSig",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("signrpc.Signer", "MuSig2CombineSig"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// MuSig2Cleanup (experimental!) allows a caller to clean up a session early in
+        /// cases where it's obvious that the signing session won't succeed and the
+        /// resources can be released.
+        ///
+        /// NOTE: The MuSig2 BIP is not final yet and therefore this API must be
+        /// considered to be HIGHLY EXPERIMENTAL and subject to change in upcoming
+        /// releases. Backward compatibility is not guaranteed!
+        pub async fn mu_sig2_cleanup(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MuSig2CleanupRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MuSig2CleanupResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/signrpc.Signer/MuSig2Cleanup",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("signrpc.Signer", "MuSig2Cleanup"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod signer_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with SignerServer.
+    #[async_trait]
+    pub trait Signer: std::marker::Send + std::marker::Sync + 'static {
+        /// SignOutputRaw is a method that can be used to generated a signature for a
+        /// set of inputs/outputs to a transaction. Each request specifies details
+        /// concerning how the outputs should be signed, which keys they should be
+        /// signed with, and also any optional tweaks. The return value is a fixed
+        /// 64-byte signature (the same format as we use on the wire in Lightning).
+        ///
+        /// If we are  unable to sign using the specified keys, then an error will be
+        /// returned.
+        async fn sign_output_raw(
+            &self,
+            request: tonic::Request<super::SignReq>,
+        ) -> std::result::Result<tonic::Response<super::SignResp>, tonic::Status>;
+        /// ComputeInputScript generates a complete InputIndex for the passed
+        /// transaction with the signature as defined within the passed SignDescriptor.
+        /// This method should be capable of generating the proper input script for both
+        /// regular p2wkh/p2tr outputs and p2wkh outputs nested within a regular p2sh
+        /// output.
+        ///
+        /// Note that when using this method to sign inputs belonging to the wallet,
+        /// the only items of the SignDescriptor that need to be populated are pkScript
+        /// in the TxOut field, the value in that same field, and finally the input
+        /// index.
+        async fn compute_input_script(
+            &self,
+            request: tonic::Request<super::SignReq>,
+        ) -> std::result::Result<tonic::Response<super::InputScriptResp>, tonic::Status>;
+        /// SignMessage signs a message with the key specified in the key locator. The
+        /// returned signature is fixed-size LN wire format encoded.
+        ///
+        /// The main difference to SignMessage in the main RPC is that a specific key is
+        /// used to sign the message instead of the node identity private key.
+        async fn sign_message(
+            &self,
+            request: tonic::Request<super::SignMessageReq>,
+        ) -> std::result::Result<tonic::Response<super::SignMessageResp>, tonic::Status>;
+        /// VerifyMessage verifies a signature over a message using the public key
+        /// provided. The signature must be fixed-size LN wire format encoded.
+        ///
+        /// The main difference to VerifyMessage in the main RPC is that the public key
+        /// used to sign the message does not have to be a node known to the network.
+        async fn verify_message(
+            &self,
+            request: tonic::Request<super::VerifyMessageReq>,
+        ) -> std::result::Result<
+            tonic::Response<super::VerifyMessageResp>,
+            tonic::Status,
+        >;
+        /// DeriveSharedKey returns a shared secret key by performing Diffie-Hellman key
+        /// derivation between the ephemeral public key in the request and the node's
+        /// key specified in the key_desc parameter. Either a key locator or a raw
+        /// public key is expected in the key_desc, if neither is supplied, defaults to
+        /// the node's identity private key:
+        /// P_shared = privKeyNode * ephemeralPubkey
+        /// The resulting shared public key is serialized in the compressed format and
+        /// hashed with sha256, resulting in the final key length of 256bit.
+        async fn derive_shared_key(
+            &self,
+            request: tonic::Request<super::SharedKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SharedKeyResponse>,
+            tonic::Status,
+        >;
+        /// MuSig2CombineKeys (experimental!) is a stateless helper RPC that can be used
+        /// to calculate the combined MuSig2 public key from a list of all participating
+        /// signers' public keys. This RPC is completely stateless and deterministic and
+        /// does not create any signing session. It can be used to determine the Taproot
+        /// public key that should be put in an on-chain output once all public keys are
+        /// known. A signing session is only needed later when that output should be
+        /// *spent* again.
+        ///
+        /// NOTE: The MuSig2 BIP is not final yet and therefore this API must be
+        /// considered to be HIGHLY EXPERIMENTAL and subject to change in upcoming
+        /// releases. Backward compatibility is not guaranteed!
+        async fn mu_sig2_combine_keys(
+            &self,
+            request: tonic::Request<super::MuSig2CombineKeysRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MuSig2CombineKeysResponse>,
+            tonic::Status,
+        >;
+        /// MuSig2CreateSession (experimental!) creates a new MuSig2 signing session
+        /// using the local key identified by the key locator. The complete list of all
+        /// public keys of all signing parties must be provided, including the public
+        /// key of the local signing key. If nonces of other parties are already known,
+        /// they can be submitted as well to reduce the number of RPC calls necessary
+        /// later on.
+        ///
+        /// NOTE: The MuSig2 BIP is not final yet and therefore this API must be
+        /// considered to be HIGHLY EXPERIMENTAL and subject to change in upcoming
+        /// releases. Backward compatibility is not guaranteed!
+        async fn mu_sig2_create_session(
+            &self,
+            request: tonic::Request<super::MuSig2SessionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MuSig2SessionResponse>,
+            tonic::Status,
+        >;
+        /// MuSig2RegisterNonces (experimental!) registers one or more public nonces of
+        /// other signing participants for a session identified by its ID. This RPC can
+        /// be called multiple times until all nonces are registered.
+        ///
+        /// NOTE: The MuSig2 BIP is not final yet and therefore this API must be
+        /// considered to be HIGHLY EXPERIMENTAL and subject to change in upcoming
+        /// releases. Backward compatibility is not guaranteed!
+        async fn mu_sig2_register_nonces(
+            &self,
+            request: tonic::Request<super::MuSig2RegisterNoncesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MuSig2RegisterNoncesResponse>,
+            tonic::Status,
+        >;
+        /// MuSig2RegisterCombinedNonce (experimental!) registers a pre-aggregated
+        /// combined nonce for a signing session. This is an alternative to
+        /// MuSig2RegisterNonces and is used when a coordinator has already aggregated
+        /// all individual nonces and wants to distribute the combined nonce to
+        /// participants.
+        ///
+        /// NOTE: This method is mutually exclusive with MuSig2RegisterNonces for the
+        /// same session. The MuSig2 BIP is not final yet and therefore this API must
+        /// be considered to be HIGHLY EXPERIMENTAL and subject to change in upcoming
+        /// releases. Backward compatibility is not guaranteed!
+        async fn mu_sig2_register_combined_nonce(
+            &self,
+            request: tonic::Request<super::MuSig2RegisterCombinedNonceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MuSig2RegisterCombinedNonceResponse>,
+            tonic::Status,
+        >;
+        /// MuSig2GetCombinedNonce (experimental!) retrieves the combined nonce for a
+        /// signing session. This will be available after either all individual nonces
+        /// have been registered via MuSig2RegisterNonces, or a combined nonce has been
+        /// registered via MuSig2RegisterCombinedNonce.
+        ///
+        /// NOTE: The MuSig2 BIP is not final yet and therefore this API must be
+        /// considered to be HIGHLY EXPERIMENTAL and subject to change in upcoming
+        /// releases. Backward compatibility is not guaranteed!
+        async fn mu_sig2_get_combined_nonce(
+            &self,
+            request: tonic::Request<super::MuSig2GetCombinedNonceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MuSig2GetCombinedNonceResponse>,
+            tonic::Status,
+        >;
+        /// MuSig2Sign (experimental!) creates a partial signature using the local
+        /// signing key that was specified when the session was created. This can only
+        /// be called when all public nonces of all participants are known and have been
+        /// registered with the session. If this node isn't responsible for combining
+        /// all the partial signatures, then the cleanup flag should be set, indicating
+        /// that the session can be removed from memory once the signature was produced.
+        ///
+        /// NOTE: The MuSig2 BIP is not final yet and therefore this API must be
+        /// considered to be HIGHLY EXPERIMENTAL and subject to change in upcoming
+        /// releases. Backward compatibility is not guaranteed!
+        async fn mu_sig2_sign(
+            &self,
+            request: tonic::Request<super::MuSig2SignRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MuSig2SignResponse>,
+            tonic::Status,
+        >;
+        /// MuSig2CombineSig (experimental!) combines the given partial signature(s)
+        /// with the local one, if it already exists. Once a partial signature of all
+        /// participants is registered, the final signature will be combined and
+        /// returned.
+        ///
+        /// NOTE: The MuSig2 BIP is not final yet and therefore this API must be
+        /// considered to be HIGHLY EXPERIMENTAL and subject to change in upcoming
+        /// releases. Backward compatibility is not guaranteed!
+        async fn mu_sig2_combine_sig(
+            &self,
+            request: tonic::Request<super::MuSig2CombineSigRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MuSig2CombineSigResponse>,
+            tonic::Status,
+        >;
+        /// MuSig2Cleanup (experimental!) allows a caller to clean up a session early in
+        /// cases where it's obvious that the signing session won't succeed and the
+        /// resources can be released.
+        ///
+        /// NOTE: The MuSig2 BIP is not final yet and therefore this API must be
+        /// considered to be HIGHLY EXPERIMENTAL and subject to change in upcoming
+        /// releases. Backward compatibility is not guaranteed!
+        async fn mu_sig2_cleanup(
+            &self,
+            request: tonic::Request<super::MuSig2CleanupRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MuSig2CleanupResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Signer is a service that gives access to the signing functionality of the
+    /// daemon's wallet.
+    #[derive(Debug)]
+    pub struct SignerServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> SignerServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for SignerServer<T>
+    where
+        T: Signer,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/signrpc.Signer/SignOutputRaw" => {
+                    #[allow(non_camel_case_types)]
+                    struct SignOutputRawSvc<T: Signer>(pub Arc<T>);
+                    impl<T: Signer> tonic::server::UnaryService<super::SignReq>
+                    for SignOutputRawSvc<T> {
+                        type Response = super::SignResp;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SignReq>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Signer>::sign_output_raw(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SignOutputRawSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/signrpc.Signer/ComputeInputScript" => {
+                    #[allow(non_camel_case_types)]
+                    struct ComputeInputScriptSvc<T: Signer>(pub Arc<T>);
+                    impl<T: Signer> tonic::server::UnaryService<super::SignReq>
+                    for ComputeInputScriptSvc<T> {
+                        type Response = super::InputScriptResp;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SignReq>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Signer>::compute_input_script(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ComputeInputScriptSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/signrpc.Signer/SignMessage" => {
+                    #[allow(non_camel_case_types)]
+                    struct SignMessageSvc<T: Signer>(pub Arc<T>);
+                    impl<T: Signer> tonic::server::UnaryService<super::SignMessageReq>
+                    for SignMessageSvc<T> {
+                        type Response = super::SignMessageResp;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SignMessageReq>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Signer>::sign_message(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SignMessageSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/signrpc.Signer/VerifyMessage" => {
+                    #[allow(non_camel_case_types)]
+                    struct VerifyMessageSvc<T: Signer>(pub Arc<T>);
+                    impl<T: Signer> tonic::server::UnaryService<super::VerifyMessageReq>
+                    for VerifyMessageSvc<T> {
+                        type Response = super::VerifyMessageResp;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::VerifyMessageReq>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Signer>::verify_message(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = VerifyMessageSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/signrpc.Signer/DeriveSharedKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeriveSharedKeySvc<T: Signer>(pub Arc<T>);
+                    impl<T: Signer> tonic::server::UnaryService<super::SharedKeyRequest>
+                    for DeriveSharedKeySvc<T> {
+                        type Response = super::SharedKeyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SharedKeyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Signer>::derive_shared_key(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeriveSharedKeySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/signrpc.Signer/MuSig2CombineKeys" => {
+                    #[allow(non_camel_case_types)]
+                    struct MuSig2CombineKeysSvc<T: Signer>(pub Arc<T>);
+                    impl<
+                        T: Signer,
+                    > tonic::server::UnaryService<super::MuSig2CombineKeysRequest>
+                    for MuSig2CombineKeysSvc<T> {
+                        type Response = super::MuSig2CombineKeysResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MuSig2CombineKeysRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Signer>::mu_sig2_combine_keys(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = MuSig2CombineKeysSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/signrpc.Signer/MuSig2CreateSession" => {
+                    #[allow(non_camel_case_types)]
+                    struct MuSig2CreateSessionSvc<T: Signer>(pub Arc<T>);
+                    impl<
+                        T: Signer,
+                    > tonic::server::UnaryService<super::MuSig2SessionRequest>
+                    for MuSig2CreateSessionSvc<T> {
+                        type Response = super::MuSig2SessionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MuSig2SessionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Signer>::mu_sig2_create_session(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = MuSig2CreateSessionSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/signrpc.Signer/MuSig2RegisterNonces" => {
+                    #[allow(non_camel_case_types)]
+                    struct MuSig2RegisterNoncesSvc<T: Signer>(pub Arc<T>);
+                    impl<
+                        T: Signer,
+                    > tonic::server::UnaryService<super::MuSig2RegisterNoncesRequest>
+                    for MuSig2RegisterNoncesSvc<T> {
+                        type Response = super::MuSig2RegisterNoncesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MuSig2RegisterNoncesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Signer>::mu_sig2_register_nonces(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = MuSig2RegisterNoncesSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/signrpc.Signer/MuSig2RegisterCombinedNonce" => {
+                    #[allow(non_camel_case_types)]
+                    struct MuSig2RegisterCombinedNonceSvc<T: Signer>(pub Arc<T>);
+                    impl<
+                        T: Signer,
+                    > tonic::server::UnaryService<
+                        super::MuSig2RegisterCombinedNonceRequest,
+                    > for MuSig2RegisterCombinedNonceSvc<T> {
+                        type Response = super::MuSig2RegisterCombinedNonceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::MuSig2RegisterCombinedNonceRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Signer>::mu_sig2_register_combined_nonce(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = MuSig2RegisterCombinedNonceSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/signrpc.Signer/MuSig2GetCombinedNonce" => {
+                    #[allow(non_camel_case_types)]
+                    struct MuSig2GetCombinedNonceSvc<T: Signer>(pub Arc<T>);
+                    impl<
+                        T: Signer,
+                    > tonic::server::UnaryService<super::MuSig2GetCombinedNonceRequest>
+                    for MuSig2GetCombinedNonceSvc<T> {
+                        type Response = super::MuSig2GetCombinedNonceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MuSig2GetCombinedNonceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Signer>::mu_sig2_get_combined_nonce(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = MuSig2GetCombinedNonceSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/signrpc.Signer/MuSig2Sign" => {
+                    #[allow(non_camel_case_types)]
+                    struct MuSig2SignSvc<T: Signer>(pub Arc<T>);
+                    impl<T: Signer> tonic::server::UnaryService<super::MuSig2SignRequest>
+                    for MuSig2SignSvc<T> {
+                        type Response = super::MuSig2SignResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MuSig2SignRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Signer>::mu_sig2_sign(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = MuSig2SignSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/signrpc.Signer/MuSig2CombineSig" => {
+                    #[allow(non_camel_case_types)]
+                    struct MuSig2CombineSigSvc<T: Signer>(pub Arc<T>);
+                    impl<
+                        T: Signer,
+                    > tonic::server::UnaryService<super::MuSig2CombineSigRequest>
+                    for MuSig2CombineSigSvc<T> {
+                        type Response = super::MuSig2CombineSigResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MuSig2CombineSigRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Signer>::mu_sig2_combine_sig(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = MuSig2CombineSigSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/signrpc.Signer/MuSig2Cleanup" => {
+                    #[allow(non_camel_case_types)]
+                    struct MuSig2CleanupSvc<T: Signer>(pub Arc<T>);
+                    impl<
+                        T: Signer,
+                    > tonic::server::UnaryService<super::MuSig2CleanupRequest>
+                    for MuSig2CleanupSvc<T> {
+                        type Response = super::MuSig2CleanupResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MuSig2CleanupRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Signer>::mu_sig2_cleanup(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = MuSig2CleanupSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for SignerServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "signrpc.Signer";
+    impl<T> tonic::server::NamedService for SignerServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+impl crate::signrpc::SignDescriptor {
+    /// Returns `sign_method`, or an error for values unknown to this version of the protos.
+    pub fn try_sign_method(&self) -> ::core::result::Result<crate::signrpc::SignMethod, ::prost::UnknownEnumValue> {
+        crate::signrpc::SignMethod::try_from(self.sign_method)
+    }
+}
+impl crate::signrpc::MuSig2CombineKeysRequest {
+    /// Returns `version`, or an error for values unknown to this version of the protos.
+    pub fn try_version(&self) -> ::core::result::Result<crate::signrpc::MuSig2Version, ::prost::UnknownEnumValue> {
+        crate::signrpc::MuSig2Version::try_from(self.version)
+    }
+}
+impl crate::signrpc::MuSig2CombineKeysResponse {
+    /// Returns `version`, or an error for values unknown to this version of the protos.
+    pub fn try_version(&self) -> ::core::result::Result<crate::signrpc::MuSig2Version, ::prost::UnknownEnumValue> {
+        crate::signrpc::MuSig2Version::try_from(self.version)
+    }
+}
+impl crate::signrpc::MuSig2SessionRequest {
+    /// Returns `version`, or an error for values unknown to this version of the protos.
+    pub fn try_version(&self) -> ::core::result::Result<crate::signrpc::MuSig2Version, ::prost::UnknownEnumValue> {
+        crate::signrpc::MuSig2Version::try_from(self.version)
+    }
+}
+impl crate::signrpc::MuSig2SessionResponse {
+    /// Returns `version`, or an error for values unknown to this version of the protos.
+    pub fn try_version(&self) -> ::core::result::Result<crate::signrpc::MuSig2Version, ::prost::UnknownEnumValue> {
+        crate::signrpc::MuSig2Version::try_from(self.version)
+    }
+}
+/// Generated blocking client implementations.
+#[cfg(feature = "blocking")]
+pub mod signer_blocking_client {
+    #![allow(clippy::wildcard_imports)]
+    use tonic::codegen::*;
+    use super::signer_client::SignerClient;
+    /// A [`SignerClient`] that blocks the current thread on every call, driving the
+    /// requests on its runtime.
+    #[derive(Debug, Clone)]
+    pub struct SignerBlockingClient<T> {
+        inner: SignerClient<T>,
+        runtime: std::sync::Arc<crate::blocking::Runtime>,
+    }
+    impl<T> SignerBlockingClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        /// Wraps `inner`, running its calls on `runtime`.
+        pub fn new(inner: SignerClient<T>, runtime: std::sync::Arc<crate::blocking::Runtime>) -> Self {
+            Self { inner, runtime }
+        }
+        /// Returns the async client.
+        pub fn into_inner(self) -> SignerClient<T> {
+            self.inner
+        }
+        /// Blocking version of [`SignerClient::sign_output_raw`].
+        pub fn sign_output_raw(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::signrpc::SignReq>,
+        ) -> std::result::Result<tonic::Response<crate::signrpc::SignResp>, tonic::Status> {
+            self.runtime.block_on(self.inner.sign_output_raw(request))
+        }
+        /// Blocking version of [`SignerClient::compute_input_script`].
+        pub fn compute_input_script(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::signrpc::SignReq>,
+        ) -> std::result::Result<tonic::Response<crate::signrpc::InputScriptResp>, tonic::Status> {
+            self.runtime.block_on(self.inner.compute_input_script(request))
+        }
+        /// Blocking version of [`SignerClient::sign_message`].
+        pub fn sign_message(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::signrpc::SignMessageReq>,
+        ) -> std::result::Result<tonic::Response<crate::signrpc::SignMessageResp>, tonic::Status> {
+            self.runtime.block_on(self.inner.sign_message(request))
+        }
+        /// Blocking version of [`SignerClient::verify_message`].
+        pub fn verify_message(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::signrpc::VerifyMessageReq>,
+        ) -> std::result::Result<tonic::Response<crate::signrpc::VerifyMessageResp>, tonic::Status> {
+            self.runtime.block_on(self.inner.verify_message(request))
+        }
+        /// Blocking version of [`SignerClient::derive_shared_key`].
+        pub fn derive_shared_key(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::signrpc::SharedKeyRequest>,
+        ) -> std::result::Result<tonic::Response<crate::signrpc::SharedKeyResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.derive_shared_key(request))
+        }
+        /// Blocking version of [`SignerClient::mu_sig2_combine_keys`].
+        pub fn mu_sig2_combine_keys(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::signrpc::MuSig2CombineKeysRequest>,
+        ) -> std::result::Result<tonic::Response<crate::signrpc::MuSig2CombineKeysResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.mu_sig2_combine_keys(request))
+        }
+        /// Blocking version of [`SignerClient::mu_sig2_create_session`].
+        pub fn mu_sig2_create_session(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::signrpc::MuSig2SessionRequest>,
+        ) -> std::result::Result<tonic::Response<crate::signrpc::MuSig2SessionResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.mu_sig2_create_session(request))
+        }
+        /// Blocking version of [`SignerClient::mu_sig2_register_nonces`].
+        pub fn mu_sig2_register_nonces(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::signrpc::MuSig2RegisterNoncesRequest>,
+        ) -> std::result::Result<tonic::Response<crate::signrpc::MuSig2RegisterNoncesResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.mu_sig2_register_nonces(request))
+        }
+        /// Blocking version of [`SignerClient::mu_sig2_register_combined_nonce`].
+        pub fn mu_sig2_register_combined_nonce(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::signrpc::MuSig2RegisterCombinedNonceRequest>,
+        ) -> std::result::Result<tonic::Response<crate::signrpc::MuSig2RegisterCombinedNonceResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.mu_sig2_register_combined_nonce(request))
+        }
+        /// Blocking version of [`SignerClient::mu_sig2_get_combined_nonce`].
+        pub fn mu_sig2_get_combined_nonce(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::signrpc::MuSig2GetCombinedNonceRequest>,
+        ) -> std::result::Result<tonic::Response<crate::signrpc::MuSig2GetCombinedNonceResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.mu_sig2_get_combined_nonce(request))
+        }
+        /// Blocking version of [`SignerClient::mu_sig2_sign`].
+        pub fn mu_sig2_sign(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::signrpc::MuSig2SignRequest>,
+        ) -> std::result::Result<tonic::Response<crate::signrpc::MuSig2SignResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.mu_sig2_sign(request))
+        }
+        /// Blocking version of [`SignerClient::mu_sig2_combine_sig`].
+        pub fn mu_sig2_combine_sig(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::signrpc::MuSig2CombineSigRequest>,
+        ) -> std::result::Result<tonic::Response<crate::signrpc::MuSig2CombineSigResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.mu_sig2_combine_sig(request))
+        }
+        /// Blocking version of [`SignerClient::mu_sig2_cleanup`].
+        pub fn mu_sig2_cleanup(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::signrpc::MuSig2CleanupRequest>,
+        ) -> std::result::Result<tonic::Response<crate::signrpc::MuSig2CleanupResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.mu_sig2_cleanup(request))
+        }
+    }
+}
@@ -0,0 +1,33 @@
+// This file is @generated by build.rs.
+pub(crate) const STREAMING_METHODS: &[&str] = &[
+    "/chainrpc.ChainNotifier/RegisterBlockEpochNtfn",
+    "/chainrpc.ChainNotifier/RegisterConfirmationsNtfn",
+    "/chainrpc.ChainNotifier/RegisterSpendNtfn",
+    "/invoicesrpc.Invoices/HtlcModifier",
+    "/invoicesrpc.Invoices/SubscribeSingleInvoice",
+    "/lnrpc.Lightning/ChannelAcceptor",
+    "/lnrpc.Lightning/CloseChannel",
+    "/lnrpc.Lightning/OpenChannel",
+    "/lnrpc.Lightning/RegisterRPCMiddleware",
+    "/lnrpc.Lightning/SubscribeChannelBackups",
+    "/lnrpc.Lightning/SubscribeChannelEvents",
+    "/lnrpc.Lightning/SubscribeChannelGraph",
+    "/lnrpc.Lightning/SubscribeCustomMessages",
+    "/lnrpc.Lightning/SubscribeInvoices",
+    "/lnrpc.Lightning/SubscribeOnionMessages",
+    "/lnrpc.Lightning/SubscribePeerEvents",
+    "/lnrpc.Lightning/SubscribeTransactions",
+    "/lnrpc.State/SubscribeState",
+    "/mintrpc.Mint/SubscribeMintEvents",
+    "/rfqrpc.Rfq/SubscribeRfqEventNtfns",
+    "/routerrpc.Router/HtlcInterceptor",
+    "/routerrpc.Router/SendPaymentV2",
+    "/routerrpc.Router/SubscribeHtlcEvents",
+    "/routerrpc.Router/TrackPaymentV2",
+    "/routerrpc.Router/TrackPayments",
+    "/tapchannelrpc.TaprootAssetChannels/SendPayment",
+    "/tapdevrpc.TapDev/SubscribeReceiveAssetEventNtfns",
+    "/tapdevrpc.TapDev/SubscribeSendAssetEventNtfns",
+    "/taprpc.TaprootAssets/SubscribeReceiveEvents",
+    "/taprpc.TaprootAssets/SubscribeSendEvents",
+];
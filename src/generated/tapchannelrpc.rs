@@ -0,0 +1,1158 @@
+// This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FundChannelRequest {
+    /// The asset amount to fund the channel with. The BTC amount is fixed and
+    /// cannot be customized (for now).
+    #[prost(uint64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub asset_amount: u64,
+    /// The asset ID to use for the channel funding.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// The public key of the peer to open the channel with. Must already be
+    /// connected to this peer.
+    #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub peer_pubkey: ::prost::alloc::vec::Vec<u8>,
+    /// The channel funding fee rate in sat/vByte.
+    #[prost(uint32, tag = "4")]
+    pub fee_rate_sat_per_vbyte: u32,
+    /// The number of satoshis to give the remote side as part of the initial
+    /// commitment state. This is equivalent to first opening a channel and then
+    /// sending the remote party funds, but all done in one step. Therefore, this
+    /// is equivalent to a donation to the remote party, unless they reimburse
+    /// the funds in another way (outside the protocol).
+    #[prost(int64, tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub push_sat: i64,
+    /// The group key to use for the channel. This can be used instead of the
+    /// asset_id to allow assets from a fungible group to be used for the channel
+    /// funding instead of just assets from a single minting tranche (asset_id).
+    #[prost(bytes = "vec", tag = "6")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub group_key: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FundChannelResponse {
+    /// The channel funding transaction ID.
+    #[prost(string, tag = "1")]
+    pub txid: ::prost::alloc::string::String,
+    /// The index of the channel funding output in the funding transaction.
+    #[prost(int32, tag = "2")]
+    pub output_index: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouterSendPaymentData {
+    /// The string encoded asset ID to amount mapping. Instructs the router to
+    /// use these assets in the given amounts for the payment. Can be empty for
+    /// a payment of an invoice, if the RFQ ID is set instead.
+    #[prost(map = "string, uint64", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Map<crate::serde_helpers::Int64>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Map<crate::serde_helpers::Int64>, _, _>"
+        )
+    )]
+    pub asset_amounts: ::std::collections::HashMap<::prost::alloc::string::String, u64>,
+    /// The RFQ ID to use for the payment. Can be empty for a direct keysend
+    /// payment that doesn't involve any conversion (and thus no RFQ).
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub rfq_id: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EncodeCustomRecordsRequest {
+    #[prost(oneof = "encode_custom_records_request::Input", tags = "1")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub input: ::core::option::Option<encode_custom_records_request::Input>,
+}
+/// Nested message and enum types in `EncodeCustomRecordsRequest`.
+pub mod encode_custom_records_request {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Input {
+        #[prost(message, tag = "1")]
+        #[cfg_attr(feature = "serde", serde(rename = "router_send_payment"))]
+        RouterSendPayment(super::RouterSendPaymentData),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EncodeCustomRecordsResponse {
+    /// The encoded custom records in TLV format.
+    #[prost(map = "uint64, bytes", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Map<crate::serde_helpers::Bytes>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Map<crate::serde_helpers::Bytes>, _, _>"
+        )
+    )]
+    pub custom_records: ::std::collections::HashMap<u64, ::prost::alloc::vec::Vec<u8>>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SendPaymentRequest {
+    /// The asset ID to use for the payment. This must be set for both invoice
+    /// and keysend payments, unless RFQ negotiation was already done beforehand
+    /// and payment_request.first_hop_custom_records already contains valid RFQ
+    /// data. Mutually exclusive to group_key.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// The asset amount to send in a keysend payment. This amount is ignored for
+    /// invoice payments as the asset amount is negotiated through RFQ with the
+    /// peer, depending on the invoice amount. This can also be left unset if RFQ
+    /// negotiation was already done beforehand and
+    /// payment_request.first_hop_custom_records already contains valid RFQ data.
+    #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub asset_amount: u64,
+    /// The node identity public key of the peer to ask for a quote for sending
+    /// out the assets and converting them to satoshis. This must be specified if
+    /// there are multiple channels with the given asset ID.
+    #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub peer_pubkey: ::prost::alloc::vec::Vec<u8>,
+    /// The full lnd payment request to send. All fields behave the same way as
+    /// they do for lnd's routerrpc.SendPaymentV2 RPC method (see the API docs
+    /// at <https://lightning.engineering/api-docs/api/lnd/router/send-payment-v2>
+    /// for more details).
+    /// To send a keysend payment, the payment_request.dest_custom_records must
+    /// contain a valid keysend record (key 5482373484 and a 32-byte preimage
+    /// that corresponds to the payment hash).
+    #[prost(message, optional, tag = "4")]
+    pub payment_request: ::core::option::Option<super::routerrpc::SendPaymentRequest>,
+    /// The rfq id to use for this payment. If the user sets this value then the
+    /// payment will immediately be dispatched, skipping the rfq negotiation
+    /// phase, and using the following rfq id instead.
+    #[prost(bytes = "vec", tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub rfq_id: ::prost::alloc::vec::Vec<u8>,
+    /// If a small invoice should be paid that is below the amount that always
+    /// needs to be sent out to carry a single asset unit, then by default the
+    /// payment is rejected. If this flag is set, then the payment will be
+    /// allowed to proceed, even if it is uneconomical, meaning that more sats
+    /// are sent out to the network than the invoice amount plus routing fees
+    /// require to be paid.
+    #[prost(bool, tag = "6")]
+    pub allow_overpay: bool,
+    /// The group key which dictates which assets may be used for this payment.
+    /// Mutually exclusive to asset_id.
+    #[prost(bytes = "vec", tag = "7")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub group_key: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SendPaymentResponse {
+    #[prost(oneof = "send_payment_response::Result", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub result: ::core::option::Option<send_payment_response::Result>,
+}
+/// Nested message and enum types in `SendPaymentResponse`.
+pub mod send_payment_response {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Result {
+        /// In case channel assets need to be swapped to another asset, an asset
+        /// sell order is negotiated with the channel peer. The result will be
+        /// the first message in the response stream. If no swap is needed, the
+        /// payment results will be streamed directly.
+        #[prost(message, tag = "1")]
+        #[cfg_attr(feature = "serde", serde(rename = "accepted_sell_order"))]
+        AcceptedSellOrder(super::super::rfqrpc::PeerAcceptedSellQuote),
+        /// The payment result of a single payment attempt. Multiple attempts may
+        /// be returned per payment request until either the payment succeeds or
+        /// the payment times out.
+        #[prost(message, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "payment_result"))]
+        PaymentResult(super::super::lnrpc::Payment),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct HodlInvoice {
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub payment_hash: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddInvoiceRequest {
+    /// The asset ID to use for the invoice. Mutually exclusive to group_key.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// The asset amount to receive.
+    #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub asset_amount: u64,
+    /// The node identity public key of the peer to ask for a quote for receiving
+    /// assets and converting them from satoshis. When specified only quotes with
+    /// this peer will be negotiated.
+    #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub peer_pubkey: ::prost::alloc::vec::Vec<u8>,
+    /// The full lnd invoice request to send. All fields behave the same way as
+    /// they do for lnd's lnrpc.AddInvoice RPC method (see the API docs at
+    /// <https://lightning.engineering/api-docs/api/lnd/lightning/add-invoice>
+    /// for more details).
+    ///
+    /// Only one of the asset_amount/value/value_msat may be set to dictate the
+    /// value of the invoice. When using asset_amount, the value/value_msat
+    /// fields will be overwritten by the satoshi (or milli-satoshi) equivalent
+    /// of the asset amount, after negotiating a quote with a peer that supports
+    /// the given asset ID.
+    ///
+    /// If the value/value_msat are used, we still receive assets, but they will
+    /// exactly evaluate to the defined amount in sats/msats.
+    #[prost(message, optional, tag = "4")]
+    pub invoice_request: ::core::option::Option<super::lnrpc::Invoice>,
+    /// If set, then this will make the invoice created a hodl invoice, which
+    /// won't be settled automatically. Instead, users will need to use the
+    /// invoicesrpc.SettleInvoice call to manually settle the invoice.
+    #[prost(message, optional, tag = "5")]
+    pub hodl_invoice: ::core::option::Option<HodlInvoice>,
+    /// The group key which dictates which assets may be accepted for this
+    /// invoice. If set, any asset that belongs to this group may be accepted to
+    /// settle this invoice. Mutually exclusive to asset_id.
+    #[prost(bytes = "vec", tag = "6")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub group_key: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AddInvoiceResponse {
+    /// The quote for the purchase of assets that was accepted by the peer.
+    #[prost(message, optional, tag = "1")]
+    pub accepted_buy_quote: ::core::option::Option<super::rfqrpc::PeerAcceptedBuyQuote>,
+    /// The result of the invoice creation.
+    #[prost(message, optional, tag = "2")]
+    pub invoice_result: ::core::option::Option<super::lnrpc::AddInvoiceResponse>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AssetPayReq {
+    /// The asset ID that will be used to resolve the invoice's satoshi amount.
+    /// Mutually exclusive to group_key.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// The normal LN invoice that whose amount will be mapped to units of the
+    /// asset ID.
+    #[prost(string, tag = "2")]
+    pub pay_req_string: ::prost::alloc::string::String,
+    /// The group key that will be used to resolve the invoice's satoshi amount.
+    /// Mutually exclusive to asset_id.
+    #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub group_key: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetPayReqResponse {
+    /// The invoice amount, expressed in asset units.
+    #[prost(uint64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub asset_amount: u64,
+    /// The decimal display corresponding to the asset_id.
+    #[prost(message, optional, tag = "2")]
+    pub decimal_display: ::core::option::Option<super::taprpc::DecimalDisplay>,
+    /// The group the asset ID belong to, if applicable.
+    #[prost(message, optional, tag = "3")]
+    pub asset_group: ::core::option::Option<super::taprpc::AssetGroup>,
+    /// Genesis information for the asset ID which includes the meta hash, and
+    /// asset ID. This is only set if the payment request was decoded with an
+    /// asset ID and not with a group key (since a group can contain assets from
+    /// different minting events or genesis infos).
+    #[prost(message, optional, tag = "4")]
+    pub genesis_info: ::core::option::Option<super::taprpc::GenesisInfo>,
+    /// The normal decoded payment request.
+    #[prost(message, optional, tag = "5")]
+    pub pay_req: ::core::option::Option<super::lnrpc::PayReq>,
+}
+/// Generated client implementations.
+pub mod taproot_asset_channels_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct TaprootAssetChannelsClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "transport")]
+    impl TaprootAssetChannelsClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> TaprootAssetChannelsClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> TaprootAssetChannelsClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            TaprootAssetChannelsClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// litcli: `ln fundchannel`
+        /// FundChannel initiates the channel funding negotiation with a peer for the
+        /// creation of a channel that contains a specified amount of a given asset.
+        pub async fn fund_channel(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FundChannelRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FundChannelResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tapchannelrpc.TaprootAssetChannels/FundChannel",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tapchannelrpc.TaprootAssetChannels", "FundChannel"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// EncodeCustomRecords allows RPC users to encode Taproot Asset channel related
+        /// data into the TLV format that is used in the custom records of the lnd
+        /// payment or other channel related RPCs. This RPC is completely stateless and
+        /// does not perform any checks on the data provided, other than pure format
+        /// validation.
+        pub async fn encode_custom_records(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EncodeCustomRecordsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EncodeCustomRecordsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tapchannelrpc.TaprootAssetChannels/EncodeCustomRecords",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "tapchannelrpc.TaprootAssetChannels",
+                        "EncodeCustomRecords",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// litcli: `ln sendpayment`
+        /// SendPayment is a wrapper around lnd's routerrpc.SendPaymentV2 RPC method
+        /// with asset specific parameters. It allows RPC users to send asset keysend
+        /// payments (direct payments) or payments to an invoice with a specified asset
+        /// amount.
+        pub async fn send_payment(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SendPaymentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::SendPaymentResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tapchannelrpc.TaprootAssetChannels/SendPayment",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tapchannelrpc.TaprootAssetChannels", "SendPayment"),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// litcli: `ln addinvoice`
+        /// AddInvoice is a wrapper around lnd's lnrpc.AddInvoice method with asset
+        /// specific parameters. It allows RPC users to create invoices that correspond
+        /// to the specified asset amount. If a peer pubkey is specified, then only that
+        /// peer will be used for RFQ negotiations. If none is specified then RFQ quotes
+        /// for all peers with suitable asset channels will be created.
+        pub async fn add_invoice(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddInvoiceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddInvoiceResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tapchannelrpc.TaprootAssetChannels/AddInvoice",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tapchannelrpc.TaprootAssetChannels", "AddInvoice"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// litcli: `ln decodeassetinvoice`
+        /// DecodeAssetPayReq is similar to lnd's lnrpc.DecodePayReq, but it accepts an
+        /// asset ID or group key and returns the invoice amount expressed in asset
+        /// units along side the normal information.
+        pub async fn decode_asset_pay_req(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AssetPayReq>,
+        ) -> std::result::Result<
+            tonic::Response<super::AssetPayReqResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tapchannelrpc.TaprootAssetChannels/DecodeAssetPayReq",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "tapchannelrpc.TaprootAssetChannels",
+                        "DecodeAssetPayReq",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod taproot_asset_channels_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with TaprootAssetChannelsServer.
+    #[async_trait]
+    pub trait TaprootAssetChannels: std::marker::Send + std::marker::Sync + 'static {
+        /// litcli: `ln fundchannel`
+        /// FundChannel initiates the channel funding negotiation with a peer for the
+        /// creation of a channel that contains a specified amount of a given asset.
+        async fn fund_channel(
+            &self,
+            request: tonic::Request<super::FundChannelRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FundChannelResponse>,
+            tonic::Status,
+        >;
+        /// EncodeCustomRecords allows RPC users to encode Taproot Asset channel related
+        /// data into the TLV format that is used in the custom records of the lnd
+        /// payment or other channel related RPCs. This RPC is completely stateless and
+        /// does not perform any checks on the data provided, other than pure format
+        /// validation.
+        async fn encode_custom_records(
+            &self,
+            request: tonic::Request<super::EncodeCustomRecordsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EncodeCustomRecordsResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the SendPayment method.
+        type SendPaymentStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::SendPaymentResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// litcli: `ln sendpayment`
+        /// SendPayment is a wrapper around lnd's routerrpc.SendPaymentV2 RPC method
+        /// with asset specific parameters. It allows RPC users to send asset keysend
+        /// payments (direct payments) or payments to an invoice with a specified asset
+        /// amount.
+        async fn send_payment(
+            &self,
+            request: tonic::Request<super::SendPaymentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::SendPaymentStream>,
+            tonic::Status,
+        >;
+        /// litcli: `ln addinvoice`
+        /// AddInvoice is a wrapper around lnd's lnrpc.AddInvoice method with asset
+        /// specific parameters. It allows RPC users to create invoices that correspond
+        /// to the specified asset amount. If a peer pubkey is specified, then only that
+        /// peer will be used for RFQ negotiations. If none is specified then RFQ quotes
+        /// for all peers with suitable asset channels will be created.
+        async fn add_invoice(
+            &self,
+            request: tonic::Request<super::AddInvoiceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddInvoiceResponse>,
+            tonic::Status,
+        >;
+        /// litcli: `ln decodeassetinvoice`
+        /// DecodeAssetPayReq is similar to lnd's lnrpc.DecodePayReq, but it accepts an
+        /// asset ID or group key and returns the invoice amount expressed in asset
+        /// units along side the normal information.
+        async fn decode_asset_pay_req(
+            &self,
+            request: tonic::Request<super::AssetPayReq>,
+        ) -> std::result::Result<
+            tonic::Response<super::AssetPayReqResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct TaprootAssetChannelsServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> TaprootAssetChannelsServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>>
+    for TaprootAssetChannelsServer<T>
+    where
+        T: TaprootAssetChannels,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/tapchannelrpc.TaprootAssetChannels/FundChannel" => {
+                    #[allow(non_camel_case_types)]
+                    struct FundChannelSvc<T: TaprootAssetChannels>(pub Arc<T>);
+                    impl<
+                        T: TaprootAssetChannels,
+                    > tonic::server::UnaryService<super::FundChannelRequest>
+                    for FundChannelSvc<T> {
+                        type Response = super::FundChannelResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FundChannelRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaprootAssetChannels>::fund_channel(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FundChannelSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/tapchannelrpc.TaprootAssetChannels/EncodeCustomRecords" => {
+                    #[allow(non_camel_case_types)]
+                    struct EncodeCustomRecordsSvc<T: TaprootAssetChannels>(pub Arc<T>);
+                    impl<
+                        T: TaprootAssetChannels,
+                    > tonic::server::UnaryService<super::EncodeCustomRecordsRequest>
+                    for EncodeCustomRecordsSvc<T> {
+                        type Response = super::EncodeCustomRecordsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EncodeCustomRecordsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaprootAssetChannels>::encode_custom_records(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EncodeCustomRecordsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/tapchannelrpc.TaprootAssetChannels/SendPayment" => {
+                    #[allow(non_camel_case_types)]
+                    struct SendPaymentSvc<T: TaprootAssetChannels>(pub Arc<T>);
+                    impl<
+                        T: TaprootAssetChannels,
+                    > tonic::server::ServerStreamingService<super::SendPaymentRequest>
+                    for SendPaymentSvc<T> {
+                        type Response = super::SendPaymentResponse;
+                        type ResponseStream = T::SendPaymentStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SendPaymentRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaprootAssetChannels>::send_payment(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SendPaymentSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/tapchannelrpc.TaprootAssetChannels/AddInvoice" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddInvoiceSvc<T: TaprootAssetChannels>(pub Arc<T>);
+                    impl<
+                        T: TaprootAssetChannels,
+                    > tonic::server::UnaryService<super::AddInvoiceRequest>
+                    for AddInvoiceSvc<T> {
+                        type Response = super::AddInvoiceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddInvoiceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaprootAssetChannels>::add_invoice(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AddInvoiceSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/tapchannelrpc.TaprootAssetChannels/DecodeAssetPayReq" => {
+                    #[allow(non_camel_case_types)]
+                    struct DecodeAssetPayReqSvc<T: TaprootAssetChannels>(pub Arc<T>);
+                    impl<
+                        T: TaprootAssetChannels,
+                    > tonic::server::UnaryService<super::AssetPayReq>
+                    for DecodeAssetPayReqSvc<T> {
+                        type Response = super::AssetPayReqResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AssetPayReq>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TaprootAssetChannels>::decode_asset_pay_req(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DecodeAssetPayReqSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for TaprootAssetChannelsServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "tapchannelrpc.TaprootAssetChannels";
+    impl<T> tonic::server::NamedService for TaprootAssetChannelsServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Generated blocking client implementations.
+#[cfg(feature = "blocking")]
+pub mod taproot_asset_channels_blocking_client {
+    #![allow(clippy::wildcard_imports)]
+    use tonic::codegen::*;
+    use super::taproot_asset_channels_client::TaprootAssetChannelsClient;
+    /// A [`TaprootAssetChannelsClient`] that blocks the current thread on every call, driving the
+    /// requests on its runtime.
+    #[derive(Debug, Clone)]
+    pub struct TaprootAssetChannelsBlockingClient<T> {
+        inner: TaprootAssetChannelsClient<T>,
+        runtime: std::sync::Arc<crate::blocking::Runtime>,
+    }
+    impl<T> TaprootAssetChannelsBlockingClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        /// Wraps `inner`, running its calls on `runtime`.
+        pub fn new(inner: TaprootAssetChannelsClient<T>, runtime: std::sync::Arc<crate::blocking::Runtime>) -> Self {
+            Self { inner, runtime }
+        }
+        /// Returns the async client.
+        pub fn into_inner(self) -> TaprootAssetChannelsClient<T> {
+            self.inner
+        }
+        /// Blocking version of [`TaprootAssetChannelsClient::fund_channel`].
+        pub fn fund_channel(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::tapchannelrpc::FundChannelRequest>,
+        ) -> std::result::Result<tonic::Response<crate::tapchannelrpc::FundChannelResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.fund_channel(request))
+        }
+        /// Blocking version of [`TaprootAssetChannelsClient::encode_custom_records`].
+        pub fn encode_custom_records(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::tapchannelrpc::EncodeCustomRecordsRequest>,
+        ) -> std::result::Result<tonic::Response<crate::tapchannelrpc::EncodeCustomRecordsResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.encode_custom_records(request))
+        }
+        /// Blocking version of [`TaprootAssetChannelsClient::send_payment`].
+        pub fn send_payment(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::tapchannelrpc::SendPaymentRequest>,
+        ) -> std::result::Result<tonic::Response<crate::blocking::Streaming<crate::tapchannelrpc::SendPaymentResponse>>, tonic::Status> {
+            let response = self.runtime.block_on(self.inner.send_payment(request))?;
+            Ok(response.map(|stream| crate::blocking::Streaming::new(stream, self.runtime.clone())))
+        }
+        /// Blocking version of [`TaprootAssetChannelsClient::add_invoice`].
+        pub fn add_invoice(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::tapchannelrpc::AddInvoiceRequest>,
+        ) -> std::result::Result<tonic::Response<crate::tapchannelrpc::AddInvoiceResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.add_invoice(request))
+        }
+        /// Blocking version of [`TaprootAssetChannelsClient::decode_asset_pay_req`].
+        pub fn decode_asset_pay_req(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::tapchannelrpc::AssetPayReq>,
+        ) -> std::result::Result<tonic::Response<crate::tapchannelrpc::AssetPayReqResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.decode_asset_pay_req(request))
+        }
+    }
+}
@@ -0,0 +1,778 @@
+// This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ImportProofRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub proof_file: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag = "2")]
+    pub genesis_point: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ImportProofResponse {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SubscribeSendAssetEventNtfnsRequest {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SendAssetEvent {
+    #[prost(oneof = "send_asset_event::Event", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub event: ::core::option::Option<send_asset_event::Event>,
+}
+/// Nested message and enum types in `SendAssetEvent`.
+pub mod send_asset_event {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Event {
+        /// An event which indicates that a send state is about to be executed.
+        #[prost(message, tag = "1")]
+        #[cfg_attr(feature = "serde", serde(rename = "execute_send_state_event"))]
+        ExecuteSendStateEvent(super::ExecuteSendStateEvent),
+        /// An event which indicates that the proof transfer backoff wait period
+        /// will start imminently.
+        #[prost(message, tag = "2")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "proof_transfer_backoff_wait_event")
+        )]
+        ProofTransferBackoffWaitEvent(super::ProofTransferBackoffWaitEvent),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ExecuteSendStateEvent {
+    /// Execute timestamp (microseconds).
+    #[prost(int64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub timestamp: i64,
+    /// The send state that is about to be executed.
+    #[prost(string, tag = "2")]
+    pub send_state: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ProofTransferBackoffWaitEvent {
+    /// Transfer attempt timestamp (microseconds).
+    #[prost(int64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub timestamp: i64,
+    /// Backoff is the active backoff wait duration.
+    #[prost(int64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub backoff: i64,
+    /// Tries counter is the number of tries we've made so far during the
+    /// course of the current backoff procedure to deliver the proof to the
+    /// receiver.
+    #[prost(int64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub tries_counter: i64,
+    /// The type of proof transfer attempt.
+    #[prost(enumeration = "ProofTransferType", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::tapdevrpc::ProofTransferType>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::tapdevrpc::ProofTransferType>, _, _>"
+        )
+    )]
+    pub transfer_type: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SubscribeReceiveAssetEventNtfnsRequest {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AssetReceiveCompleteEvent {
+    /// Event creation timestamp.
+    #[prost(int64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub timestamp: i64,
+    /// The address that received the asset.
+    #[prost(message, optional, tag = "2")]
+    pub address: ::core::option::Option<super::taprpc::Addr>,
+    /// The outpoint of the transaction that was used to receive the asset.
+    #[prost(string, tag = "3")]
+    pub outpoint: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ReceiveAssetEvent {
+    #[prost(oneof = "receive_asset_event::Event", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub event: ::core::option::Option<receive_asset_event::Event>,
+}
+/// Nested message and enum types in `ReceiveAssetEvent`.
+pub mod receive_asset_event {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Event {
+        /// An event which indicates that the proof transfer backoff wait period
+        /// will start imminently.
+        #[prost(message, tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "proof_transfer_backoff_wait_event")
+        )]
+        ProofTransferBackoffWaitEvent(super::ProofTransferBackoffWaitEvent),
+        /// An event which indicates that an asset receive process has finished.
+        #[prost(message, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "asset_receive_complete_event"))]
+        AssetReceiveCompleteEvent(super::AssetReceiveCompleteEvent),
+    }
+}
+/// ProofTransferType is the type of proof transfer attempt. The transfer is
+/// either a proof delivery to the transfer counterparty or receiving a proof
+/// from the transfer counterparty. Note that the transfer counterparty is
+/// usually the proof courier service.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProofTransferType {
+    /// This value indicates that the proof transfer attempt is a delivery to the
+    /// transfer counterparty.
+    #[cfg_attr(feature = "serde", serde(rename = "PROOF_TRANSFER_TYPE_SEND"))]
+    Send = 0,
+    /// This value indicates that the proof transfer attempt is a receive from
+    /// the transfer counterparty.
+    #[cfg_attr(feature = "serde", serde(rename = "PROOF_TRANSFER_TYPE_RECEIVE"))]
+    Receive = 1,
+}
+impl ProofTransferType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Send => "PROOF_TRANSFER_TYPE_SEND",
+            Self::Receive => "PROOF_TRANSFER_TYPE_RECEIVE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PROOF_TRANSFER_TYPE_SEND" => Some(Self::Send),
+            "PROOF_TRANSFER_TYPE_RECEIVE" => Some(Self::Receive),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod tap_dev_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct TapDevClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "transport")]
+    impl TapDevClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> TapDevClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> TapDevClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            TapDevClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// tapcli: `dev importproof`
+        /// Deprecated, use the new taprpc.RegisterTransfer RPC instead! ImportProof
+        /// attempts to import a proof file into the daemon. If successful,
+        /// a new asset will be inserted on disk, spendable using the specified target
+        /// script key, and internal key.
+        /// NOTE: This RPC will be removed with the next major release.
+        #[deprecated]
+        pub async fn import_proof(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ImportProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportProofResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tapdevrpc.TapDev/ImportProof",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tapdevrpc.TapDev", "ImportProof"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// SubscribeSendAssetEventNtfns registers a subscription to the event
+        /// notification stream which relates to the asset sending process.
+        pub async fn subscribe_send_asset_event_ntfns(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubscribeSendAssetEventNtfnsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::SendAssetEvent>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tapdevrpc.TapDev/SubscribeSendAssetEventNtfns",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tapdevrpc.TapDev", "SubscribeSendAssetEventNtfns"),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// SubscribeReceiveAssetEventNtfns registers a subscription to the event
+        /// notification stream which relates to the asset receive process.
+        pub async fn subscribe_receive_asset_event_ntfns(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::SubscribeReceiveAssetEventNtfnsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ReceiveAssetEvent>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tapdevrpc.TapDev/SubscribeReceiveAssetEventNtfns",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "tapdevrpc.TapDev",
+                        "SubscribeReceiveAssetEventNtfns",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod tap_dev_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with TapDevServer.
+    #[async_trait]
+    pub trait TapDev: std::marker::Send + std::marker::Sync + 'static {
+        /// tapcli: `dev importproof`
+        /// Deprecated, use the new taprpc.RegisterTransfer RPC instead! ImportProof
+        /// attempts to import a proof file into the daemon. If successful,
+        /// a new asset will be inserted on disk, spendable using the specified target
+        /// script key, and internal key.
+        /// NOTE: This RPC will be removed with the next major release.
+        async fn import_proof(
+            &self,
+            request: tonic::Request<super::ImportProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportProofResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the SubscribeSendAssetEventNtfns method.
+        type SubscribeSendAssetEventNtfnsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::SendAssetEvent, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// SubscribeSendAssetEventNtfns registers a subscription to the event
+        /// notification stream which relates to the asset sending process.
+        async fn subscribe_send_asset_event_ntfns(
+            &self,
+            request: tonic::Request<super::SubscribeSendAssetEventNtfnsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::SubscribeSendAssetEventNtfnsStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the SubscribeReceiveAssetEventNtfns method.
+        type SubscribeReceiveAssetEventNtfnsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ReceiveAssetEvent, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// SubscribeReceiveAssetEventNtfns registers a subscription to the event
+        /// notification stream which relates to the asset receive process.
+        async fn subscribe_receive_asset_event_ntfns(
+            &self,
+            request: tonic::Request<super::SubscribeReceiveAssetEventNtfnsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::SubscribeReceiveAssetEventNtfnsStream>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct TapDevServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> TapDevServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for TapDevServer<T>
+    where
+        T: TapDev,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/tapdevrpc.TapDev/ImportProof" => {
+                    #[allow(non_camel_case_types)]
+                    struct ImportProofSvc<T: TapDev>(pub Arc<T>);
+                    impl<
+                        T: TapDev,
+                    > tonic::server::UnaryService<super::ImportProofRequest>
+                    for ImportProofSvc<T> {
+                        type Response = super::ImportProofResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ImportProofRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TapDev>::import_proof(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ImportProofSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/tapdevrpc.TapDev/SubscribeSendAssetEventNtfns" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeSendAssetEventNtfnsSvc<T: TapDev>(pub Arc<T>);
+                    impl<
+                        T: TapDev,
+                    > tonic::server::ServerStreamingService<
+                        super::SubscribeSendAssetEventNtfnsRequest,
+                    > for SubscribeSendAssetEventNtfnsSvc<T> {
+                        type Response = super::SendAssetEvent;
+                        type ResponseStream = T::SubscribeSendAssetEventNtfnsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::SubscribeSendAssetEventNtfnsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TapDev>::subscribe_send_asset_event_ntfns(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SubscribeSendAssetEventNtfnsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/tapdevrpc.TapDev/SubscribeReceiveAssetEventNtfns" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeReceiveAssetEventNtfnsSvc<T: TapDev>(pub Arc<T>);
+                    impl<
+                        T: TapDev,
+                    > tonic::server::ServerStreamingService<
+                        super::SubscribeReceiveAssetEventNtfnsRequest,
+                    > for SubscribeReceiveAssetEventNtfnsSvc<T> {
+                        type Response = super::ReceiveAssetEvent;
+                        type ResponseStream = T::SubscribeReceiveAssetEventNtfnsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::SubscribeReceiveAssetEventNtfnsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TapDev>::subscribe_receive_asset_event_ntfns(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SubscribeReceiveAssetEventNtfnsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for TapDevServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "tapdevrpc.TapDev";
+    impl<T> tonic::server::NamedService for TapDevServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+impl crate::tapdevrpc::ProofTransferBackoffWaitEvent {
+    /// Returns `transfer_type`, or an error for values unknown to this version of the protos.
+    pub fn try_transfer_type(&self) -> ::core::result::Result<crate::tapdevrpc::ProofTransferType, ::prost::UnknownEnumValue> {
+        crate::tapdevrpc::ProofTransferType::try_from(self.transfer_type)
+    }
+}
+/// Generated blocking client implementations.
+#[cfg(feature = "blocking")]
+pub mod tap_dev_blocking_client {
+    #![allow(clippy::wildcard_imports)]
+    use tonic::codegen::*;
+    use super::tap_dev_client::TapDevClient;
+    /// A [`TapDevClient`] that blocks the current thread on every call, driving the
+    /// requests on its runtime.
+    #[derive(Debug, Clone)]
+    pub struct TapDevBlockingClient<T> {
+        inner: TapDevClient<T>,
+        runtime: std::sync::Arc<crate::blocking::Runtime>,
+    }
+    impl<T> TapDevBlockingClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        /// Wraps `inner`, running its calls on `runtime`.
+        pub fn new(inner: TapDevClient<T>, runtime: std::sync::Arc<crate::blocking::Runtime>) -> Self {
+            Self { inner, runtime }
+        }
+        /// Returns the async client.
+        pub fn into_inner(self) -> TapDevClient<T> {
+            self.inner
+        }
+        /// Blocking version of [`TapDevClient::import_proof`].
+        #[deprecated]
+        #[allow(deprecated)]
+        pub fn import_proof(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::tapdevrpc::ImportProofRequest>,
+        ) -> std::result::Result<tonic::Response<crate::tapdevrpc::ImportProofResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.import_proof(request))
+        }
+        /// Blocking version of [`TapDevClient::subscribe_send_asset_event_ntfns`].
+        pub fn subscribe_send_asset_event_ntfns(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::tapdevrpc::SubscribeSendAssetEventNtfnsRequest>,
+        ) -> std::result::Result<tonic::Response<crate::blocking::Streaming<crate::tapdevrpc::SendAssetEvent>>, tonic::Status> {
+            let response = self.runtime.block_on(self.inner.subscribe_send_asset_event_ntfns(request))?;
+            Ok(response.map(|stream| crate::blocking::Streaming::new(stream, self.runtime.clone())))
+        }
+        /// Blocking version of [`TapDevClient::subscribe_receive_asset_event_ntfns`].
+        pub fn subscribe_receive_asset_event_ntfns(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::tapdevrpc::SubscribeReceiveAssetEventNtfnsRequest>,
+        ) -> std::result::Result<tonic::Response<crate::blocking::Streaming<crate::tapdevrpc::ReceiveAssetEvent>>, tonic::Status> {
+            let response = self.runtime.block_on(self.inner.subscribe_receive_asset_event_ntfns(request))?;
+            Ok(response.map(|stream| crate::blocking::Streaming::new(stream, self.runtime.clone())))
+        }
+    }
+}
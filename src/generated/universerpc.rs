@@ -0,0 +1,3096 @@
+// This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MultiverseRootRequest {
+    /// The proof type to calculate the multiverse root for.
+    #[prost(enumeration = "ProofType", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::universerpc::ProofType>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::universerpc::ProofType>, _, _>"
+        )
+    )]
+    pub proof_type: i32,
+    /// An optional list of universe IDs to include in the multiverse root. If
+    /// none are specified, then all known universes of the given proof type are
+    /// included. NOTE: The proof type within the IDs must either be unspecified
+    /// or match the proof type above.
+    #[prost(message, repeated, tag = "2")]
+    pub specific_ids: ::prost::alloc::vec::Vec<Id>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MultiverseRootResponse {
+    /// The root of the multiverse tree.
+    #[prost(message, optional, tag = "1")]
+    pub multiverse_root: ::core::option::Option<MerkleSumNode>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AssetRootRequest {
+    /// If true, then the response will include the amounts for each asset ID
+    /// of grouped assets.
+    #[prost(bool, tag = "1")]
+    pub with_amounts_by_id: bool,
+    /// The offset for the page.
+    #[prost(int32, tag = "2")]
+    pub offset: i32,
+    /// The length limit for the page.
+    #[prost(int32, tag = "3")]
+    pub limit: i32,
+    /// The direction of the page.
+    #[prost(enumeration = "SortDirection", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::universerpc::SortDirection>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::universerpc::SortDirection>, _, _>"
+        )
+    )]
+    pub direction: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MerkleSumNode {
+    /// The MS-SMT root hash for the branch node.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub root_hash: ::prost::alloc::vec::Vec<u8>,
+    /// The root sum of the branch node. This is hashed to create the root_hash
+    /// along with the left and right siblings. This value represents the total
+    /// known supply of the asset.
+    #[prost(int64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub root_sum: i64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Id {
+    #[prost(enumeration = "ProofType", tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::universerpc::ProofType>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::universerpc::ProofType>, _, _>"
+        )
+    )]
+    pub proof_type: i32,
+    #[prost(oneof = "id::Id", tags = "1, 2, 3, 4")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub id: ::core::option::Option<id::Id>,
+}
+/// Nested message and enum types in `ID`.
+pub mod id {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Id {
+        /// The 32-byte asset ID specified as raw bytes (gRPC only).
+        #[prost(bytes, tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                rename = "asset_id",
+                serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+                deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+            )
+        )]
+        AssetId(::prost::alloc::vec::Vec<u8>),
+        /// The 32-byte asset ID encoded as a hex string (use this for REST).
+        #[prost(string, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "asset_id_str"))]
+        AssetIdStr(::prost::alloc::string::String),
+        /// The 32-byte asset group key specified as raw bytes (gRPC only).
+        #[prost(bytes, tag = "3")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                rename = "group_key",
+                serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+                deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+            )
+        )]
+        GroupKey(::prost::alloc::vec::Vec<u8>),
+        /// The 32-byte asset group key encoded as hex string (use this for
+        /// REST).
+        #[prost(string, tag = "4")]
+        #[cfg_attr(feature = "serde", serde(rename = "group_key_str"))]
+        GroupKeyStr(::prost::alloc::string::String),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UniverseRoot {
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<Id>,
+    /// The merkle sum sparse merkle tree root associated with the above
+    /// universe ID.
+    #[prost(message, optional, tag = "3")]
+    pub mssmt_root: ::core::option::Option<MerkleSumNode>,
+    /// The name of the asset.
+    #[prost(string, tag = "4")]
+    pub asset_name: ::prost::alloc::string::String,
+    /// A map of hex encoded asset IDs to the number of units minted for that
+    /// asset. This only contains more than one entry for grouped assets and in
+    /// that case represents the whole list of assets currently known to exist
+    /// within the group. For single (non-grouped) assets, this is equal to the
+    /// asset ID above and the sum in the mssmt_root. A hex encoded string is
+    /// used as the map key because gRPC does not support using raw bytes for a
+    /// map key.
+    #[prost(map = "string, uint64", tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Map<crate::serde_helpers::Int64>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Map<crate::serde_helpers::Int64>, _, _>"
+        )
+    )]
+    pub amounts_by_asset_id: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        u64,
+    >,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetRootResponse {
+    /// A map of the set of known universe roots for each asset. The key in the
+    /// map is the 32-byte asset_id or group key hash.
+    #[prost(map = "string, message", tag = "1")]
+    pub universe_roots: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        UniverseRoot,
+    >,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AssetRootQuery {
+    /// An ID value to uniquely identify a Universe root.
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<Id>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryRootResponse {
+    /// The issuance universe root for the given asset ID or group key.
+    #[prost(message, optional, tag = "1")]
+    pub issuance_root: ::core::option::Option<UniverseRoot>,
+    /// The transfer universe root for the given asset ID or group key.
+    #[prost(message, optional, tag = "2")]
+    pub transfer_root: ::core::option::Option<UniverseRoot>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeleteRootQuery {
+    /// An ID value to uniquely identify a Universe root.
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<Id>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeleteRootResponse {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Outpoint {
+    /// The output as a hex encoded (and reversed!) string.
+    #[prost(string, tag = "1")]
+    pub hash_str: ::prost::alloc::string::String,
+    /// The index of the output.
+    #[prost(int32, tag = "2")]
+    pub index: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AssetKey {
+    /// The outpoint of the asset key, either as a single hex encoded string, or
+    /// an unrolled outpoint.
+    #[prost(oneof = "asset_key::Outpoint", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub outpoint: ::core::option::Option<asset_key::Outpoint>,
+    /// The script key of the asset.
+    #[prost(oneof = "asset_key::ScriptKey", tags = "3, 4")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub script_key: ::core::option::Option<asset_key::ScriptKey>,
+}
+/// Nested message and enum types in `AssetKey`.
+pub mod asset_key {
+    /// The outpoint of the asset key, either as a single hex encoded string, or
+    /// an unrolled outpoint.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Outpoint {
+        #[prost(string, tag = "1")]
+        #[cfg_attr(feature = "serde", serde(rename = "op_str"))]
+        OpStr(::prost::alloc::string::String),
+        #[prost(message, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "op"))]
+        Op(super::Outpoint),
+    }
+    /// The script key of the asset.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum ScriptKey {
+        #[prost(bytes, tag = "3")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                rename = "script_key_bytes",
+                serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+                deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+            )
+        )]
+        ScriptKeyBytes(::prost::alloc::vec::Vec<u8>),
+        #[prost(string, tag = "4")]
+        #[cfg_attr(feature = "serde", serde(rename = "script_key_str"))]
+        ScriptKeyStr(::prost::alloc::string::String),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AssetLeafKeysRequest {
+    /// The ID of the asset to query for.
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<Id>,
+    /// The offset for the page.
+    #[prost(int32, tag = "2")]
+    pub offset: i32,
+    /// The length limit for the page.
+    #[prost(int32, tag = "3")]
+    pub limit: i32,
+    /// The direction of the page.
+    #[prost(enumeration = "SortDirection", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::universerpc::SortDirection>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::universerpc::SortDirection>, _, _>"
+        )
+    )]
+    pub direction: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetLeafKeyResponse {
+    /// The set of asset leaf keys for the given asset ID or group key.
+    #[prost(message, repeated, tag = "1")]
+    pub asset_keys: ::prost::alloc::vec::Vec<AssetKey>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetLeaf {
+    /// The asset included in the leaf.
+    #[prost(message, optional, tag = "1")]
+    pub asset: ::core::option::Option<super::taprpc::Asset>,
+    /// The asset issuance or transfer proof, which proves that the asset
+    /// specified above was issued or transferred properly. This is always just
+    /// an individual mint/transfer proof and never a proof file.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub proof: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetLeafResponse {
+    /// The set of asset leaves for the given asset ID or group key.
+    #[prost(message, repeated, tag = "1")]
+    pub leaves: ::prost::alloc::vec::Vec<AssetLeaf>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct UniverseKey {
+    /// The ID of the asset to query for.
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<Id>,
+    /// The asset key to query for.
+    #[prost(message, optional, tag = "2")]
+    pub leaf_key: ::core::option::Option<AssetKey>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetProofResponse {
+    /// The original request for the issuance proof.
+    #[prost(message, optional, tag = "1")]
+    pub req: ::core::option::Option<UniverseKey>,
+    /// The Universe root that includes this asset leaf.
+    #[prost(message, optional, tag = "2")]
+    pub universe_root: ::core::option::Option<UniverseRoot>,
+    /// An inclusion proof for the asset leaf included below. The value is that
+    /// issuance proof itself, with a sum value of the amount of the asset.
+    #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub universe_inclusion_proof: ::prost::alloc::vec::Vec<u8>,
+    /// The asset leaf itself, which includes the asset and the issuance proof.
+    #[prost(message, optional, tag = "4")]
+    pub asset_leaf: ::core::option::Option<AssetLeaf>,
+    /// MultiverseRoot is the root of the multiverse tree that includes this
+    /// asset leaf.
+    #[prost(message, optional, tag = "5")]
+    pub multiverse_root: ::core::option::Option<MerkleSumNode>,
+    /// MultiverseInclusionProof is the inclusion proof for the asset leaf in the
+    /// multiverse.
+    #[prost(bytes = "vec", tag = "6")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub multiverse_inclusion_proof: ::prost::alloc::vec::Vec<u8>,
+    /// The issuance related data for an issuance asset leaf. This is empty for
+    /// any other type of asset leaf.
+    #[prost(message, optional, tag = "7")]
+    pub issuance_data: ::core::option::Option<IssuanceData>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct IssuanceData {
+    /// The reveal meta data associated with the proof, if available.
+    #[prost(message, optional, tag = "1")]
+    pub meta_reveal: ::core::option::Option<super::taprpc::AssetMeta>,
+    /// GenesisReveal is an optional field that is the Genesis information for
+    /// the asset. This is required for minting proofs.
+    #[prost(message, optional, tag = "2")]
+    pub genesis_reveal: ::core::option::Option<super::taprpc::GenesisReveal>,
+    /// GroupKeyReveal is an optional field that includes the information needed
+    /// to derive the tweaked group key.
+    #[prost(message, optional, tag = "3")]
+    pub group_key_reveal: ::core::option::Option<super::taprpc::GroupKeyReveal>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetProof {
+    /// The ID of the asset to insert the proof for.
+    #[prost(message, optional, tag = "1")]
+    pub key: ::core::option::Option<UniverseKey>,
+    /// The asset leaf to insert into the Universe tree.
+    #[prost(message, optional, tag = "4")]
+    pub asset_leaf: ::core::option::Option<AssetLeaf>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PushProofRequest {
+    /// The ID of the asset to push the proof for.
+    #[prost(message, optional, tag = "1")]
+    pub key: ::core::option::Option<UniverseKey>,
+    /// The universe server to push the proof to.
+    #[prost(message, optional, tag = "2")]
+    pub server: ::core::option::Option<UniverseFederationServer>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PushProofResponse {
+    /// The ID of the asset a push was requested for.
+    #[prost(message, optional, tag = "1")]
+    pub key: ::core::option::Option<UniverseKey>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct InfoRequest {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct InfoResponse {
+    /// A pseudo-random runtime ID for the current instance of the Universe
+    /// server, changes with each restart. Mainly used to identify identical
+    /// servers when they are exposed under different hostnames/ports.
+    #[prost(int64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub runtime_id: i64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SyncTarget {
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<Id>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SyncRequest {
+    /// TODO(roasbeef): accept connection type? so can pass along self-signed
+    /// cert, also brontide based RPC handshake
+    #[prost(string, tag = "1")]
+    pub universe_host: ::prost::alloc::string::String,
+    /// The sync mode. This determines what type of proofs are synced.
+    #[prost(enumeration = "UniverseSyncMode", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::universerpc::UniverseSyncMode>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::universerpc::UniverseSyncMode>, _, _>"
+        )
+    )]
+    pub sync_mode: i32,
+    /// The set of assets to sync. If none are specified, then all assets are
+    /// synced.
+    #[prost(message, repeated, tag = "3")]
+    pub sync_targets: ::prost::alloc::vec::Vec<SyncTarget>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SyncedUniverse {
+    /// The old Universe root for the synced asset.
+    #[prost(message, optional, tag = "1")]
+    pub old_asset_root: ::core::option::Option<UniverseRoot>,
+    /// The new Universe root for the synced asset.
+    #[prost(message, optional, tag = "2")]
+    pub new_asset_root: ::core::option::Option<UniverseRoot>,
+    /// The set of new asset leaves that were synced.
+    #[prost(message, repeated, tag = "3")]
+    pub new_asset_leaves: ::prost::alloc::vec::Vec<AssetLeaf>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct StatsRequest {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SyncResponse {
+    /// The set of synced asset Universes.
+    #[prost(message, repeated, tag = "1")]
+    pub synced_universes: ::prost::alloc::vec::Vec<SyncedUniverse>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct UniverseFederationServer {
+    #[prost(string, tag = "1")]
+    pub host: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub id: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListFederationServersRequest {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListFederationServersResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub servers: ::prost::alloc::vec::Vec<UniverseFederationServer>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddFederationServerRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub servers: ::prost::alloc::vec::Vec<UniverseFederationServer>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AddFederationServerResponse {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteFederationServerRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub servers: ::prost::alloc::vec::Vec<UniverseFederationServer>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeleteFederationServerResponse {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct StatsResponse {
+    #[prost(int64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub num_total_assets: i64,
+    #[prost(int64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub num_total_groups: i64,
+    #[prost(int64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub num_total_syncs: i64,
+    #[prost(int64, tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub num_total_proofs: i64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AssetStatsQuery {
+    #[prost(string, tag = "1")]
+    pub asset_name_filter: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub asset_id_filter: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration = "AssetTypeFilter", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::universerpc::AssetTypeFilter>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::universerpc::AssetTypeFilter>, _, _>"
+        )
+    )]
+    pub asset_type_filter: i32,
+    #[prost(enumeration = "AssetQuerySort", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::universerpc::AssetQuerySort>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::universerpc::AssetQuerySort>, _, _>"
+        )
+    )]
+    pub sort_by: i32,
+    #[prost(int32, tag = "5")]
+    pub offset: i32,
+    #[prost(int32, tag = "6")]
+    pub limit: i32,
+    #[prost(enumeration = "SortDirection", tag = "7")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::universerpc::SortDirection>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::universerpc::SortDirection>, _, _>"
+        )
+    )]
+    pub direction: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AssetStatsSnapshot {
+    /// The group key of the asset group. If this is empty, then the asset is
+    /// not part of a group.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub group_key: ::prost::alloc::vec::Vec<u8>,
+    /// The total supply of the asset group. If the asset is not part of an asset
+    /// group then this is always zero.
+    #[prost(int64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub group_supply: i64,
+    /// The group anchor that was used to group assets together into an asset
+    /// group. This is only set if the asset is part of an asset group.
+    #[prost(message, optional, tag = "3")]
+    pub group_anchor: ::core::option::Option<AssetStatsAsset>,
+    /// If the asset is not part of an asset group, then this is the asset the
+    /// stats below refer to.
+    #[prost(message, optional, tag = "4")]
+    pub asset: ::core::option::Option<AssetStatsAsset>,
+    /// The total number of syncs either for the asset group or the single asset
+    /// if it is not part of a group.
+    #[prost(int64, tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub total_syncs: i64,
+    /// The total number of proofs either for the asset group or the single asset
+    /// if it is not part of a group.
+    #[prost(int64, tag = "6")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub total_proofs: i64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AssetStatsAsset {
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag = "2")]
+    pub genesis_point: ::prost::alloc::string::String,
+    #[prost(int64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub total_supply: i64,
+    #[prost(string, tag = "4")]
+    pub asset_name: ::prost::alloc::string::String,
+    #[prost(enumeration = "super::taprpc::AssetType", tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::taprpc::AssetType>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::taprpc::AssetType>, _, _>"
+        )
+    )]
+    pub asset_type: i32,
+    #[prost(int32, tag = "6")]
+    pub genesis_height: i32,
+    #[prost(int64, tag = "7")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub genesis_timestamp: i64,
+    #[prost(string, tag = "8")]
+    pub anchor_point: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "9")]
+    pub decimal_display: u32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UniverseAssetStats {
+    #[prost(message, repeated, tag = "1")]
+    pub asset_stats: ::prost::alloc::vec::Vec<AssetStatsSnapshot>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QueryEventsRequest {
+    #[prost(int64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub start_timestamp: i64,
+    #[prost(int64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub end_timestamp: i64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryEventsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub events: ::prost::alloc::vec::Vec<GroupedUniverseEvents>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GroupedUniverseEvents {
+    /// The date the events occurred on, formatted as YYYY-MM-DD.
+    #[prost(string, tag = "1")]
+    pub date: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub sync_events: u64,
+    #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub new_proof_events: u64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetFederationSyncConfigRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub global_sync_configs: ::prost::alloc::vec::Vec<GlobalFederationSyncConfig>,
+    #[prost(message, repeated, tag = "2")]
+    pub asset_sync_configs: ::prost::alloc::vec::Vec<AssetFederationSyncConfig>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetFederationSyncConfigResponse {}
+/// GlobalFederationSyncConfig is a global proof type specific configuration
+/// for universe federation syncing.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GlobalFederationSyncConfig {
+    /// proof_type is the universe proof type which this config applies to.
+    #[prost(enumeration = "ProofType", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::universerpc::ProofType>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::universerpc::ProofType>, _, _>"
+        )
+    )]
+    pub proof_type: i32,
+    /// allow_sync_insert is a boolean that indicates whether leaves from
+    /// universes of the given proof type have may be inserted via federation
+    /// sync.
+    #[prost(bool, tag = "2")]
+    pub allow_sync_insert: bool,
+    /// allow_sync_export is a boolean that indicates whether leaves from
+    /// universes of the given proof type have may be exported via federation
+    /// sync.
+    #[prost(bool, tag = "3")]
+    pub allow_sync_export: bool,
+}
+/// AssetFederationSyncConfig is an asset universe specific configuration for
+/// federation syncing.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AssetFederationSyncConfig {
+    /// id is the ID of the universe to configure.
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<Id>,
+    /// allow_sync_insert is a boolean that indicates whether leaves from
+    /// universes of the given proof type have may be inserted via federation
+    /// sync.
+    #[prost(bool, tag = "2")]
+    pub allow_sync_insert: bool,
+    /// allow_sync_export is a boolean that indicates whether leaves from
+    /// universes of the given proof type have may be exported via federation
+    /// sync.
+    #[prost(bool, tag = "3")]
+    pub allow_sync_export: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryFederationSyncConfigRequest {
+    /// Target universe ID(s).
+    #[prost(message, repeated, tag = "1")]
+    pub id: ::prost::alloc::vec::Vec<Id>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryFederationSyncConfigResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub global_sync_configs: ::prost::alloc::vec::Vec<GlobalFederationSyncConfig>,
+    #[prost(message, repeated, tag = "2")]
+    pub asset_sync_configs: ::prost::alloc::vec::Vec<AssetFederationSyncConfig>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProofType {
+    #[cfg_attr(feature = "serde", serde(rename = "PROOF_TYPE_UNSPECIFIED"))]
+    Unspecified = 0,
+    #[cfg_attr(feature = "serde", serde(rename = "PROOF_TYPE_ISSUANCE"))]
+    Issuance = 1,
+    #[cfg_attr(feature = "serde", serde(rename = "PROOF_TYPE_TRANSFER"))]
+    Transfer = 2,
+}
+impl ProofType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "PROOF_TYPE_UNSPECIFIED",
+            Self::Issuance => "PROOF_TYPE_ISSUANCE",
+            Self::Transfer => "PROOF_TYPE_TRANSFER",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PROOF_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "PROOF_TYPE_ISSUANCE" => Some(Self::Issuance),
+            "PROOF_TYPE_TRANSFER" => Some(Self::Transfer),
+            _ => None,
+        }
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum UniverseSyncMode {
+    /// A sync node that indicates that only new asset creation (minting) proofs
+    /// should be synced.
+    #[cfg_attr(feature = "serde", serde(rename = "SYNC_ISSUANCE_ONLY"))]
+    SyncIssuanceOnly = 0,
+    /// A syncing mode that indicates that all asset proofs should be synced.
+    /// This includes normal transfers as well.
+    #[cfg_attr(feature = "serde", serde(rename = "SYNC_FULL"))]
+    SyncFull = 1,
+}
+impl UniverseSyncMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::SyncIssuanceOnly => "SYNC_ISSUANCE_ONLY",
+            Self::SyncFull => "SYNC_FULL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SYNC_ISSUANCE_ONLY" => Some(Self::SyncIssuanceOnly),
+            "SYNC_FULL" => Some(Self::SyncFull),
+            _ => None,
+        }
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AssetQuerySort {
+    #[cfg_attr(feature = "serde", serde(rename = "SORT_BY_NONE"))]
+    SortByNone = 0,
+    #[cfg_attr(feature = "serde", serde(rename = "SORT_BY_ASSET_NAME"))]
+    SortByAssetName = 1,
+    #[cfg_attr(feature = "serde", serde(rename = "SORT_BY_ASSET_ID"))]
+    SortByAssetId = 2,
+    #[cfg_attr(feature = "serde", serde(rename = "SORT_BY_ASSET_TYPE"))]
+    SortByAssetType = 3,
+    #[cfg_attr(feature = "serde", serde(rename = "SORT_BY_TOTAL_SYNCS"))]
+    SortByTotalSyncs = 4,
+    #[cfg_attr(feature = "serde", serde(rename = "SORT_BY_TOTAL_PROOFS"))]
+    SortByTotalProofs = 5,
+    #[cfg_attr(feature = "serde", serde(rename = "SORT_BY_GENESIS_HEIGHT"))]
+    SortByGenesisHeight = 6,
+    #[cfg_attr(feature = "serde", serde(rename = "SORT_BY_TOTAL_SUPPLY"))]
+    SortByTotalSupply = 7,
+}
+impl AssetQuerySort {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::SortByNone => "SORT_BY_NONE",
+            Self::SortByAssetName => "SORT_BY_ASSET_NAME",
+            Self::SortByAssetId => "SORT_BY_ASSET_ID",
+            Self::SortByAssetType => "SORT_BY_ASSET_TYPE",
+            Self::SortByTotalSyncs => "SORT_BY_TOTAL_SYNCS",
+            Self::SortByTotalProofs => "SORT_BY_TOTAL_PROOFS",
+            Self::SortByGenesisHeight => "SORT_BY_GENESIS_HEIGHT",
+            Self::SortByTotalSupply => "SORT_BY_TOTAL_SUPPLY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SORT_BY_NONE" => Some(Self::SortByNone),
+            "SORT_BY_ASSET_NAME" => Some(Self::SortByAssetName),
+            "SORT_BY_ASSET_ID" => Some(Self::SortByAssetId),
+            "SORT_BY_ASSET_TYPE" => Some(Self::SortByAssetType),
+            "SORT_BY_TOTAL_SYNCS" => Some(Self::SortByTotalSyncs),
+            "SORT_BY_TOTAL_PROOFS" => Some(Self::SortByTotalProofs),
+            "SORT_BY_GENESIS_HEIGHT" => Some(Self::SortByGenesisHeight),
+            "SORT_BY_TOTAL_SUPPLY" => Some(Self::SortByTotalSupply),
+            _ => None,
+        }
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SortDirection {
+    #[cfg_attr(feature = "serde", serde(rename = "SORT_DIRECTION_ASC"))]
+    Asc = 0,
+    #[cfg_attr(feature = "serde", serde(rename = "SORT_DIRECTION_DESC"))]
+    Desc = 1,
+}
+impl SortDirection {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Asc => "SORT_DIRECTION_ASC",
+            Self::Desc => "SORT_DIRECTION_DESC",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SORT_DIRECTION_ASC" => Some(Self::Asc),
+            "SORT_DIRECTION_DESC" => Some(Self::Desc),
+            _ => None,
+        }
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AssetTypeFilter {
+    #[cfg_attr(feature = "serde", serde(rename = "FILTER_ASSET_NONE"))]
+    FilterAssetNone = 0,
+    #[cfg_attr(feature = "serde", serde(rename = "FILTER_ASSET_NORMAL"))]
+    FilterAssetNormal = 1,
+    #[cfg_attr(feature = "serde", serde(rename = "FILTER_ASSET_COLLECTIBLE"))]
+    FilterAssetCollectible = 2,
+}
+impl AssetTypeFilter {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::FilterAssetNone => "FILTER_ASSET_NONE",
+            Self::FilterAssetNormal => "FILTER_ASSET_NORMAL",
+            Self::FilterAssetCollectible => "FILTER_ASSET_COLLECTIBLE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "FILTER_ASSET_NONE" => Some(Self::FilterAssetNone),
+            "FILTER_ASSET_NORMAL" => Some(Self::FilterAssetNormal),
+            "FILTER_ASSET_COLLECTIBLE" => Some(Self::FilterAssetCollectible),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod universe_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct UniverseClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "transport")]
+    impl UniverseClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> UniverseClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> UniverseClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            UniverseClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// tapcli: `universe multiverse`
+        /// MultiverseRoot returns the root of the multiverse tree. This is useful to
+        /// determine the equality of two multiverse trees, since the root can directly
+        /// be compared to another multiverse root to find out if a sync is required.
+        pub async fn multiverse_root(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MultiverseRootRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MultiverseRootResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/MultiverseRoot",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("universerpc.Universe", "MultiverseRoot"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `universe roots`
+        /// AssetRoots queries for the known Universe roots associated with each known
+        /// asset. These roots represent the supply/audit state for each known asset.
+        pub async fn asset_roots(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AssetRootRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AssetRootResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/AssetRoots",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("universerpc.Universe", "AssetRoots"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `universe roots`
+        /// QueryAssetRoots attempts to locate the current Universe root for a specific
+        /// asset. This asset can be identified by its asset ID or group key.
+        pub async fn query_asset_roots(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AssetRootQuery>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryRootResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/QueryAssetRoots",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("universerpc.Universe", "QueryAssetRoots"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `universe delete`
+        /// DeleteAssetRoot deletes the Universe root for a specific asset, including
+        /// all asoociated universe keys, leaves, and events.
+        pub async fn delete_asset_root(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteRootQuery>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteRootResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/DeleteAssetRoot",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("universerpc.Universe", "DeleteAssetRoot"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `universe keys`
+        /// AssetLeafKeys queries for the set of Universe keys associated with a given
+        /// asset_id or group_key. Each key takes the form: (outpoint, script_key),
+        /// where outpoint is an outpoint in the Bitcoin blockchain that anchors a
+        /// valid Taproot Asset commitment, and script_key is the script_key of
+        /// the asset within the Taproot Asset commitment for the given asset_id or
+        /// group_key.
+        pub async fn asset_leaf_keys(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AssetLeafKeysRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AssetLeafKeyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/AssetLeafKeys",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("universerpc.Universe", "AssetLeafKeys"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `universe leaves`
+        /// AssetLeaves queries for the set of asset leaves (the values in the Universe
+        /// MS-SMT tree) for a given asset_id or group_key. These represents either
+        /// asset issuance events (they have a genesis witness) or asset transfers that
+        /// took place on chain. The leaves contain a normal Taproot Asset proof, as
+        /// well as details for the asset.
+        pub async fn asset_leaves(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Id>,
+        ) -> std::result::Result<
+            tonic::Response<super::AssetLeafResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/AssetLeaves",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("universerpc.Universe", "AssetLeaves"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `universe proofs query`
+        /// QueryProof attempts to query for an issuance or transfer proof for a given
+        /// asset based on its UniverseKey. A UniverseKey is composed of the Universe
+        /// ID (asset_id/group_key) and also a leaf key (outpoint || script_key). If
+        /// found, then the issuance proof is returned that includes an inclusion proof
+        /// to the known Universe root, as well as a Taproot Asset state transition or
+        /// issuance proof for the said asset.
+        pub async fn query_proof(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UniverseKey>,
+        ) -> std::result::Result<
+            tonic::Response<super::AssetProofResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/QueryProof",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("universerpc.Universe", "QueryProof"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `universe proofs insert`
+        /// InsertProof attempts to insert a new issuance or transfer proof into the
+        /// Universe tree specified by the UniverseKey. If valid, then the proof is
+        /// inserted into the database, with a new Universe root returned for the
+        /// updated asset_id/group_key.
+        pub async fn insert_proof(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AssetProof>,
+        ) -> std::result::Result<
+            tonic::Response<super::AssetProofResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/InsertProof",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("universerpc.Universe", "InsertProof"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `universe proofs push`
+        /// PushProof attempts to query the local universe for a proof specified by a
+        /// UniverseKey. If found, a connection is made to a remote Universe server to
+        /// attempt to upload the asset leaf.
+        pub async fn push_proof(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PushProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PushProofResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/PushProof",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("universerpc.Universe", "PushProof"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `universe info`
+        /// Info returns a set of information about the current state of the Universe.
+        pub async fn info(
+            &mut self,
+            request: impl tonic::IntoRequest<super::InfoRequest>,
+        ) -> std::result::Result<tonic::Response<super::InfoResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/Info",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("universerpc.Universe", "Info"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `universe sync`
+        /// SyncUniverse takes host information for a remote Universe server, then
+        /// attempts to synchronize either only the set of specified asset_ids, or all
+        /// assets if none are specified. The sync process will attempt to query for
+        /// the latest known root for each asset, performing tree based reconciliation
+        /// to arrive at a new shared root.
+        pub async fn sync_universe(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SyncRequest>,
+        ) -> std::result::Result<tonic::Response<super::SyncResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/SyncUniverse",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("universerpc.Universe", "SyncUniverse"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `universe federation list`
+        /// ListFederationServers lists the set of servers that make up the federation
+        /// of the local Universe server. This servers are used to push out new proofs,
+        /// and also periodically call sync new proofs from the remote server.
+        pub async fn list_federation_servers(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListFederationServersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListFederationServersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/ListFederationServers",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("universerpc.Universe", "ListFederationServers"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `universe federation add`
+        /// AddFederationServer adds a new server to the federation of the local
+        /// Universe server. Once a server is added, this call can also optionally be
+        /// used to trigger a sync of the remote server.
+        pub async fn add_federation_server(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddFederationServerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddFederationServerResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/AddFederationServer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("universerpc.Universe", "AddFederationServer"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `universe federation del`
+        /// DeleteFederationServer removes a server from the federation of the local
+        /// Universe server.
+        pub async fn delete_federation_server(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteFederationServerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteFederationServerResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/DeleteFederationServer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("universerpc.Universe", "DeleteFederationServer"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `universe stats`
+        /// UniverseStats returns a set of aggregate statistics for the current state
+        /// of the Universe. Stats returned include: total number of syncs, total
+        /// number of proofs, and total number of known assets.
+        pub async fn universe_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StatsRequest>,
+        ) -> std::result::Result<tonic::Response<super::StatsResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/UniverseStats",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("universerpc.Universe", "UniverseStats"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli `universe stats assets`
+        /// QueryAssetStats returns a set of statistics for a given set of assets.
+        /// Stats can be queried for all assets, or based on the: asset ID, name, or
+        /// asset type. Pagination is supported via the offset and limit params.
+        /// Results can also be sorted based on any of the main query params.
+        pub async fn query_asset_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AssetStatsQuery>,
+        ) -> std::result::Result<
+            tonic::Response<super::UniverseAssetStats>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/QueryAssetStats",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("universerpc.Universe", "QueryAssetStats"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli `universe stats events`
+        /// QueryEvents returns the number of sync and proof events for a given time
+        /// period, grouped by day.
+        pub async fn query_events(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryEventsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/QueryEvents",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("universerpc.Universe", "QueryEvents"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// SetFederationSyncConfig sets the configuration of the universe federation
+        /// sync.
+        pub async fn set_federation_sync_config(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetFederationSyncConfigRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetFederationSyncConfigResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/SetFederationSyncConfig",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("universerpc.Universe", "SetFederationSyncConfig"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// tapcli: `universe federation config info`
+        /// QueryFederationSyncConfig queries the universe federation sync configuration
+        /// settings.
+        pub async fn query_federation_sync_config(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryFederationSyncConfigRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryFederationSyncConfigResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/universerpc.Universe/QueryFederationSyncConfig",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("universerpc.Universe", "QueryFederationSyncConfig"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod universe_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with UniverseServer.
+    #[async_trait]
+    pub trait Universe: std::marker::Send + std::marker::Sync + 'static {
+        /// tapcli: `universe multiverse`
+        /// MultiverseRoot returns the root of the multiverse tree. This is useful to
+        /// determine the equality of two multiverse trees, since the root can directly
+        /// be compared to another multiverse root to find out if a sync is required.
+        async fn multiverse_root(
+            &self,
+            request: tonic::Request<super::MultiverseRootRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MultiverseRootResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `universe roots`
+        /// AssetRoots queries for the known Universe roots associated with each known
+        /// asset. These roots represent the supply/audit state for each known asset.
+        async fn asset_roots(
+            &self,
+            request: tonic::Request<super::AssetRootRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AssetRootResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `universe roots`
+        /// QueryAssetRoots attempts to locate the current Universe root for a specific
+        /// asset. This asset can be identified by its asset ID or group key.
+        async fn query_asset_roots(
+            &self,
+            request: tonic::Request<super::AssetRootQuery>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryRootResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `universe delete`
+        /// DeleteAssetRoot deletes the Universe root for a specific asset, including
+        /// all asoociated universe keys, leaves, and events.
+        async fn delete_asset_root(
+            &self,
+            request: tonic::Request<super::DeleteRootQuery>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteRootResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `universe keys`
+        /// AssetLeafKeys queries for the set of Universe keys associated with a given
+        /// asset_id or group_key. Each key takes the form: (outpoint, script_key),
+        /// where outpoint is an outpoint in the Bitcoin blockchain that anchors a
+        /// valid Taproot Asset commitment, and script_key is the script_key of
+        /// the asset within the Taproot Asset commitment for the given asset_id or
+        /// group_key.
+        async fn asset_leaf_keys(
+            &self,
+            request: tonic::Request<super::AssetLeafKeysRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AssetLeafKeyResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `universe leaves`
+        /// AssetLeaves queries for the set of asset leaves (the values in the Universe
+        /// MS-SMT tree) for a given asset_id or group_key. These represents either
+        /// asset issuance events (they have a genesis witness) or asset transfers that
+        /// took place on chain. The leaves contain a normal Taproot Asset proof, as
+        /// well as details for the asset.
+        async fn asset_leaves(
+            &self,
+            request: tonic::Request<super::Id>,
+        ) -> std::result::Result<
+            tonic::Response<super::AssetLeafResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `universe proofs query`
+        /// QueryProof attempts to query for an issuance or transfer proof for a given
+        /// asset based on its UniverseKey. A UniverseKey is composed of the Universe
+        /// ID (asset_id/group_key) and also a leaf key (outpoint || script_key). If
+        /// found, then the issuance proof is returned that includes an inclusion proof
+        /// to the known Universe root, as well as a Taproot Asset state transition or
+        /// issuance proof for the said asset.
+        async fn query_proof(
+            &self,
+            request: tonic::Request<super::UniverseKey>,
+        ) -> std::result::Result<
+            tonic::Response<super::AssetProofResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `universe proofs insert`
+        /// InsertProof attempts to insert a new issuance or transfer proof into the
+        /// Universe tree specified by the UniverseKey. If valid, then the proof is
+        /// inserted into the database, with a new Universe root returned for the
+        /// updated asset_id/group_key.
+        async fn insert_proof(
+            &self,
+            request: tonic::Request<super::AssetProof>,
+        ) -> std::result::Result<
+            tonic::Response<super::AssetProofResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `universe proofs push`
+        /// PushProof attempts to query the local universe for a proof specified by a
+        /// UniverseKey. If found, a connection is made to a remote Universe server to
+        /// attempt to upload the asset leaf.
+        async fn push_proof(
+            &self,
+            request: tonic::Request<super::PushProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PushProofResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `universe info`
+        /// Info returns a set of information about the current state of the Universe.
+        async fn info(
+            &self,
+            request: tonic::Request<super::InfoRequest>,
+        ) -> std::result::Result<tonic::Response<super::InfoResponse>, tonic::Status>;
+        /// tapcli: `universe sync`
+        /// SyncUniverse takes host information for a remote Universe server, then
+        /// attempts to synchronize either only the set of specified asset_ids, or all
+        /// assets if none are specified. The sync process will attempt to query for
+        /// the latest known root for each asset, performing tree based reconciliation
+        /// to arrive at a new shared root.
+        async fn sync_universe(
+            &self,
+            request: tonic::Request<super::SyncRequest>,
+        ) -> std::result::Result<tonic::Response<super::SyncResponse>, tonic::Status>;
+        /// tapcli: `universe federation list`
+        /// ListFederationServers lists the set of servers that make up the federation
+        /// of the local Universe server. This servers are used to push out new proofs,
+        /// and also periodically call sync new proofs from the remote server.
+        async fn list_federation_servers(
+            &self,
+            request: tonic::Request<super::ListFederationServersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListFederationServersResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `universe federation add`
+        /// AddFederationServer adds a new server to the federation of the local
+        /// Universe server. Once a server is added, this call can also optionally be
+        /// used to trigger a sync of the remote server.
+        async fn add_federation_server(
+            &self,
+            request: tonic::Request<super::AddFederationServerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddFederationServerResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `universe federation del`
+        /// DeleteFederationServer removes a server from the federation of the local
+        /// Universe server.
+        async fn delete_federation_server(
+            &self,
+            request: tonic::Request<super::DeleteFederationServerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteFederationServerResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `universe stats`
+        /// UniverseStats returns a set of aggregate statistics for the current state
+        /// of the Universe. Stats returned include: total number of syncs, total
+        /// number of proofs, and total number of known assets.
+        async fn universe_stats(
+            &self,
+            request: tonic::Request<super::StatsRequest>,
+        ) -> std::result::Result<tonic::Response<super::StatsResponse>, tonic::Status>;
+        /// tapcli `universe stats assets`
+        /// QueryAssetStats returns a set of statistics for a given set of assets.
+        /// Stats can be queried for all assets, or based on the: asset ID, name, or
+        /// asset type. Pagination is supported via the offset and limit params.
+        /// Results can also be sorted based on any of the main query params.
+        async fn query_asset_stats(
+            &self,
+            request: tonic::Request<super::AssetStatsQuery>,
+        ) -> std::result::Result<
+            tonic::Response<super::UniverseAssetStats>,
+            tonic::Status,
+        >;
+        /// tapcli `universe stats events`
+        /// QueryEvents returns the number of sync and proof events for a given time
+        /// period, grouped by day.
+        async fn query_events(
+            &self,
+            request: tonic::Request<super::QueryEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryEventsResponse>,
+            tonic::Status,
+        >;
+        /// SetFederationSyncConfig sets the configuration of the universe federation
+        /// sync.
+        async fn set_federation_sync_config(
+            &self,
+            request: tonic::Request<super::SetFederationSyncConfigRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetFederationSyncConfigResponse>,
+            tonic::Status,
+        >;
+        /// tapcli: `universe federation config info`
+        /// QueryFederationSyncConfig queries the universe federation sync configuration
+        /// settings.
+        async fn query_federation_sync_config(
+            &self,
+            request: tonic::Request<super::QueryFederationSyncConfigRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryFederationSyncConfigResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct UniverseServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> UniverseServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for UniverseServer<T>
+    where
+        T: Universe,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/universerpc.Universe/MultiverseRoot" => {
+                    #[allow(non_camel_case_types)]
+                    struct MultiverseRootSvc<T: Universe>(pub Arc<T>);
+                    impl<
+                        T: Universe,
+                    > tonic::server::UnaryService<super::MultiverseRootRequest>
+                    for MultiverseRootSvc<T> {
+                        type Response = super::MultiverseRootResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MultiverseRootRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::multiverse_root(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = MultiverseRootSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/universerpc.Universe/AssetRoots" => {
+                    #[allow(non_camel_case_types)]
+                    struct AssetRootsSvc<T: Universe>(pub Arc<T>);
+                    impl<
+                        T: Universe,
+                    > tonic::server::UnaryService<super::AssetRootRequest>
+                    for AssetRootsSvc<T> {
+                        type Response = super::AssetRootResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AssetRootRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::asset_roots(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AssetRootsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/universerpc.Universe/QueryAssetRoots" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryAssetRootsSvc<T: Universe>(pub Arc<T>);
+                    impl<T: Universe> tonic::server::UnaryService<super::AssetRootQuery>
+                    for QueryAssetRootsSvc<T> {
+                        type Response = super::QueryRootResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AssetRootQuery>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::query_asset_roots(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryAssetRootsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/universerpc.Universe/DeleteAssetRoot" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteAssetRootSvc<T: Universe>(pub Arc<T>);
+                    impl<T: Universe> tonic::server::UnaryService<super::DeleteRootQuery>
+                    for DeleteAssetRootSvc<T> {
+                        type Response = super::DeleteRootResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteRootQuery>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::delete_asset_root(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeleteAssetRootSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/universerpc.Universe/AssetLeafKeys" => {
+                    #[allow(non_camel_case_types)]
+                    struct AssetLeafKeysSvc<T: Universe>(pub Arc<T>);
+                    impl<
+                        T: Universe,
+                    > tonic::server::UnaryService<super::AssetLeafKeysRequest>
+                    for AssetLeafKeysSvc<T> {
+                        type Response = super::AssetLeafKeyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AssetLeafKeysRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::asset_leaf_keys(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AssetLeafKeysSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/universerpc.Universe/AssetLeaves" => {
+                    #[allow(non_camel_case_types)]
+                    struct AssetLeavesSvc<T: Universe>(pub Arc<T>);
+                    impl<T: Universe> tonic::server::UnaryService<super::Id>
+                    for AssetLeavesSvc<T> {
+                        type Response = super::AssetLeafResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::Id>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::asset_leaves(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AssetLeavesSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/universerpc.Universe/QueryProof" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryProofSvc<T: Universe>(pub Arc<T>);
+                    impl<T: Universe> tonic::server::UnaryService<super::UniverseKey>
+                    for QueryProofSvc<T> {
+                        type Response = super::AssetProofResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UniverseKey>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::query_proof(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryProofSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/universerpc.Universe/InsertProof" => {
+                    #[allow(non_camel_case_types)]
+                    struct InsertProofSvc<T: Universe>(pub Arc<T>);
+                    impl<T: Universe> tonic::server::UnaryService<super::AssetProof>
+                    for InsertProofSvc<T> {
+                        type Response = super::AssetProofResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AssetProof>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::insert_proof(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = InsertProofSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/universerpc.Universe/PushProof" => {
+                    #[allow(non_camel_case_types)]
+                    struct PushProofSvc<T: Universe>(pub Arc<T>);
+                    impl<
+                        T: Universe,
+                    > tonic::server::UnaryService<super::PushProofRequest>
+                    for PushProofSvc<T> {
+                        type Response = super::PushProofResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PushProofRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::push_proof(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PushProofSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/universerpc.Universe/Info" => {
+                    #[allow(non_camel_case_types)]
+                    struct InfoSvc<T: Universe>(pub Arc<T>);
+                    impl<T: Universe> tonic::server::UnaryService<super::InfoRequest>
+                    for InfoSvc<T> {
+                        type Response = super::InfoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::InfoRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::info(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = InfoSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/universerpc.Universe/SyncUniverse" => {
+                    #[allow(non_camel_case_types)]
+                    struct SyncUniverseSvc<T: Universe>(pub Arc<T>);
+                    impl<T: Universe> tonic::server::UnaryService<super::SyncRequest>
+                    for SyncUniverseSvc<T> {
+                        type Response = super::SyncResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SyncRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::sync_universe(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SyncUniverseSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/universerpc.Universe/ListFederationServers" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListFederationServersSvc<T: Universe>(pub Arc<T>);
+                    impl<
+                        T: Universe,
+                    > tonic::server::UnaryService<super::ListFederationServersRequest>
+                    for ListFederationServersSvc<T> {
+                        type Response = super::ListFederationServersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListFederationServersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::list_federation_servers(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListFederationServersSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/universerpc.Universe/AddFederationServer" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddFederationServerSvc<T: Universe>(pub Arc<T>);
+                    impl<
+                        T: Universe,
+                    > tonic::server::UnaryService<super::AddFederationServerRequest>
+                    for AddFederationServerSvc<T> {
+                        type Response = super::AddFederationServerResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddFederationServerRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::add_federation_server(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AddFederationServerSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/universerpc.Universe/DeleteFederationServer" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteFederationServerSvc<T: Universe>(pub Arc<T>);
+                    impl<
+                        T: Universe,
+                    > tonic::server::UnaryService<super::DeleteFederationServerRequest>
+                    for DeleteFederationServerSvc<T> {
+                        type Response = super::DeleteFederationServerResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteFederationServerRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::delete_federation_server(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeleteFederationServerSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/universerpc.Universe/UniverseStats" => {
+                    #[allow(non_camel_case_types)]
+                    struct UniverseStatsSvc<T: Universe>(pub Arc<T>);
+                    impl<T: Universe> tonic::server::UnaryService<super::StatsRequest>
+                    for UniverseStatsSvc<T> {
+                        type Response = super::StatsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::StatsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::universe_stats(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UniverseStatsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/universerpc.Universe/QueryAssetStats" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryAssetStatsSvc<T: Universe>(pub Arc<T>);
+                    impl<T: Universe> tonic::server::UnaryService<super::AssetStatsQuery>
+                    for QueryAssetStatsSvc<T> {
+                        type Response = super::UniverseAssetStats;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AssetStatsQuery>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::query_asset_stats(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryAssetStatsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/universerpc.Universe/QueryEvents" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryEventsSvc<T: Universe>(pub Arc<T>);
+                    impl<
+                        T: Universe,
+                    > tonic::server::UnaryService<super::QueryEventsRequest>
+                    for QueryEventsSvc<T> {
+                        type Response = super::QueryEventsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryEventsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::query_events(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryEventsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/universerpc.Universe/SetFederationSyncConfig" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetFederationSyncConfigSvc<T: Universe>(pub Arc<T>);
+                    impl<
+                        T: Universe,
+                    > tonic::server::UnaryService<super::SetFederationSyncConfigRequest>
+                    for SetFederationSyncConfigSvc<T> {
+                        type Response = super::SetFederationSyncConfigResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::SetFederationSyncConfigRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::set_federation_sync_config(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetFederationSyncConfigSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/universerpc.Universe/QueryFederationSyncConfig" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryFederationSyncConfigSvc<T: Universe>(pub Arc<T>);
+                    impl<
+                        T: Universe,
+                    > tonic::server::UnaryService<
+                        super::QueryFederationSyncConfigRequest,
+                    > for QueryFederationSyncConfigSvc<T> {
+                        type Response = super::QueryFederationSyncConfigResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryFederationSyncConfigRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Universe>::query_federation_sync_config(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryFederationSyncConfigSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for UniverseServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "universerpc.Universe";
+    impl<T> tonic::server::NamedService for UniverseServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+impl crate::universerpc::MultiverseRootRequest {
+    /// Returns `proof_type`, or an error for values unknown to this version of the protos.
+    pub fn try_proof_type(&self) -> ::core::result::Result<crate::universerpc::ProofType, ::prost::UnknownEnumValue> {
+        crate::universerpc::ProofType::try_from(self.proof_type)
+    }
+}
+impl crate::universerpc::AssetRootRequest {
+    /// Returns `direction`, or an error for values unknown to this version of the protos.
+    pub fn try_direction(&self) -> ::core::result::Result<crate::universerpc::SortDirection, ::prost::UnknownEnumValue> {
+        crate::universerpc::SortDirection::try_from(self.direction)
+    }
+}
+impl crate::universerpc::Id {
+    /// Returns `proof_type`, or an error for values unknown to this version of the protos.
+    pub fn try_proof_type(&self) -> ::core::result::Result<crate::universerpc::ProofType, ::prost::UnknownEnumValue> {
+        crate::universerpc::ProofType::try_from(self.proof_type)
+    }
+}
+impl crate::universerpc::AssetLeafKeysRequest {
+    /// Returns `direction`, or an error for values unknown to this version of the protos.
+    pub fn try_direction(&self) -> ::core::result::Result<crate::universerpc::SortDirection, ::prost::UnknownEnumValue> {
+        crate::universerpc::SortDirection::try_from(self.direction)
+    }
+}
+impl crate::universerpc::SyncRequest {
+    /// Returns `sync_mode`, or an error for values unknown to this version of the protos.
+    pub fn try_sync_mode(&self) -> ::core::result::Result<crate::universerpc::UniverseSyncMode, ::prost::UnknownEnumValue> {
+        crate::universerpc::UniverseSyncMode::try_from(self.sync_mode)
+    }
+}
+impl crate::universerpc::AssetStatsQuery {
+    /// Returns `asset_type_filter`, or an error for values unknown to this version of the protos.
+    pub fn try_asset_type_filter(&self) -> ::core::result::Result<crate::universerpc::AssetTypeFilter, ::prost::UnknownEnumValue> {
+        crate::universerpc::AssetTypeFilter::try_from(self.asset_type_filter)
+    }
+    /// Returns `sort_by`, or an error for values unknown to this version of the protos.
+    pub fn try_sort_by(&self) -> ::core::result::Result<crate::universerpc::AssetQuerySort, ::prost::UnknownEnumValue> {
+        crate::universerpc::AssetQuerySort::try_from(self.sort_by)
+    }
+    /// Returns `direction`, or an error for values unknown to this version of the protos.
+    pub fn try_direction(&self) -> ::core::result::Result<crate::universerpc::SortDirection, ::prost::UnknownEnumValue> {
+        crate::universerpc::SortDirection::try_from(self.direction)
+    }
+}
+impl crate::universerpc::AssetStatsAsset {
+    /// Returns `asset_type`, or an error for values unknown to this version of the protos.
+    pub fn try_asset_type(&self) -> ::core::result::Result<crate::taprpc::AssetType, ::prost::UnknownEnumValue> {
+        crate::taprpc::AssetType::try_from(self.asset_type)
+    }
+}
+impl crate::universerpc::GlobalFederationSyncConfig {
+    /// Returns `proof_type`, or an error for values unknown to this version of the protos.
+    pub fn try_proof_type(&self) -> ::core::result::Result<crate::universerpc::ProofType, ::prost::UnknownEnumValue> {
+        crate::universerpc::ProofType::try_from(self.proof_type)
+    }
+}
+/// Generated blocking client implementations.
+#[cfg(feature = "blocking")]
+pub mod universe_blocking_client {
+    #![allow(clippy::wildcard_imports)]
+    use tonic::codegen::*;
+    use super::universe_client::UniverseClient;
+    /// A [`UniverseClient`] that blocks the current thread on every call, driving the
+    /// requests on its runtime.
+    #[derive(Debug, Clone)]
+    pub struct UniverseBlockingClient<T> {
+        inner: UniverseClient<T>,
+        runtime: std::sync::Arc<crate::blocking::Runtime>,
+    }
+    impl<T> UniverseBlockingClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        /// Wraps `inner`, running its calls on `runtime`.
+        pub fn new(inner: UniverseClient<T>, runtime: std::sync::Arc<crate::blocking::Runtime>) -> Self {
+            Self { inner, runtime }
+        }
+        /// Returns the async client.
+        pub fn into_inner(self) -> UniverseClient<T> {
+            self.inner
+        }
+        /// Blocking version of [`UniverseClient::multiverse_root`].
+        pub fn multiverse_root(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::MultiverseRootRequest>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::MultiverseRootResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.multiverse_root(request))
+        }
+        /// Blocking version of [`UniverseClient::asset_roots`].
+        pub fn asset_roots(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::AssetRootRequest>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::AssetRootResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.asset_roots(request))
+        }
+        /// Blocking version of [`UniverseClient::query_asset_roots`].
+        pub fn query_asset_roots(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::AssetRootQuery>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::QueryRootResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.query_asset_roots(request))
+        }
+        /// Blocking version of [`UniverseClient::delete_asset_root`].
+        pub fn delete_asset_root(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::DeleteRootQuery>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::DeleteRootResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.delete_asset_root(request))
+        }
+        /// Blocking version of [`UniverseClient::asset_leaf_keys`].
+        pub fn asset_leaf_keys(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::AssetLeafKeysRequest>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::AssetLeafKeyResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.asset_leaf_keys(request))
+        }
+        /// Blocking version of [`UniverseClient::asset_leaves`].
+        pub fn asset_leaves(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::Id>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::AssetLeafResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.asset_leaves(request))
+        }
+        /// Blocking version of [`UniverseClient::query_proof`].
+        pub fn query_proof(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::UniverseKey>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::AssetProofResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.query_proof(request))
+        }
+        /// Blocking version of [`UniverseClient::insert_proof`].
+        pub fn insert_proof(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::AssetProof>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::AssetProofResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.insert_proof(request))
+        }
+        /// Blocking version of [`UniverseClient::push_proof`].
+        pub fn push_proof(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::PushProofRequest>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::PushProofResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.push_proof(request))
+        }
+        /// Blocking version of [`UniverseClient::info`].
+        pub fn info(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::InfoRequest>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::InfoResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.info(request))
+        }
+        /// Blocking version of [`UniverseClient::sync_universe`].
+        pub fn sync_universe(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::SyncRequest>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::SyncResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.sync_universe(request))
+        }
+        /// Blocking version of [`UniverseClient::list_federation_servers`].
+        pub fn list_federation_servers(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::ListFederationServersRequest>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::ListFederationServersResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.list_federation_servers(request))
+        }
+        /// Blocking version of [`UniverseClient::add_federation_server`].
+        pub fn add_federation_server(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::AddFederationServerRequest>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::AddFederationServerResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.add_federation_server(request))
+        }
+        /// Blocking version of [`UniverseClient::delete_federation_server`].
+        pub fn delete_federation_server(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::DeleteFederationServerRequest>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::DeleteFederationServerResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.delete_federation_server(request))
+        }
+        /// Blocking version of [`UniverseClient::universe_stats`].
+        pub fn universe_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::StatsRequest>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::StatsResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.universe_stats(request))
+        }
+        /// Blocking version of [`UniverseClient::query_asset_stats`].
+        pub fn query_asset_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::AssetStatsQuery>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::UniverseAssetStats>, tonic::Status> {
+            self.runtime.block_on(self.inner.query_asset_stats(request))
+        }
+        /// Blocking version of [`UniverseClient::query_events`].
+        pub fn query_events(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::QueryEventsRequest>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::QueryEventsResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.query_events(request))
+        }
+        /// Blocking version of [`UniverseClient::set_federation_sync_config`].
+        pub fn set_federation_sync_config(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::SetFederationSyncConfigRequest>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::SetFederationSyncConfigResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.set_federation_sync_config(request))
+        }
+        /// Blocking version of [`UniverseClient::query_federation_sync_config`].
+        pub fn query_federation_sync_config(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::universerpc::QueryFederationSyncConfigRequest>,
+        ) -> std::result::Result<tonic::Response<crate::universerpc::QueryFederationSyncConfigResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.query_federation_sync_config(request))
+        }
+    }
+}
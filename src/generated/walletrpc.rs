@@ -0,0 +1,5044 @@
+// This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListUnspentRequest {
+    /// The minimum number of confirmations to be included.
+    #[prost(int32, tag = "1")]
+    pub min_confs: i32,
+    /// The maximum number of confirmations to be included.
+    #[prost(int32, tag = "2")]
+    pub max_confs: i32,
+    /// An optional filter to only include outputs belonging to an account.
+    #[prost(string, tag = "3")]
+    pub account: ::prost::alloc::string::String,
+    /// When min_confs and max_confs are zero, setting false implicitly
+    /// overrides max_confs to be MaxInt32, otherwise max_confs remains
+    /// zero. An error is returned if the value is true and both min_confs
+    /// and max_confs are non-zero. (default: false)
+    #[prost(bool, tag = "4")]
+    pub unconfirmed_only: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListUnspentResponse {
+    /// A list of utxos satisfying the specified number of confirmations.
+    #[prost(message, repeated, tag = "1")]
+    pub utxos: ::prost::alloc::vec::Vec<super::lnrpc::Utxo>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct LeaseOutputRequest {
+    /// An ID of 32 random bytes that must be unique for each distinct application
+    /// using this RPC which will be used to bound the output lease to.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub id: ::prost::alloc::vec::Vec<u8>,
+    /// The identifying outpoint of the output being leased.
+    #[prost(message, optional, tag = "2")]
+    pub outpoint: ::core::option::Option<super::lnrpc::OutPoint>,
+    /// The time in seconds before the lock expires. If set to zero, the default
+    /// lock duration is used.
+    #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub expiration_seconds: u64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct LeaseOutputResponse {
+    /// The absolute expiration of the output lease represented as a unix timestamp.
+    #[prost(uint64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub expiration: u64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ReleaseOutputRequest {
+    /// The unique ID that was used to lock the output.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub id: ::prost::alloc::vec::Vec<u8>,
+    /// The identifying outpoint of the output being released.
+    #[prost(message, optional, tag = "2")]
+    pub outpoint: ::core::option::Option<super::lnrpc::OutPoint>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ReleaseOutputResponse {
+    /// The status of the release operation.
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct KeyReq {
+    /// Is the key finger print of the root pubkey that this request is targeting.
+    /// This allows the WalletKit to possibly serve out keys for multiple HD chains
+    /// via public derivation.
+    #[prost(int32, tag = "1")]
+    pub key_finger_print: i32,
+    /// The target key family to derive a key from. In other contexts, this is
+    /// known as the "account".
+    #[prost(int32, tag = "2")]
+    pub key_family: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AddrRequest {
+    /// The name of the account to retrieve the next address of. If empty, the
+    /// default wallet account is used.
+    #[prost(string, tag = "1")]
+    pub account: ::prost::alloc::string::String,
+    /// The type of address to derive.
+    #[prost(enumeration = "AddressType", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::walletrpc::AddressType>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::walletrpc::AddressType>, _, _>"
+        )
+    )]
+    pub r#type: i32,
+    /// Whether a change address should be derived.
+    #[prost(bool, tag = "3")]
+    pub change: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AddrResponse {
+    /// The address encoded using a bech32 format.
+    #[prost(string, tag = "1")]
+    pub addr: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Account {
+    /// The name used to identify the account.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// The type of addresses the account supports.
+    #[prost(enumeration = "AddressType", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::walletrpc::AddressType>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::walletrpc::AddressType>, _, _>"
+        )
+    )]
+    pub address_type: i32,
+    /// The public key backing the account that all keys are derived from
+    /// represented as an extended key. This will always be empty for the default
+    /// imported account in which single public keys are imported into.
+    #[prost(string, tag = "3")]
+    pub extended_public_key: ::prost::alloc::string::String,
+    /// The fingerprint of the root key from which the account public key was
+    /// derived from. This will always be zero for the default imported account in
+    /// which single public keys are imported into. The bytes are in big-endian
+    /// order.
+    #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub master_key_fingerprint: ::prost::alloc::vec::Vec<u8>,
+    /// The derivation path corresponding to the account public key. This will
+    /// always be empty for the default imported account in which single public keys
+    /// are imported into.
+    #[prost(string, tag = "5")]
+    pub derivation_path: ::prost::alloc::string::String,
+    /// The number of keys derived from the external branch of the account public
+    /// key. This will always be zero for the default imported account in which
+    /// single public keys are imported into.
+    #[prost(uint32, tag = "6")]
+    pub external_key_count: u32,
+    /// The number of keys derived from the internal branch of the account public
+    /// key. This will always be zero for the default imported account in which
+    /// single public keys are imported into.
+    #[prost(uint32, tag = "7")]
+    pub internal_key_count: u32,
+    /// Whether the wallet stores private keys for the account.
+    #[prost(bool, tag = "8")]
+    pub watch_only: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AddressProperty {
+    /// The address encoded using the appropriate format depending on the
+    /// address type (base58, bech32, bech32m).
+    ///
+    /// Note that lnd's internal/custom keys for channels and other
+    /// functionality are derived from the same scope. Since they
+    /// aren't really used as addresses and will never have an
+    /// on-chain balance, we'll show the public key instead (only if
+    /// the show_custom_accounts flag is provided).
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    /// Denotes if the address is a change address.
+    #[prost(bool, tag = "2")]
+    pub is_internal: bool,
+    /// The balance of the address.
+    #[prost(int64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub balance: i64,
+    /// The full derivation path of the address. This will be empty for imported
+    /// addresses.
+    #[prost(string, tag = "4")]
+    pub derivation_path: ::prost::alloc::string::String,
+    /// The public key of the address. This will be empty for imported addresses.
+    #[prost(bytes = "vec", tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub public_key: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountWithAddresses {
+    /// The name used to identify the account.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// The type of addresses the account supports.
+    #[prost(enumeration = "AddressType", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::walletrpc::AddressType>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::walletrpc::AddressType>, _, _>"
+        )
+    )]
+    pub address_type: i32,
+    /// The derivation path corresponding to the account public key. This will
+    /// always be empty for the default imported account in which single public keys
+    /// are imported into.
+    #[prost(string, tag = "3")]
+    pub derivation_path: ::prost::alloc::string::String,
+    /// List of address, its type internal/external & balance.
+    /// Note that the order of addresses will be random and not according to the
+    /// derivation index, since that information is not stored by the underlying
+    /// wallet.
+    #[prost(message, repeated, tag = "4")]
+    pub addresses: ::prost::alloc::vec::Vec<AddressProperty>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListAccountsRequest {
+    /// An optional filter to only return accounts matching this name.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// An optional filter to only return accounts matching this address type.
+    #[prost(enumeration = "AddressType", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::walletrpc::AddressType>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::walletrpc::AddressType>, _, _>"
+        )
+    )]
+    pub address_type: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAccountsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub accounts: ::prost::alloc::vec::Vec<Account>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RequiredReserveRequest {
+    /// The number of additional channels the user would like to open.
+    #[prost(uint32, tag = "1")]
+    pub additional_public_channels: u32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RequiredReserveResponse {
+    /// The amount of reserve required.
+    #[prost(int64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub required_reserve: i64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListAddressesRequest {
+    /// An optional filter to only return addresses matching this account.
+    #[prost(string, tag = "1")]
+    pub account_name: ::prost::alloc::string::String,
+    /// An optional flag to return LND's custom accounts (Purpose=1017)
+    /// public key along with other addresses.
+    #[prost(bool, tag = "2")]
+    pub show_custom_accounts: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAddressesResponse {
+    /// A list of all the accounts and their addresses.
+    #[prost(message, repeated, tag = "1")]
+    pub account_with_addresses: ::prost::alloc::vec::Vec<AccountWithAddresses>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetTransactionRequest {
+    /// The txid of the transaction.
+    #[prost(string, tag = "1")]
+    pub txid: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SignMessageWithAddrRequest {
+    /// The message to be signed. When using REST, this field must be encoded as
+    /// base64.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub msg: ::prost::alloc::vec::Vec<u8>,
+    /// The address which will be used to look up the private key and sign the
+    /// corresponding message.
+    #[prost(string, tag = "2")]
+    pub addr: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SignMessageWithAddrResponse {
+    /// The compact ECDSA signature for the given message encoded in base64.
+    #[prost(string, tag = "1")]
+    pub signature: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct VerifyMessageWithAddrRequest {
+    /// The message to be signed. When using REST, this field must be encoded as
+    /// base64.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub msg: ::prost::alloc::vec::Vec<u8>,
+    /// The compact ECDSA signature to be verified over the given message
+    /// ecoded in base64.
+    #[prost(string, tag = "2")]
+    pub signature: ::prost::alloc::string::String,
+    /// The address which will be used to look up the public key and verify the
+    /// the signature.
+    #[prost(string, tag = "3")]
+    pub addr: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct VerifyMessageWithAddrResponse {
+    /// Whether the signature was valid over the given message.
+    #[prost(bool, tag = "1")]
+    pub valid: bool,
+    /// The pubkey recovered from the signature.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub pubkey: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ImportAccountRequest {
+    /// A name to identify the account with.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// A public key that corresponds to a wallet account represented as an extended
+    /// key. It must conform to a derivation path of the form
+    /// m/purpose'/coin_type'/account'.
+    #[prost(string, tag = "2")]
+    pub extended_public_key: ::prost::alloc::string::String,
+    /// The fingerprint of the root key (also known as the key with derivation path
+    /// m/) from which the account public key was derived from. This may be required
+    /// by some hardware wallets for proper identification and signing. The bytes
+    /// must be in big-endian order.
+    #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub master_key_fingerprint: ::prost::alloc::vec::Vec<u8>,
+    /// An address type is only required when the extended account public key has a
+    /// legacy version (xpub, tpub, etc.), such that the wallet cannot detect what
+    /// address scheme it belongs to.
+    #[prost(enumeration = "AddressType", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::walletrpc::AddressType>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::walletrpc::AddressType>, _, _>"
+        )
+    )]
+    pub address_type: i32,
+    /// Whether a dry run should be attempted when importing the account. This
+    /// serves as a way to confirm whether the account is being imported correctly
+    /// by returning the first N addresses for the external and internal branches of
+    /// the account. If these addresses match as expected, then it should be safe to
+    /// import the account as is.
+    #[prost(bool, tag = "5")]
+    pub dry_run: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ImportAccountResponse {
+    /// The details of the imported account.
+    #[prost(message, optional, tag = "1")]
+    pub account: ::core::option::Option<Account>,
+    /// The first N addresses that belong to the external branch of the account.
+    /// The external branch is typically used for external non-change addresses.
+    /// These are only returned if a dry run was specified within the request.
+    #[prost(string, repeated, tag = "2")]
+    pub dry_run_external_addrs: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// The first N addresses that belong to the internal branch of the account.
+    /// The internal branch is typically used for change addresses. These are only
+    /// returned if a dry run was specified within the request.
+    #[prost(string, repeated, tag = "3")]
+    pub dry_run_internal_addrs: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ImportPublicKeyRequest {
+    /// A compressed public key represented as raw bytes.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub public_key: ::prost::alloc::vec::Vec<u8>,
+    /// The type of address that will be generated from the public key.
+    #[prost(enumeration = "AddressType", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::walletrpc::AddressType>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::walletrpc::AddressType>, _, _>"
+        )
+    )]
+    pub address_type: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ImportPublicKeyResponse {
+    /// The status of the import operation.
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportTapscriptRequest {
+    /// The internal public key, serialized as 32-byte x-only public key.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub internal_public_key: ::prost::alloc::vec::Vec<u8>,
+    #[prost(oneof = "import_tapscript_request::Script", tags = "2, 3, 4, 5")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub script: ::core::option::Option<import_tapscript_request::Script>,
+}
+/// Nested message and enum types in `ImportTapscriptRequest`.
+pub mod import_tapscript_request {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Script {
+        /// The full script tree with all individual leaves is known and the root
+        /// hash can be constructed from the full tree directly.
+        #[prost(message, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "full_tree"))]
+        FullTree(super::TapscriptFullTree),
+        /// Only a single script leaf is known. To construct the root hash, the full
+        /// inclusion proof must also be provided.
+        #[prost(message, tag = "3")]
+        #[cfg_attr(feature = "serde", serde(rename = "partial_reveal"))]
+        PartialReveal(super::TapscriptPartialReveal),
+        /// Only the root hash of the Taproot script tree (or other form of Taproot
+        /// commitment) is known.
+        #[prost(bytes, tag = "4")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                rename = "root_hash_only",
+                serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+                deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+            )
+        )]
+        RootHashOnly(::prost::alloc::vec::Vec<u8>),
+        /// Only the final, tweaked Taproot key is known and no additional
+        /// information about the internal key or type of tweak that was used to
+        /// derive it. When this is set, the wallet treats the key in
+        /// internal_public_key as the Taproot key directly. This can be useful for
+        /// tracking arbitrary Taproot outputs without the goal of ever being able
+        /// to spend from them through the internal wallet.
+        #[prost(bool, tag = "5")]
+        #[cfg_attr(feature = "serde", serde(rename = "full_key_only"))]
+        FullKeyOnly(bool),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TapscriptFullTree {
+    /// The complete, ordered list of all tap leaves of the tree.
+    #[prost(message, repeated, tag = "1")]
+    pub all_leaves: ::prost::alloc::vec::Vec<TapLeaf>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TapLeaf {
+    /// The leaf version. Should be 0xc0 (192) in case of a SegWit v1 script.
+    #[prost(uint32, tag = "1")]
+    pub leaf_version: u32,
+    /// The script of the tap leaf.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub script: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TapscriptPartialReveal {
+    /// The tap leaf that is known and will be revealed.
+    #[prost(message, optional, tag = "1")]
+    pub revealed_leaf: ::core::option::Option<TapLeaf>,
+    /// The BIP-0341 serialized inclusion proof that is required to prove that
+    /// the revealed leaf is part of the tree. This contains 0..n blocks of 32
+    /// bytes. If the tree only contained a single leaf (which is the revealed
+    /// leaf), this can be empty.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub full_inclusion_proof: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ImportTapscriptResponse {
+    /// The resulting pay-to-Taproot address that represents the imported internal
+    /// key with the script committed to it.
+    #[prost(string, tag = "1")]
+    pub p2tr_address: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Transaction {
+    /// The raw serialized transaction. Despite the field name, this does need to be
+    /// specified in raw bytes (or base64 encoded when using REST) and not in hex.
+    /// To not break existing software, the field can't simply be renamed.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub tx_hex: ::prost::alloc::vec::Vec<u8>,
+    /// An optional label to save with the transaction. Limited to 500 characters.
+    #[prost(string, tag = "2")]
+    pub label: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PublishResponse {
+    /// If blank, then no error occurred and the transaction was successfully
+    /// published. If not the empty string, then a string representation of the
+    /// broadcast error.
+    ///
+    /// TODO(roasbeef): map to a proper enum type
+    #[prost(string, tag = "1")]
+    pub publish_error: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RemoveTransactionResponse {
+    /// The status of the remove transaction operation.
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SendOutputsRequest {
+    /// The number of satoshis per kilo weight that should be used when crafting
+    /// this transaction.
+    #[prost(int64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub sat_per_kw: i64,
+    /// A slice of the outputs that should be created in the transaction produced.
+    #[prost(message, repeated, tag = "2")]
+    pub outputs: ::prost::alloc::vec::Vec<super::signrpc::TxOut>,
+    /// An optional label for the transaction, limited to 500 characters.
+    #[prost(string, tag = "3")]
+    pub label: ::prost::alloc::string::String,
+    /// The minimum number of confirmations each one of your outputs used for
+    /// the transaction must satisfy.
+    #[prost(int32, tag = "4")]
+    pub min_confs: i32,
+    /// Whether unconfirmed outputs should be used as inputs for the transaction.
+    #[prost(bool, tag = "5")]
+    pub spend_unconfirmed: bool,
+    /// The strategy to use for selecting coins during sending the outputs.
+    #[prost(enumeration = "super::lnrpc::CoinSelectionStrategy", tag = "6")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::lnrpc::CoinSelectionStrategy>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::lnrpc::CoinSelectionStrategy>, _, _>"
+        )
+    )]
+    pub coin_selection_strategy: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SendOutputsResponse {
+    /// The serialized transaction sent out on the network.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub raw_tx: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct EstimateFeeRequest {
+    /// The number of confirmations to shoot for when estimating the fee.
+    #[prost(int32, tag = "1")]
+    pub conf_target: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct EstimateFeeResponse {
+    /// The amount of satoshis per kw that should be used in order to reach the
+    /// confirmation target in the request.
+    #[prost(int64, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub sat_per_kw: i64,
+    /// The current minimum relay fee based on our chain backend in sat/kw.
+    #[prost(int64, tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub min_relay_fee_sat_per_kw: i64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PendingSweep {
+    /// The outpoint of the output we're attempting to sweep.
+    #[prost(message, optional, tag = "1")]
+    pub outpoint: ::core::option::Option<super::lnrpc::OutPoint>,
+    /// The witness type of the output we're attempting to sweep.
+    #[prost(enumeration = "WitnessType", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::walletrpc::WitnessType>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::walletrpc::WitnessType>, _, _>"
+        )
+    )]
+    pub witness_type: i32,
+    /// The value of the output we're attempting to sweep.
+    #[prost(uint32, tag = "3")]
+    pub amount_sat: u32,
+    /// Deprecated, use sat_per_vbyte.
+    /// The fee rate we'll use to sweep the output, expressed in sat/vbyte. The fee
+    /// rate is only determined once a sweeping transaction for the output is
+    /// created, so it's possible for this to be 0 before this.
+    #[deprecated]
+    #[prost(uint32, tag = "4")]
+    pub sat_per_byte: u32,
+    /// The number of broadcast attempts we've made to sweep the output.
+    #[prost(uint32, tag = "5")]
+    pub broadcast_attempts: u32,
+    /// Deprecated.
+    /// The next height of the chain at which we'll attempt to broadcast the
+    /// sweep transaction of the output.
+    #[deprecated]
+    #[prost(uint32, tag = "6")]
+    pub next_broadcast_height: u32,
+    /// Deprecated, use immediate.
+    /// Whether this input must be force-swept. This means that it is swept
+    /// immediately.
+    #[deprecated]
+    #[prost(bool, tag = "7")]
+    pub force: bool,
+    /// Deprecated, use deadline.
+    /// The requested confirmation target for this output, which is the deadline
+    /// used by the sweeper.
+    #[deprecated]
+    #[prost(uint32, tag = "8")]
+    pub requested_conf_target: u32,
+    /// Deprecated, use requested_sat_per_vbyte.
+    /// The requested fee rate, expressed in sat/vbyte, for this output.
+    #[deprecated]
+    #[prost(uint32, tag = "9")]
+    pub requested_sat_per_byte: u32,
+    /// The current fee rate we'll use to sweep the output, expressed in sat/vbyte.
+    /// The fee rate is only determined once a sweeping transaction for the output
+    /// is created, so it's possible for this to be 0 before this.
+    #[prost(uint64, tag = "10")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub sat_per_vbyte: u64,
+    /// The requested starting fee rate, expressed in sat/vbyte, for this
+    /// output. When not requested, this field will be 0.
+    #[prost(uint64, tag = "11")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub requested_sat_per_vbyte: u64,
+    /// Whether this input will be swept immediately.
+    #[prost(bool, tag = "12")]
+    pub immediate: bool,
+    /// The budget for this sweep, expressed in satoshis. This is the maximum amount
+    /// that can be spent as fees to sweep this output.
+    #[prost(uint64, tag = "13")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub budget: u64,
+    /// The deadline height used for this output when perform fee bumping.
+    #[prost(uint32, tag = "14")]
+    pub deadline_height: u32,
+    /// The block height which the input's locktime will expire at. Zero if the
+    /// input has no locktime.
+    #[prost(uint32, tag = "15")]
+    pub maturity_height: u32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PendingSweepsRequest {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingSweepsResponse {
+    /// The set of outputs currently being swept by lnd's central batching engine.
+    #[prost(message, repeated, tag = "1")]
+    pub pending_sweeps: ::prost::alloc::vec::Vec<PendingSweep>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BumpFeeRequest {
+    /// The input we're attempting to bump the fee of.
+    #[prost(message, optional, tag = "1")]
+    pub outpoint: ::core::option::Option<super::lnrpc::OutPoint>,
+    /// Optional. The conf target the underlying fee estimator will use to
+    /// estimate the starting fee rate for the fee function.
+    #[prost(uint32, tag = "2")]
+    pub target_conf: u32,
+    /// Deprecated, use sat_per_vbyte.
+    /// The fee rate, expressed in sat/vbyte, that should be used to spend the input
+    /// with.
+    #[deprecated]
+    #[prost(uint32, tag = "3")]
+    pub sat_per_byte: u32,
+    /// Deprecated, use immediate.
+    /// Whether this input must be force-swept. This means that it is swept
+    /// immediately.
+    #[deprecated]
+    #[prost(bool, tag = "4")]
+    pub force: bool,
+    /// Optional. The starting fee rate, expressed in sat/vbyte, that will be used
+    /// to spend the input with initially. This value will be used by the sweeper's
+    /// fee function as its starting fee rate. When not set, the sweeper will use
+    /// the estimated fee rate using the `target_conf` as the starting fee rate.
+    #[prost(uint64, tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub sat_per_vbyte: u64,
+    /// Optional. Whether this input will be swept immediately. When set to true,
+    /// the sweeper will sweep this input without waiting for the next block.
+    #[prost(bool, tag = "6")]
+    pub immediate: bool,
+    /// Optional. The max amount in sats that can be used as the fees. Setting this
+    /// value greater than the input's value may result in CPFP - one or more wallet
+    /// utxos will be used to pay the fees specified by the budget. If not set, for
+    /// new inputs, by default 50% of the input's value will be treated as the
+    /// budget for fee bumping; for existing inputs, their current budgets will be
+    /// retained.
+    #[prost(uint64, tag = "7")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub budget: u64,
+    /// Optional. The deadline delta in number of blocks that the output
+    /// should be spent within. This translates internally to the width of the
+    /// fee function that the sweeper will use to bump the fee rate. When the
+    /// deadline is reached, ALL the budget will be spent as fees.
+    #[prost(uint32, tag = "8")]
+    pub deadline_delta: u32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BumpFeeResponse {
+    /// The status of the bump fee operation.
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BumpForceCloseFeeRequest {
+    /// The channel point which force close transaction we are attempting to
+    /// bump the fee rate for.
+    #[prost(message, optional, tag = "1")]
+    pub chan_point: ::core::option::Option<super::lnrpc::ChannelPoint>,
+    /// Optional. The deadline delta in number of blocks that the anchor output
+    /// should be spent within to bump the closing transaction. When the
+    /// deadline is reached, ALL the budget will be spent as fees
+    #[prost(uint32, tag = "2")]
+    pub deadline_delta: u32,
+    /// Optional. The starting fee rate, expressed in sat/vbyte. This value will be
+    /// used by the sweeper's fee function as its starting fee rate. When not set,
+    /// the sweeper will use the estimated fee rate using the target_conf as the
+    /// starting fee rate.
+    #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub starting_feerate: u64,
+    /// Optional. Whether this cpfp transaction will be triggered immediately. When
+    /// set to true, the sweeper will consider all currently registered sweeps and
+    /// trigger new batch transactions including the sweeping of the anchor output
+    /// related to the selected force close transaction.
+    #[prost(bool, tag = "4")]
+    pub immediate: bool,
+    /// Optional. The max amount in sats that can be used as the fees. For already
+    /// registered anchor outputs if not set explicitly the old value will be used.
+    /// For channel force closes which have no HTLCs in their commitment transaction
+    /// this value has to be set to an appropriate amount to pay for the cpfp
+    /// transaction of the force closed channel otherwise the fee bumping will fail.
+    #[prost(uint64, tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub budget: u64,
+    /// Optional. The conf target the underlying fee estimator will use to
+    /// estimate the starting fee rate for the fee function.
+    #[prost(uint32, tag = "6")]
+    pub target_conf: u32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BumpForceCloseFeeResponse {
+    /// The status of the force close fee bump operation.
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListSweepsRequest {
+    /// Retrieve the full sweep transaction details. If false, only the sweep txids
+    /// will be returned. Note that some sweeps that LND publishes will have been
+    /// replaced-by-fee, so will not be included in this output.
+    #[prost(bool, tag = "1")]
+    pub verbose: bool,
+    /// The start height to use when fetching sweeps. If not specified (0), the
+    /// result will start from the earliest sweep. If set to -1 the result will
+    /// only include unconfirmed sweeps (at the time of the call).
+    #[prost(int32, tag = "2")]
+    pub start_height: i32,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListSweepsResponse {
+    #[prost(oneof = "list_sweeps_response::Sweeps", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub sweeps: ::core::option::Option<list_sweeps_response::Sweeps>,
+}
+/// Nested message and enum types in `ListSweepsResponse`.
+pub mod list_sweeps_response {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+    pub struct TransactionIDs {
+        /// Reversed, hex-encoded string representing the transaction ids of the
+        /// sweeps that our node has broadcast. Note that these transactions may
+        /// not have confirmed yet, we record sweeps on broadcast, not confirmation.
+        #[prost(string, repeated, tag = "1")]
+        pub transaction_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    }
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Sweeps {
+        #[prost(message, tag = "1")]
+        #[cfg_attr(feature = "serde", serde(rename = "transaction_details"))]
+        TransactionDetails(super::super::lnrpc::TransactionDetails),
+        #[prost(message, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "transaction_ids"))]
+        TransactionIds(TransactionIDs),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct LabelTransactionRequest {
+    /// The txid of the transaction to label. Note: When using gRPC, the bytes
+    /// must be in little-endian (reverse) order.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub txid: ::prost::alloc::vec::Vec<u8>,
+    /// The label to add to the transaction, limited to 500 characters.
+    #[prost(string, tag = "2")]
+    pub label: ::prost::alloc::string::String,
+    /// Whether to overwrite the existing label, if it is present.
+    #[prost(bool, tag = "3")]
+    pub overwrite: bool,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct LabelTransactionResponse {
+    /// The status of the label operation.
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundPsbtRequest {
+    /// The name of the account to fund the PSBT with. If empty, the default wallet
+    /// account is used.
+    #[prost(string, tag = "5")]
+    pub account: ::prost::alloc::string::String,
+    /// The minimum number of confirmations each one of your outputs used for
+    /// the transaction must satisfy.
+    #[prost(int32, tag = "6")]
+    pub min_confs: i32,
+    /// Whether unconfirmed outputs should be used as inputs for the transaction.
+    #[prost(bool, tag = "7")]
+    pub spend_unconfirmed: bool,
+    /// The address type for the change. If empty, P2WPKH addresses will be used
+    /// for default accounts and single imported public keys. For custom
+    /// accounts, no change type should be provided as the coin selection key
+    /// scope will always be used to generate the change address.
+    #[prost(enumeration = "ChangeAddressType", tag = "8")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::walletrpc::ChangeAddressType>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::walletrpc::ChangeAddressType>, _, _>"
+        )
+    )]
+    pub change_type: i32,
+    /// The strategy to use for selecting coins during funding the PSBT.
+    #[prost(enumeration = "super::lnrpc::CoinSelectionStrategy", tag = "10")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Enum<crate::lnrpc::CoinSelectionStrategy>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Enum<crate::lnrpc::CoinSelectionStrategy>, _, _>"
+        )
+    )]
+    pub coin_selection_strategy: i32,
+    /// The max fee to total output amount ratio that this psbt should adhere to.
+    #[prost(double, tag = "12")]
+    pub max_fee_ratio: f64,
+    /// The custom lock ID to use for the inputs in the funded PSBT. The value
+    /// if set must be exactly 32 bytes long. If empty, the default lock ID will
+    /// be used.
+    #[prost(bytes = "vec", tag = "13")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub custom_lock_id: ::prost::alloc::vec::Vec<u8>,
+    /// If set, then the inputs in the funded PSBT will be locked for the
+    /// specified duration. The lock duration is specified in seconds. If not
+    /// set, the default lock duration will be used.
+    #[prost(uint64, tag = "14")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub lock_expiration_seconds: u64,
+    #[prost(oneof = "fund_psbt_request::Template", tags = "1, 2, 9")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub template: ::core::option::Option<fund_psbt_request::Template>,
+    #[prost(oneof = "fund_psbt_request::Fees", tags = "3, 4, 11")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub fees: ::core::option::Option<fund_psbt_request::Fees>,
+}
+/// Nested message and enum types in `FundPsbtRequest`.
+pub mod fund_psbt_request {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Template {
+        /// Use an existing PSBT packet as the template for the funded PSBT.
+        ///
+        /// The packet must contain at least one non-dust output. If one or more
+        /// inputs are specified, no coin selection is performed. In that case every
+        /// input must be an UTXO known to the wallet that has not been locked
+        /// before. The sum of all inputs must be sufficiently greater than the sum
+        /// of all outputs to pay a miner fee with the specified fee rate. A change
+        /// output is added to the PSBT if necessary.
+        #[prost(bytes, tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                rename = "psbt",
+                serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+                deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+            )
+        )]
+        Psbt(::prost::alloc::vec::Vec<u8>),
+        /// Use the outputs and optional inputs from this raw template.
+        #[prost(message, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "raw"))]
+        Raw(super::TxTemplate),
+        /// Use an existing PSBT packet as the template for the funded PSBT.
+        ///
+        /// The difference to the pure PSBT template above is that coin selection is
+        /// performed even if inputs are specified. The output amounts are summed up
+        /// and used as the target amount for coin selection. A change output must
+        /// either already exist in the PSBT and be marked as such, otherwise a new
+        /// change output of the specified output type will be added. Any inputs
+        /// already specified in the PSBT must already be locked (if they belong to
+        /// this node), only newly added inputs will be locked by this RPC.
+        ///
+        /// In case the sum of the already provided inputs exceeds the required
+        /// output amount, no new coins are selected. Instead only the fee and
+        /// change amount calculation is performed (e.g. a change output is added if
+        /// requested or the change is added to the specified existing change
+        /// output, given there is any non-dust change). This can be identified by
+        /// the returned locked UTXOs being empty.
+        #[prost(message, tag = "9")]
+        #[cfg_attr(feature = "serde", serde(rename = "coin_select"))]
+        CoinSelect(super::PsbtCoinSelect),
+    }
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Fees {
+        /// The target number of blocks that the transaction should be confirmed in.
+        #[prost(uint32, tag = "3")]
+        #[cfg_attr(feature = "serde", serde(rename = "target_conf"))]
+        TargetConf(u32),
+        /// The fee rate, expressed in sat/vbyte, that should be used to spend the
+        /// input with.
+        #[prost(uint64, tag = "4")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                rename = "sat_per_vbyte",
+                serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+                deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+            )
+        )]
+        SatPerVbyte(u64),
+        /// The fee rate, expressed in sat/kWU, that should be used to spend the
+        /// input with.
+        #[prost(uint64, tag = "11")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                rename = "sat_per_kw",
+                serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+                deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+            )
+        )]
+        SatPerKw(u64),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundPsbtResponse {
+    /// The funded but not yet signed PSBT packet.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub funded_psbt: ::prost::alloc::vec::Vec<u8>,
+    /// The index of the added change output or -1 if no change was left over.
+    #[prost(int32, tag = "2")]
+    pub change_output_index: i32,
+    /// The list of lock leases that were acquired for the inputs in the funded PSBT
+    /// packet. Only inputs added to the PSBT by this RPC are locked, inputs that
+    /// were already present in the PSBT are not locked.
+    #[prost(message, repeated, tag = "3")]
+    pub locked_utxos: ::prost::alloc::vec::Vec<UtxoLease>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TxTemplate {
+    /// An optional list of inputs to use. Every input must be an UTXO known to the
+    /// wallet that has not been locked before. The sum of all inputs must be
+    /// sufficiently greater than the sum of all outputs to pay a miner fee with the
+    /// fee rate specified in the parent message.
+    ///
+    /// If no inputs are specified, coin selection will be performed instead and
+    /// inputs of sufficient value will be added to the resulting PSBT.
+    #[prost(message, repeated, tag = "1")]
+    pub inputs: ::prost::alloc::vec::Vec<super::lnrpc::OutPoint>,
+    /// A map of all addresses and the amounts to send to in the funded PSBT.
+    #[prost(map = "string, uint64", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Map<crate::serde_helpers::Int64>, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Map<crate::serde_helpers::Int64>, _, _>"
+        )
+    )]
+    pub outputs: ::std::collections::HashMap<::prost::alloc::string::String, u64>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PsbtCoinSelect {
+    /// The template to use for the funded PSBT. The template must contain at least
+    /// one non-dust output. The amount to be funded is calculated by summing up the
+    /// amounts of all outputs in the template, subtracting all the input values of
+    /// the already specified inputs. The change value is added to the output that
+    /// is marked as such (or a new change output is added if none is marked). For
+    /// the input amount calculation to be correct, the template must have the
+    /// WitnessUtxo field set for all inputs. Any inputs already specified in the
+    /// PSBT must already be locked (if they belong to this node), only newly added
+    /// inputs will be locked by this RPC.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub psbt: ::prost::alloc::vec::Vec<u8>,
+    #[prost(oneof = "psbt_coin_select::ChangeOutput", tags = "2, 3")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub change_output: ::core::option::Option<psbt_coin_select::ChangeOutput>,
+}
+/// Nested message and enum types in `PsbtCoinSelect`.
+pub mod psbt_coin_select {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum ChangeOutput {
+        /// Use the existing output within the template PSBT with the specified
+        /// index as the change output. Any leftover change will be added to the
+        /// already specified amount of that output. To add a new change output to
+        /// the PSBT, set the "add" field below instead. The type of change output
+        /// added is defined by change_type in the parent message.
+        #[prost(int32, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(rename = "existing_output_index"))]
+        ExistingOutputIndex(i32),
+        /// Add a new change output to the PSBT using the change_type specified in
+        /// the parent message.
+        #[prost(bool, tag = "3")]
+        #[cfg_attr(feature = "serde", serde(rename = "add"))]
+        Add(bool),
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct UtxoLease {
+    /// A 32 byte random ID that identifies the lease.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub id: ::prost::alloc::vec::Vec<u8>,
+    /// The identifying outpoint of the output being leased.
+    #[prost(message, optional, tag = "2")]
+    pub outpoint: ::core::option::Option<super::lnrpc::OutPoint>,
+    /// The absolute expiration of the output lease represented as a unix timestamp.
+    #[prost(uint64, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub expiration: u64,
+    /// The public key script of the leased output.
+    #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub pk_script: ::prost::alloc::vec::Vec<u8>,
+    /// The value of the leased output in satoshis.
+    #[prost(uint64, tag = "5")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+        )
+    )]
+    pub value: u64,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SignPsbtRequest {
+    /// The PSBT that should be signed. The PSBT must contain all required inputs,
+    /// outputs, UTXO data and custom fields required to identify the signing key.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub funded_psbt: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SignPsbtResponse {
+    /// The signed transaction in PSBT format.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub signed_psbt: ::prost::alloc::vec::Vec<u8>,
+    /// The indices of signed inputs.
+    #[prost(uint32, repeated, tag = "2")]
+    pub signed_inputs: ::prost::alloc::vec::Vec<u32>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FinalizePsbtRequest {
+    /// A PSBT that should be signed and finalized. The PSBT must contain all
+    /// required inputs, outputs, UTXO data and partial signatures of all other
+    /// signers.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub funded_psbt: ::prost::alloc::vec::Vec<u8>,
+    /// The name of the account to finalize the PSBT with. If empty, the default
+    /// wallet account is used.
+    #[prost(string, tag = "5")]
+    pub account: ::prost::alloc::string::String,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FinalizePsbtResponse {
+    /// The fully signed and finalized transaction in PSBT format.
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub signed_psbt: ::prost::alloc::vec::Vec<u8>,
+    /// The fully signed and finalized transaction in the raw wire format.
+    #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Bytes, _, _>",
+            deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Bytes, _, _>"
+        )
+    )]
+    pub raw_final_tx: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListLeasesRequest {}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListLeasesResponse {
+    /// The list of currently leased utxos.
+    #[prost(message, repeated, tag = "1")]
+    pub locked_utxos: ::prost::alloc::vec::Vec<UtxoLease>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AddressType {
+    #[cfg_attr(feature = "serde", serde(rename = "UNKNOWN"))]
+    Unknown = 0,
+    #[cfg_attr(feature = "serde", serde(rename = "WITNESS_PUBKEY_HASH"))]
+    WitnessPubkeyHash = 1,
+    #[cfg_attr(feature = "serde", serde(rename = "NESTED_WITNESS_PUBKEY_HASH"))]
+    NestedWitnessPubkeyHash = 2,
+    #[cfg_attr(feature = "serde", serde(rename = "HYBRID_NESTED_WITNESS_PUBKEY_HASH"))]
+    HybridNestedWitnessPubkeyHash = 3,
+    #[cfg_attr(feature = "serde", serde(rename = "TAPROOT_PUBKEY"))]
+    TaprootPubkey = 4,
+}
+impl AddressType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "UNKNOWN",
+            Self::WitnessPubkeyHash => "WITNESS_PUBKEY_HASH",
+            Self::NestedWitnessPubkeyHash => "NESTED_WITNESS_PUBKEY_HASH",
+            Self::HybridNestedWitnessPubkeyHash => "HYBRID_NESTED_WITNESS_PUBKEY_HASH",
+            Self::TaprootPubkey => "TAPROOT_PUBKEY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "UNKNOWN" => Some(Self::Unknown),
+            "WITNESS_PUBKEY_HASH" => Some(Self::WitnessPubkeyHash),
+            "NESTED_WITNESS_PUBKEY_HASH" => Some(Self::NestedWitnessPubkeyHash),
+            "HYBRID_NESTED_WITNESS_PUBKEY_HASH" => {
+                Some(Self::HybridNestedWitnessPubkeyHash)
+            }
+            "TAPROOT_PUBKEY" => Some(Self::TaprootPubkey),
+            _ => None,
+        }
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum WitnessType {
+    #[cfg_attr(feature = "serde", serde(rename = "UNKNOWN_WITNESS"))]
+    UnknownWitness = 0,
+    /// A witness that allows us to spend the output of a commitment transaction
+    /// after a relative lock-time lockout.
+    #[cfg_attr(feature = "serde", serde(rename = "COMMITMENT_TIME_LOCK"))]
+    CommitmentTimeLock = 1,
+    /// A witness that allows us to spend a settled no-delay output immediately on a
+    /// counterparty's commitment transaction.
+    #[cfg_attr(feature = "serde", serde(rename = "COMMITMENT_NO_DELAY"))]
+    CommitmentNoDelay = 2,
+    /// A witness that allows us to sweep the settled output of a malicious
+    /// counterparty's who broadcasts a revoked commitment transaction.
+    #[cfg_attr(feature = "serde", serde(rename = "COMMITMENT_REVOKE"))]
+    CommitmentRevoke = 3,
+    /// A witness that allows us to sweep an HTLC which we offered to the remote
+    /// party in the case that they broadcast a revoked commitment state.
+    #[cfg_attr(feature = "serde", serde(rename = "HTLC_OFFERED_REVOKE"))]
+    HtlcOfferedRevoke = 4,
+    /// A witness that allows us to sweep an HTLC output sent to us in the case that
+    /// the remote party broadcasts a revoked commitment state.
+    #[cfg_attr(feature = "serde", serde(rename = "HTLC_ACCEPTED_REVOKE"))]
+    HtlcAcceptedRevoke = 5,
+    /// A witness that allows us to sweep an HTLC output that we extended to a
+    /// party, but was never fulfilled.  This HTLC output isn't directly on the
+    /// commitment transaction, but is the result of a confirmed second-level HTLC
+    /// transaction. As a result, we can only spend this after a CSV delay.
+    #[cfg_attr(feature = "serde", serde(rename = "HTLC_OFFERED_TIMEOUT_SECOND_LEVEL"))]
+    HtlcOfferedTimeoutSecondLevel = 6,
+    /// A witness that allows us to sweep an HTLC output that was offered to us, and
+    /// for which we have a payment preimage. This HTLC output isn't directly on our
+    /// commitment transaction, but is the result of confirmed second-level HTLC
+    /// transaction. As a result, we can only spend this after a CSV delay.
+    #[cfg_attr(feature = "serde", serde(rename = "HTLC_ACCEPTED_SUCCESS_SECOND_LEVEL"))]
+    HtlcAcceptedSuccessSecondLevel = 7,
+    /// A witness that allows us to sweep an HTLC that we offered to the remote
+    /// party which lies in the commitment transaction of the remote party. We can
+    /// spend this output after the absolute CLTV timeout of the HTLC as passed.
+    #[cfg_attr(feature = "serde", serde(rename = "HTLC_OFFERED_REMOTE_TIMEOUT"))]
+    HtlcOfferedRemoteTimeout = 8,
+    /// A witness that allows us to sweep an HTLC that was offered to us by the
+    /// remote party. We use this witness in the case that the remote party goes to
+    /// chain, and we know the pre-image to the HTLC. We can sweep this without any
+    /// additional timeout.
+    #[cfg_attr(feature = "serde", serde(rename = "HTLC_ACCEPTED_REMOTE_SUCCESS"))]
+    HtlcAcceptedRemoteSuccess = 9,
+    /// A witness that allows us to sweep an HTLC from the remote party's commitment
+    /// transaction in the case that the broadcast a revoked commitment, but then
+    /// also immediately attempt to go to the second level to claim the HTLC.
+    #[cfg_attr(feature = "serde", serde(rename = "HTLC_SECOND_LEVEL_REVOKE"))]
+    HtlcSecondLevelRevoke = 10,
+    /// A witness type that allows us to spend a regular p2wkh output that's sent to
+    /// an output which is under complete control of the backing wallet.
+    #[cfg_attr(feature = "serde", serde(rename = "WITNESS_KEY_HASH"))]
+    WitnessKeyHash = 11,
+    /// A witness type that allows us to sweep an output that sends to a nested P2SH
+    /// script that pays to a key solely under our control.
+    #[cfg_attr(feature = "serde", serde(rename = "NESTED_WITNESS_KEY_HASH"))]
+    NestedWitnessKeyHash = 12,
+    /// A witness type that allows us to spend our anchor on the commitment
+    /// transaction.
+    #[cfg_attr(feature = "serde", serde(rename = "COMMITMENT_ANCHOR"))]
+    CommitmentAnchor = 13,
+    /// A witness type that is similar to the COMMITMENT_NO_DELAY type,
+    /// but it omits the tweak that randomizes the key we need to
+    /// spend with a channel peer supplied set of randomness.
+    #[cfg_attr(feature = "serde", serde(rename = "COMMITMENT_NO_DELAY_TWEAKLESS"))]
+    CommitmentNoDelayTweakless = 14,
+    /// A witness type that allows us to spend our output on the counterparty's
+    /// commitment transaction after a confirmation.
+    #[cfg_attr(feature = "serde", serde(rename = "COMMITMENT_TO_REMOTE_CONFIRMED"))]
+    CommitmentToRemoteConfirmed = 15,
+    /// A witness type that allows us to sweep an HTLC output that we extended
+    /// to a party, but was never fulfilled. This *is* the HTLC output directly
+    /// on our commitment transaction, and the input to the second-level HTLC
+    /// timeout transaction. It can only be spent after CLTV expiry, and
+    /// commitment confirmation.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "HTLC_OFFERED_TIMEOUT_SECOND_LEVEL_INPUT_CONFIRMED")
+    )]
+    HtlcOfferedTimeoutSecondLevelInputConfirmed = 16,
+    /// A witness type that allows us to sweep an HTLC output that was offered
+    /// to us, and for which we have a payment preimage. This *is* the HTLC
+    /// output directly on our commitment transaction, and the input to the
+    /// second-level HTLC success transaction. It can only be spent after the
+    /// commitment has confirmed.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "HTLC_ACCEPTED_SUCCESS_SECOND_LEVEL_INPUT_CONFIRMED")
+    )]
+    HtlcAcceptedSuccessSecondLevelInputConfirmed = 17,
+    /// A witness type that allows us to spend our output on our local
+    /// commitment transaction after a relative and absolute lock-time lockout as
+    /// part of the script enforced lease commitment type.
+    #[cfg_attr(feature = "serde", serde(rename = "LEASE_COMMITMENT_TIME_LOCK"))]
+    LeaseCommitmentTimeLock = 18,
+    /// A witness type that allows us to spend our output on the counterparty's
+    /// commitment transaction after a confirmation and absolute locktime as part
+    /// of the script enforced lease commitment type.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "LEASE_COMMITMENT_TO_REMOTE_CONFIRMED")
+    )]
+    LeaseCommitmentToRemoteConfirmed = 19,
+    /// A witness type that allows us to sweep an HTLC output that we extended
+    /// to a party, but was never fulfilled. This HTLC output isn't directly on
+    /// the commitment transaction, but is the result of a confirmed second-level
+    /// HTLC transaction. As a result, we can only spend this after a CSV delay
+    /// and CLTV locktime as part of the script enforced lease commitment type.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "LEASE_HTLC_OFFERED_TIMEOUT_SECOND_LEVEL")
+    )]
+    LeaseHtlcOfferedTimeoutSecondLevel = 20,
+    /// A witness type that allows us to sweep an HTLC output that was offered
+    /// to us, and for which we have a payment preimage. This HTLC output isn't
+    /// directly on our commitment transaction, but is the result of confirmed
+    /// second-level HTLC transaction. As a result, we can only spend this after
+    /// a CSV delay and CLTV locktime as part of the script enforced lease
+    /// commitment type.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "LEASE_HTLC_ACCEPTED_SUCCESS_SECOND_LEVEL")
+    )]
+    LeaseHtlcAcceptedSuccessSecondLevel = 21,
+    /// A witness type that allows us to spend a regular p2tr output that's sent
+    /// to an output which is under complete control of the backing wallet.
+    #[cfg_attr(feature = "serde", serde(rename = "TAPROOT_PUB_KEY_SPEND"))]
+    TaprootPubKeySpend = 22,
+    /// A witness type that allows us to spend our settled local commitment after a
+    /// CSV delay when we force close the channel.
+    #[cfg_attr(feature = "serde", serde(rename = "TAPROOT_LOCAL_COMMIT_SPEND"))]
+    TaprootLocalCommitSpend = 23,
+    /// A witness type that allows us to spend our settled local commitment after
+    /// a CSV delay when the remote party has force closed the channel.
+    #[cfg_attr(feature = "serde", serde(rename = "TAPROOT_REMOTE_COMMIT_SPEND"))]
+    TaprootRemoteCommitSpend = 24,
+    /// A witness type that we'll use for spending our own anchor output.
+    #[cfg_attr(feature = "serde", serde(rename = "TAPROOT_ANCHOR_SWEEP_SPEND"))]
+    TaprootAnchorSweepSpend = 25,
+    /// A witness that allows us to timeout an HTLC we offered to the remote party
+    /// on our commitment transaction. We use this when we need to go on chain to
+    /// time out an HTLC.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "TAPROOT_HTLC_OFFERED_TIMEOUT_SECOND_LEVEL")
+    )]
+    TaprootHtlcOfferedTimeoutSecondLevel = 26,
+    /// A witness type that allows us to sweep an HTLC we accepted on our commitment
+    /// transaction after we go to the second level on chain.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "TAPROOT_HTLC_ACCEPTED_SUCCESS_SECOND_LEVEL")
+    )]
+    TaprootHtlcAcceptedSuccessSecondLevel = 27,
+    /// A witness that allows us to sweep an HTLC on the revoked transaction of the
+    /// remote party that goes to the second level.
+    #[cfg_attr(feature = "serde", serde(rename = "TAPROOT_HTLC_SECOND_LEVEL_REVOKE"))]
+    TaprootHtlcSecondLevelRevoke = 28,
+    /// A witness that allows us to sweep an HTLC sent to us by the remote party
+    /// in the event that they broadcast a revoked state.
+    #[cfg_attr(feature = "serde", serde(rename = "TAPROOT_HTLC_ACCEPTED_REVOKE"))]
+    TaprootHtlcAcceptedRevoke = 29,
+    /// A witness that allows us to sweep an HTLC we offered to the remote party if
+    /// they broadcast a revoked commitment.
+    #[cfg_attr(feature = "serde", serde(rename = "TAPROOT_HTLC_OFFERED_REVOKE"))]
+    TaprootHtlcOfferedRevoke = 30,
+    /// A witness that allows us to sweep an HTLC we offered to the remote party
+    /// that lies on the commitment transaction for the remote party. We can spend
+    /// this output after the absolute CLTV timeout of the HTLC as passed.
+    #[cfg_attr(feature = "serde", serde(rename = "TAPROOT_HTLC_OFFERED_REMOTE_TIMEOUT"))]
+    TaprootHtlcOfferedRemoteTimeout = 31,
+    /// A witness type that allows us to sign the second level HTLC timeout
+    /// transaction when spending from an HTLC residing on our local commitment
+    /// transaction.
+    /// This is used by the sweeper to re-sign inputs if it needs to aggregate
+    /// several second level HTLCs.
+    #[cfg_attr(feature = "serde", serde(rename = "TAPROOT_HTLC_LOCAL_OFFERED_TIMEOUT"))]
+    TaprootHtlcLocalOfferedTimeout = 32,
+    /// A witness that allows us to sweep an HTLC that was offered to us by the
+    /// remote party for a taproot channels. We use this witness in the case that
+    /// the remote party goes to chain, and we know the pre-image to the HTLC. We
+    /// can sweep this without any additional timeout.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "TAPROOT_HTLC_ACCEPTED_REMOTE_SUCCESS")
+    )]
+    TaprootHtlcAcceptedRemoteSuccess = 33,
+    /// A witness type that allows us to sweep the HTLC offered to us on our local
+    /// commitment transaction. We'll use this when we need to go on chain to sweep
+    /// the HTLC. In this case, this is the second level HTLC success transaction.
+    #[cfg_attr(feature = "serde", serde(rename = "TAPROOT_HTLC_ACCEPTED_LOCAL_SUCCESS"))]
+    TaprootHtlcAcceptedLocalSuccess = 34,
+    /// A witness that allows us to sweep the settled output of a malicious
+    /// counterparty's who broadcasts a revoked taproot commitment transaction.
+    #[cfg_attr(feature = "serde", serde(rename = "TAPROOT_COMMITMENT_REVOKE"))]
+    TaprootCommitmentRevoke = 35,
+    /// A witness type that allows us to spend our settled local commitment after a
+    /// CSV delay when we force close a production taproot channel.
+    #[cfg_attr(feature = "serde", serde(rename = "TAPROOT_LOCAL_COMMIT_SPEND_FINAL"))]
+    TaprootLocalCommitSpendFinal = 36,
+    /// A witness type that allows us to spend our settled local commitment after
+    /// a CSV delay when the remote party has force closed a production taproot
+    /// channel.
+    #[cfg_attr(feature = "serde", serde(rename = "TAPROOT_REMOTE_COMMIT_SPEND_FINAL"))]
+    TaprootRemoteCommitSpendFinal = 37,
+    /// A witness that allows us to timeout an HTLC we offered to the remote party
+    /// on our production taproot commitment transaction. We use this when we need
+    /// to go on chain to time out an HTLC.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "TAPROOT_HTLC_OFFERED_TIMEOUT_SECOND_LEVEL_FINAL")
+    )]
+    TaprootHtlcOfferedTimeoutSecondLevelFinal = 38,
+    /// A witness type that allows us to sweep an HTLC we accepted on our
+    /// production taproot commitment transaction after we go to the second level
+    /// on chain.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "TAPROOT_HTLC_ACCEPTED_SUCCESS_SECOND_LEVEL_FINAL")
+    )]
+    TaprootHtlcAcceptedSuccessSecondLevelFinal = 39,
+    /// A witness that allows us to sweep an HTLC we offered to the remote party
+    /// that lies on the production taproot commitment transaction for the remote
+    /// party. We can spend this output after the absolute CLTV timeout of the
+    /// HTLC as passed.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "TAPROOT_HTLC_OFFERED_REMOTE_TIMEOUT_FINAL")
+    )]
+    TaprootHtlcOfferedRemoteTimeoutFinal = 40,
+    /// A witness that allows us to sweep an HTLC that was offered to us by the
+    /// remote party for a production taproot channel. We use this witness in the
+    /// case that the remote party goes to chain, and we know the pre-image to the
+    /// HTLC. We can sweep this without any additional timeout.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "TAPROOT_HTLC_ACCEPTED_REMOTE_SUCCESS_FINAL")
+    )]
+    TaprootHtlcAcceptedRemoteSuccessFinal = 41,
+    /// A witness type that allows us to sweep the settled output of a malicious
+    /// counterparty's who broadcasts a revoked production taproot commitment
+    /// transaction.
+    #[cfg_attr(feature = "serde", serde(rename = "TAPROOT_COMMITMENT_REVOKE_FINAL"))]
+    TaprootCommitmentRevokeFinal = 42,
+}
+impl WitnessType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::UnknownWitness => "UNKNOWN_WITNESS",
+            Self::CommitmentTimeLock => "COMMITMENT_TIME_LOCK",
+            Self::CommitmentNoDelay => "COMMITMENT_NO_DELAY",
+            Self::CommitmentRevoke => "COMMITMENT_REVOKE",
+            Self::HtlcOfferedRevoke => "HTLC_OFFERED_REVOKE",
+            Self::HtlcAcceptedRevoke => "HTLC_ACCEPTED_REVOKE",
+            Self::HtlcOfferedTimeoutSecondLevel => "HTLC_OFFERED_TIMEOUT_SECOND_LEVEL",
+            Self::HtlcAcceptedSuccessSecondLevel => "HTLC_ACCEPTED_SUCCESS_SECOND_LEVEL",
+            Self::HtlcOfferedRemoteTimeout => "HTLC_OFFERED_REMOTE_TIMEOUT",
+            Self::HtlcAcceptedRemoteSuccess => "HTLC_ACCEPTED_REMOTE_SUCCESS",
+            Self::HtlcSecondLevelRevoke => "HTLC_SECOND_LEVEL_REVOKE",
+            Self::WitnessKeyHash => "WITNESS_KEY_HASH",
+            Self::NestedWitnessKeyHash => "NESTED_WITNESS_KEY_HASH",
+            Self::CommitmentAnchor => "COMMITMENT_ANCHOR",
+            Self::CommitmentNoDelayTweakless => "COMMITMENT_NO_DELAY_TWEAKLESS",
+            Self::CommitmentToRemoteConfirmed => "COMMITMENT_TO_REMOTE_CONFIRMED",
+            Self::HtlcOfferedTimeoutSecondLevelInputConfirmed => {
+                "HTLC_OFFERED_TIMEOUT_SECOND_LEVEL_INPUT_CONFIRMED"
+            }
+            Self::HtlcAcceptedSuccessSecondLevelInputConfirmed => {
+                "HTLC_ACCEPTED_SUCCESS_SECOND_LEVEL_INPUT_CONFIRMED"
+            }
+            Self::LeaseCommitmentTimeLock => "LEASE_COMMITMENT_TIME_LOCK",
+            Self::LeaseCommitmentToRemoteConfirmed => {
+                "LEASE_COMMITMENT_TO_REMOTE_CONFIRMED"
+            }
+            Self::LeaseHtlcOfferedTimeoutSecondLevel => {
+                "LEASE_HTLC_OFFERED_TIMEOUT_SECOND_LEVEL"
+            }
+            Self::LeaseHtlcAcceptedSuccessSecondLevel => {
+                "LEASE_HTLC_ACCEPTED_SUCCESS_SECOND_LEVEL"
+            }
+            Self::TaprootPubKeySpend => "TAPROOT_PUB_KEY_SPEND",
+            Self::TaprootLocalCommitSpend => "TAPROOT_LOCAL_COMMIT_SPEND",
+            Self::TaprootRemoteCommitSpend => "TAPROOT_REMOTE_COMMIT_SPEND",
+            Self::TaprootAnchorSweepSpend => "TAPROOT_ANCHOR_SWEEP_SPEND",
+            Self::TaprootHtlcOfferedTimeoutSecondLevel => {
+                "TAPROOT_HTLC_OFFERED_TIMEOUT_SECOND_LEVEL"
+            }
+            Self::TaprootHtlcAcceptedSuccessSecondLevel => {
+                "TAPROOT_HTLC_ACCEPTED_SUCCESS_SECOND_LEVEL"
+            }
+            Self::TaprootHtlcSecondLevelRevoke => "TAPROOT_HTLC_SECOND_LEVEL_REVOKE",
+            Self::TaprootHtlcAcceptedRevoke => "TAPROOT_HTLC_ACCEPTED_REVOKE",
+            Self::TaprootHtlcOfferedRevoke => "TAPROOT_HTLC_OFFERED_REVOKE",
+            Self::TaprootHtlcOfferedRemoteTimeout => {
+                "TAPROOT_HTLC_OFFERED_REMOTE_TIMEOUT"
+            }
+            Self::TaprootHtlcLocalOfferedTimeout => "TAPROOT_HTLC_LOCAL_OFFERED_TIMEOUT",
+            Self::TaprootHtlcAcceptedRemoteSuccess => {
+                "TAPROOT_HTLC_ACCEPTED_REMOTE_SUCCESS"
+            }
+            Self::TaprootHtlcAcceptedLocalSuccess => {
+                "TAPROOT_HTLC_ACCEPTED_LOCAL_SUCCESS"
+            }
+            Self::TaprootCommitmentRevoke => "TAPROOT_COMMITMENT_REVOKE",
+            Self::TaprootLocalCommitSpendFinal => "TAPROOT_LOCAL_COMMIT_SPEND_FINAL",
+            Self::TaprootRemoteCommitSpendFinal => "TAPROOT_REMOTE_COMMIT_SPEND_FINAL",
+            Self::TaprootHtlcOfferedTimeoutSecondLevelFinal => {
+                "TAPROOT_HTLC_OFFERED_TIMEOUT_SECOND_LEVEL_FINAL"
+            }
+            Self::TaprootHtlcAcceptedSuccessSecondLevelFinal => {
+                "TAPROOT_HTLC_ACCEPTED_SUCCESS_SECOND_LEVEL_FINAL"
+            }
+            Self::TaprootHtlcOfferedRemoteTimeoutFinal => {
+                "TAPROOT_HTLC_OFFERED_REMOTE_TIMEOUT_FINAL"
+            }
+            Self::TaprootHtlcAcceptedRemoteSuccessFinal => {
+                "TAPROOT_HTLC_ACCEPTED_REMOTE_SUCCESS_FINAL"
+            }
+            Self::TaprootCommitmentRevokeFinal => "TAPROOT_COMMITMENT_REVOKE_FINAL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "UNKNOWN_WITNESS" => Some(Self::UnknownWitness),
+            "COMMITMENT_TIME_LOCK" => Some(Self::CommitmentTimeLock),
+            "COMMITMENT_NO_DELAY" => Some(Self::CommitmentNoDelay),
+            "COMMITMENT_REVOKE" => Some(Self::CommitmentRevoke),
+            "HTLC_OFFERED_REVOKE" => Some(Self::HtlcOfferedRevoke),
+            "HTLC_ACCEPTED_REVOKE" => Some(Self::HtlcAcceptedRevoke),
+            "HTLC_OFFERED_TIMEOUT_SECOND_LEVEL" => {
+                Some(Self::HtlcOfferedTimeoutSecondLevel)
+            }
+            "HTLC_ACCEPTED_SUCCESS_SECOND_LEVEL" => {
+                Some(Self::HtlcAcceptedSuccessSecondLevel)
+            }
+            "HTLC_OFFERED_REMOTE_TIMEOUT" => Some(Self::HtlcOfferedRemoteTimeout),
+            "HTLC_ACCEPTED_REMOTE_SUCCESS" => Some(Self::HtlcAcceptedRemoteSuccess),
+            "HTLC_SECOND_LEVEL_REVOKE" => Some(Self::HtlcSecondLevelRevoke),
+            "WITNESS_KEY_HASH" => Some(Self::WitnessKeyHash),
+            "NESTED_WITNESS_KEY_HASH" => Some(Self::NestedWitnessKeyHash),
+            "COMMITMENT_ANCHOR" => Some(Self::CommitmentAnchor),
+            "COMMITMENT_NO_DELAY_TWEAKLESS" => Some(Self::CommitmentNoDelayTweakless),
+            "COMMITMENT_TO_REMOTE_CONFIRMED" => Some(Self::CommitmentToRemoteConfirmed),
+            "HTLC_OFFERED_TIMEOUT_SECOND_LEVEL_INPUT_CONFIRMED" => {
+                Some(Self::HtlcOfferedTimeoutSecondLevelInputConfirmed)
+            }
+            "HTLC_ACCEPTED_SUCCESS_SECOND_LEVEL_INPUT_CONFIRMED" => {
+                Some(Self::HtlcAcceptedSuccessSecondLevelInputConfirmed)
+            }
+            "LEASE_COMMITMENT_TIME_LOCK" => Some(Self::LeaseCommitmentTimeLock),
+            "LEASE_COMMITMENT_TO_REMOTE_CONFIRMED" => {
+                Some(Self::LeaseCommitmentToRemoteConfirmed)
+            }
+            "LEASE_HTLC_OFFERED_TIMEOUT_SECOND_LEVEL" => {
+                Some(Self::LeaseHtlcOfferedTimeoutSecondLevel)
+            }
+            "LEASE_HTLC_ACCEPTED_SUCCESS_SECOND_LEVEL" => {
+                Some(Self::LeaseHtlcAcceptedSuccessSecondLevel)
+            }
+            "TAPROOT_PUB_KEY_SPEND" => Some(Self::TaprootPubKeySpend),
+            "TAPROOT_LOCAL_COMMIT_SPEND" => Some(Self::TaprootLocalCommitSpend),
+            "TAPROOT_REMOTE_COMMIT_SPEND" => Some(Self::TaprootRemoteCommitSpend),
+            "TAPROOT_ANCHOR_SWEEP_SPEND" => Some(Self::TaprootAnchorSweepSpend),
+            "TAPROOT_HTLC_OFFERED_TIMEOUT_SECOND_LEVEL" => {
+                Some(Self::TaprootHtlcOfferedTimeoutSecondLevel)
+            }
+            "TAPROOT_HTLC_ACCEPTED_SUCCESS_SECOND_LEVEL" => {
+                Some(Self::TaprootHtlcAcceptedSuccessSecondLevel)
+            }
+            "TAPROOT_HTLC_SECOND_LEVEL_REVOKE" => {
+                Some(Self::TaprootHtlcSecondLevelRevoke)
+            }
+            "TAPROOT_HTLC_ACCEPTED_REVOKE" => Some(Self::TaprootHtlcAcceptedRevoke),
+            "TAPROOT_HTLC_OFFERED_REVOKE" => Some(Self::TaprootHtlcOfferedRevoke),
+            "TAPROOT_HTLC_OFFERED_REMOTE_TIMEOUT" => {
+                Some(Self::TaprootHtlcOfferedRemoteTimeout)
+            }
+            "TAPROOT_HTLC_LOCAL_OFFERED_TIMEOUT" => {
+                Some(Self::TaprootHtlcLocalOfferedTimeout)
+            }
+            "TAPROOT_HTLC_ACCEPTED_REMOTE_SUCCESS" => {
+                Some(Self::TaprootHtlcAcceptedRemoteSuccess)
+            }
+            "TAPROOT_HTLC_ACCEPTED_LOCAL_SUCCESS" => {
+                Some(Self::TaprootHtlcAcceptedLocalSuccess)
+            }
+            "TAPROOT_COMMITMENT_REVOKE" => Some(Self::TaprootCommitmentRevoke),
+            "TAPROOT_LOCAL_COMMIT_SPEND_FINAL" => {
+                Some(Self::TaprootLocalCommitSpendFinal)
+            }
+            "TAPROOT_REMOTE_COMMIT_SPEND_FINAL" => {
+                Some(Self::TaprootRemoteCommitSpendFinal)
+            }
+            "TAPROOT_HTLC_OFFERED_TIMEOUT_SECOND_LEVEL_FINAL" => {
+                Some(Self::TaprootHtlcOfferedTimeoutSecondLevelFinal)
+            }
+            "TAPROOT_HTLC_ACCEPTED_SUCCESS_SECOND_LEVEL_FINAL" => {
+                Some(Self::TaprootHtlcAcceptedSuccessSecondLevelFinal)
+            }
+            "TAPROOT_HTLC_OFFERED_REMOTE_TIMEOUT_FINAL" => {
+                Some(Self::TaprootHtlcOfferedRemoteTimeoutFinal)
+            }
+            "TAPROOT_HTLC_ACCEPTED_REMOTE_SUCCESS_FINAL" => {
+                Some(Self::TaprootHtlcAcceptedRemoteSuccessFinal)
+            }
+            "TAPROOT_COMMITMENT_REVOKE_FINAL" => Some(Self::TaprootCommitmentRevokeFinal),
+            _ => None,
+        }
+    }
+}
+/// The possible change address types for default accounts and single imported
+/// public keys. By default, P2WPKH will be used. We don't provide the
+/// possibility to choose P2PKH as it is a legacy key scope, nor NP2WPKH as
+/// no key scope permits to do so. For custom accounts, no change type should
+/// be provided as the coin selection key scope will always be used to generate
+/// the change address.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ChangeAddressType {
+    /// CHANGE_ADDRESS_TYPE_UNSPECIFIED indicates that no change address type is
+    /// provided. We will then use P2WPKH address type for change (BIP0084 key
+    /// scope).
+    #[cfg_attr(feature = "serde", serde(rename = "CHANGE_ADDRESS_TYPE_UNSPECIFIED"))]
+    Unspecified = 0,
+    /// CHANGE_ADDRESS_TYPE_P2TR indicates to use P2TR address for change output
+    /// (BIP0086 key scope).
+    #[cfg_attr(feature = "serde", serde(rename = "CHANGE_ADDRESS_TYPE_P2TR"))]
+    P2tr = 1,
+}
+impl ChangeAddressType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "CHANGE_ADDRESS_TYPE_UNSPECIFIED",
+            Self::P2tr => "CHANGE_ADDRESS_TYPE_P2TR",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CHANGE_ADDRESS_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "CHANGE_ADDRESS_TYPE_P2TR" => Some(Self::P2tr),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod wallet_kit_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// WalletKit is a service that gives access to the core functionalities of the
+    /// daemon's wallet.
+    #[derive(Debug, Clone)]
+    pub struct WalletKitClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "transport")]
+    impl WalletKitClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> WalletKitClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> WalletKitClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            WalletKitClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// ListUnspent returns a list of all utxos spendable by the wallet with a
+        /// number of confirmations between the specified minimum and maximum. By
+        /// default, all utxos are listed. To list only the unconfirmed utxos, set
+        /// the unconfirmed_only to true.
+        pub async fn list_unspent(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListUnspentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListUnspentResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/ListUnspent",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "ListUnspent"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet leaseoutput`
+        /// LeaseOutput locks an output to the given ID, preventing it from being
+        /// available for any future coin selection attempts. The absolute time of the
+        /// lock's expiration is returned. The expiration of the lock can be extended by
+        /// successive invocations of this RPC. Outputs can be unlocked before their
+        /// expiration through `ReleaseOutput`.
+        pub async fn lease_output(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LeaseOutputRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LeaseOutputResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/LeaseOutput",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "LeaseOutput"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet releaseoutput`
+        /// ReleaseOutput unlocks an output, allowing it to be available for coin
+        /// selection if it remains unspent. The ID should match the one used to
+        /// originally lock the output.
+        pub async fn release_output(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReleaseOutputRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReleaseOutputResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/ReleaseOutput",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "ReleaseOutput"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet listleases`
+        /// ListLeases lists all currently locked utxos.
+        pub async fn list_leases(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListLeasesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListLeasesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/ListLeases",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "ListLeases"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// DeriveNextKey attempts to derive the *next* key within the key family
+        /// (account in BIP43) specified. This method should return the next external
+        /// child within this branch.
+        pub async fn derive_next_key(
+            &mut self,
+            request: impl tonic::IntoRequest<super::KeyReq>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::signrpc::KeyDescriptor>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/DeriveNextKey",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "DeriveNextKey"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// DeriveKey attempts to derive an arbitrary key specified by the passed
+        /// KeyLocator.
+        pub async fn derive_key(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::signrpc::KeyLocator>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::signrpc::KeyDescriptor>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/DeriveKey",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "DeriveKey"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// NextAddr returns the next unused address within the wallet.
+        pub async fn next_addr(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddrRequest>,
+        ) -> std::result::Result<tonic::Response<super::AddrResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/NextAddr",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "NextAddr"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet gettx`
+        /// GetTransaction returns details for a transaction found in the wallet.
+        pub async fn get_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTransactionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::lnrpc::Transaction>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/GetTransaction",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "GetTransaction"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet accounts list`
+        /// ListAccounts retrieves all accounts belonging to the wallet by default. A
+        /// name and key scope filter can be provided to filter through all of the
+        /// wallet accounts and return only those matching.
+        pub async fn list_accounts(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListAccountsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAccountsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/ListAccounts",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "ListAccounts"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet requiredreserve`
+        /// RequiredReserve returns the minimum amount of satoshis that should be kept
+        /// in the wallet in order to fee bump anchor channels if necessary. The value
+        /// scales with the number of public anchor channels but is capped at a maximum.
+        pub async fn required_reserve(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequiredReserveRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RequiredReserveResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/RequiredReserve",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "RequiredReserve"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet addresses list`
+        /// ListAddresses retrieves all the addresses along with their balance. An
+        /// account name filter can be provided to filter through all of the
+        /// wallet accounts and return the addresses of only those matching.
+        pub async fn list_addresses(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListAddressesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAddressesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/ListAddresses",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "ListAddresses"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet addresses signmessage`
+        /// SignMessageWithAddr returns the compact signature (base64 encoded) created
+        /// with the private key of the provided address. This requires the address
+        /// to be solely based on a public key lock (no scripts). Obviously the internal
+        /// lnd wallet has to possess the private key of the address otherwise
+        /// an error is returned.
+        ///
+        /// This method aims to provide full compatibility with the bitcoin-core and
+        /// btcd implementation. Bitcoin-core's algorithm is not specified in a
+        /// BIP and only applicable for legacy addresses. This method enhances the
+        /// signing for additional address types: P2WKH, NP2WKH, P2TR.
+        /// For P2TR addresses this represents a special case. ECDSA is used to create
+        /// a compact signature which makes the public key of the signature recoverable.
+        pub async fn sign_message_with_addr(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SignMessageWithAddrRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SignMessageWithAddrResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/SignMessageWithAddr",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "SignMessageWithAddr"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet addresses verifymessage`
+        /// VerifyMessageWithAddr returns the validity and the recovered public key of
+        /// the provided compact signature (base64 encoded). The verification is
+        /// twofold. First the validity of the signature itself is checked and then
+        /// it is verified that the recovered public key of the signature equals
+        /// the public key of the provided address. There is no dependence on the
+        /// private key of the address therefore also external addresses are allowed
+        /// to verify signatures.
+        /// Supported address types are P2PKH, P2WKH, NP2WKH, P2TR.
+        ///
+        /// This method is the counterpart of the related signing method
+        /// (SignMessageWithAddr) and aims to provide full compatibility to
+        /// bitcoin-core's implementation. Although bitcoin-core/btcd only provide
+        /// this functionality for legacy addresses this function enhances it to
+        /// the address types: P2PKH, P2WKH, NP2WKH, P2TR.
+        ///
+        /// The verification for P2TR addresses is a special case and requires the
+        /// ECDSA compact signature to compare the reovered public key to the internal
+        /// taproot key. The compact ECDSA signature format was used because there
+        /// are still no known compact signature schemes for schnorr signatures.
+        pub async fn verify_message_with_addr(
+            &mut self,
+            request: impl tonic::IntoRequest<super::VerifyMessageWithAddrRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::VerifyMessageWithAddrResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/VerifyMessageWithAddr",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "VerifyMessageWithAddr"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet accounts import`
+        /// ImportAccount imports an account backed by an account extended public key.
+        /// The master key fingerprint denotes the fingerprint of the root key
+        /// corresponding to the account public key (also known as the key with
+        /// derivation path m/). This may be required by some hardware wallets for
+        /// proper identification and signing.
+        ///
+        /// The address type can usually be inferred from the key's version, but may be
+        /// required for certain keys to map them into the proper scope.
+        ///
+        /// For BIP-0044 keys, an address type must be specified as we intend to not
+        /// support importing BIP-0044 keys into the wallet using the legacy
+        /// pay-to-pubkey-hash (P2PKH) scheme. A nested witness address type will force
+        /// the standard BIP-0049 derivation scheme, while a witness address type will
+        /// force the standard BIP-0084 derivation scheme.
+        ///
+        /// For BIP-0049 keys, an address type must also be specified to make a
+        /// distinction between the standard BIP-0049 address schema (nested witness
+        /// pubkeys everywhere) and our own BIP-0049Plus address schema (nested pubkeys
+        /// externally, witness pubkeys internally).
+        ///
+        /// NOTE: Events (deposits/spends) for keys derived from an account will only be
+        /// detected by lnd if they happen after the import. Rescans to detect past
+        /// events will be supported later on.
+        pub async fn import_account(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ImportAccountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportAccountResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/ImportAccount",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "ImportAccount"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet accounts import-pubkey`
+        /// ImportPublicKey imports a public key as watch-only into the wallet. The
+        /// public key is converted into a simple address of the given type and that
+        /// address script is watched on chain. For Taproot keys, this will only watch
+        /// the BIP-0086 style output script. Use ImportTapscript for more advanced key
+        /// spend or script spend outputs.
+        ///
+        /// NOTE: Events (deposits/spends) for a key will only be detected by lnd if
+        /// they happen after the import. Rescans to detect past events will be
+        /// supported later on.
+        pub async fn import_public_key(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ImportPublicKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportPublicKeyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/ImportPublicKey",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "ImportPublicKey"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// ImportTapscript imports a Taproot script and internal key and adds the
+        /// resulting Taproot output key as a watch-only output script into the wallet.
+        /// For BIP-0086 style Taproot keys (no root hash commitment and no script spend
+        /// path) use ImportPublicKey.
+        ///
+        /// NOTE: Events (deposits/spends) for a key will only be detected by lnd if
+        /// they happen after the import. Rescans to detect past events will be
+        /// supported later on.
+        ///
+        /// NOTE: Taproot keys imported through this RPC currently *cannot* be used for
+        /// funding PSBTs. Only tracking the balance and UTXOs is currently supported.
+        pub async fn import_tapscript(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ImportTapscriptRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportTapscriptResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/ImportTapscript",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "ImportTapscript"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet publishtx`
+        /// PublishTransaction attempts to publish the passed transaction to the
+        /// network. Once this returns without an error, the wallet will continually
+        /// attempt to re-broadcast the transaction on start up, until it enters the
+        /// chain.
+        pub async fn publish_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Transaction>,
+        ) -> std::result::Result<
+            tonic::Response<super::PublishResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/PublishTransaction",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "PublishTransaction"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet removetx`
+        /// RemoveTransaction attempts to remove the provided transaction from the
+        /// internal transaction store of the wallet.
+        pub async fn remove_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTransactionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveTransactionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/RemoveTransaction",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "RemoveTransaction"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// SendOutputs is similar to the existing sendmany call in Bitcoind, and
+        /// allows the caller to create a transaction that sends to several outputs at
+        /// once. This is ideal when wanting to batch create a set of transactions.
+        pub async fn send_outputs(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SendOutputsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SendOutputsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/SendOutputs",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "SendOutputs"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet estimatefeerate`
+        /// EstimateFee attempts to query the internal fee estimator of the wallet to
+        /// determine the fee (in sat/kw) to attach to a transaction in order to
+        /// achieve the confirmation target.
+        pub async fn estimate_fee(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EstimateFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimateFeeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/EstimateFee",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "EstimateFee"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet pendingsweeps`
+        /// PendingSweeps returns lists of on-chain outputs that lnd is currently
+        /// attempting to sweep within its central batching engine. Outputs with similar
+        /// fee rates are batched together in order to sweep them within a single
+        /// transaction.
+        ///
+        /// NOTE: Some of the fields within PendingSweepsRequest are not guaranteed to
+        /// remain supported. This is an advanced API that depends on the internals of
+        /// the UtxoSweeper, so things may change.
+        pub async fn pending_sweeps(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PendingSweepsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PendingSweepsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/PendingSweeps",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "PendingSweeps"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet bumpfee`
+        /// BumpFee is an endpoint that allows users to interact with lnd's sweeper
+        /// directly. It takes an outpoint from an unconfirmed transaction and sends it
+        /// to the sweeper for potential fee bumping. Depending on whether the outpoint
+        /// has been registered in the sweeper (an existing input, e.g., an anchor
+        /// output) or not (a new input, e.g., an unconfirmed wallet utxo), this will
+        /// either be an RBF or CPFP attempt.
+        ///
+        /// When receiving an input, lnd’s sweeper needs to understand its time
+        /// sensitivity to make economical fee bumps - internally a fee function is
+        /// created using the deadline and budget to guide the process. When the
+        /// deadline is approaching, the fee function will increase the fee rate and
+        /// perform an RBF.
+        ///
+        /// When a force close happens, all the outputs from the force closing
+        /// transaction will be registered in the sweeper. The sweeper will then handle
+        /// the creation, publish, and fee bumping of the sweeping transactions.
+        /// Everytime a new block comes in, unless the sweeping transaction is
+        /// confirmed, an RBF is attempted. To interfere with this automatic process,
+        /// users can use BumpFee to specify customized fee rate, budget, deadline, and
+        /// whether the sweep should happen immediately. It's recommended to call
+        /// `ListSweeps` to understand the shape of the existing sweeping transaction
+        /// first - depending on the number of inputs in this transaction, the RBF
+        /// requirements can be quite different.
+        ///
+        /// This RPC also serves useful when wanting to perform a Child-Pays-For-Parent
+        /// (CPFP), where the child transaction pays for its parent's fee. This can be
+        /// done by specifying an outpoint within the low fee transaction that is under
+        /// the control of the wallet.
+        pub async fn bump_fee(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BumpFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BumpFeeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/BumpFee",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "BumpFee"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet bumpforceclosefee`
+        /// BumpForceCloseFee is an endpoint that allows users to bump the fee of a
+        /// channel force close. This only works for channels with option_anchors.
+        pub async fn bump_force_close_fee(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BumpForceCloseFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BumpForceCloseFeeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/BumpForceCloseFee",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "BumpForceCloseFee"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet listsweeps`
+        /// ListSweeps returns a list of the sweep transactions our node has produced.
+        /// Note that these sweeps may not be confirmed yet, as we record sweeps on
+        /// broadcast, not confirmation.
+        pub async fn list_sweeps(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListSweepsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListSweepsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/ListSweeps",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "ListSweeps"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet labeltx`
+        /// LabelTransaction adds a label to a transaction. If the transaction already
+        /// has a label the call will fail unless the overwrite bool is set. This will
+        /// overwrite the existing transaction label. Labels must not be empty, and
+        /// cannot exceed 500 characters.
+        pub async fn label_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LabelTransactionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LabelTransactionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/LabelTransaction",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "LabelTransaction"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet psbt fund`
+        /// FundPsbt creates a fully populated PSBT that contains enough inputs to fund
+        /// the outputs specified in the template. There are three ways a user can
+        /// specify what we call the template (a list of inputs and outputs to use in
+        /// the PSBT): Either as a PSBT packet directly with no coin selection (using
+        /// the legacy "psbt" field), a PSBT with advanced coin selection support (using
+        /// the new "coin_select" field) or as a raw RPC message (using the "raw"
+        /// field).
+        /// The legacy "psbt" and "raw" modes, the following restrictions apply:
+        ///
+        /// 1. If there are no inputs specified in the template, coin selection is
+        ///   performed automatically.
+        /// 1. If the template does contain any inputs, it is assumed that full
+        ///   coin selection happened externally and no additional inputs are added. If
+        ///   the specified inputs aren't enough to fund the outputs with the given fee
+        ///   rate, an error is returned.
+        ///
+        /// The new "coin_select" mode does not have these restrictions and allows the
+        /// user to specify a PSBT with inputs and outputs and still perform coin
+        /// selection on top of that.
+        /// For all modes this RPC requires any inputs that are specified to be locked
+        /// by the user (if they belong to this node in the first place).
+        ///
+        /// After either selecting or verifying the inputs, all input UTXOs are locked
+        /// with an internal app ID.
+        ///
+        /// NOTE: If this method returns without an error, it is the caller's
+        /// responsibility to either spend the locked UTXOs (by finalizing and then
+        /// publishing the transaction) or to unlock/release the locked UTXOs in case of
+        /// an error on the caller's side.
+        pub async fn fund_psbt(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FundPsbtRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FundPsbtResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/FundPsbt",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "FundPsbt"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet psbt sign`
+        /// SignPsbt expects a partial transaction with all inputs and outputs fully
+        /// declared and tries to sign all unsigned inputs that have all required fields
+        /// (UTXO information, BIP32 derivation information, witness or sig scripts)
+        /// set.
+        /// If no error is returned, the PSBT is ready to be given to the next signer or
+        /// to be finalized if lnd was the last signer.
+        ///
+        /// NOTE: This RPC only signs inputs (and only those it can sign), it does not
+        /// perform any other tasks (such as coin selection, UTXO locking or
+        /// input/output/fee value validation, PSBT finalization). Any input that is
+        /// incomplete will be skipped.
+        pub async fn sign_psbt(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SignPsbtRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SignPsbtResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/SignPsbt",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "SignPsbt"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// lncli: `wallet psbt finalize`
+        /// FinalizePsbt expects a partial transaction with all inputs and outputs fully
+        /// declared and tries to sign all inputs that belong to the wallet. Lnd must be
+        /// the last signer of the transaction. That means, if there are any unsigned
+        /// non-witness inputs or inputs without UTXO information attached or inputs
+        /// without witness data that do not belong to lnd's wallet, this method will
+        /// fail. If no error is returned, the PSBT is ready to be extracted and the
+        /// final TX within to be broadcast.
+        ///
+        /// NOTE: This method does NOT publish the transaction once finalized. It is the
+        /// caller's responsibility to either publish the transaction on success or
+        /// unlock/release any locked UTXOs in case of an error in this method.
+        pub async fn finalize_psbt(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FinalizePsbtRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FinalizePsbtResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/walletrpc.WalletKit/FinalizePsbt",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("walletrpc.WalletKit", "FinalizePsbt"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod wallet_kit_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with WalletKitServer.
+    #[async_trait]
+    pub trait WalletKit: std::marker::Send + std::marker::Sync + 'static {
+        /// ListUnspent returns a list of all utxos spendable by the wallet with a
+        /// number of confirmations between the specified minimum and maximum. By
+        /// default, all utxos are listed. To list only the unconfirmed utxos, set
+        /// the unconfirmed_only to true.
+        async fn list_unspent(
+            &self,
+            request: tonic::Request<super::ListUnspentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListUnspentResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet leaseoutput`
+        /// LeaseOutput locks an output to the given ID, preventing it from being
+        /// available for any future coin selection attempts. The absolute time of the
+        /// lock's expiration is returned. The expiration of the lock can be extended by
+        /// successive invocations of this RPC. Outputs can be unlocked before their
+        /// expiration through `ReleaseOutput`.
+        async fn lease_output(
+            &self,
+            request: tonic::Request<super::LeaseOutputRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LeaseOutputResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet releaseoutput`
+        /// ReleaseOutput unlocks an output, allowing it to be available for coin
+        /// selection if it remains unspent. The ID should match the one used to
+        /// originally lock the output.
+        async fn release_output(
+            &self,
+            request: tonic::Request<super::ReleaseOutputRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReleaseOutputResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet listleases`
+        /// ListLeases lists all currently locked utxos.
+        async fn list_leases(
+            &self,
+            request: tonic::Request<super::ListLeasesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListLeasesResponse>,
+            tonic::Status,
+        >;
+        /// DeriveNextKey attempts to derive the *next* key within the key family
+        /// (account in BIP43) specified. This method should return the next external
+        /// child within this branch.
+        async fn derive_next_key(
+            &self,
+            request: tonic::Request<super::KeyReq>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::signrpc::KeyDescriptor>,
+            tonic::Status,
+        >;
+        /// DeriveKey attempts to derive an arbitrary key specified by the passed
+        /// KeyLocator.
+        async fn derive_key(
+            &self,
+            request: tonic::Request<super::super::signrpc::KeyLocator>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::signrpc::KeyDescriptor>,
+            tonic::Status,
+        >;
+        /// NextAddr returns the next unused address within the wallet.
+        async fn next_addr(
+            &self,
+            request: tonic::Request<super::AddrRequest>,
+        ) -> std::result::Result<tonic::Response<super::AddrResponse>, tonic::Status>;
+        /// lncli: `wallet gettx`
+        /// GetTransaction returns details for a transaction found in the wallet.
+        async fn get_transaction(
+            &self,
+            request: tonic::Request<super::GetTransactionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::lnrpc::Transaction>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet accounts list`
+        /// ListAccounts retrieves all accounts belonging to the wallet by default. A
+        /// name and key scope filter can be provided to filter through all of the
+        /// wallet accounts and return only those matching.
+        async fn list_accounts(
+            &self,
+            request: tonic::Request<super::ListAccountsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAccountsResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet requiredreserve`
+        /// RequiredReserve returns the minimum amount of satoshis that should be kept
+        /// in the wallet in order to fee bump anchor channels if necessary. The value
+        /// scales with the number of public anchor channels but is capped at a maximum.
+        async fn required_reserve(
+            &self,
+            request: tonic::Request<super::RequiredReserveRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RequiredReserveResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet addresses list`
+        /// ListAddresses retrieves all the addresses along with their balance. An
+        /// account name filter can be provided to filter through all of the
+        /// wallet accounts and return the addresses of only those matching.
+        async fn list_addresses(
+            &self,
+            request: tonic::Request<super::ListAddressesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAddressesResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet addresses signmessage`
+        /// SignMessageWithAddr returns the compact signature (base64 encoded) created
+        /// with the private key of the provided address. This requires the address
+        /// to be solely based on a public key lock (no scripts). Obviously the internal
+        /// lnd wallet has to possess the private key of the address otherwise
+        /// an error is returned.
+        ///
+        /// This method aims to provide full compatibility with the bitcoin-core and
+        /// btcd implementation. Bitcoin-core's algorithm is not specified in a
+        /// BIP and only applicable for legacy addresses. This method enhances the
+        /// signing for additional address types: P2WKH, NP2WKH, P2TR.
+        /// For P2TR addresses this represents a special case. ECDSA is used to create
+        /// a compact signature which makes the public key of the signature recoverable.
+        async fn sign_message_with_addr(
+            &self,
+            request: tonic::Request<super::SignMessageWithAddrRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SignMessageWithAddrResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet addresses verifymessage`
+        /// VerifyMessageWithAddr returns the validity and the recovered public key of
+        /// the provided compact signature (base64 encoded). The verification is
+        /// twofold. First the validity of the signature itself is checked and then
+        /// it is verified that the recovered public key of the signature equals
+        /// the public key of the provided address. There is no dependence on the
+        /// private key of the address therefore also external addresses are allowed
+        /// to verify signatures.
+        /// Supported address types are P2PKH, P2WKH, NP2WKH, P2TR.
+        ///
+        /// This method is the counterpart of the related signing method
+        /// (SignMessageWithAddr) and aims to provide full compatibility to
+        /// bitcoin-core's implementation. Although bitcoin-core/btcd only provide
+        /// this functionality for legacy addresses this function enhances it to
+        /// the address types: P2PKH, P2WKH, NP2WKH, P2TR.
+        ///
+        /// The verification for P2TR addresses is a special case and requires the
+        /// ECDSA compact signature to compare the reovered public key to the internal
+        /// taproot key. The compact ECDSA signature format was used because there
+        /// are still no known compact signature schemes for schnorr signatures.
+        async fn verify_message_with_addr(
+            &self,
+            request: tonic::Request<super::VerifyMessageWithAddrRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::VerifyMessageWithAddrResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet accounts import`
+        /// ImportAccount imports an account backed by an account extended public key.
+        /// The master key fingerprint denotes the fingerprint of the root key
+        /// corresponding to the account public key (also known as the key with
+        /// derivation path m/). This may be required by some hardware wallets for
+        /// proper identification and signing.
+        ///
+        /// The address type can usually be inferred from the key's version, but may be
+        /// required for certain keys to map them into the proper scope.
+        ///
+        /// For BIP-0044 keys, an address type must be specified as we intend to not
+        /// support importing BIP-0044 keys into the wallet using the legacy
+        /// pay-to-pubkey-hash (P2PKH) scheme. A nested witness address type will force
+        /// the standard BIP-0049 derivation scheme, while a witness address type will
+        /// force the standard BIP-0084 derivation scheme.
+        ///
+        /// For BIP-0049 keys, an address type must also be specified to make a
+        /// distinction between the standard BIP-0049 address schema (nested witness
+        /// pubkeys everywhere) and our own BIP-0049Plus address schema (nested pubkeys
+        /// externally, witness pubkeys internally).
+        ///
+        /// NOTE: Events (deposits/spends) for keys derived from an account will only be
+        /// detected by lnd if they happen after the import. Rescans to detect past
+        /// events will be supported later on.
+        async fn import_account(
+            &self,
+            request: tonic::Request<super::ImportAccountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportAccountResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet accounts import-pubkey`
+        /// ImportPublicKey imports a public key as watch-only into the wallet. The
+        /// public key is converted into a simple address of the given type and that
+        /// address script is watched on chain. For Taproot keys, this will only watch
+        /// the BIP-0086 style output script. Use ImportTapscript for more advanced key
+        /// spend or script spend outputs.
+        ///
+        /// NOTE: Events (deposits/spends) for a key will only be detected by lnd if
+        /// they happen after the import. Rescans to detect past events will be
+        /// supported later on.
+        async fn import_public_key(
+            &self,
+            request: tonic::Request<super::ImportPublicKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportPublicKeyResponse>,
+            tonic::Status,
+        >;
+        /// ImportTapscript imports a Taproot script and internal key and adds the
+        /// resulting Taproot output key as a watch-only output script into the wallet.
+        /// For BIP-0086 style Taproot keys (no root hash commitment and no script spend
+        /// path) use ImportPublicKey.
+        ///
+        /// NOTE: Events (deposits/spends) for a key will only be detected by lnd if
+        /// they happen after the import. Rescans to detect past events will be
+        /// supported later on.
+        ///
+        /// NOTE: Taproot keys imported through this RPC currently *cannot* be used for
+        /// funding PSBTs. Only tracking the balance and UTXOs is currently supported.
+        async fn import_tapscript(
+            &self,
+            request: tonic::Request<super::ImportTapscriptRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportTapscriptResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet publishtx`
+        /// PublishTransaction attempts to publish the passed transaction to the
+        /// network. Once this returns without an error, the wallet will continually
+        /// attempt to re-broadcast the transaction on start up, until it enters the
+        /// chain.
+        async fn publish_transaction(
+            &self,
+            request: tonic::Request<super::Transaction>,
+        ) -> std::result::Result<tonic::Response<super::PublishResponse>, tonic::Status>;
+        /// lncli: `wallet removetx`
+        /// RemoveTransaction attempts to remove the provided transaction from the
+        /// internal transaction store of the wallet.
+        async fn remove_transaction(
+            &self,
+            request: tonic::Request<super::GetTransactionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveTransactionResponse>,
+            tonic::Status,
+        >;
+        /// SendOutputs is similar to the existing sendmany call in Bitcoind, and
+        /// allows the caller to create a transaction that sends to several outputs at
+        /// once. This is ideal when wanting to batch create a set of transactions.
+        async fn send_outputs(
+            &self,
+            request: tonic::Request<super::SendOutputsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SendOutputsResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet estimatefeerate`
+        /// EstimateFee attempts to query the internal fee estimator of the wallet to
+        /// determine the fee (in sat/kw) to attach to a transaction in order to
+        /// achieve the confirmation target.
+        async fn estimate_fee(
+            &self,
+            request: tonic::Request<super::EstimateFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimateFeeResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet pendingsweeps`
+        /// PendingSweeps returns lists of on-chain outputs that lnd is currently
+        /// attempting to sweep within its central batching engine. Outputs with similar
+        /// fee rates are batched together in order to sweep them within a single
+        /// transaction.
+        ///
+        /// NOTE: Some of the fields within PendingSweepsRequest are not guaranteed to
+        /// remain supported. This is an advanced API that depends on the internals of
+        /// the UtxoSweeper, so things may change.
+        async fn pending_sweeps(
+            &self,
+            request: tonic::Request<super::PendingSweepsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PendingSweepsResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet bumpfee`
+        /// BumpFee is an endpoint that allows users to interact with lnd's sweeper
+        /// directly. It takes an outpoint from an unconfirmed transaction and sends it
+        /// to the sweeper for potential fee bumping. Depending on whether the outpoint
+        /// has been registered in the sweeper (an existing input, e.g., an anchor
+        /// output) or not (a new input, e.g., an unconfirmed wallet utxo), this will
+        /// either be an RBF or CPFP attempt.
+        ///
+        /// When receiving an input, lnd’s sweeper needs to understand its time
+        /// sensitivity to make economical fee bumps - internally a fee function is
+        /// created using the deadline and budget to guide the process. When the
+        /// deadline is approaching, the fee function will increase the fee rate and
+        /// perform an RBF.
+        ///
+        /// When a force close happens, all the outputs from the force closing
+        /// transaction will be registered in the sweeper. The sweeper will then handle
+        /// the creation, publish, and fee bumping of the sweeping transactions.
+        /// Everytime a new block comes in, unless the sweeping transaction is
+        /// confirmed, an RBF is attempted. To interfere with this automatic process,
+        /// users can use BumpFee to specify customized fee rate, budget, deadline, and
+        /// whether the sweep should happen immediately. It's recommended to call
+        /// `ListSweeps` to understand the shape of the existing sweeping transaction
+        /// first - depending on the number of inputs in this transaction, the RBF
+        /// requirements can be quite different.
+        ///
+        /// This RPC also serves useful when wanting to perform a Child-Pays-For-Parent
+        /// (CPFP), where the child transaction pays for its parent's fee. This can be
+        /// done by specifying an outpoint within the low fee transaction that is under
+        /// the control of the wallet.
+        async fn bump_fee(
+            &self,
+            request: tonic::Request<super::BumpFeeRequest>,
+        ) -> std::result::Result<tonic::Response<super::BumpFeeResponse>, tonic::Status>;
+        /// lncli: `wallet bumpforceclosefee`
+        /// BumpForceCloseFee is an endpoint that allows users to bump the fee of a
+        /// channel force close. This only works for channels with option_anchors.
+        async fn bump_force_close_fee(
+            &self,
+            request: tonic::Request<super::BumpForceCloseFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BumpForceCloseFeeResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet listsweeps`
+        /// ListSweeps returns a list of the sweep transactions our node has produced.
+        /// Note that these sweeps may not be confirmed yet, as we record sweeps on
+        /// broadcast, not confirmation.
+        async fn list_sweeps(
+            &self,
+            request: tonic::Request<super::ListSweepsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListSweepsResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet labeltx`
+        /// LabelTransaction adds a label to a transaction. If the transaction already
+        /// has a label the call will fail unless the overwrite bool is set. This will
+        /// overwrite the existing transaction label. Labels must not be empty, and
+        /// cannot exceed 500 characters.
+        async fn label_transaction(
+            &self,
+            request: tonic::Request<super::LabelTransactionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LabelTransactionResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet psbt fund`
+        /// FundPsbt creates a fully populated PSBT that contains enough inputs to fund
+        /// the outputs specified in the template. There are three ways a user can
+        /// specify what we call the template (a list of inputs and outputs to use in
+        /// the PSBT): Either as a PSBT packet directly with no coin selection (using
+        /// the legacy "psbt" field), a PSBT with advanced coin selection support (using
+        /// the new "coin_select" field) or as a raw RPC message (using the "raw"
+        /// field).
+        /// The legacy "psbt" and "raw" modes, the following restrictions apply:
+        ///
+        /// 1. If there are no inputs specified in the template, coin selection is
+        ///   performed automatically.
+        /// 1. If the template does contain any inputs, it is assumed that full
+        ///   coin selection happened externally and no additional inputs are added. If
+        ///   the specified inputs aren't enough to fund the outputs with the given fee
+        ///   rate, an error is returned.
+        ///
+        /// The new "coin_select" mode does not have these restrictions and allows the
+        /// user to specify a PSBT with inputs and outputs and still perform coin
+        /// selection on top of that.
+        /// For all modes this RPC requires any inputs that are specified to be locked
+        /// by the user (if they belong to this node in the first place).
+        ///
+        /// After either selecting or verifying the inputs, all input UTXOs are locked
+        /// with an internal app ID.
+        ///
+        /// NOTE: If this method returns without an error, it is the caller's
+        /// responsibility to either spend the locked UTXOs (by finalizing and then
+        /// publishing the transaction) or to unlock/release the locked UTXOs in case of
+        /// an error on the caller's side.
+        async fn fund_psbt(
+            &self,
+            request: tonic::Request<super::FundPsbtRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FundPsbtResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet psbt sign`
+        /// SignPsbt expects a partial transaction with all inputs and outputs fully
+        /// declared and tries to sign all unsigned inputs that have all required fields
+        /// (UTXO information, BIP32 derivation information, witness or sig scripts)
+        /// set.
+        /// If no error is returned, the PSBT is ready to be given to the next signer or
+        /// to be finalized if lnd was the last signer.
+        ///
+        /// NOTE: This RPC only signs inputs (and only those it can sign), it does not
+        /// perform any other tasks (such as coin selection, UTXO locking or
+        /// input/output/fee value validation, PSBT finalization). Any input that is
+        /// incomplete will be skipped.
+        async fn sign_psbt(
+            &self,
+            request: tonic::Request<super::SignPsbtRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SignPsbtResponse>,
+            tonic::Status,
+        >;
+        /// lncli: `wallet psbt finalize`
+        /// FinalizePsbt expects a partial transaction with all inputs and outputs fully
+        /// declared and tries to sign all inputs that belong to the wallet. Lnd must be
+        /// the last signer of the transaction. That means, if there are any unsigned
+        /// non-witness inputs or inputs without UTXO information attached or inputs
+        /// without witness data that do not belong to lnd's wallet, this method will
+        /// fail. If no error is returned, the PSBT is ready to be extracted and the
+        /// final TX within to be broadcast.
+        ///
+        /// NOTE: This method does NOT publish the transaction once finalized. It is the
+        /// caller's responsibility to either publish the transaction on success or
+        /// unlock/release any locked UTXOs in case of an error in this method.
+        async fn finalize_psbt(
+            &self,
+            request: tonic::Request<super::FinalizePsbtRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FinalizePsbtResponse>,
+            tonic::Status,
+        >;
+    }
+    /// WalletKit is a service that gives access to the core functionalities of the
+    /// daemon's wallet.
+    #[derive(Debug)]
+    pub struct WalletKitServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> WalletKitServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for WalletKitServer<T>
+    where
+        T: WalletKit,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/walletrpc.WalletKit/ListUnspent" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListUnspentSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::ListUnspentRequest>
+                    for ListUnspentSvc<T> {
+                        type Response = super::ListUnspentResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListUnspentRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::list_unspent(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListUnspentSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/LeaseOutput" => {
+                    #[allow(non_camel_case_types)]
+                    struct LeaseOutputSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::LeaseOutputRequest>
+                    for LeaseOutputSvc<T> {
+                        type Response = super::LeaseOutputResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LeaseOutputRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::lease_output(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LeaseOutputSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/ReleaseOutput" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReleaseOutputSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::ReleaseOutputRequest>
+                    for ReleaseOutputSvc<T> {
+                        type Response = super::ReleaseOutputResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReleaseOutputRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::release_output(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReleaseOutputSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/ListLeases" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListLeasesSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::ListLeasesRequest>
+                    for ListLeasesSvc<T> {
+                        type Response = super::ListLeasesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListLeasesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::list_leases(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListLeasesSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/DeriveNextKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeriveNextKeySvc<T: WalletKit>(pub Arc<T>);
+                    impl<T: WalletKit> tonic::server::UnaryService<super::KeyReq>
+                    for DeriveNextKeySvc<T> {
+                        type Response = super::super::signrpc::KeyDescriptor;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::KeyReq>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::derive_next_key(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeriveNextKeySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/DeriveKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeriveKeySvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::super::signrpc::KeyLocator>
+                    for DeriveKeySvc<T> {
+                        type Response = super::super::signrpc::KeyDescriptor;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::signrpc::KeyLocator>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::derive_key(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeriveKeySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/NextAddr" => {
+                    #[allow(non_camel_case_types)]
+                    struct NextAddrSvc<T: WalletKit>(pub Arc<T>);
+                    impl<T: WalletKit> tonic::server::UnaryService<super::AddrRequest>
+                    for NextAddrSvc<T> {
+                        type Response = super::AddrResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddrRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::next_addr(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = NextAddrSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/GetTransaction" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTransactionSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::GetTransactionRequest>
+                    for GetTransactionSvc<T> {
+                        type Response = super::super::lnrpc::Transaction;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTransactionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::get_transaction(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetTransactionSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/ListAccounts" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListAccountsSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::ListAccountsRequest>
+                    for ListAccountsSvc<T> {
+                        type Response = super::ListAccountsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListAccountsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::list_accounts(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListAccountsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/RequiredReserve" => {
+                    #[allow(non_camel_case_types)]
+                    struct RequiredReserveSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::RequiredReserveRequest>
+                    for RequiredReserveSvc<T> {
+                        type Response = super::RequiredReserveResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequiredReserveRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::required_reserve(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RequiredReserveSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/ListAddresses" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListAddressesSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::ListAddressesRequest>
+                    for ListAddressesSvc<T> {
+                        type Response = super::ListAddressesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListAddressesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::list_addresses(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListAddressesSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/SignMessageWithAddr" => {
+                    #[allow(non_camel_case_types)]
+                    struct SignMessageWithAddrSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::SignMessageWithAddrRequest>
+                    for SignMessageWithAddrSvc<T> {
+                        type Response = super::SignMessageWithAddrResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SignMessageWithAddrRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::sign_message_with_addr(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SignMessageWithAddrSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/VerifyMessageWithAddr" => {
+                    #[allow(non_camel_case_types)]
+                    struct VerifyMessageWithAddrSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::VerifyMessageWithAddrRequest>
+                    for VerifyMessageWithAddrSvc<T> {
+                        type Response = super::VerifyMessageWithAddrResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::VerifyMessageWithAddrRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::verify_message_with_addr(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = VerifyMessageWithAddrSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/ImportAccount" => {
+                    #[allow(non_camel_case_types)]
+                    struct ImportAccountSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::ImportAccountRequest>
+                    for ImportAccountSvc<T> {
+                        type Response = super::ImportAccountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ImportAccountRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::import_account(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ImportAccountSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/ImportPublicKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct ImportPublicKeySvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::ImportPublicKeyRequest>
+                    for ImportPublicKeySvc<T> {
+                        type Response = super::ImportPublicKeyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ImportPublicKeyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::import_public_key(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ImportPublicKeySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/ImportTapscript" => {
+                    #[allow(non_camel_case_types)]
+                    struct ImportTapscriptSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::ImportTapscriptRequest>
+                    for ImportTapscriptSvc<T> {
+                        type Response = super::ImportTapscriptResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ImportTapscriptRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::import_tapscript(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ImportTapscriptSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/PublishTransaction" => {
+                    #[allow(non_camel_case_types)]
+                    struct PublishTransactionSvc<T: WalletKit>(pub Arc<T>);
+                    impl<T: WalletKit> tonic::server::UnaryService<super::Transaction>
+                    for PublishTransactionSvc<T> {
+                        type Response = super::PublishResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::Transaction>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::publish_transaction(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PublishTransactionSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/RemoveTransaction" => {
+                    #[allow(non_camel_case_types)]
+                    struct RemoveTransactionSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::GetTransactionRequest>
+                    for RemoveTransactionSvc<T> {
+                        type Response = super::RemoveTransactionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTransactionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::remove_transaction(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RemoveTransactionSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/SendOutputs" => {
+                    #[allow(non_camel_case_types)]
+                    struct SendOutputsSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::SendOutputsRequest>
+                    for SendOutputsSvc<T> {
+                        type Response = super::SendOutputsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SendOutputsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::send_outputs(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SendOutputsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/EstimateFee" => {
+                    #[allow(non_camel_case_types)]
+                    struct EstimateFeeSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::EstimateFeeRequest>
+                    for EstimateFeeSvc<T> {
+                        type Response = super::EstimateFeeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EstimateFeeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::estimate_fee(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EstimateFeeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/PendingSweeps" => {
+                    #[allow(non_camel_case_types)]
+                    struct PendingSweepsSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::PendingSweepsRequest>
+                    for PendingSweepsSvc<T> {
+                        type Response = super::PendingSweepsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PendingSweepsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::pending_sweeps(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PendingSweepsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/BumpFee" => {
+                    #[allow(non_camel_case_types)]
+                    struct BumpFeeSvc<T: WalletKit>(pub Arc<T>);
+                    impl<T: WalletKit> tonic::server::UnaryService<super::BumpFeeRequest>
+                    for BumpFeeSvc<T> {
+                        type Response = super::BumpFeeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BumpFeeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::bump_fee(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BumpFeeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/BumpForceCloseFee" => {
+                    #[allow(non_camel_case_types)]
+                    struct BumpForceCloseFeeSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::BumpForceCloseFeeRequest>
+                    for BumpForceCloseFeeSvc<T> {
+                        type Response = super::BumpForceCloseFeeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BumpForceCloseFeeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::bump_force_close_fee(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BumpForceCloseFeeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/ListSweeps" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListSweepsSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::ListSweepsRequest>
+                    for ListSweepsSvc<T> {
+                        type Response = super::ListSweepsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListSweepsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::list_sweeps(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListSweepsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/LabelTransaction" => {
+                    #[allow(non_camel_case_types)]
+                    struct LabelTransactionSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::LabelTransactionRequest>
+                    for LabelTransactionSvc<T> {
+                        type Response = super::LabelTransactionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LabelTransactionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::label_transaction(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LabelTransactionSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/FundPsbt" => {
+                    #[allow(non_camel_case_types)]
+                    struct FundPsbtSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::FundPsbtRequest>
+                    for FundPsbtSvc<T> {
+                        type Response = super::FundPsbtResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FundPsbtRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::fund_psbt(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FundPsbtSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/SignPsbt" => {
+                    #[allow(non_camel_case_types)]
+                    struct SignPsbtSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::SignPsbtRequest>
+                    for SignPsbtSvc<T> {
+                        type Response = super::SignPsbtResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SignPsbtRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::sign_psbt(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SignPsbtSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/walletrpc.WalletKit/FinalizePsbt" => {
+                    #[allow(non_camel_case_types)]
+                    struct FinalizePsbtSvc<T: WalletKit>(pub Arc<T>);
+                    impl<
+                        T: WalletKit,
+                    > tonic::server::UnaryService<super::FinalizePsbtRequest>
+                    for FinalizePsbtSvc<T> {
+                        type Response = super::FinalizePsbtResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FinalizePsbtRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WalletKit>::finalize_psbt(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FinalizePsbtSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for WalletKitServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "walletrpc.WalletKit";
+    impl<T> tonic::server::NamedService for WalletKitServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+impl crate::walletrpc::AddrRequest {
+    /// Returns `type`, or an error for values unknown to this version of the protos.
+    pub fn try_type(&self) -> ::core::result::Result<crate::walletrpc::AddressType, ::prost::UnknownEnumValue> {
+        crate::walletrpc::AddressType::try_from(self.r#type)
+    }
+}
+impl crate::walletrpc::Account {
+    /// Returns `address_type`, or an error for values unknown to this version of the protos.
+    pub fn try_address_type(&self) -> ::core::result::Result<crate::walletrpc::AddressType, ::prost::UnknownEnumValue> {
+        crate::walletrpc::AddressType::try_from(self.address_type)
+    }
+}
+impl crate::walletrpc::AccountWithAddresses {
+    /// Returns `address_type`, or an error for values unknown to this version of the protos.
+    pub fn try_address_type(&self) -> ::core::result::Result<crate::walletrpc::AddressType, ::prost::UnknownEnumValue> {
+        crate::walletrpc::AddressType::try_from(self.address_type)
+    }
+}
+impl crate::walletrpc::ListAccountsRequest {
+    /// Returns `address_type`, or an error for values unknown to this version of the protos.
+    pub fn try_address_type(&self) -> ::core::result::Result<crate::walletrpc::AddressType, ::prost::UnknownEnumValue> {
+        crate::walletrpc::AddressType::try_from(self.address_type)
+    }
+}
+impl crate::walletrpc::ImportAccountRequest {
+    /// Returns `address_type`, or an error for values unknown to this version of the protos.
+    pub fn try_address_type(&self) -> ::core::result::Result<crate::walletrpc::AddressType, ::prost::UnknownEnumValue> {
+        crate::walletrpc::AddressType::try_from(self.address_type)
+    }
+}
+impl crate::walletrpc::ImportPublicKeyRequest {
+    /// Returns `address_type`, or an error for values unknown to this version of the protos.
+    pub fn try_address_type(&self) -> ::core::result::Result<crate::walletrpc::AddressType, ::prost::UnknownEnumValue> {
+        crate::walletrpc::AddressType::try_from(self.address_type)
+    }
+}
+impl crate::walletrpc::SendOutputsRequest {
+    /// Returns `coin_selection_strategy`, or an error for values unknown to this version of the protos.
+    pub fn try_coin_selection_strategy(&self) -> ::core::result::Result<crate::lnrpc::CoinSelectionStrategy, ::prost::UnknownEnumValue> {
+        crate::lnrpc::CoinSelectionStrategy::try_from(self.coin_selection_strategy)
+    }
+}
+impl crate::walletrpc::PendingSweep {
+    /// Returns `witness_type`, or an error for values unknown to this version of the protos.
+    pub fn try_witness_type(&self) -> ::core::result::Result<crate::walletrpc::WitnessType, ::prost::UnknownEnumValue> {
+        crate::walletrpc::WitnessType::try_from(self.witness_type)
+    }
+}
+impl crate::walletrpc::FundPsbtRequest {
+    /// Returns `change_type`, or an error for values unknown to this version of the protos.
+    pub fn try_change_type(&self) -> ::core::result::Result<crate::walletrpc::ChangeAddressType, ::prost::UnknownEnumValue> {
+        crate::walletrpc::ChangeAddressType::try_from(self.change_type)
+    }
+    /// Returns `coin_selection_strategy`, or an error for values unknown to this version of the protos.
+    pub fn try_coin_selection_strategy(&self) -> ::core::result::Result<crate::lnrpc::CoinSelectionStrategy, ::prost::UnknownEnumValue> {
+        crate::lnrpc::CoinSelectionStrategy::try_from(self.coin_selection_strategy)
+    }
+}
+/// Generated blocking client implementations.
+#[cfg(feature = "blocking")]
+pub mod wallet_kit_blocking_client {
+    #![allow(clippy::wildcard_imports)]
+    use tonic::codegen::*;
+    use super::wallet_kit_client::WalletKitClient;
+    /// A [`WalletKitClient`] that blocks the current thread on every call, driving the
+    /// requests on its runtime.
+    #[derive(Debug, Clone)]
+    pub struct WalletKitBlockingClient<T> {
+        inner: WalletKitClient<T>,
+        runtime: std::sync::Arc<crate::blocking::Runtime>,
+    }
+    impl<T> WalletKitBlockingClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        /// Wraps `inner`, running its calls on `runtime`.
+        pub fn new(inner: WalletKitClient<T>, runtime: std::sync::Arc<crate::blocking::Runtime>) -> Self {
+            Self { inner, runtime }
+        }
+        /// Returns the async client.
+        pub fn into_inner(self) -> WalletKitClient<T> {
+            self.inner
+        }
+        /// Blocking version of [`WalletKitClient::list_unspent`].
+        pub fn list_unspent(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::ListUnspentRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::ListUnspentResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.list_unspent(request))
+        }
+        /// Blocking version of [`WalletKitClient::lease_output`].
+        pub fn lease_output(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::LeaseOutputRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::LeaseOutputResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.lease_output(request))
+        }
+        /// Blocking version of [`WalletKitClient::release_output`].
+        pub fn release_output(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::ReleaseOutputRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::ReleaseOutputResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.release_output(request))
+        }
+        /// Blocking version of [`WalletKitClient::list_leases`].
+        pub fn list_leases(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::ListLeasesRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::ListLeasesResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.list_leases(request))
+        }
+        /// Blocking version of [`WalletKitClient::derive_next_key`].
+        pub fn derive_next_key(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::KeyReq>,
+        ) -> std::result::Result<tonic::Response<crate::signrpc::KeyDescriptor>, tonic::Status> {
+            self.runtime.block_on(self.inner.derive_next_key(request))
+        }
+        /// Blocking version of [`WalletKitClient::derive_key`].
+        pub fn derive_key(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::signrpc::KeyLocator>,
+        ) -> std::result::Result<tonic::Response<crate::signrpc::KeyDescriptor>, tonic::Status> {
+            self.runtime.block_on(self.inner.derive_key(request))
+        }
+        /// Blocking version of [`WalletKitClient::next_addr`].
+        pub fn next_addr(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::AddrRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::AddrResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.next_addr(request))
+        }
+        /// Blocking version of [`WalletKitClient::get_transaction`].
+        pub fn get_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::GetTransactionRequest>,
+        ) -> std::result::Result<tonic::Response<crate::lnrpc::Transaction>, tonic::Status> {
+            self.runtime.block_on(self.inner.get_transaction(request))
+        }
+        /// Blocking version of [`WalletKitClient::list_accounts`].
+        pub fn list_accounts(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::ListAccountsRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::ListAccountsResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.list_accounts(request))
+        }
+        /// Blocking version of [`WalletKitClient::required_reserve`].
+        pub fn required_reserve(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::RequiredReserveRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::RequiredReserveResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.required_reserve(request))
+        }
+        /// Blocking version of [`WalletKitClient::list_addresses`].
+        pub fn list_addresses(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::ListAddressesRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::ListAddressesResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.list_addresses(request))
+        }
+        /// Blocking version of [`WalletKitClient::sign_message_with_addr`].
+        pub fn sign_message_with_addr(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::SignMessageWithAddrRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::SignMessageWithAddrResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.sign_message_with_addr(request))
+        }
+        /// Blocking version of [`WalletKitClient::verify_message_with_addr`].
+        pub fn verify_message_with_addr(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::VerifyMessageWithAddrRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::VerifyMessageWithAddrResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.verify_message_with_addr(request))
+        }
+        /// Blocking version of [`WalletKitClient::import_account`].
+        pub fn import_account(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::ImportAccountRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::ImportAccountResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.import_account(request))
+        }
+        /// Blocking version of [`WalletKitClient::import_public_key`].
+        pub fn import_public_key(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::ImportPublicKeyRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::ImportPublicKeyResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.import_public_key(request))
+        }
+        /// Blocking version of [`WalletKitClient::import_tapscript`].
+        pub fn import_tapscript(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::ImportTapscriptRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::ImportTapscriptResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.import_tapscript(request))
+        }
+        /// Blocking version of [`WalletKitClient::publish_transaction`].
+        pub fn publish_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::Transaction>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::PublishResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.publish_transaction(request))
+        }
+        /// Blocking version of [`WalletKitClient::remove_transaction`].
+        pub fn remove_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::GetTransactionRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::RemoveTransactionResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.remove_transaction(request))
+        }
+        /// Blocking version of [`WalletKitClient::send_outputs`].
+        pub fn send_outputs(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::SendOutputsRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::SendOutputsResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.send_outputs(request))
+        }
+        /// Blocking version of [`WalletKitClient::estimate_fee`].
+        pub fn estimate_fee(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::EstimateFeeRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::EstimateFeeResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.estimate_fee(request))
+        }
+        /// Blocking version of [`WalletKitClient::pending_sweeps`].
+        pub fn pending_sweeps(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::PendingSweepsRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::PendingSweepsResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.pending_sweeps(request))
+        }
+        /// Blocking version of [`WalletKitClient::bump_fee`].
+        pub fn bump_fee(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::BumpFeeRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::BumpFeeResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.bump_fee(request))
+        }
+        /// Blocking version of [`WalletKitClient::bump_force_close_fee`].
+        pub fn bump_force_close_fee(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::BumpForceCloseFeeRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::BumpForceCloseFeeResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.bump_force_close_fee(request))
+        }
+        /// Blocking version of [`WalletKitClient::list_sweeps`].
+        pub fn list_sweeps(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::ListSweepsRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::ListSweepsResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.list_sweeps(request))
+        }
+        /// Blocking version of [`WalletKitClient::label_transaction`].
+        pub fn label_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::LabelTransactionRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::LabelTransactionResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.label_transaction(request))
+        }
+        /// Blocking version of [`WalletKitClient::fund_psbt`].
+        pub fn fund_psbt(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::FundPsbtRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::FundPsbtResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.fund_psbt(request))
+        }
+        /// Blocking version of [`WalletKitClient::sign_psbt`].
+        pub fn sign_psbt(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::SignPsbtRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::SignPsbtResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.sign_psbt(request))
+        }
+        /// Blocking version of [`WalletKitClient::finalize_psbt`].
+        pub fn finalize_psbt(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::walletrpc::FinalizePsbtRequest>,
+        ) -> std::result::Result<tonic::Response<crate::walletrpc::FinalizePsbtResponse>, tonic::Status> {
+            self.runtime.block_on(self.inner.finalize_psbt(request))
+        }
+    }
+}
@@ -0,0 +1,441 @@
+//! An invoice event stream that reconnects by itself and resumes where it stopped.
+//!
+//! `SubscribeInvoices` sends the invoices added after `add_index` and settled after
+//! `settle_index`, then the live updates. [`subscribe`] keeps those two indices in an
+//! [`IndexStore`], resubscribes from them when the stream drops, skips the updates it already
+//! delivered and turns each update into a typed [`InvoiceEvent`]. With a store that outlives the
+//! process, such as a [`FileStore`], a restarted process continues from the last event it
+//! handled.
+//!
+//! # Example
+//! ```rust,no_run
+//! # async fn example(client: voltage_tonic_lnd::Client) -> voltage_tonic_lnd::Result<()> {
+//! use futures_util::TryStreamExt;
+//! use voltage_tonic_lnd::invoice_stream::{self, FileStore, InvoiceEvent, RetryOptions};
+//!
+//! let store = FileStore::new("/var/lib/shop/invoice-indices");
+//! let events = invoice_stream::subscribe(client.lightning()?, store, RetryOptions::new());
+//! let mut events = std::pin::pin!(events);
+//! while let Some(event) = events.try_next().await? {
+//!     match event {
+//!         InvoiceEvent::Settled(invoice) => println!("paid: {}", invoice.memo),
+//!         event => println!("{event:?}"),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Delivery
+//!
+//! The indices of an event are saved when the next event is requested, so an event counts as
+//! handled once the caller asks for the one after it. Each add and settle is then delivered
+//! exactly once, across reconnects and restarts, except for the event being handled when the
+//! process stops, which is delivered again. Callers that can't handle an event twice can
+//! implement [`IndexStore`] on top of the database they record events in.
+//!
+//! LND doesn't index cancellations and accepted hold invoices, so [`InvoiceEvent::Canceled`] and
+//! [`InvoiceEvent::Accepted`] are only delivered while connected, or for invoices that were also
+//! added while disconnected. Without saved indices, the stream starts with the live updates.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::future::Future;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures_util::stream::{self, Stream};
+use tonic::codegen::{Body, Bytes, StdError};
+use tonic::{Code, Status, Streaming};
+
+use crate::error::{Error, ParseError, Result};
+use crate::lnrpc::invoice::InvoiceState;
+use crate::lnrpc::lightning_client::LightningClient;
+use crate::lnrpc::{Invoice, InvoiceSubscription};
+
+/// An update to an invoice, delivered by [`subscribe`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum InvoiceEvent {
+    /// The invoice was added. Its `add_index` is saved once the event was handled.
+    Added(Invoice),
+    /// The invoice was paid. Its `settle_index` is saved once the event was handled.
+    Settled(Invoice),
+    /// The HTLCs paying a hold invoice were accepted and wait to be settled or canceled.
+    Accepted(Invoice),
+    /// The invoice was canceled.
+    Canceled(Invoice),
+}
+
+impl InvoiceEvent {
+    /// Returns the invoice, in its state at the time of the update.
+    pub fn invoice(&self) -> &Invoice {
+        match self {
+            Self::Added(invoice)
+            | Self::Settled(invoice)
+            | Self::Accepted(invoice)
+            | Self::Canceled(invoice) => invoice,
+        }
+    }
+
+    /// Returns the invoice, in its state at the time of the update.
+    pub fn into_invoice(self) -> Invoice {
+        match self {
+            Self::Added(invoice)
+            | Self::Settled(invoice)
+            | Self::Accepted(invoice)
+            | Self::Canceled(invoice) => invoice,
+        }
+    }
+}
+
+/// The `add_index` and `settle_index` of the last delivered events, 0 if there were none.
+///
+/// Displayed, and parsed, as `add_index=12 settle_index=7`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct InvoiceIndices {
+    /// The `add_index` of the last invoice delivered as [`InvoiceEvent::Added`].
+    pub add_index: u64,
+    /// The `settle_index` of the last invoice delivered as [`InvoiceEvent::Settled`].
+    pub settle_index: u64,
+}
+
+impl InvoiceIndices {
+    /// Moves the indices past `event`, returning whether they changed.
+    fn advance(&mut self, event: &InvoiceEvent) -> bool {
+        let (index, delivered) = match event {
+            InvoiceEvent::Added(invoice) => (&mut self.add_index, invoice.add_index),
+            InvoiceEvent::Settled(invoice) => (&mut self.settle_index, invoice.settle_index),
+            InvoiceEvent::Accepted(_) | InvoiceEvent::Canceled(_) => return false,
+        };
+        let changed = delivered > *index;
+        *index = (*index).max(delivered);
+        changed
+    }
+
+    /// Returns the events of an update from `SubscribeInvoices` that weren't delivered yet.
+    ///
+    /// Resuming from an `add_index`, LND first sends the invoices added since, in their current
+    /// state, then the invoices settled since `settle_index`, in settle order. An invoice added
+    /// and settled in between is in both, so only its addition is taken from the first: the
+    /// settlements then all come in `settle_index` order, and none is skipped because a later one
+    /// moved the index past it. `add_backlog` is whether the subscription resumed from an
+    /// `add_index`; without it, a settled invoice with a new `add_index` was added before the
+    /// stream started and only its settlement is new.
+    fn events(&self, invoice: Invoice, add_backlog: bool) -> Vec<InvoiceEvent> {
+        let state = invoice.try_state();
+        let added = invoice.add_index > self.add_index
+            && (add_backlog || matches!(state, Ok(InvoiceState::Open)));
+        let update: Option<fn(Invoice) -> InvoiceEvent> = match state {
+            Ok(InvoiceState::Settled) if !added && invoice.settle_index > self.settle_index => {
+                Some(InvoiceEvent::Settled)
+            }
+            Ok(InvoiceState::Accepted) => Some(InvoiceEvent::Accepted),
+            Ok(InvoiceState::Canceled) => Some(InvoiceEvent::Canceled),
+            _ => None,
+        };
+        match (added, update) {
+            (true, Some(update)) => vec![InvoiceEvent::Added(invoice.clone()), update(invoice)],
+            (true, None) => vec![InvoiceEvent::Added(invoice)],
+            (false, Some(update)) => vec![update(invoice)],
+            (false, None) => Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for InvoiceIndices {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "add_index={} settle_index={}", self.add_index, self.settle_index)
+    }
+}
+
+impl FromStr for InvoiceIndices {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = || ParseError::InvalidFormat(format!("invalid invoice indices {s:?}"));
+        let mut indices = Self::default();
+        for field in s.split_whitespace() {
+            let (name, value) = field.split_once('=').ok_or_else(invalid)?;
+            let value = value.parse().map_err(|_| invalid())?;
+            match name {
+                "add_index" => indices.add_index = value,
+                "settle_index" => indices.settle_index = value,
+                _ => return Err(invalid()),
+            }
+        }
+        Ok(indices)
+    }
+}
+
+/// Keeps the [`InvoiceIndices`] of an invoice stream between subscriptions.
+pub trait IndexStore: Send + Sync {
+    /// Returns the saved indices, or the default if none were saved yet.
+    fn load(&self) -> impl Future<Output = Result<InvoiceIndices>> + Send;
+
+    /// Saves `indices`, replacing the previous ones.
+    fn save(&self, indices: InvoiceIndices) -> impl Future<Output = Result<()>> + Send;
+}
+
+/// Keeps the indices in memory, to resume after a reconnect but not after a restart.
+///
+/// Cloning is cheap: clones share the same indices.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore {
+    indices: Arc<Mutex<InvoiceIndices>>,
+}
+
+impl MemoryStore {
+    /// Creates a store starting from `indices`.
+    pub fn new(indices: InvoiceIndices) -> Self {
+        Self {
+            indices: Arc::new(Mutex::new(indices)),
+        }
+    }
+
+    /// Returns the indices saved last.
+    pub fn indices(&self) -> InvoiceIndices {
+        *self.indices.lock().expect("poisoned")
+    }
+}
+
+impl IndexStore for MemoryStore {
+    async fn load(&self) -> Result<InvoiceIndices> {
+        Ok(self.indices())
+    }
+
+    async fn save(&self, indices: InvoiceIndices) -> Result<()> {
+        *self.indices.lock().expect("poisoned") = indices;
+        Ok(())
+    }
+}
+
+/// Keeps the indices in a file, in the format of [`InvoiceIndices`]'s `Display`.
+///
+/// A missing file holds the default indices. The file is replaced by renaming a temporary file
+/// next to it, so a crash while saving leaves either the old or the new indices.
+#[derive(Debug, Clone)]
+pub struct FileStore {
+    path: PathBuf,
+}
+
+impl FileStore {
+    /// Creates a store keeping the indices at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+        }
+    }
+}
+
+impl IndexStore for FileStore {
+    async fn load(&self) -> Result<InvoiceIndices> {
+        match tokio::fs::read_to_string(&self.path).await {
+            Ok(contents) => Ok(contents.parse()?),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                Ok(InvoiceIndices::default())
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    async fn save(&self, indices: InvoiceIndices) -> Result<()> {
+        let mut temporary = self.path.clone().into_os_string();
+        temporary.push(".tmp");
+        tokio::fs::write(&temporary, format!("{indices}\n")).await?;
+        tokio::fs::OpenOptions::new().write(true).open(&temporary).await?.sync_all().await?;
+        tokio::fs::rename(&temporary, &self.path).await?;
+        Ok(())
+    }
+}
+
+/// When an invoice stream reconnects.
+///
+/// A stream that fails with `UNAVAILABLE` or another error that may go away, or that LND ends,
+/// is resubscribed after a delay that starts at the minimum and doubles up to the maximum for
+/// every failed attempt in a row. Other errors, and failures beyond [`RetryOptions::max_retries`],
+/// end the stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryOptions {
+    min_delay: Duration,
+    max_delay: Duration,
+    max_retries: Option<u32>,
+}
+
+impl Default for RetryOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RetryOptions {
+    /// Creates options retrying forever, after 1 second up to 1 minute.
+    pub fn new() -> Self {
+        Self {
+            min_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            max_retries: None,
+        }
+    }
+
+    /// Sets the delay before the first attempt after a failure.
+    pub fn min_delay(mut self, delay: Duration) -> Self {
+        self.min_delay = delay;
+        self
+    }
+
+    /// Sets the longest delay between two attempts.
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Ends the stream with the last error after `retries` failed attempts in a row.
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = Some(retries);
+        self
+    }
+}
+
+/// Returns the invoice events after the indices in `store`, saving the indices as the events are
+/// handled. See the [module documentation](self) for the delivery guarantees.
+///
+/// An error from the store, or from LND that isn't retried, is returned as the last item.
+pub fn subscribe<T, S>(
+    client: LightningClient<T>,
+    store: S,
+    options: RetryOptions,
+) -> impl Stream<Item = Result<InvoiceEvent>>
+where
+    T: tonic::client::GrpcService<tonic::body::Body>,
+    T::Error: Into<StdError>,
+    T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    S: IndexStore,
+{
+    let subscriber = Subscriber {
+        client,
+        store,
+        options,
+        indices: None,
+        unsaved: false,
+        stream: None,
+        add_backlog: false,
+        pending: VecDeque::new(),
+        failures: 0,
+    };
+    stream::unfold(Some(subscriber), |subscriber| async move {
+        let mut subscriber = subscriber?;
+        match subscriber.next().await {
+            Ok(event) => Some((Ok(event), Some(subscriber))),
+            Err(error) => Some((Err(error), None)),
+        }
+    })
+}
+
+struct Subscriber<T, S> {
+    client: LightningClient<T>,
+    store: S,
+    options: RetryOptions,
+    /// The indices of the delivered events, `None` until loaded from the store.
+    indices: Option<InvoiceIndices>,
+    /// Whether the last delivered event moved the indices past the saved ones.
+    unsaved: bool,
+    stream: Option<Streaming<Invoice>>,
+    /// Whether `stream` was resumed from an `add_index`, see [`InvoiceIndices::events`].
+    add_backlog: bool,
+    /// Events of the last update that weren't delivered yet.
+    pending: VecDeque<InvoiceEvent>,
+    /// Failed attempts since the last update.
+    failures: u32,
+}
+
+impl<T, S> Subscriber<T, S>
+where
+    T: tonic::client::GrpcService<tonic::body::Body>,
+    T::Error: Into<StdError>,
+    T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    S: IndexStore,
+{
+    async fn next(&mut self) -> Result<InvoiceEvent> {
+        let indices = match &mut self.indices {
+            Some(indices) => indices,
+            None => self.indices.insert(self.store.load().await?),
+        };
+        if self.unsaved {
+            self.store.save(*indices).await?;
+            self.unsaved = false;
+        }
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                self.unsaved = indices.advance(&event);
+                return Ok(event);
+            }
+            let Some(stream) = &mut self.stream else {
+                let request = InvoiceSubscription {
+                    add_index: indices.add_index,
+                    settle_index: indices.settle_index,
+                };
+                self.add_backlog = request.add_index > 0;
+                match self.client.subscribe_invoices(request).await {
+                    Ok(response) => self.stream = Some(response.into_inner()),
+                    Err(status) => retry(&mut self.failures, self.options, status).await?,
+                }
+                continue;
+            };
+            let status = match stream.message().await {
+                Ok(Some(invoice)) => {
+                    self.failures = 0;
+                    self.pending.extend(indices.events(invoice, self.add_backlog));
+                    continue;
+                }
+                Ok(None) => Status::unavailable("SubscribeInvoices stream ended"),
+                Err(status) => status,
+            };
+            self.stream = None;
+            retry(&mut self.failures, self.options, status).await?;
+        }
+    }
+}
+
+/// Waits before the next attempt after `status`, or returns it if it shouldn't be retried.
+async fn retry(failures: &mut u32, options: RetryOptions, status: Status) -> Result<()> {
+    let retryable = matches!(
+        status.code(),
+        Code::Unavailable
+            | Code::Unknown
+            | Code::Internal
+            | Code::Aborted
+            | Code::Cancelled
+            | Code::DeadlineExceeded
+            | Code::ResourceExhausted
+    );
+    if !retryable || options.max_retries.is_some_and(|max| *failures >= max) {
+        return Err(Error::Status(status));
+    }
+    let delay = options.min_delay.saturating_mul(2u32.saturating_pow(*failures));
+    *failures += 1;
+    tokio::time::sleep(delay.min(options.max_delay)).await;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indices_format() {
+        let indices = InvoiceIndices {
+            add_index: 12,
+            settle_index: 7,
+        };
+        assert_eq!(indices.to_string(), "add_index=12 settle_index=7");
+        assert_eq!(" add_index=12\nsettle_index=7\n".parse(), Ok(indices));
+        assert_eq!("".parse(), Ok(InvoiceIndices::default()));
+        for invalid in ["add_index", "add_index=x", "add_index=-1", "other=1"] {
+            assert!(invalid.parse::<InvoiceIndices>().is_err(), "{invalid}");
+        }
+    }
+}
@@ -17,12 +17,33 @@
 //! - `staterpc` (State)
 //! - `versionrpc` (Versioner)
 //! - `all` (enables all RPCs)
+//! - `fleet` (the [`fleet`] module for managing clients of many nodes, depends on `staterpc`)
 //! - TLS backend selection: `ring` (default), `aws-lc`
 //! - TLS root CA selection: `tls-native-roots`, `tls-webpki-roots`, `tls`
+//! - Message compression: `gzip`, `zstd`
+//! - `server` (the generated `*_server` modules, for implementing the services, for example a
+//!   mock node or a price oracle for tapd)
+//! - `testing` (the [`testing`] module with an in-process mock node and session record/replay,
+//!   depends on `server`)
+//! - `serde` (`Serialize` and `Deserialize` for all messages and enums, with the JSON shape of
+//!   LND's REST API and `lncli`, and the [`serde_hex`] module for bytes as hex instead of base64)
+//! - `bolt11` (the [`bolt11`] module for decoding payment requests without a node)
+//! - `bitcoin` (conversions between messages and [`bitcoin`] types, see [`bitcoin_interop`])
+//! - `blocking` (the [`blocking`] module, a client for code without an async runtime)
+//! - `rest` (the [`rest`] module, a client for LND's REST API instead of gRPC, depends on
+//!   `lightningrpc` and `serde`)
+//! - `pagination` (the [`pagination`] module, streams over the paged list RPCs, depends on
+//!   `lightningrpc`)
+//! - `invoice-stream` (the [`invoice_stream`] module, an invoice subscription that reconnects and
+//!   resumes from indices it saves, depends on `lightningrpc`)
+//! - `grpc-web` (the [`web`] module, a gRPC-web client for browsers, which builds for `wasm32`
+//!   without `transport`)
+//! - `transport` (the [`Client`], over tonic's HTTP/2 transport; enabled by the TLS features)
+//! - `bytes` (proto `bytes` fields as `Bytes` instead of `Vec<u8>`, decoded without copying)
 //!
 //! **Default features:** `all`, `ring`, `tls`
 //!
-//! At least one TLS backend is required. The default is `ring`.
+//! At least one TLS backend is required for [`Client`]. The default is `ring`.
 //!
 //! ## Example
 //!
@@ -48,6 +69,8 @@
 //!
 //! ```rust,no_run
 //! use voltage_tonic_lnd::Client;
+//! # const HEX_MACAROON_STRING: &str = "";
+//! # const PEM_CERT_STRING: &str = "";
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), voltage_tonic_lnd::Error> {
@@ -82,16 +105,79 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! ### Example: Large Responses and Compression
+//!
+//! tonic limits decoded messages to 4 MiB by default, which `DescribeGraph` on mainnet exceeds.
+//! Limits and compression (with the `gzip` or `zstd` feature) can be set for all services or per
+//! service with [`MessageOptions`]:
+//!
+//! ```rust,no_run
+//! use voltage_tonic_lnd::{Client, MessageOptions, ServiceKind};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), voltage_tonic_lnd::Error> {
+//!     let client = Client::builder()
+//!         .address("https://localhost:10009")
+//!         .macaroon_path("/path/to/admin.macaroon")
+//!         .max_decoding_message_size(16 * 1024 * 1024)
+//!         .service_options(
+//!             ServiceKind::Lightning,
+//!             MessageOptions::new().max_decoding_message_size(256 * 1024 * 1024),
+//!         )
+//!         .build()
+//!         .await?;
+//!     Ok(())
+//! }
+//! ```
 
 #![allow(clippy::large_enum_variant)]
 #![allow(clippy::doc_lazy_continuation)]
 #![allow(clippy::doc_overindented_list_items)]
 
+// The mock node serves TLS with a certificate generated by rcgen, which needs a crypto backend.
+#[cfg(all(feature = "testing", not(any(feature = "ring", feature = "aws-lc"))))]
+compile_error!("the `testing` feature requires a TLS backend, enable `ring` or `aws-lc`");
+
+#[cfg(feature = "bitcoin")]
+pub mod bitcoin_interop;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "bolt11")]
+pub mod bolt11;
+#[cfg(feature = "transport")]
 mod client;
 mod error;
+#[cfg(feature = "fleet")]
+pub mod fleet;
+#[cfg(feature = "invoice-stream")]
+pub mod invoice_stream;
+#[cfg(feature = "pagination")]
+pub mod pagination;
 mod protos;
+#[cfg(feature = "rest")]
+pub mod rest;
+#[cfg(feature = "serde")]
+mod serde_helpers;
+#[cfg(feature = "serde")]
+pub mod serde_hex;
+mod service;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "transport")]
+mod transport;
+pub mod types;
+#[cfg(feature = "grpc-web")]
+pub mod web;
 
+#[cfg(feature = "bitcoin")]
+pub use bitcoin;
+#[cfg(feature = "transport")]
 pub use client::*;
 pub use error::*;
+#[cfg(services)]
 pub use protos::*;
+pub use service::*;
 pub use tonic;
+#[cfg(feature = "transport")]
+pub use transport::{BalanceStrategy, Transport};
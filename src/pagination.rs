@@ -0,0 +1,391 @@
+//! Streams over the list RPCs that LND pages, fetching the pages as they are read.
+//!
+//! `ListInvoices`, `ListPayments`, `ForwardingHistory` and `GetTransactions` return at most one
+//! page per call, and the caller has to ask for the next page with an index offset taken from
+//! the previous response. The functions here do that and return a [`Stream`] of the items, which
+//! only fetches the next page once the previous one was read. [`PageOptions`] sets the page size,
+//! the time or block height range and the order.
+//!
+//! # Example
+//! ```rust,no_run
+//! # async fn example(client: voltage_tonic_lnd::Client) -> voltage_tonic_lnd::Result<()> {
+//! use std::time::{Duration, SystemTime};
+//!
+//! use futures_util::TryStreamExt;
+//! use voltage_tonic_lnd::lnrpc::ListPaymentsRequest;
+//! use voltage_tonic_lnd::pagination::{self, PageOptions};
+//!
+//! // The payments of the last week, newest first.
+//! let week_ago = SystemTime::now() - Duration::from_secs(7 * 24 * 60 * 60);
+//! let options = PageOptions::new().page_size(500).start_time(week_ago).reversed(true);
+//! let payments =
+//!     pagination::payments(client.lightning()?, ListPaymentsRequest::default(), options);
+//! let mut payments = std::pin::pin!(payments);
+//! while let Some(payment) = payments.try_next().await? {
+//!     println!("{} {}", payment.payment_hash, payment.value_msat);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The other fields of the request, such as `pending_only` for invoices, are passed on as they
+//! are. An error ends the stream.
+
+use std::future::Future;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use futures_util::stream::{self, Stream, StreamExt};
+use tonic::codegen::{Body, Bytes, StdError};
+use tonic::Status;
+
+use crate::lnrpc::lightning_client::LightningClient;
+use crate::lnrpc::{
+    ChannelCloseSummary, ClosedChannelsRequest, ForwardingEvent, ForwardingHistoryRequest,
+    GetTransactionsRequest, Invoice, ListInvoiceRequest, ListPaymentsRequest, Payment, Transaction,
+};
+
+/// The page size used unless [`PageOptions::page_size`] sets another one.
+pub const DEFAULT_PAGE_SIZE: u32 = 1000;
+
+/// Page size, range and order of a paginated stream.
+///
+/// Not every RPC supports every option, see the stream functions. A stream given an option its
+/// RPC doesn't support fails with `INVALID_ARGUMENT` rather than ignoring it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageOptions {
+    page_size: u32,
+    start_time: Option<SystemTime>,
+    end_time: Option<SystemTime>,
+    start_height: Option<u32>,
+    end_height: Option<u32>,
+    reversed: bool,
+}
+
+impl Default for PageOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PageOptions {
+    /// Creates options for pages of [`DEFAULT_PAGE_SIZE`] items, over the whole range, oldest
+    /// first.
+    pub fn new() -> Self {
+        Self {
+            page_size: DEFAULT_PAGE_SIZE,
+            start_time: None,
+            end_time: None,
+            start_height: None,
+            end_height: None,
+            reversed: false,
+        }
+    }
+
+    /// Sets how many items are fetched per call. Values below 1 are raised to 1.
+    pub fn page_size(mut self, size: u32) -> Self {
+        self.page_size = size.max(1);
+        self
+    }
+
+    /// Only lists items created (or forwards completed) at or after `time`.
+    ///
+    /// LND compares whole seconds.
+    pub fn start_time(mut self, time: SystemTime) -> Self {
+        self.start_time = Some(time);
+        self
+    }
+
+    /// Only lists items created (or forwards completed) before or at `time`.
+    ///
+    /// LND compares whole seconds.
+    pub fn end_time(mut self, time: SystemTime) -> Self {
+        self.end_time = Some(time);
+        self
+    }
+
+    /// Only lists transactions confirmed, or channels closed, at or above `height`.
+    pub fn start_height(mut self, height: u32) -> Self {
+        self.start_height = Some(height);
+        self
+    }
+
+    /// Only lists transactions confirmed, or channels closed, at or below `height`.
+    pub fn end_height(mut self, height: u32) -> Self {
+        self.end_height = Some(height);
+        self
+    }
+
+    /// Lists the newest items first.
+    pub fn reversed(mut self, reversed: bool) -> Self {
+        self.reversed = reversed;
+        self
+    }
+
+    /// Fails if a time range was set for an RPC that can only filter by height.
+    fn check_no_time(&self, rpc: &str) -> Result<(), Status> {
+        match self.start_time.is_some() || self.end_time.is_some() {
+            true => Err(Status::invalid_argument(format!("{rpc} can't be filtered by time"))),
+            false => Ok(()),
+        }
+    }
+
+    /// Fails if a height range was set for an RPC that can only filter by time.
+    fn check_no_height(&self, rpc: &str) -> Result<(), Status> {
+        match self.start_height.is_some() || self.end_height.is_some() {
+            true => Err(Status::invalid_argument(format!("{rpc} can't be filtered by height"))),
+            false => Ok(()),
+        }
+    }
+}
+
+/// Returns the invoices of `request`, page by page.
+///
+/// Supports time ranges, on the creation date, and reverse order. Settled and canceled invoices
+/// are included unless `pending_only` is set.
+pub fn invoices<T>(
+    client: LightningClient<T>,
+    request: ListInvoiceRequest,
+    options: PageOptions,
+) -> impl Stream<Item = Result<Invoice, Status>>
+where
+    T: tonic::client::GrpcService<tonic::body::Body>,
+    T::Error: Into<StdError>,
+    T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+{
+    let request = options.check_no_height("ListInvoices").map(|()| ListInvoiceRequest {
+        index_offset: 0,
+        num_max_invoices: options.page_size.into(),
+        reversed: options.reversed,
+        creation_date_start: options.start_time.map_or(request.creation_date_start, unix_time),
+        creation_date_end: options.end_time.map_or(request.creation_date_end, unix_time),
+        ..request
+    });
+    paginate(client, request, |mut client, request| async move {
+        let response = client.list_invoices(request).await?.into_inner();
+        let (mut invoices, next) = match request.reversed {
+            true => (response.invoices, response.first_index_offset),
+            false => (response.invoices, response.last_index_offset),
+        };
+        if request.reversed {
+            invoices.reverse();
+        }
+        let last =
+            invoices.len() < request.num_max_invoices as usize || next == request.index_offset;
+        let next = (!last).then(|| {
+            let request = ListInvoiceRequest {
+                index_offset: next,
+                ..request
+            };
+            (client, request)
+        });
+        Ok((invoices, next))
+    })
+}
+
+/// Returns the payments of `request`, page by page.
+///
+/// Supports time ranges, on the creation date, and reverse order. Failed and in-flight payments
+/// are only included if `include_incomplete` is set.
+pub fn payments<T>(
+    client: LightningClient<T>,
+    request: ListPaymentsRequest,
+    options: PageOptions,
+) -> impl Stream<Item = Result<Payment, Status>>
+where
+    T: tonic::client::GrpcService<tonic::body::Body>,
+    T::Error: Into<StdError>,
+    T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+{
+    let request = options.check_no_height("ListPayments").map(|()| ListPaymentsRequest {
+        index_offset: 0,
+        max_payments: options.page_size.into(),
+        reversed: options.reversed,
+        creation_date_start: options.start_time.map_or(request.creation_date_start, unix_time),
+        creation_date_end: options.end_time.map_or(request.creation_date_end, unix_time),
+        ..request
+    });
+    paginate(client, request, |mut client, request| async move {
+        let response = client.list_payments(request).await?.into_inner();
+        let (mut payments, next) = match request.reversed {
+            true => (response.payments, response.first_index_offset),
+            false => (response.payments, response.last_index_offset),
+        };
+        if request.reversed {
+            payments.reverse();
+        }
+        let last = payments.len() < request.max_payments as usize || next == request.index_offset;
+        let next = (!last).then(|| {
+            let request = ListPaymentsRequest {
+                index_offset: next,
+                ..request
+            };
+            (client, request)
+        });
+        Ok((payments, next))
+    })
+}
+
+/// Returns the forwarding events of `request`, page by page, oldest first.
+///
+/// Supports time ranges. LND can't list forwards in reverse, so `reversed` fails. Without an
+/// end time, LND lists up to the current time.
+pub fn forwarding_events<T>(
+    client: LightningClient<T>,
+    request: ForwardingHistoryRequest,
+    options: PageOptions,
+) -> impl Stream<Item = Result<ForwardingEvent, Status>>
+where
+    T: tonic::client::GrpcService<tonic::body::Body>,
+    T::Error: Into<StdError>,
+    T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+{
+    let request = options
+        .check_no_height("ForwardingHistory")
+        .and_then(|()| match options.reversed {
+            true => Err(Status::invalid_argument("ForwardingHistory can't be listed in reverse")),
+            false => Ok(()),
+        })
+        .map(|()| ForwardingHistoryRequest {
+            start_time: options.start_time.map_or(request.start_time, unix_time),
+            end_time: options.end_time.map_or(request.end_time, unix_time),
+            index_offset: 0,
+            num_max_events: options.page_size,
+            ..request
+        });
+    paginate(client, request, |mut client, request| async move {
+        let response = client.forwarding_history(request.clone()).await?.into_inner();
+        let events = response.forwarding_events;
+        let next = response.last_offset_index;
+        let last = events.len() < request.num_max_events as usize || next == request.index_offset;
+        let next = (!last).then(|| {
+            let request = ForwardingHistoryRequest {
+                index_offset: next,
+                ..request
+            };
+            (client, request)
+        });
+        Ok((events, next))
+    })
+}
+
+/// Returns the on-chain transactions of `request`, page by page.
+///
+/// Supports block height ranges and reverse order. Unconfirmed transactions are listed last,
+/// unless a reverse order or an end height leaves them out.
+pub fn transactions<T>(
+    client: LightningClient<T>,
+    request: GetTransactionsRequest,
+    options: PageOptions,
+) -> impl Stream<Item = Result<Transaction, Status>>
+where
+    T: tonic::client::GrpcService<tonic::body::Body>,
+    T::Error: Into<StdError>,
+    T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+{
+    let height = |height: u32| i32::try_from(height).unwrap_or(i32::MAX);
+    let request = options.check_no_time("GetTransactions").map(|()| {
+        let start = options.start_height.map_or(request.start_height, height);
+        // LND reads in reverse if the start height is above the end height, and reads up to the
+        // tip, including unconfirmed transactions, if the end height is -1.
+        let (start_height, end_height) = match options.reversed {
+            true => (options.end_height.map_or(i32::MAX, height), start),
+            false => (start, options.end_height.map_or(-1, height)),
+        };
+        GetTransactionsRequest {
+            start_height,
+            end_height,
+            index_offset: 0,
+            max_transactions: options.page_size,
+            ..request
+        }
+    });
+    paginate(client, request, |mut client, request| async move {
+        let response = client.get_transactions(request.clone()).await?.into_inner();
+        let transactions = response.transactions;
+        let last = transactions.len() < request.max_transactions as usize;
+        let next = (!last).then(|| {
+            let request = GetTransactionsRequest {
+                index_offset: request.index_offset + transactions.len() as u32,
+                ..request
+            };
+            (client, request)
+        });
+        Ok((transactions, next))
+    })
+}
+
+/// Returns the closed channels of `request`, ordered by close height.
+///
+/// LND returns all closed channels in one response, so the page size doesn't apply. Supports
+/// block height ranges, on the close height, and reverse order.
+pub fn closed_channels<T>(
+    client: LightningClient<T>,
+    request: ClosedChannelsRequest,
+    options: PageOptions,
+) -> impl Stream<Item = Result<ChannelCloseSummary, Status>>
+where
+    T: tonic::client::GrpcService<tonic::body::Body>,
+    T::Error: Into<StdError>,
+    T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+{
+    let request = options.check_no_time("ClosedChannels").map(|()| request);
+    paginate(client, request, move |mut client, request| async move {
+        let response = client.closed_channels(request).await?.into_inner();
+        let start = options.start_height.unwrap_or(0);
+        let end = options.end_height.unwrap_or(u32::MAX);
+        let mut channels: Vec<_> = response
+            .channels
+            .into_iter()
+            .filter(|channel| (start..=end).contains(&channel.close_height))
+            .collect();
+        channels.sort_by_key(|channel| channel.close_height);
+        if options.reversed {
+            channels.reverse();
+        }
+        Ok((channels, None))
+    })
+}
+
+/// Returns a stream of the items of the pages `next_page` fetches, starting from `request`.
+///
+/// `next_page` returns the items of one page along with the client and request for the next
+/// one, or `None` after the last page. An invalid `request` is returned as the only item.
+fn paginate<C, R, I, F, Fut>(
+    client: C,
+    request: Result<R, Status>,
+    next_page: F,
+) -> impl Stream<Item = Result<I, Status>>
+where
+    F: Fn(C, R) -> Fut,
+    Fut: Future<Output = Result<(Vec<I>, Option<(C, R)>), Status>>,
+{
+    let (next, error) = match request {
+        Ok(request) => (Some((client, request)), None),
+        Err(status) => (None, Some(status)),
+    };
+    let pages = stream::unfold(next, move |next| {
+        let page = next.map(|(client, request)| next_page(client, request));
+        async move {
+            match page?.await {
+                Ok((items, next)) => Some((Ok(items), next)),
+                Err(status) => Some((Err(status), None)),
+            }
+        }
+    });
+    stream::iter(error.map(Err)).chain(pages.flat_map(|page| {
+        let (items, error) = match page {
+            Ok(items) => (items, None),
+            Err(status) => (Vec::new(), Some(status)),
+        };
+        stream::iter(items.into_iter().map(Ok).chain(error.map(Err)))
+    }))
+}
+
+fn unix_time(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs())
+}
@@ -1,83 +1,108 @@
+/// Includes the code generated for a proto package: the checked-in code under `src/generated`,
+/// or the code `build.rs` generated into `OUT_DIR` from `LND_REPO_DIR`/`TAPROOT_ASSETS_REPO_DIR`.
+#[cfg(services)]
+macro_rules! include_generated {
+    ($package:literal) => {
+        #[cfg(not(out_dir_protos))]
+        include!(concat!("generated/", $package, ".rs"));
+        #[cfg(out_dir_protos)]
+        include!(concat!(env!("OUT_DIR"), "/", $package, ".rs"));
+    };
+}
+
+/// Converts `bytes` to the type of proto `bytes` fields, which is `Vec<u8>` or, with the `bytes`
+/// feature, `Bytes`.
+#[cfg(any(
+    feature = "bolt11",
+    all(
+        feature = "bitcoin",
+        any(feature = "lightningrpc", feature = "walletrpc", feature = "chainrpc")
+    )
+))]
+pub(crate) fn bytes_field<T: From<Vec<u8>>>(bytes: Vec<u8>) -> T {
+    T::from(bytes)
+}
+
 /// Messages and other types generated by `tonic`/`prost`
 ///
 /// This is the go-to module you will need to look in to find documentation on various message
 /// types. However it may be better to start from methods on the [`LightningClient`](lnrpc::lightning_client::LightningClient) type.
 #[cfg(feature = "lightningrpc")]
 pub mod lnrpc {
-    tonic::include_proto!("lnrpc");
+    include_generated!("lnrpc");
 }
 
 #[cfg(feature = "walletrpc")]
 pub mod walletrpc {
-    tonic::include_proto!("walletrpc");
+    include_generated!("walletrpc");
 }
 
 #[cfg(feature = "signrpc")]
 pub mod signrpc {
-    tonic::include_proto!("signrpc");
+    include_generated!("signrpc");
 }
 
 #[cfg(feature = "peersrpc")]
 pub mod peersrpc {
-    tonic::include_proto!("peersrpc");
+    include_generated!("peersrpc");
 }
 
 #[cfg(feature = "routerrpc")]
 pub mod routerrpc {
-    tonic::include_proto!("routerrpc");
+    include_generated!("routerrpc");
 }
 
 #[cfg(feature = "versionrpc")]
 pub mod verrpc {
-    tonic::include_proto!("verrpc");
+    include_generated!("verrpc");
 }
 
 #[cfg(feature = "invoicesrpc")]
 pub mod invoicesrpc {
-    tonic::include_proto!("invoicesrpc");
+    include_generated!("invoicesrpc");
 }
 
 #[cfg(feature = "chainrpc")]
 pub mod chainrpc {
-    tonic::include_proto!("chainrpc");
+    include_generated!("chainrpc");
 }
 
 #[cfg(feature = "taprpc")]
 pub mod taprpc {
-    tonic::include_proto!("taprpc");
+    include_generated!("taprpc");
 }
 
 #[cfg(feature = "assetwalletrpc")]
 pub mod assetwalletrpc {
-    tonic::include_proto!("assetwalletrpc");
+    include_generated!("assetwalletrpc");
 }
 
 #[cfg(feature = "mintrpc")]
 pub mod mintrpc {
-    tonic::include_proto!("mintrpc");
+    include_generated!("mintrpc");
 }
 
 #[cfg(feature = "priceoraclerpc")]
 pub mod priceoraclerpc {
-    tonic::include_proto!("priceoraclerpc");
+    include_generated!("priceoraclerpc");
 }
 
 #[cfg(feature = "rfqrpc")]
 pub mod rfqrpc {
-    tonic::include_proto!("rfqrpc");
+    include_generated!("rfqrpc");
 }
 
 #[cfg(feature = "tapchannelrpc")]
 pub mod tapchannelrpc {
-    tonic::include_proto!("tapchannelrpc");
+    include_generated!("tapchannelrpc");
 }
 
 #[cfg(feature = "tapdevrpc")]
 pub mod tapdevrpc {
-    tonic::include_proto!("tapdevrpc");
+    include_generated!("tapdevrpc");
 }
 
 #[cfg(feature = "universerpc")]
 pub mod universerpc {
-    tonic::include_proto!("universerpc");
+    include_generated!("universerpc");
 }
@@ -0,0 +1,870 @@
+//! A client for LND's REST API, for networks where gRPC doesn't get through.
+//!
+//! LND serves its RPCs over REST as well, by default on port 8080. That is plain HTTP/1.1 with
+//! JSON, which passes proxies that break gRPC's HTTP/2. [`RestClient`] talks to it with the same
+//! request and response types as [`Client`](crate::Client), converted to JSON with their `serde`
+//! implementations, and returns the same [`Status`] errors. It covers the Lightning, Router,
+//! Invoices and WalletKit services:
+//!
+//! ```rust,no_run
+//! # async fn example() -> voltage_tonic_lnd::Result<()> {
+//! use voltage_tonic_lnd::lnrpc::{GetInfoRequest, InvoiceSubscription};
+//! use voltage_tonic_lnd::Client;
+//!
+//! let client = Client::builder()
+//!     .address("https://localhost:8080")
+//!     .macaroon_path("/path/to/admin.macaroon")
+//!     .cert_path("/path/to/tls.cert")
+//!     .build_rest()
+//!     .await?;
+//!
+//! let mut lightning = client.lightning()?;
+//! println!("{:?}", lightning.get_info(GetInfoRequest {}).await?.into_inner());
+//!
+//! let mut invoices = lightning.subscribe_invoices(InvoiceSubscription::default()).await?.into_inner();
+//! while let Some(invoice) = invoices.message().await? {
+//!     println!("{invoice:?}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! RPCs that stream responses are read from a WebSocket, as [`Streaming`]. RPCs that stream
+//! requests, such as the HTLC interceptor, aren't covered.
+
+use std::collections::HashSet;
+use std::future::poll_fn;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+use std::time::Duration;
+
+use futures_util::{SinkExt, Stream, StreamExt};
+use reqwest::header::{HeaderName, HeaderValue, CONNECTION, CONTENT_TYPE, UPGRADE};
+use reqwest::{Method, StatusCode, Upgraded, Url};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio_tungstenite::tungstenite::handshake::client::generate_key;
+use tokio_tungstenite::tungstenite::handshake::derive_accept_key;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::Role;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+use tonic::{Code, Status};
+use zeroize::Zeroizing;
+
+use crate::error::{Error, Result};
+use crate::protos::*;
+use crate::service::ServiceKind;
+
+/// The header LND reads the hex-encoded macaroon from, as for gRPC.
+const MACAROON_HEADER: &str = "grpc-metadata-macaroon";
+
+macro_rules! rest_client {
+    (
+        $(#[$attr:meta])*
+        $client:ident, $feature:literal, $kind:ident, $accessor:ident,
+        $package:ident::$grpc_module:ident::$grpc_client:ident {
+            $(
+                $method:ident: $call:ident $verb:ident $path:literal, $request:ty => $response:ty;
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        #[cfg(feature = $feature)]
+        #[derive(Clone)]
+        pub struct $client {
+            client: RestClient,
+        }
+
+        #[cfg(feature = $feature)]
+        impl $client {
+            $(
+                #[doc = concat!(
+                    "`", stringify!($verb), " ", $path, "`, the REST version of [`",
+                    stringify!($method), "`](", stringify!($package), "::",
+                    stringify!($grpc_module), "::", stringify!($grpc_client), "::",
+                    stringify!($method), ")."
+                )]
+                pub async fn $method(
+                    &mut self,
+                    request: impl tonic::IntoRequest<$request>,
+                ) -> std::result::Result<tonic::Response<rest_client!(@response $call $response)>, Status> {
+                    self.client.$call(Method::$verb, $path, request.into_request()).await
+                }
+            )*
+        }
+
+        impl RestClient {
+            #[doc = concat!("Returns the REST version of [`Client::", stringify!($accessor), "`](crate::Client::", stringify!($accessor), ").")]
+            ///
+            /// # Errors
+            /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+            /// [`ClientBuilder::services`](crate::ClientBuilder::services).
+            #[cfg(feature = $feature)]
+            pub fn $accessor(&self) -> Result<$client> {
+                self.check_enabled(ServiceKind::$kind)?;
+                Ok($client {
+                    client: self.clone(),
+                })
+            }
+        }
+    };
+    (@response unary $response:ty) => { $response };
+    (@response streaming $response:ty) => { Streaming<$response> };
+}
+
+rest_client! {
+    /// The REST version of [`LightningClient`](crate::LightningClient).
+    LightningClient, "lightningrpc", Lightning, lightning,
+    lnrpc::lightning_client::LightningClient {
+        wallet_balance: unary GET "/v1/balance/blockchain",
+            lnrpc::WalletBalanceRequest => lnrpc::WalletBalanceResponse;
+        channel_balance: unary GET "/v1/balance/channels",
+            lnrpc::ChannelBalanceRequest => lnrpc::ChannelBalanceResponse;
+        get_transactions: unary GET "/v1/transactions",
+            lnrpc::GetTransactionsRequest => lnrpc::TransactionDetails;
+        estimate_fee: unary GET "/v1/transactions/fee",
+            lnrpc::EstimateFeeRequest => lnrpc::EstimateFeeResponse;
+        send_coins: unary POST "/v1/transactions",
+            lnrpc::SendCoinsRequest => lnrpc::SendCoinsResponse;
+        list_unspent: unary GET "/v1/utxos",
+            lnrpc::ListUnspentRequest => lnrpc::ListUnspentResponse;
+        subscribe_transactions: streaming GET "/v1/transactions/subscribe",
+            lnrpc::GetTransactionsRequest => lnrpc::Transaction;
+        send_many: unary POST "/v1/transactions/many",
+            lnrpc::SendManyRequest => lnrpc::SendManyResponse;
+        new_address: unary GET "/v1/newaddress",
+            lnrpc::NewAddressRequest => lnrpc::NewAddressResponse;
+        sign_message: unary POST "/v1/signmessage",
+            lnrpc::SignMessageRequest => lnrpc::SignMessageResponse;
+        verify_message: unary POST "/v1/verifymessage",
+            lnrpc::VerifyMessageRequest => lnrpc::VerifyMessageResponse;
+        connect_peer: unary POST "/v1/peers",
+            lnrpc::ConnectPeerRequest => lnrpc::ConnectPeerResponse;
+        disconnect_peer: unary DELETE "/v1/peers/{pub_key}",
+            lnrpc::DisconnectPeerRequest => lnrpc::DisconnectPeerResponse;
+        list_peers: unary GET "/v1/peers",
+            lnrpc::ListPeersRequest => lnrpc::ListPeersResponse;
+        subscribe_peer_events: streaming GET "/v1/peers/subscribe",
+            lnrpc::PeerEventSubscription => lnrpc::PeerEvent;
+        get_info: unary GET "/v1/getinfo",
+            lnrpc::GetInfoRequest => lnrpc::GetInfoResponse;
+        get_debug_info: unary GET "/v1/getdebuginfo",
+            lnrpc::GetDebugInfoRequest => lnrpc::GetDebugInfoResponse;
+        get_recovery_info: unary GET "/v1/getrecoveryinfo",
+            lnrpc::GetRecoveryInfoRequest => lnrpc::GetRecoveryInfoResponse;
+        pending_channels: unary GET "/v1/channels/pending",
+            lnrpc::PendingChannelsRequest => lnrpc::PendingChannelsResponse;
+        list_channels: unary GET "/v1/channels",
+            lnrpc::ListChannelsRequest => lnrpc::ListChannelsResponse;
+        subscribe_channel_events: streaming GET "/v1/channels/subscribe",
+            lnrpc::ChannelEventSubscription => lnrpc::ChannelEventUpdate;
+        closed_channels: unary GET "/v1/channels/closed",
+            lnrpc::ClosedChannelsRequest => lnrpc::ClosedChannelsResponse;
+        open_channel_sync: unary POST "/v1/channels",
+            lnrpc::OpenChannelRequest => lnrpc::ChannelPoint;
+        open_channel: streaming POST "/v1/channels/stream",
+            lnrpc::OpenChannelRequest => lnrpc::OpenStatusUpdate;
+        batch_open_channel: unary POST "/v1/channels/batch",
+            lnrpc::BatchOpenChannelRequest => lnrpc::BatchOpenChannelResponse;
+        funding_state_step: unary POST "/v1/funding/step",
+            lnrpc::FundingTransitionMsg => lnrpc::FundingStateStepResp;
+        close_channel: streaming DELETE
+            "/v1/channels/{channel_point.funding_txid_str}/{channel_point.output_index}",
+            lnrpc::CloseChannelRequest => lnrpc::CloseStatusUpdate;
+        abandon_channel: unary DELETE
+            "/v1/channels/abandon/{channel_point.funding_txid_str}/{channel_point.output_index}",
+            lnrpc::AbandonChannelRequest => lnrpc::AbandonChannelResponse;
+        add_invoice: unary POST "/v1/invoices",
+            lnrpc::Invoice => lnrpc::AddInvoiceResponse;
+        list_invoices: unary GET "/v1/invoices",
+            lnrpc::ListInvoiceRequest => lnrpc::ListInvoiceResponse;
+        lookup_invoice: unary GET "/v1/invoice/{r_hash_str}",
+            lnrpc::PaymentHash => lnrpc::Invoice;
+        subscribe_invoices: streaming GET "/v1/invoices/subscribe",
+            lnrpc::InvoiceSubscription => lnrpc::Invoice;
+        decode_pay_req: unary GET "/v1/payreq/{pay_req}",
+            lnrpc::PayReqString => lnrpc::PayReq;
+        list_payments: unary GET "/v1/payments",
+            lnrpc::ListPaymentsRequest => lnrpc::ListPaymentsResponse;
+        delete_payment: unary DELETE "/v1/payment",
+            lnrpc::DeletePaymentRequest => lnrpc::DeletePaymentResponse;
+        delete_all_payments: unary DELETE "/v1/payments",
+            lnrpc::DeleteAllPaymentsRequest => lnrpc::DeleteAllPaymentsResponse;
+        describe_graph: unary GET "/v1/graph",
+            lnrpc::ChannelGraphRequest => lnrpc::ChannelGraph;
+        get_node_metrics: unary GET "/v1/graph/nodemetrics",
+            lnrpc::NodeMetricsRequest => lnrpc::NodeMetricsResponse;
+        get_chan_info: unary GET "/v1/graph/edge/{chan_id}",
+            lnrpc::ChanInfoRequest => lnrpc::ChannelEdge;
+        get_node_info: unary GET "/v1/graph/node/{pub_key}",
+            lnrpc::NodeInfoRequest => lnrpc::NodeInfo;
+        query_routes: unary GET "/v1/graph/routes/{pub_key}/{amt}",
+            lnrpc::QueryRoutesRequest => lnrpc::QueryRoutesResponse;
+        get_network_info: unary GET "/v1/graph/info",
+            lnrpc::NetworkInfoRequest => lnrpc::NetworkInfo;
+        stop_daemon: unary POST "/v1/stop",
+            lnrpc::StopRequest => lnrpc::StopResponse;
+        subscribe_channel_graph: streaming GET "/v1/graph/subscribe",
+            lnrpc::GraphTopologySubscription => lnrpc::GraphTopologyUpdate;
+        debug_level: unary POST "/v1/debuglevel",
+            lnrpc::DebugLevelRequest => lnrpc::DebugLevelResponse;
+        fee_report: unary GET "/v1/fees",
+            lnrpc::FeeReportRequest => lnrpc::FeeReportResponse;
+        update_channel_policy: unary POST "/v1/chanpolicy",
+            lnrpc::PolicyUpdateRequest => lnrpc::PolicyUpdateResponse;
+        forwarding_history: unary POST "/v1/switch",
+            lnrpc::ForwardingHistoryRequest => lnrpc::ForwardingHistoryResponse;
+        export_channel_backup: unary GET
+            "/v1/channels/backup/{chan_point.funding_txid_str}/{chan_point.output_index}",
+            lnrpc::ExportChannelBackupRequest => lnrpc::ChannelBackup;
+        export_all_channel_backups: unary GET "/v1/channels/backup",
+            lnrpc::ChanBackupExportRequest => lnrpc::ChanBackupSnapshot;
+        verify_chan_backup: unary POST "/v1/channels/backup/verify",
+            lnrpc::ChanBackupSnapshot => lnrpc::VerifyChanBackupResponse;
+        restore_channel_backups: unary POST "/v1/channels/backup/restore",
+            lnrpc::RestoreChanBackupRequest => lnrpc::RestoreBackupResponse;
+        subscribe_channel_backups: streaming GET "/v1/channels/backup/subscribe",
+            lnrpc::ChannelBackupSubscription => lnrpc::ChanBackupSnapshot;
+        bake_macaroon: unary POST "/v1/macaroon",
+            lnrpc::BakeMacaroonRequest => lnrpc::BakeMacaroonResponse;
+        list_macaroon_i_ds: unary GET "/v1/macaroon/ids",
+            lnrpc::ListMacaroonIDsRequest => lnrpc::ListMacaroonIDsResponse;
+        delete_macaroon_id: unary DELETE "/v1/macaroon/{root_key_id}",
+            lnrpc::DeleteMacaroonIdRequest => lnrpc::DeleteMacaroonIdResponse;
+        list_permissions: unary GET "/v1/macaroon/permissions",
+            lnrpc::ListPermissionsRequest => lnrpc::ListPermissionsResponse;
+        check_macaroon_permissions: unary POST "/v1/macaroon/checkpermissions",
+            lnrpc::CheckMacPermRequest => lnrpc::CheckMacPermResponse;
+        send_custom_message: unary POST "/v1/custommessage",
+            lnrpc::SendCustomMessageRequest => lnrpc::SendCustomMessageResponse;
+        subscribe_custom_messages: streaming GET "/v1/custommessage/subscribe",
+            lnrpc::SubscribeCustomMessagesRequest => lnrpc::CustomMessage;
+        list_aliases: unary GET "/v1/aliases/list",
+            lnrpc::ListAliasesRequest => lnrpc::ListAliasesResponse;
+        lookup_htlc_resolution: unary GET "/v1/htlc-resolution/{chan_id}/{htlc_index}",
+            lnrpc::LookupHtlcResolutionRequest => lnrpc::LookupHtlcResolutionResponse;
+    }
+}
+
+rest_client! {
+    /// The REST version of [`RouterClient`](crate::RouterClient).
+    RouterClient, "routerrpc", Router, router,
+    routerrpc::router_client::RouterClient {
+        send_payment_v2: streaming POST "/v2/router/send",
+            routerrpc::SendPaymentRequest => lnrpc::Payment;
+        track_payment_v2: streaming GET "/v2/router/track/{payment_hash}",
+            routerrpc::TrackPaymentRequest => lnrpc::Payment;
+        track_payments: streaming GET "/v2/router/payments",
+            routerrpc::TrackPaymentsRequest => lnrpc::Payment;
+        estimate_route_fee: unary POST "/v2/router/route/estimatefee",
+            routerrpc::RouteFeeRequest => routerrpc::RouteFeeResponse;
+        send_to_route_v2: unary POST "/v2/router/route/send",
+            routerrpc::SendToRouteRequest => lnrpc::HtlcAttempt;
+        reset_mission_control: unary POST "/v2/router/mc/reset",
+            routerrpc::ResetMissionControlRequest => routerrpc::ResetMissionControlResponse;
+        query_mission_control: unary GET "/v2/router/mc",
+            routerrpc::QueryMissionControlRequest => routerrpc::QueryMissionControlResponse;
+        x_import_mission_control: unary POST "/v2/router/x/importhistory",
+            routerrpc::XImportMissionControlRequest => routerrpc::XImportMissionControlResponse;
+        get_mission_control_config: unary GET "/v2/router/mccfg",
+            routerrpc::GetMissionControlConfigRequest => routerrpc::GetMissionControlConfigResponse;
+        set_mission_control_config: unary POST "/v2/router/mccfg",
+            routerrpc::SetMissionControlConfigRequest => routerrpc::SetMissionControlConfigResponse;
+        query_probability: unary GET "/v2/router/mc/probability/{from_node}/{to_node}/{amt_msat}",
+            routerrpc::QueryProbabilityRequest => routerrpc::QueryProbabilityResponse;
+        build_route: unary POST "/v2/router/route",
+            routerrpc::BuildRouteRequest => routerrpc::BuildRouteResponse;
+        subscribe_htlc_events: streaming GET "/v2/router/htlcevents",
+            routerrpc::SubscribeHtlcEventsRequest => routerrpc::HtlcEvent;
+        update_chan_status: unary POST "/v2/router/updatechanstatus",
+            routerrpc::UpdateChanStatusRequest => routerrpc::UpdateChanStatusResponse;
+        x_add_local_chan_aliases: unary POST "/v2/router/x/addaliases",
+            routerrpc::AddAliasesRequest => routerrpc::AddAliasesResponse;
+        x_delete_local_chan_aliases: unary POST "/v2/router/x/deletealiases",
+            routerrpc::DeleteAliasesRequest => routerrpc::DeleteAliasesResponse;
+    }
+}
+
+rest_client! {
+    /// The REST version of [`InvoicesClient`](crate::InvoicesClient).
+    InvoicesClient, "invoicesrpc", Invoices, invoices,
+    invoicesrpc::invoices_client::InvoicesClient {
+        subscribe_single_invoice: streaming GET "/v2/invoices/subscribe/{r_hash}",
+            invoicesrpc::SubscribeSingleInvoiceRequest => lnrpc::Invoice;
+        cancel_invoice: unary POST "/v2/invoices/cancel",
+            invoicesrpc::CancelInvoiceMsg => invoicesrpc::CancelInvoiceResp;
+        add_hold_invoice: unary POST "/v2/invoices/hodl",
+            invoicesrpc::AddHoldInvoiceRequest => invoicesrpc::AddHoldInvoiceResp;
+        settle_invoice: unary POST "/v2/invoices/settle",
+            invoicesrpc::SettleInvoiceMsg => invoicesrpc::SettleInvoiceResp;
+        lookup_invoice_v2: unary GET "/v2/invoices/lookup",
+            invoicesrpc::LookupInvoiceMsg => lnrpc::Invoice;
+    }
+}
+
+rest_client! {
+    /// The REST version of [`WalletKitClient`](crate::WalletKitClient).
+    WalletKitClient, "walletrpc", WalletKit, wallet,
+    walletrpc::wallet_kit_client::WalletKitClient {
+        list_unspent: unary POST "/v2/wallet/utxos",
+            walletrpc::ListUnspentRequest => walletrpc::ListUnspentResponse;
+        lease_output: unary POST "/v2/wallet/utxos/lease",
+            walletrpc::LeaseOutputRequest => walletrpc::LeaseOutputResponse;
+        release_output: unary POST "/v2/wallet/utxos/release",
+            walletrpc::ReleaseOutputRequest => walletrpc::ReleaseOutputResponse;
+        list_leases: unary POST "/v2/wallet/utxos/leases",
+            walletrpc::ListLeasesRequest => walletrpc::ListLeasesResponse;
+        derive_next_key: unary POST "/v2/wallet/key/next",
+            walletrpc::KeyReq => signrpc::KeyDescriptor;
+        derive_key: unary POST "/v2/wallet/key",
+            signrpc::KeyLocator => signrpc::KeyDescriptor;
+        next_addr: unary POST "/v2/wallet/address/next",
+            walletrpc::AddrRequest => walletrpc::AddrResponse;
+        get_transaction: unary GET "/v2/wallet/tx",
+            walletrpc::GetTransactionRequest => lnrpc::Transaction;
+        list_accounts: unary GET "/v2/wallet/accounts",
+            walletrpc::ListAccountsRequest => walletrpc::ListAccountsResponse;
+        required_reserve: unary GET "/v2/wallet/reserve",
+            walletrpc::RequiredReserveRequest => walletrpc::RequiredReserveResponse;
+        list_addresses: unary GET "/v2/wallet/addresses",
+            walletrpc::ListAddressesRequest => walletrpc::ListAddressesResponse;
+        sign_message_with_addr: unary POST "/v2/wallet/address/signmessage",
+            walletrpc::SignMessageWithAddrRequest => walletrpc::SignMessageWithAddrResponse;
+        verify_message_with_addr: unary POST "/v2/wallet/address/verifymessage",
+            walletrpc::VerifyMessageWithAddrRequest => walletrpc::VerifyMessageWithAddrResponse;
+        import_account: unary POST "/v2/wallet/accounts/import",
+            walletrpc::ImportAccountRequest => walletrpc::ImportAccountResponse;
+        import_public_key: unary POST "/v2/wallet/key/import",
+            walletrpc::ImportPublicKeyRequest => walletrpc::ImportPublicKeyResponse;
+        import_tapscript: unary POST "/v2/wallet/tapscript/import",
+            walletrpc::ImportTapscriptRequest => walletrpc::ImportTapscriptResponse;
+        publish_transaction: unary POST "/v2/wallet/tx",
+            walletrpc::Transaction => walletrpc::PublishResponse;
+        remove_transaction: unary POST "/v2/wallet/removetx",
+            walletrpc::GetTransactionRequest => walletrpc::RemoveTransactionResponse;
+        send_outputs: unary POST "/v2/wallet/send",
+            walletrpc::SendOutputsRequest => walletrpc::SendOutputsResponse;
+        estimate_fee: unary GET "/v2/wallet/estimatefee/{conf_target}",
+            walletrpc::EstimateFeeRequest => walletrpc::EstimateFeeResponse;
+        pending_sweeps: unary GET "/v2/wallet/sweeps/pending",
+            walletrpc::PendingSweepsRequest => walletrpc::PendingSweepsResponse;
+        bump_fee: unary POST "/v2/wallet/bumpfee",
+            walletrpc::BumpFeeRequest => walletrpc::BumpFeeResponse;
+        bump_force_close_fee: unary POST "/v2/wallet/BumpForceCloseFee",
+            walletrpc::BumpForceCloseFeeRequest => walletrpc::BumpForceCloseFeeResponse;
+        list_sweeps: unary GET "/v2/wallet/sweeps",
+            walletrpc::ListSweepsRequest => walletrpc::ListSweepsResponse;
+        label_transaction: unary POST "/v2/wallet/tx/label",
+            walletrpc::LabelTransactionRequest => walletrpc::LabelTransactionResponse;
+        fund_psbt: unary POST "/v2/wallet/psbt/fund",
+            walletrpc::FundPsbtRequest => walletrpc::FundPsbtResponse;
+        sign_psbt: unary POST "/v2/wallet/psbt/sign",
+            walletrpc::SignPsbtRequest => walletrpc::SignPsbtResponse;
+        finalize_psbt: unary POST "/v2/wallet/psbt/finalize",
+            walletrpc::FinalizePsbtRequest => walletrpc::FinalizePsbtResponse;
+    }
+}
+
+/// A client for LND's REST API, built with
+/// [`ClientBuilder::build_rest`](crate::ClientBuilder::build_rest).
+///
+/// Its accessors mirror those of [`Client`](crate::Client) for the services it covers. Clones
+/// share the same connections.
+#[derive(Clone)]
+pub struct RestClient {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    http: reqwest::Client,
+    base: Url,
+    macaroon: Zeroizing<String>,
+    timeout: Option<Duration>,
+    services: Option<HashSet<ServiceKind>>,
+}
+
+impl RestClient {
+    pub(crate) fn new(
+        http: reqwest::Client,
+        base: Url,
+        macaroon: Zeroizing<String>,
+        timeout: Option<Duration>,
+        services: Option<HashSet<ServiceKind>>,
+    ) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                http,
+                base,
+                macaroon,
+                timeout,
+                services,
+            }),
+        }
+    }
+
+    fn check_enabled(&self, service: ServiceKind) -> Result<()> {
+        match &self.inner.services {
+            Some(services) if !services.contains(&service) => Err(Error::ServiceDisabled(service)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Sends `request` to `path` and returns the response.
+    async fn unary<Req: Serialize, Res: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        request: tonic::Request<Req>,
+    ) -> std::result::Result<tonic::Response<Res>, Status> {
+        let (request, body) = self.request(method, path, request)?;
+        let mut request = match body {
+            Some(body) => request.header(CONTENT_TYPE, "application/json").body(body.to_string()),
+            None => request,
+        };
+        if let Some(timeout) = self.inner.timeout {
+            request = request.timeout(timeout);
+        }
+
+        let response = request.send().await.map_err(transport_status)?;
+        let status = response.status();
+        let body = response.bytes().await.map_err(transport_status)?;
+        if !status.is_success() {
+            return Err(error_status(status, &body));
+        }
+        serde_json::from_slice(&body)
+            .map(tonic::Response::new)
+            .map_err(|error| Status::internal(format!("failed to decode response: {error}")))
+    }
+
+    /// Opens a WebSocket to `path`, sends `request` and returns the stream of responses.
+    async fn streaming<Req: Serialize, Res: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        request: tonic::Request<Req>,
+    ) -> std::result::Result<tonic::Response<Streaming<Res>>, Status> {
+        // WebSockets always open with a GET, LND's proxy takes the method of the RPC from the
+        // query instead.
+        let (request, body) = self.request(method.clone(), path, request)?;
+        let mut request = request.build().map_err(transport_status)?;
+        *request.method_mut() = Method::GET;
+        if method != Method::GET {
+            request.url_mut().query_pairs_mut().append_pair("method", method.as_str());
+        }
+        let key = generate_key();
+        let headers = request.headers_mut();
+        headers.insert(CONNECTION, HeaderValue::from_static("upgrade"));
+        headers.insert(UPGRADE, HeaderValue::from_static("websocket"));
+        headers.insert("sec-websocket-version", HeaderValue::from_static("13"));
+        headers.insert("sec-websocket-key", HeaderValue::try_from(&key).expect("keys are base64"));
+
+        let response = self.inner.http.execute(request).await.map_err(transport_status)?;
+        if response.status() != StatusCode::SWITCHING_PROTOCOLS {
+            let status = response.status();
+            let body = response.bytes().await.map_err(transport_status)?;
+            return Err(error_status(status, &body));
+        }
+        let accept = response.headers().get("sec-websocket-accept");
+        if accept.map_or(true, |accept| *accept != derive_accept_key(key.as_bytes())) {
+            return Err(Status::unavailable("invalid WebSocket handshake"));
+        }
+        let upgraded = response.upgrade().await.map_err(transport_status)?;
+        let mut socket = WebSocketStream::from_raw_socket(upgraded, Role::Client, None).await;
+        if let Some(body) = body {
+            socket.send(Message::text(body.to_string())).await.map_err(websocket_status)?;
+        }
+        Ok(tonic::Response::new(Streaming {
+            socket,
+            done: false,
+            _message: PhantomData,
+        }))
+    }
+
+    /// Prepares the HTTP request for `request`, returning the JSON body to send with it, if the
+    /// method has one.
+    ///
+    /// Fields in `path` (`{field}`) are filled in from the request. The remaining fields are
+    /// sent as the body, or for `GET` and `DELETE` as the query.
+    fn request<Req: Serialize>(
+        &self,
+        method: Method,
+        path: &str,
+        request: tonic::Request<Req>,
+    ) -> std::result::Result<(reqwest::RequestBuilder, Option<Value>), Status> {
+        let (metadata, _, message) = request.into_parts();
+        let mut message = serde_json::to_value(&message).map_err(|error| {
+            Status::invalid_argument(format!("failed to encode request: {error}"))
+        })?;
+
+        let mut url = self.inner.base.clone();
+        {
+            let mut segments = url
+                .path_segments_mut()
+                .map_err(|_| Status::invalid_argument("address can't have a path"))?;
+            segments.pop_if_empty();
+            for segment in path.split('/').skip(1) {
+                match segment.strip_prefix('{').and_then(|field| field.strip_suffix('}')) {
+                    Some(field) => segments.push(&path_parameter(&mut message, field)?),
+                    None => segments.push(segment),
+                };
+            }
+        }
+        let body = match method {
+            Method::GET | Method::DELETE => {
+                let mut query = Vec::new();
+                query_parameters(&mut query, String::new(), message);
+                if !query.is_empty() {
+                    url.query_pairs_mut().extend_pairs(query);
+                }
+                None
+            }
+            _ => Some(message),
+        };
+
+        let mut request = self
+            .inner
+            .http
+            .request(method, url)
+            .header(MACAROON_HEADER, self.inner.macaroon.as_str());
+        // Like grpc-gateway, pass other metadata as `Grpc-Metadata-` headers.
+        for (name, value) in metadata.into_headers().iter() {
+            if let Ok(name) = HeaderName::try_from(format!("grpc-metadata-{name}")) {
+                request = request.header(name, value);
+            }
+        }
+        Ok((request, body))
+    }
+}
+
+/// Takes the value of `field` (`a.b` for nested messages) out of `message` for the path.
+///
+/// Bytes are written as URL-safe base64, which LND expects in paths. The other values of path
+/// parameters (hex, numbers) don't contain the characters this changes.
+fn path_parameter(message: &mut Value, field: &str) -> std::result::Result<String, Status> {
+    let missing = || Status::invalid_argument(format!("missing `{field}`, which is in the path"));
+    let (parent, name) = match field.rsplit_once('.') {
+        Some((parent, name)) => {
+            (parent.split('.').try_fold(message, |value, name| value.get_mut(name)), name)
+        }
+        None => (Some(message), field),
+    };
+    let value =
+        parent.and_then(|parent| parent.as_object_mut()?.remove(name)).ok_or_else(missing)?;
+    match value {
+        Value::String(value) => Ok(value.replace('+', "-").replace('/', "_")),
+        Value::Number(value) => Ok(value.to_string()),
+        Value::Bool(value) => Ok(value.to_string()),
+        _ => Err(missing()),
+    }
+}
+
+/// Appends the fields of `value` to `query` the way grpc-gateway reads them: nested fields
+/// joined with dots and repeated fields as repeated parameters. Default values are left out,
+/// except for 64-bit integers: their JSON is a string, and `"0"` could as well be a string field.
+fn query_parameters(query: &mut Vec<(String, String)>, name: String, value: Value) {
+    match value {
+        Value::Null | Value::Bool(false) => {}
+        Value::String(value) if value.is_empty() => {}
+        Value::Number(value) if value.as_f64() == Some(0.0) => {}
+        Value::Bool(true) => query.push((name, "true".to_owned())),
+        Value::Number(value) => query.push((name, value.to_string())),
+        Value::String(value) => query.push((name, value)),
+        Value::Array(values) => {
+            for value in values {
+                query_parameters(query, name.clone(), value);
+            }
+        }
+        Value::Object(fields) => {
+            for (field, value) in fields {
+                let name = match name.is_empty() {
+                    true => field,
+                    false => format!("{name}.{field}"),
+                };
+                query_parameters(query, name, value);
+            }
+        }
+    }
+}
+
+/// The JSON of the errors LND returns, the `google.rpc.Status` of the failed call.
+#[derive(Deserialize)]
+struct RestError {
+    #[serde(default)]
+    code: i32,
+    #[serde(default)]
+    message: String,
+}
+
+impl From<RestError> for Status {
+    fn from(error: RestError) -> Self {
+        Status::new(Code::from_i32(error.code), error.message)
+    }
+}
+
+/// Returns the status for an HTTP error response, from its body or otherwise its status code.
+fn error_status(status: StatusCode, body: &[u8]) -> Status {
+    if let Ok(error) = serde_json::from_slice::<RestError>(body) {
+        if error.code != 0 {
+            return error.into();
+        }
+    }
+    let code = match status {
+        StatusCode::BAD_REQUEST => Code::InvalidArgument,
+        StatusCode::UNAUTHORIZED => Code::Unauthenticated,
+        StatusCode::FORBIDDEN => Code::PermissionDenied,
+        StatusCode::NOT_FOUND => Code::NotFound,
+        StatusCode::CONFLICT => Code::AlreadyExists,
+        StatusCode::TOO_MANY_REQUESTS => Code::ResourceExhausted,
+        StatusCode::NOT_IMPLEMENTED => Code::Unimplemented,
+        StatusCode::SERVICE_UNAVAILABLE => Code::Unavailable,
+        StatusCode::GATEWAY_TIMEOUT => Code::DeadlineExceeded,
+        _ => Code::Unknown,
+    };
+    Status::new(code, format!("{status}: {}", String::from_utf8_lossy(body)))
+}
+
+fn transport_status(error: reqwest::Error) -> Status {
+    match error.is_timeout() {
+        true => Status::deadline_exceeded(error.to_string()),
+        false => Status::unavailable(error.to_string()),
+    }
+}
+
+fn websocket_status(error: tokio_tungstenite::tungstenite::Error) -> Status {
+    Status::unavailable(format!("WebSocket error: {error}"))
+}
+
+/// The messages LND sends on the WebSocket of a streaming RPC.
+#[derive(Deserialize)]
+#[serde(bound = "T: DeserializeOwned")]
+struct StreamMessage<T> {
+    result: Option<T>,
+    error: Option<RestError>,
+}
+
+/// A stream of responses read from a WebSocket, returned by the RPCs that stream.
+///
+/// It ends when LND closes the WebSocket, and after the first error.
+pub struct Streaming<T> {
+    socket: WebSocketStream<Upgraded>,
+    done: bool,
+    _message: PhantomData<fn() -> T>,
+}
+
+impl<T: DeserializeOwned> Streaming<T> {
+    /// Returns the next message, or `None` once the stream ended.
+    ///
+    /// # Errors
+    /// Returns the status the stream failed with.
+    pub async fn message(&mut self) -> std::result::Result<Option<T>, Status> {
+        poll_fn(|cx| self.poll_next_unpin(cx)).await.transpose()
+    }
+}
+
+impl<T: DeserializeOwned> Stream for Streaming<T> {
+    type Item = std::result::Result<T, Status>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if self.done {
+                return Poll::Ready(None);
+            }
+            let message = match ready!(self.socket.poll_next_unpin(cx)) {
+                Some(Ok(Message::Text(text))) => serde_json::from_str::<StreamMessage<T>>(&text),
+                Some(Ok(Message::Binary(binary))) => {
+                    serde_json::from_slice::<StreamMessage<T>>(&binary)
+                }
+                Some(Ok(Message::Close(frame)))
+                    if frame.as_ref().is_some_and(|frame| frame.code != CloseCode::Normal) =>
+                {
+                    self.done = true;
+                    let frame = frame.expect("checked above");
+                    return Poll::Ready(Some(Err(Status::unavailable(format!(
+                        "WebSocket closed: {} {}",
+                        u16::from(frame.code),
+                        frame.reason
+                    )))));
+                }
+                Some(Ok(Message::Close(_))) | None => {
+                    self.done = true;
+                    return Poll::Ready(None);
+                }
+                Some(Ok(_)) => continue,
+                Some(Err(error)) => {
+                    self.done = true;
+                    return Poll::Ready(Some(Err(websocket_status(error))));
+                }
+            };
+            let result = match message {
+                Ok(StreamMessage {
+                    error: Some(error),
+                    ..
+                }) => Err(error.into()),
+                Ok(StreamMessage {
+                    result: Some(result),
+                    ..
+                }) => Ok(result),
+                Ok(_) => Err(Status::internal("stream message without result or error")),
+                Err(error) => Err(Status::internal(format!("failed to decode response: {error}"))),
+            };
+            self.done = result.is_err();
+            return Poll::Ready(Some(result));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const TXID: &str = "2b2ba6b6bb4d9bd6df2fd2c4b7f5a1e5c4f0d5b0b3c1d2e3f4a5b6c7d8e9f0a1";
+
+    /// Returns the HTTP request and the JSON body that `request` is sent as.
+    fn build<Req: Serialize>(
+        method: Method,
+        path: &str,
+        request: tonic::Request<Req>,
+    ) -> std::result::Result<(reqwest::Request, Option<Value>), Status> {
+        let client = RestClient::new(
+            reqwest::Client::new(),
+            Url::parse("https://localhost:8080").unwrap(),
+            Zeroizing::new("0201".to_owned()),
+            None,
+            None,
+        );
+        let (request, body) = client.request(method, path, request)?;
+        Ok((request.build().unwrap(), body))
+    }
+
+    fn query(request: &reqwest::Request) -> Vec<(String, String)> {
+        let mut query: Vec<_> = request.url().query_pairs().into_owned().collect();
+        query.sort();
+        query
+    }
+
+    #[test]
+    fn path_and_query() {
+        let request = lnrpc::QueryRoutesRequest {
+            pub_key: "02ab".to_owned(),
+            amt: 1000,
+            final_cltv_delta: 40,
+            use_mission_control: true,
+            ..Default::default()
+        };
+        let (request, body) =
+            build(Method::GET, "/v1/graph/routes/{pub_key}/{amt}", tonic::Request::new(request))
+                .unwrap();
+
+        assert_eq!(request.url().path(), "/v1/graph/routes/02ab/1000");
+        assert_eq!(
+            query(&request),
+            [
+                ("amt_msat".to_owned(), "0".to_owned()),
+                ("final_cltv_delta".to_owned(), "40".to_owned()),
+                ("use_mission_control".to_owned(), "true".to_owned()),
+            ]
+        );
+        assert_eq!(body, None);
+        assert_eq!(request.headers()[MACAROON_HEADER], "0201");
+    }
+
+    #[test]
+    fn nested_path_fields() {
+        let channel_point = lnrpc::ChannelPoint {
+            funding_txid: Some(lnrpc::channel_point::FundingTxid::FundingTxidStr(TXID.into())),
+            output_index: 0,
+        };
+        let request = lnrpc::CloseChannelRequest {
+            channel_point: Some(channel_point),
+            force: true,
+            ..Default::default()
+        };
+        let path = "/v1/channels/{channel_point.funding_txid_str}/{channel_point.output_index}";
+        let (request, _) = build(Method::DELETE, path, tonic::Request::new(request)).unwrap();
+
+        assert_eq!(request.url().path(), format!("/v1/channels/{TXID}/0"));
+        assert!(query(&request).contains(&("force".to_owned(), "true".to_owned())));
+        assert!(!query(&request).iter().any(|(name, _)| name.starts_with("channel_point")));
+
+        let missing = lnrpc::CloseChannelRequest::default();
+        let error = build(Method::DELETE, path, tonic::Request::new(missing)).unwrap_err();
+        assert_eq!(error.code(), Code::InvalidArgument);
+    }
+
+    #[test]
+    fn body() {
+        let invoice = lnrpc::Invoice {
+            memo: "coffee".to_owned(),
+            value: 1000,
+            ..Default::default()
+        };
+        let mut request = tonic::Request::new(invoice);
+        request.metadata_mut().insert("x-request-id", "42".parse().unwrap());
+        let (request, body) = build(Method::POST, "/v1/invoices", request).unwrap();
+
+        assert_eq!(request.url().path(), "/v1/invoices");
+        assert_eq!(request.url().query(), None);
+        let body = body.unwrap();
+        assert_eq!(body["memo"], "coffee");
+        assert_eq!(body["value"], "1000");
+        assert_eq!(request.headers()["grpc-metadata-x-request-id"], "42");
+    }
+
+    #[test]
+    fn path_parameters() {
+        let mut message = json!({ "hash": "ab+/cd==", "nested": { "index": 7 }, "list": [] });
+        assert_eq!(path_parameter(&mut message, "hash").unwrap(), "ab-_cd==");
+        assert_eq!(path_parameter(&mut message, "nested.index").unwrap(), "7");
+        // Taken out of the message, so they aren't sent again in the query.
+        assert_eq!(message, json!({ "nested": {}, "list": [] }));
+
+        for missing in ["hash", "list", "nested.other", "other.index"] {
+            assert!(path_parameter(&mut message, missing).is_err(), "{missing}");
+        }
+    }
+
+    #[test]
+    fn query_parameter_names() {
+        let mut query = Vec::new();
+        let message = json!({
+            "nested": { "amount": "5", "memo": "", "inner": { "flag": true } },
+            "list": ["a", "b"],
+            "zero": 0,
+            "off": false,
+            "none": null,
+        });
+        query_parameters(&mut query, String::new(), message);
+        query.sort();
+
+        let expected =
+            [("list", "a"), ("list", "b"), ("nested.amount", "5"), ("nested.inner.flag", "true")];
+        let expected: Vec<_> =
+            expected.map(|(name, value)| (name.to_owned(), value.to_owned())).into();
+        assert_eq!(query, expected);
+    }
+
+    #[test]
+    fn error_statuses() {
+        let body = br#"{"code": 5, "message": "unable to locate invoice"}"#;
+        let status = error_status(StatusCode::NOT_FOUND, body);
+        assert_eq!((status.code(), status.message()), (Code::NotFound, "unable to locate invoice"));
+
+        // The code of the body wins over the HTTP status.
+        let status = error_status(StatusCode::INTERNAL_SERVER_ERROR, br#"{"code": 9}"#);
+        assert_eq!(status.code(), Code::FailedPrecondition);
+
+        let status = error_status(StatusCode::SERVICE_UNAVAILABLE, b"upstream down");
+        assert_eq!(status.code(), Code::Unavailable);
+        assert!(status.message().contains("upstream down"));
+
+        let status = error_status(StatusCode::UNAUTHORIZED, br#"{"code": 0}"#);
+        assert_eq!(status.code(), Code::Unauthenticated);
+        assert_eq!(error_status(StatusCode::IM_A_TEAPOT, b"").code(), Code::Unknown);
+    }
+}
@@ -0,0 +1,320 @@
+//! Conversions used by the serde attributes on the generated types.
+//!
+//! The JSON follows LND's REST proxy and `lncli`: 64-bit integers are strings, enums are names
+//! and bytes are base64, or hex within [`Hex`](crate::serde_hex::Hex). Numbers are accepted for
+//! integers and enums as well. For formats that are not human readable, integers, enums and bytes are
+//! written as they are.
+
+#[cfg(services)]
+use std::collections::HashMap;
+use std::fmt;
+#[cfg(services)]
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+#[cfg(services)]
+use serde::de::IntoDeserializer;
+use serde::de::{self, DeserializeOwned, Visitor};
+#[cfg(services)]
+use serde::ser::{SerializeMap, SerializeSeq};
+#[cfg(services)]
+use serde::Deserialize;
+use serde::{Deserializer, Serialize, Serializer};
+
+#[cfg(services)]
+use crate::serde_hex::hex_bytes;
+
+/// Serializes a value of type `T` with codec `C`.
+pub(crate) fn serialize<C: Codec<T>, T, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    C::serialize(value, serializer)
+}
+
+/// Deserializes a value of type `T` with codec `C`.
+pub(crate) fn deserialize<'de, C: Codec<T>, T, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    C::deserialize(deserializer)
+}
+
+/// A way to serialize and deserialize `T` other than its own `Serialize` and `Deserialize`.
+pub(crate) trait Codec<T> {
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error>;
+
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error>;
+}
+
+/// `bytes` fields, as `Vec<u8>` or, with the `bytes` feature, `Bytes`.
+#[cfg(services)]
+pub(crate) struct Bytes;
+
+#[cfg(services)]
+impl<T: AsRef<[u8]> + From<Vec<u8>>> Codec<T> for Bytes {
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&encode_bytes(value.as_ref(), hex_bytes()))
+        } else {
+            serializer.serialize_bytes(value.as_ref())
+        }
+    }
+
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        if deserializer.is_human_readable() {
+            let hex = hex_bytes();
+            let encoded = String::deserialize(deserializer)?;
+            decode_bytes(&encoded, hex).map(T::from).ok_or_else(|| {
+                let expected = match hex {
+                    true => "hex encoded bytes",
+                    false => "base64 encoded bytes",
+                };
+                de::Error::invalid_value(de::Unexpected::Str(&encoded), &expected)
+            })
+        } else {
+            byte_buf(deserializer).map(T::from)
+        }
+    }
+}
+
+#[cfg(services)]
+fn encode_bytes(bytes: &[u8], hex: bool) -> String {
+    use base64::Engine;
+
+    match hex {
+        true => hex::encode(bytes),
+        false => base64::engine::general_purpose::STANDARD.encode(bytes),
+    }
+}
+
+#[cfg(services)]
+fn decode_bytes(encoded: &str, hex: bool) -> Option<Vec<u8>> {
+    use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig};
+    use base64::engine::DecodePaddingMode;
+    use base64::{alphabet, Engine};
+
+    // Like protojson, accept both alphabets, with or without padding.
+    const CONFIG: GeneralPurposeConfig =
+        GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent);
+    const STANDARD: GeneralPurpose = GeneralPurpose::new(&alphabet::STANDARD, CONFIG);
+    const URL_SAFE: GeneralPurpose = GeneralPurpose::new(&alphabet::URL_SAFE, CONFIG);
+
+    match hex {
+        true => hex::decode(encoded).ok(),
+        false => STANDARD.decode(encoded).or_else(|_| URL_SAFE.decode(encoded)).ok(),
+    }
+}
+
+#[cfg(services)]
+fn byte_buf<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    struct BytesVisitor;
+
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("bytes")
+        }
+
+        fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Vec<u8>, E> {
+            Ok(bytes.to_vec())
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, bytes: Vec<u8>) -> Result<Vec<u8>, E> {
+            Ok(bytes)
+        }
+
+        fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+            Ok(bytes)
+        }
+    }
+
+    deserializer.deserialize_byte_buf(BytesVisitor)
+}
+
+/// `int64`, `uint64` and the other 64-bit integer fields, which JSON numbers can't hold exactly.
+pub(crate) struct Int64;
+
+impl<T> Codec<T> for Int64
+where
+    T: Copy + fmt::Display + FromStr + TryFrom<i64> + TryFrom<u64> + Serialize + DeserializeOwned,
+{
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(value)
+        } else {
+            value.serialize(serializer)
+        }
+    }
+
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        struct Int64Visitor<T>(PhantomData<T>);
+
+        impl<T: FromStr + TryFrom<i64> + TryFrom<u64>> Visitor<'_> for Int64Visitor<T> {
+            type Value = T;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a 64-bit integer or a string holding one")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+                value.parse().map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<T, E> {
+                T::try_from(value)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<T, E> {
+                T::try_from(value)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(Int64Visitor(PhantomData))
+        } else {
+            T::deserialize(deserializer)
+        }
+    }
+}
+
+/// Enum fields, which prost stores as `i32`. Values that `E` doesn't know are kept as numbers.
+#[cfg(services)]
+pub(crate) struct Enum<E>(PhantomData<E>);
+
+#[cfg(services)]
+impl<E> Codec<i32> for Enum<E>
+where
+    E: TryFrom<i32> + Into<i32> + Serialize + DeserializeOwned,
+{
+    fn serialize<S: Serializer>(value: &i32, serializer: S) -> Result<S::Ok, S::Error> {
+        match E::try_from(*value) {
+            Ok(known) if serializer.is_human_readable() => known.serialize(serializer),
+            _ => serializer.serialize_i32(*value),
+        }
+    }
+
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i32, D::Error> {
+        struct EnumVisitor<E>(PhantomData<E>);
+
+        impl<E: Into<i32> + DeserializeOwned> Visitor<'_> for EnumVisitor<E> {
+            type Value = i32;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an enum value name or number")
+            }
+
+            fn visit_str<Err: de::Error>(self, value: &str) -> Result<i32, Err> {
+                E::deserialize(value.into_deserializer()).map(Into::into)
+            }
+
+            fn visit_i64<Err: de::Error>(self, value: i64) -> Result<i32, Err> {
+                i32::try_from(value)
+                    .map_err(|_| Err::invalid_value(de::Unexpected::Signed(value), &self))
+            }
+
+            fn visit_u64<Err: de::Error>(self, value: u64) -> Result<i32, Err> {
+                i32::try_from(value)
+                    .map_err(|_| Err::invalid_value(de::Unexpected::Unsigned(value), &self))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(EnumVisitor::<E>(PhantomData))
+        } else {
+            i32::deserialize(deserializer)
+        }
+    }
+}
+
+/// `repeated` fields whose elements use codec `C`.
+#[cfg(services)]
+pub(crate) struct Repeated<C>(PhantomData<C>);
+
+#[cfg(services)]
+impl<C: Codec<T>, T> Codec<Vec<T>> for Repeated<C> {
+    fn serialize<S: Serializer>(values: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(values.len()))?;
+        for value in values {
+            seq.serialize_element(&With::<C, _>(value, PhantomData))?;
+        }
+        seq.end()
+    }
+
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<T>, D::Error> {
+        let values = Vec::<Owned<C, T>>::deserialize(deserializer)?;
+        Ok(values.into_iter().map(|value| value.0).collect())
+    }
+}
+
+/// `optional` fields whose value uses codec `C`.
+#[cfg(services)]
+pub(crate) struct Optional<C>(PhantomData<C>);
+
+#[cfg(services)]
+impl<C: Codec<T>, T> Codec<Option<T>> for Optional<C> {
+    fn serialize<S: Serializer>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.serialize_some(&With::<C, _>(value, PhantomData)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<T>, D::Error> {
+        let value = Option::<Owned<C, T>>::deserialize(deserializer)?;
+        Ok(value.map(|value| value.0))
+    }
+}
+
+/// `map` fields whose values use codec `C`.
+#[cfg(services)]
+pub(crate) struct Map<C>(PhantomData<C>);
+
+#[cfg(services)]
+impl<C, K, V> Codec<HashMap<K, V>> for Map<C>
+where
+    C: Codec<V>,
+    K: Eq + Hash + Serialize + DeserializeOwned,
+{
+    fn serialize<S: Serializer>(values: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(values.len()))?;
+        for (key, value) in values {
+            map.serialize_entry(key, &With::<C, _>(value, PhantomData))?;
+        }
+        map.end()
+    }
+
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HashMap<K, V>, D::Error> {
+        let values = HashMap::<K, Owned<C, V>>::deserialize(deserializer)?;
+        Ok(values.into_iter().map(|(key, value)| (key, value.0)).collect())
+    }
+}
+
+/// Serializes a borrowed `T` with codec `C`.
+#[cfg(services)]
+struct With<'a, C, T>(&'a T, PhantomData<C>);
+
+#[cfg(services)]
+impl<C: Codec<T>, T> Serialize for With<'_, C, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        C::serialize(self.0, serializer)
+    }
+}
+
+/// Deserializes a `T` with codec `C`.
+#[cfg(services)]
+struct Owned<C, T>(T, PhantomData<C>);
+
+#[cfg(services)]
+impl<'de, C: Codec<T>, T> Deserialize<'de> for Owned<C, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        C::deserialize(deserializer).map(|value| Owned(value, PhantomData))
+    }
+}
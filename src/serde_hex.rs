@@ -0,0 +1,172 @@
+//! Bytes as hex, like `lncli` prints them, instead of base64.
+//!
+//! With the `serde` feature, bytes are base64 in the JSON of the messages, which is what LND's REST
+//! API expects. Wrapping a message in [`Hex`] writes and reads its bytes as hex instead, including
+//! those of the messages nested in it. Fields of your own types holding messages can do the same
+//! with `#[serde(with = "voltage_tonic_lnd::serde_hex")]`.
+//!
+//! ```rust
+//! use voltage_tonic_lnd::lnrpc::{channel_point::FundingTxid, ChannelPoint};
+//! use voltage_tonic_lnd::serde_hex::Hex;
+//!
+//! let point = ChannelPoint {
+//!     funding_txid: Some(FundingTxid::FundingTxidBytes(vec![0xab; 32].into())),
+//!     output_index: 1,
+//! };
+//! let json = serde_json::to_string(&Hex(&point)).unwrap();
+//! assert!(json.contains(&"ab".repeat(32)));
+//!
+//! let Hex(parsed): Hex<ChannelPoint> = serde_json::from_str(&json).unwrap();
+//! assert_eq!(parsed, point);
+//! ```
+//!
+//! Other types are written as they are, so `Hex` works with any `Serialize` and `Deserialize`
+//! type and any format.
+
+use std::cell::Cell;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+thread_local! {
+    /// Set while a [`Hex`] value is being serialized or deserialized on this thread.
+    static HEX: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Serializes and deserializes `T` with bytes as hex instead of base64.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Hex<T>(pub T);
+
+impl<T: Serialize> Serialize for Hex<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        with_hex(|| self.0.serialize(serializer))
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Hex<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        with_hex(|| T::deserialize(deserializer)).map(Hex)
+    }
+}
+
+/// Serializes `value` with bytes as hex, for `#[serde(with = "voltage_tonic_lnd::serde_hex")]`.
+pub fn serialize<T: ?Sized + Serialize, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    Hex(value).serialize(serializer)
+}
+
+/// Deserializes a value with bytes as hex, for `#[serde(with = "voltage_tonic_lnd::serde_hex")]`.
+pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    Hex::<T>::deserialize(deserializer).map(|hex| hex.0)
+}
+
+/// Runs `f` with bytes as hex.
+///
+/// Serde serializes and deserializes synchronously, including the fields of a flattened oneof it
+/// buffers, so everything `f` writes or reads sees the flag. The previous value is restored
+/// afterwards, even if `f` panics.
+fn with_hex<R>(f: impl FnOnce() -> R) -> R {
+    struct Restore(bool);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            HEX.with(|hex| hex.set(self.0));
+        }
+    }
+
+    let _restore = Restore(HEX.with(|hex| hex.replace(true)));
+    f()
+}
+
+/// Whether bytes are written and read as hex on this thread, inside a [`Hex`] value.
+#[cfg(services)]
+pub(crate) fn hex_bytes() -> bool {
+    HEX.with(Cell::get)
+}
+
+#[cfg(all(test, feature = "lightningrpc"))]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::lnrpc::channel_point::FundingTxid;
+    use crate::lnrpc::{ChannelPoint, QueryRoutesRequest};
+
+    fn channel_point() -> ChannelPoint {
+        ChannelPoint {
+            funding_txid: Some(FundingTxid::FundingTxidBytes(vec![0xab, 0xcd].into())),
+            output_index: 1,
+        }
+    }
+
+    #[test]
+    fn writes_bytes_as_hex() {
+        let request = QueryRoutesRequest {
+            ignored_nodes: vec![vec![0x02, 0xff].into()],
+            dest_custom_records: [(65536, vec![0x01].into())].into(),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(Hex(&request)).unwrap();
+        assert_eq!(json["ignored_nodes"], json!(["02ff"]));
+        assert_eq!(json["dest_custom_records"], json!({ "65536": "01" }));
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["ignored_nodes"], json!(["Av8="]));
+    }
+
+    #[test]
+    fn reads_bytes_as_hex() {
+        let Hex(request): Hex<QueryRoutesRequest> = serde_json::from_value(
+            json!({ "ignored_nodes": ["02ff"], "dest_custom_records": { "65536": "01" } }),
+        )
+        .unwrap();
+        assert_eq!(request.ignored_nodes[0][..], [0x02, 0xff]);
+        assert_eq!(request.dest_custom_records[&65536][..], [0x01]);
+
+        let error =
+            serde_json::from_value::<Hex<QueryRoutesRequest>>(json!({ "ignored_nodes": ["Av8="] }));
+        assert!(error.unwrap_err().to_string().contains("hex encoded bytes"));
+    }
+
+    #[test]
+    fn oneof_members() {
+        let json = serde_json::to_string(&Hex(channel_point())).unwrap();
+        assert_eq!(json, r#"{"output_index":1,"funding_txid_bytes":"abcd"}"#);
+
+        let Hex(point): Hex<ChannelPoint> = serde_json::from_str(&json).unwrap();
+        assert_eq!(point, channel_point());
+    }
+
+    #[test]
+    fn nested_in_other_types() {
+        let points = vec![Some(channel_point()), None];
+        let json = serde_json::to_value(Hex(&points)).unwrap();
+        assert_eq!(json[0]["funding_txid_bytes"], "abcd");
+        assert_eq!(json[1], serde_json::Value::Null);
+
+        let Hex(parsed): Hex<Vec<Option<ChannelPoint>>> = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, points);
+    }
+
+    #[test]
+    fn with_attribute() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Cached {
+            #[serde(with = "crate::serde_hex")]
+            point: ChannelPoint,
+            other: ChannelPoint,
+        }
+
+        let cached = Cached {
+            point: channel_point(),
+            other: channel_point(),
+        };
+        let json = serde_json::to_value(&cached).unwrap();
+        assert_eq!(json["point"]["funding_txid_bytes"], "abcd");
+        assert_eq!(json["other"]["funding_txid_bytes"], "q80=");
+        assert_eq!(serde_json::from_value::<Cached>(json).unwrap(), cached);
+    }
+}
@@ -0,0 +1,343 @@
+//! Settings shared by the clients of every transport.
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use tonic::codec::CompressionEncoding;
+use zeroize::Zeroizing;
+
+/// Identifies one of the gRPC services exposed through [`Client`](crate::Client).
+///
+/// Used to select which services a [`Client`](crate::Client) talks to (see
+/// [`ClientBuilder::services`](crate::ClientBuilder::services)) and to scope settings such as
+/// [`MessageOptions`] to a single service client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServiceKind {
+    /// The `lnrpc.Lightning` service.
+    #[cfg(feature = "lightningrpc")]
+    Lightning,
+    /// The `walletrpc.WalletKit` service.
+    #[cfg(feature = "walletrpc")]
+    WalletKit,
+    /// The `signrpc.Signer` service.
+    #[cfg(feature = "signrpc")]
+    Signer,
+    /// The `peersrpc.Peers` service.
+    #[cfg(feature = "peersrpc")]
+    Peers,
+    /// The `verrpc.Versioner` service.
+    #[cfg(feature = "versionrpc")]
+    Versioner,
+    /// The `routerrpc.Router` service.
+    #[cfg(feature = "routerrpc")]
+    Router,
+    /// The `invoicesrpc.Invoices` service.
+    #[cfg(feature = "invoicesrpc")]
+    Invoices,
+    /// The `lnrpc.State` service.
+    #[cfg(feature = "staterpc")]
+    State,
+    /// The `chainrpc.ChainNotifier` service.
+    #[cfg(feature = "chainrpc")]
+    ChainNotifier,
+    /// The `chainrpc.ChainKit` service.
+    #[cfg(feature = "chainrpc")]
+    ChainKit,
+    /// The `taprpc.TaprootAssets` service.
+    #[cfg(feature = "taprpc")]
+    TaprootAssets,
+    /// The `assetwalletrpc.AssetWallet` service.
+    #[cfg(feature = "assetwalletrpc")]
+    AssetWallet,
+    /// The `mintrpc.Mint` service.
+    #[cfg(feature = "mintrpc")]
+    Mint,
+    /// The `priceoraclerpc.PriceOracle` service.
+    #[cfg(feature = "priceoraclerpc")]
+    PriceOracle,
+    /// The `rfqrpc.Rfq` service.
+    #[cfg(feature = "rfqrpc")]
+    Rfq,
+    /// The `tapchannelrpc.TaprootAssetChannels` service.
+    #[cfg(feature = "tapchannelrpc")]
+    TapChannel,
+    /// The `tapdevrpc.TapDev` service.
+    #[cfg(feature = "tapdevrpc")]
+    TapDev,
+    /// The `universerpc.Universe` service.
+    #[cfg(feature = "universerpc")]
+    Universe,
+}
+
+impl ServiceKind {
+    /// All services compiled into this build.
+    pub const ALL: &'static [ServiceKind] = &[
+        #[cfg(feature = "lightningrpc")]
+        ServiceKind::Lightning,
+        #[cfg(feature = "walletrpc")]
+        ServiceKind::WalletKit,
+        #[cfg(feature = "signrpc")]
+        ServiceKind::Signer,
+        #[cfg(feature = "peersrpc")]
+        ServiceKind::Peers,
+        #[cfg(feature = "versionrpc")]
+        ServiceKind::Versioner,
+        #[cfg(feature = "routerrpc")]
+        ServiceKind::Router,
+        #[cfg(feature = "invoicesrpc")]
+        ServiceKind::Invoices,
+        #[cfg(feature = "staterpc")]
+        ServiceKind::State,
+        #[cfg(feature = "chainrpc")]
+        ServiceKind::ChainNotifier,
+        #[cfg(feature = "chainrpc")]
+        ServiceKind::ChainKit,
+        #[cfg(feature = "taprpc")]
+        ServiceKind::TaprootAssets,
+        #[cfg(feature = "assetwalletrpc")]
+        ServiceKind::AssetWallet,
+        #[cfg(feature = "mintrpc")]
+        ServiceKind::Mint,
+        #[cfg(feature = "priceoraclerpc")]
+        ServiceKind::PriceOracle,
+        #[cfg(feature = "rfqrpc")]
+        ServiceKind::Rfq,
+        #[cfg(feature = "tapchannelrpc")]
+        ServiceKind::TapChannel,
+        #[cfg(feature = "tapdevrpc")]
+        ServiceKind::TapDev,
+        #[cfg(feature = "universerpc")]
+        ServiceKind::Universe,
+    ];
+
+    /// The services served by LND itself, as far as they are compiled into this build.
+    pub const LND: &'static [ServiceKind] = &[
+        #[cfg(feature = "lightningrpc")]
+        ServiceKind::Lightning,
+        #[cfg(feature = "walletrpc")]
+        ServiceKind::WalletKit,
+        #[cfg(feature = "signrpc")]
+        ServiceKind::Signer,
+        #[cfg(feature = "peersrpc")]
+        ServiceKind::Peers,
+        #[cfg(feature = "versionrpc")]
+        ServiceKind::Versioner,
+        #[cfg(feature = "routerrpc")]
+        ServiceKind::Router,
+        #[cfg(feature = "invoicesrpc")]
+        ServiceKind::Invoices,
+        #[cfg(feature = "staterpc")]
+        ServiceKind::State,
+        #[cfg(feature = "chainrpc")]
+        ServiceKind::ChainNotifier,
+        #[cfg(feature = "chainrpc")]
+        ServiceKind::ChainKit,
+    ];
+
+    /// The services served by tapd (standalone or inside litd), as far as they are compiled into
+    /// this build.
+    pub const TAPROOT_ASSETS: &'static [ServiceKind] = &[
+        #[cfg(feature = "taprpc")]
+        ServiceKind::TaprootAssets,
+        #[cfg(feature = "assetwalletrpc")]
+        ServiceKind::AssetWallet,
+        #[cfg(feature = "mintrpc")]
+        ServiceKind::Mint,
+        #[cfg(feature = "priceoraclerpc")]
+        ServiceKind::PriceOracle,
+        #[cfg(feature = "rfqrpc")]
+        ServiceKind::Rfq,
+        #[cfg(feature = "tapchannelrpc")]
+        ServiceKind::TapChannel,
+        #[cfg(feature = "tapdevrpc")]
+        ServiceKind::TapDev,
+        #[cfg(feature = "universerpc")]
+        ServiceKind::Universe,
+    ];
+
+    /// Returns the fully qualified gRPC name of the service, e.g. `lnrpc.Lightning`.
+    pub fn name(self) -> &'static str {
+        match self {
+            #[cfg(feature = "lightningrpc")]
+            ServiceKind::Lightning => "lnrpc.Lightning",
+            #[cfg(feature = "walletrpc")]
+            ServiceKind::WalletKit => "walletrpc.WalletKit",
+            #[cfg(feature = "signrpc")]
+            ServiceKind::Signer => "signrpc.Signer",
+            #[cfg(feature = "peersrpc")]
+            ServiceKind::Peers => "peersrpc.Peers",
+            #[cfg(feature = "versionrpc")]
+            ServiceKind::Versioner => "verrpc.Versioner",
+            #[cfg(feature = "routerrpc")]
+            ServiceKind::Router => "routerrpc.Router",
+            #[cfg(feature = "invoicesrpc")]
+            ServiceKind::Invoices => "invoicesrpc.Invoices",
+            #[cfg(feature = "staterpc")]
+            ServiceKind::State => "lnrpc.State",
+            #[cfg(feature = "chainrpc")]
+            ServiceKind::ChainNotifier => "chainrpc.ChainNotifier",
+            #[cfg(feature = "chainrpc")]
+            ServiceKind::ChainKit => "chainrpc.ChainKit",
+            #[cfg(feature = "taprpc")]
+            ServiceKind::TaprootAssets => "taprpc.TaprootAssets",
+            #[cfg(feature = "assetwalletrpc")]
+            ServiceKind::AssetWallet => "assetwalletrpc.AssetWallet",
+            #[cfg(feature = "mintrpc")]
+            ServiceKind::Mint => "mintrpc.Mint",
+            #[cfg(feature = "priceoraclerpc")]
+            ServiceKind::PriceOracle => "priceoraclerpc.PriceOracle",
+            #[cfg(feature = "rfqrpc")]
+            ServiceKind::Rfq => "rfqrpc.Rfq",
+            #[cfg(feature = "tapchannelrpc")]
+            ServiceKind::TapChannel => "tapchannelrpc.TaprootAssetChannels",
+            #[cfg(feature = "tapdevrpc")]
+            ServiceKind::TapDev => "tapdevrpc.TapDev",
+            #[cfg(feature = "universerpc")]
+            ServiceKind::Universe => "universerpc.Universe",
+        }
+    }
+}
+
+impl std::fmt::Display for ServiceKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Message size limits and compression settings for a service client.
+///
+/// Unset fields fall back to the values configured on [`ClientBuilder`](crate::ClientBuilder) for
+/// all services, and then to tonic's defaults (4 MiB decoding limit, no encoding limit, no
+/// compression).
+///
+/// Compression requires the `gzip` or `zstd` feature; LND accepts both.
+///
+/// # Example
+/// ```rust,no_run
+/// use voltage_tonic_lnd::{Client, MessageOptions, ServiceKind};
+///
+/// # async fn example() -> voltage_tonic_lnd::Result<()> {
+/// let client = Client::builder()
+///     .address("https://localhost:10009")
+///     .macaroon_path("/path/to/admin.macaroon")
+///     // `DescribeGraph` on mainnet is well above the default 4 MiB.
+///     .service_options(
+///         ServiceKind::Lightning,
+///         MessageOptions::new().max_decoding_message_size(256 * 1024 * 1024),
+///     )
+///     .build()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MessageOptions {
+    pub(crate) max_decoding_message_size: Option<usize>,
+    pub(crate) max_encoding_message_size: Option<usize>,
+    pub(crate) send_compressed: Option<CompressionEncoding>,
+    pub(crate) accept_compressed: Option<CompressionEncoding>,
+}
+
+impl MessageOptions {
+    /// Creates a new [`MessageOptions`] with no fields set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum size of a decoded (received) message, in bytes.
+    pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+        self.max_decoding_message_size = Some(limit);
+        self
+    }
+
+    /// Sets the maximum size of an encoded (sent) message, in bytes.
+    pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+        self.max_encoding_message_size = Some(limit);
+        self
+    }
+
+    /// Compresses requests with the given encoding.
+    pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+        self.send_compressed = Some(encoding);
+        self
+    }
+
+    /// Advertises that responses compressed with the given encoding are accepted.
+    pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+        self.accept_compressed = Some(encoding);
+        self
+    }
+
+    /// Returns `self` with every unset field taken from `fallback`.
+    #[cfg(any(all(services, feature = "transport"), feature = "grpc-web"))]
+    pub(crate) fn or(self, fallback: Self) -> Self {
+        Self {
+            max_decoding_message_size: self
+                .max_decoding_message_size
+                .or(fallback.max_decoding_message_size),
+            max_encoding_message_size: self
+                .max_encoding_message_size
+                .or(fallback.max_encoding_message_size),
+            send_compressed: self.send_compressed.or(fallback.send_compressed),
+            accept_compressed: self.accept_compressed.or(fallback.accept_compressed),
+        }
+    }
+}
+
+/// Applies [`MessageOptions`] to a generated service client.
+///
+/// The generated clients share no common trait, so this has to be a macro.
+#[cfg(all(services, any(feature = "transport", feature = "grpc-web")))]
+macro_rules! configure_client {
+    ($client:expr, $options:expr) => {{
+        let options: $crate::MessageOptions = $options;
+        let mut client = $client;
+        if let Some(limit) = options.max_decoding_message_size {
+            client = client.max_decoding_message_size(limit);
+        }
+        if let Some(limit) = options.max_encoding_message_size {
+            client = client.max_encoding_message_size(limit);
+        }
+        if let Some(encoding) = options.send_compressed {
+            client = client.send_compressed(encoding);
+        }
+        if let Some(encoding) = options.accept_compressed {
+            client = client.accept_compressed(encoding);
+        }
+        client
+    }};
+}
+
+#[cfg(all(services, any(feature = "transport", feature = "grpc-web")))]
+pub(crate) use configure_client;
+
+/// Supplies requests with macaroon
+#[derive(Clone)]
+pub struct MacaroonInterceptor {
+    macaroon: Arc<Zeroizing<String>>,
+}
+
+impl MacaroonInterceptor {
+    /// Creates an interceptor sending `macaroon`, a hex-encoded string.
+    #[cfg(any(all(services, feature = "transport"), feature = "grpc-web"))]
+    pub(crate) fn new(macaroon: Zeroizing<String>) -> Self {
+        Self {
+            macaroon: Arc::new(macaroon),
+        }
+    }
+}
+
+impl tonic::service::Interceptor for MacaroonInterceptor {
+    fn call(
+        &mut self,
+        mut request: tonic::Request<()>,
+    ) -> std::result::Result<tonic::Request<()>, tonic::Status> {
+        request.metadata_mut().insert(
+            "macaroon",
+            tonic::metadata::MetadataValue::from_str(&self.macaroon)
+                .expect("hex produced non-ascii"),
+        );
+        Ok(request)
+    }
+}
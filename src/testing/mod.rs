@@ -0,0 +1,744 @@
+//! An in-process mock of LND for tests.
+//!
+//! [`MockLnd`] serves every LND and Taproot Assets service over TLS on a local port, with a
+//! self-signed certificate and a random macaroon.
+//! Clients connect through the normal [`ClientBuilder`], so certificate and macaroon handling are
+//! exercised like against a real node.
+//!
+//! Every RPC answers `UNIMPLEMENTED` until a handler is registered for its gRPC path
+//! (`/package.Service/Method`):
+//!
+//! - [`MockLnd::respond`] returns the same response to every call,
+//! - [`MockLnd::unary`] runs a closure for every call,
+//! - [`MockLnd::server_stream`] returns a [`StreamHandle`] that pushes messages to every open
+//!   subscription,
+//! - [`MockLnd::bidi_stream`] returns a [`BidiHandle`] that exchanges messages with the client,
+//!   like the HTLC interceptor.
+//!
+//! Instead of writing handlers by hand, calls to a real node can be captured with a [`Recorder`]
+//! and served back with [`MockLnd::replay`].
+//!
+//! `State.GetState` answers `SERVER_ACTIVE` unless overridden. Calls without the right macaroon
+//! fail with `UNAUTHENTICATED`, except to `State`, which LND serves without one.
+//!
+//! # Example
+//! ```rust,no_run
+//! use voltage_tonic_lnd::lnrpc::{GetInfoRequest, GetInfoResponse, Invoice, InvoiceSubscription};
+//! use voltage_tonic_lnd::testing::MockLnd;
+//!
+//! # async fn example() -> voltage_tonic_lnd::Result<()> {
+//! let mock = MockLnd::start().await?;
+//! mock.respond(
+//!     "/lnrpc.Lightning/GetInfo",
+//!     GetInfoResponse {
+//!         alias: "mock".to_owned(),
+//!         ..Default::default()
+//!     },
+//! );
+//! let invoices = mock.subscribe_invoices();
+//!
+//! let client = mock.client_builder().build().await?;
+//! let info = client.lightning()?.get_info(GetInfoRequest {}).await?.into_inner();
+//! assert_eq!(info.alias, "mock");
+//!
+//! let mut stream = client
+//!     .lightning()?
+//!     .subscribe_invoices(InvoiceSubscription::default())
+//!     .await?
+//!     .into_inner();
+//! invoices.wait_for_subscriber().await;
+//! invoices.send(Invoice {
+//!     memo: "coffee".to_owned(),
+//!     ..Default::default()
+//! });
+//! assert_eq!(stream.message().await?.unwrap().memo, "coffee");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::marker::PhantomData;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use tokio::net::TcpListener;
+use tokio::sync::{mpsc, oneshot, watch};
+use tokio::task::JoinHandle;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tokio_stream::StreamExt;
+use tonic::body::Body;
+use tonic::codegen::Service;
+use tonic::server::{Grpc, NamedService};
+use tonic::service::Routes;
+use tonic::transport::server::{TcpConnectInfo, TcpIncoming, TlsConnectInfo};
+use tonic::transport::{
+    Certificate, Channel, ClientTlsConfig, Endpoint, Identity, Server, ServerTlsConfig,
+};
+use tonic::{Request, Response, Status, Streaming};
+use tonic_prost::ProstCodec;
+
+use crate::error::Result;
+use crate::lnrpc::{GetStateResponse, Invoice, InvoiceSubscription, WalletState};
+use crate::routerrpc::{
+    ForwardHtlcInterceptRequest, ForwardHtlcInterceptResponse, HtlcEvent,
+    SubscribeHtlcEventsRequest,
+};
+use crate::ClientBuilder;
+
+mod record;
+
+pub use record::{Event, RecordedCall, Recorder, Recording, Replay, Session};
+
+type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+/// A mock LND node serving gRPC on a local port. The server stops when this is dropped.
+pub struct MockLnd {
+    address: SocketAddr,
+    cert_pem: String,
+    key_pem: String,
+    macaroon: Vec<u8>,
+    handlers: Arc<Mutex<HashMap<String, Arc<dyn Handler>>>>,
+    /// The number of connections accepted.
+    connections: Arc<AtomicUsize>,
+    /// The path and client address of every call.
+    peers: Arc<Mutex<Vec<(String, SocketAddr)>>>,
+    shutdown: Option<oneshot::Sender<()>>,
+    server: Option<JoinHandle<std::result::Result<(), tonic::transport::Error>>>,
+}
+
+impl MockLnd {
+    /// Starts a mock node on a free port of `127.0.0.1`, with a fresh certificate and macaroon.
+    ///
+    /// # Errors
+    /// Returns an error if the certificate can't be generated, the port can't be bound or the TLS
+    /// configuration is rejected.
+    pub async fn start() -> Result<Self> {
+        let certified = rcgen::generate_simple_self_signed(vec![
+            "localhost".to_owned(),
+            "127.0.0.1".to_owned(),
+        ])?;
+        let cert_pem = certified.cert.pem();
+        let key_pem = certified.signing_key.serialize_pem();
+        Self::with_credentials(cert_pem, key_pem, random_bytes(32)).await
+    }
+
+    /// Starts another mock node with the same certificate and macaroon on a free port, like a
+    /// second address of the same LND node for [`ClientBuilder::addresses`]. It has handlers of
+    /// its own.
+    ///
+    /// # Errors
+    /// Returns an error if the port can't be bound or the TLS configuration is rejected.
+    pub async fn replica(&self) -> Result<Self> {
+        Self::with_credentials(self.cert_pem.clone(), self.key_pem.clone(), self.macaroon.clone())
+            .await
+    }
+
+    async fn with_credentials(
+        cert_pem: String,
+        key_pem: String,
+        macaroon: Vec<u8>,
+    ) -> Result<Self> {
+        let mut mock = Self {
+            address: SocketAddr::from(([127, 0, 0, 1], 0)),
+            cert_pem,
+            key_pem,
+            macaroon,
+            handlers: Arc::new(Mutex::new(HashMap::new())),
+            connections: Arc::new(AtomicUsize::new(0)),
+            peers: Arc::new(Mutex::new(Vec::new())),
+            shutdown: None,
+            server: None,
+        };
+        mock.listen().await?;
+        mock.respond(
+            "/lnrpc.State/GetState",
+            GetStateResponse {
+                state: WalletState::ServerActive.into(),
+            },
+        );
+        Ok(mock)
+    }
+
+    /// Serves the handlers on `self.address`, binding a free port the first time.
+    async fn listen(&mut self) -> Result<()> {
+        let identity = Identity::from_pem(&self.cert_pem, &self.key_pem);
+        let listener = TcpListener::bind(self.address).await?;
+        self.address = listener.local_addr()?;
+        let connections = self.connections.clone();
+        let incoming = TcpIncoming::from(listener).map(move |stream| {
+            connections.fetch_add(1, Ordering::Relaxed);
+            stream
+        });
+
+        let (shutdown, stopped) = oneshot::channel::<()>();
+        let server = Server::builder()
+            .tls_config(ServerTlsConfig::new().identity(identity))?
+            .add_routes(self.routes())
+            .serve_with_incoming_shutdown(incoming, async {
+                stopped.await.ok();
+            });
+        self.server = Some(tokio::spawn(server));
+        self.shutdown = Some(shutdown);
+        Ok(())
+    }
+
+    /// Stops the node, like an LND that went offline: the port is closed and connections are shut
+    /// down once their calls in progress finish. Handlers are kept for [`restart`](Self::restart).
+    pub async fn stop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        if let Some(server) = self.server.take() {
+            let _ = server.await;
+        }
+    }
+
+    /// Serves the node again on the same address after [`stop`](Self::stop).
+    ///
+    /// # Errors
+    /// Returns an error if the port can't be bound again.
+    pub async fn restart(&mut self) -> Result<()> {
+        self.stop().await;
+        self.listen().await
+    }
+
+    /// Returns the address to connect to, `https://127.0.0.1:<port>`.
+    pub fn address(&self) -> String {
+        format!("https://{}", self.address)
+    }
+
+    /// Returns the PEM-encoded TLS certificate of the node.
+    pub fn cert_pem(&self) -> &str {
+        &self.cert_pem
+    }
+
+    /// Returns the macaroon the node accepts, hex encoded.
+    pub fn macaroon_hex(&self) -> String {
+        hex::encode(&self.macaroon)
+    }
+
+    /// Returns a builder configured with the address, certificate and macaroon of the node.
+    pub fn client_builder(&self) -> ClientBuilder {
+        ClientBuilder::new()
+            .address(self.address())
+            .cert_contents(&self.cert_pem)
+            .macaroon_contents(self.macaroon_hex())
+    }
+
+    /// Returns the number of connections the node has accepted, across restarts.
+    pub fn connections(&self) -> usize {
+        self.connections.load(Ordering::Relaxed)
+    }
+
+    /// Returns the client address of every call to `path` so far, in order. Calls from the same
+    /// address came in over the same connection. Calls served through [`routes`](Self::routes)
+    /// are not included.
+    pub fn peers(&self, path: &str) -> Vec<SocketAddr> {
+        let peers = self.peers.lock().expect("poisoned");
+        peers.iter().filter(|(called, _)| called == path).map(|&(_, peer)| peer).collect()
+    }
+
+    /// Returns the handlers of the node as a service, for serving them in-process without TLS,
+    /// for example behind `tonic_web::GrpcWebLayer` for a `WebClient`. Calls still need the
+    /// macaroon.
+    pub fn routes(&self) -> Routes {
+        routes(&Dispatcher {
+            handlers: self.handlers.clone(),
+            peers: self.peers.clone(),
+            macaroon: hex::encode(&self.macaroon),
+        })
+    }
+
+    /// Connects a channel to the node, for [`Client::from_channel`](crate::Client::from_channel),
+    /// for example wrapped in a [`Recorder`].
+    ///
+    /// # Errors
+    /// Returns an error if the connection fails.
+    pub async fn channel(&self) -> Result<Channel> {
+        let tls_config =
+            ClientTlsConfig::new().ca_certificate(Certificate::from_pem(&self.cert_pem));
+        Ok(Endpoint::from_shared(self.address())?.tls_config(tls_config)?.connect().await?)
+    }
+
+    /// Writes the certificate and macaroon to `tls.cert` and `admin.macaroon` in `dir`, like LND
+    /// does, for testing [`ClientBuilder::cert_path`] and [`ClientBuilder::macaroon_path`].
+    ///
+    /// Returns the paths of the certificate and the macaroon.
+    pub fn write_credentials(&self, dir: impl AsRef<Path>) -> std::io::Result<(PathBuf, PathBuf)> {
+        let cert_path = dir.as_ref().join("tls.cert");
+        let macaroon_path = dir.as_ref().join("admin.macaroon");
+        std::fs::write(&cert_path, &self.cert_pem)?;
+        std::fs::write(&macaroon_path, &self.macaroon)?;
+        Ok((cert_path, macaroon_path))
+    }
+
+    /// Answers every call to `path` with a clone of `response`, whatever the request.
+    ///
+    /// # Arguments
+    /// * `path` - The gRPC path of a unary method, for example `/lnrpc.Lightning/GetInfo`.
+    /// * `response` - The response message.
+    pub fn respond<Resp>(&self, path: &str, response: Resp)
+    where
+        Resp: prost::Message + Clone + Send + Sync + 'static,
+    {
+        self.unary(path, move |_: ()| Ok(response.clone()));
+    }
+
+    /// Answers every call to `path` by running `handler` on the request.
+    ///
+    /// # Arguments
+    /// * `path` - The gRPC path of a unary method, for example `/lnrpc.Lightning/GetInfo`.
+    /// * `handler` - Returns the response or the status to fail the call with.
+    pub fn unary<Req, Resp, F>(&self, path: &str, handler: F)
+    where
+        Req: prost::Message + Default + Send + 'static,
+        Resp: prost::Message + Send + 'static,
+        F: Fn(Req) -> std::result::Result<Resp, Status> + Send + Sync + 'static,
+    {
+        self.register(
+            path,
+            Unary {
+                handler: Arc::new(handler),
+                _messages: PhantomData,
+            },
+        );
+    }
+
+    /// Serves the server-streaming method at `path` from the returned handle. Every call opens
+    /// a stream that receives the messages sent through the handle from then on.
+    ///
+    /// # Arguments
+    /// * `path` - The gRPC path of a server-streaming method, for example
+    ///   `/lnrpc.Lightning/SubscribeInvoices`.
+    pub fn server_stream<Req, Resp>(&self, path: &str) -> StreamHandle<Req, Resp>
+    where
+        Req: prost::Message + Default + Clone + Send + 'static,
+        Resp: prost::Message + Clone + Send + 'static,
+    {
+        let handle = StreamHandle {
+            streams: Arc::new(Streams::new()),
+            requests: Arc::new(Mutex::new(Vec::new())),
+        };
+        self.register(path, handle.clone());
+        handle
+    }
+
+    /// Serves the bidirectional-streaming method at `path` from the returned handle.
+    ///
+    /// # Arguments
+    /// * `path` - The gRPC path of a bidirectional-streaming method, for example
+    ///   `/routerrpc.Router/HtlcInterceptor`.
+    pub fn bidi_stream<In, Out>(&self, path: &str) -> BidiHandle<In, Out>
+    where
+        In: prost::Message + Default + Send + 'static,
+        Out: prost::Message + Clone + Send + 'static,
+    {
+        let (incoming, received) = mpsc::unbounded_channel();
+        let streams = Arc::new(Streams::new());
+        self.register(
+            path,
+            Bidi {
+                streams: streams.clone(),
+                incoming,
+            },
+        );
+        BidiHandle {
+            streams,
+            received: Arc::new(tokio::sync::Mutex::new(received)),
+        }
+    }
+
+    /// Serves `Lightning.SubscribeInvoices`. See [`server_stream`](Self::server_stream).
+    pub fn subscribe_invoices(&self) -> StreamHandle<InvoiceSubscription, Invoice> {
+        self.server_stream("/lnrpc.Lightning/SubscribeInvoices")
+    }
+
+    /// Serves `Router.SubscribeHtlcEvents`. See [`server_stream`](Self::server_stream).
+    pub fn subscribe_htlc_events(&self) -> StreamHandle<SubscribeHtlcEventsRequest, HtlcEvent> {
+        self.server_stream("/routerrpc.Router/SubscribeHtlcEvents")
+    }
+
+    /// Serves `Router.HtlcInterceptor`: HTLCs sent through the handle are offered to the
+    /// client, and its resolutions are received from the handle. See
+    /// [`bidi_stream`](Self::bidi_stream).
+    pub fn htlc_interceptor(
+        &self,
+    ) -> BidiHandle<ForwardHtlcInterceptResponse, ForwardHtlcInterceptRequest> {
+        self.bidi_stream("/routerrpc.Router/HtlcInterceptor")
+    }
+
+    fn register(&self, path: &str, handler: impl Handler) {
+        self.handlers.lock().expect("poisoned").insert(path.to_owned(), Arc::new(handler));
+    }
+}
+
+impl Drop for MockLnd {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+/// The server side of a server-streaming method of a [`MockLnd`].
+///
+/// Cloning is cheap: clones control the same streams.
+pub struct StreamHandle<Req, Resp> {
+    streams: Arc<Streams<Resp>>,
+    requests: Arc<Mutex<Vec<Req>>>,
+}
+
+impl<Req, Resp> Clone for StreamHandle<Req, Resp> {
+    fn clone(&self) -> Self {
+        Self {
+            streams: self.streams.clone(),
+            requests: self.requests.clone(),
+        }
+    }
+}
+
+impl<Req: Clone, Resp: Clone> StreamHandle<Req, Resp> {
+    /// Sends `message` to every open stream and returns how many streams it was sent to.
+    pub fn send(&self, message: Resp) -> usize {
+        self.streams.send(message)
+    }
+
+    /// Ends every open stream with `status`.
+    pub fn fail(&self, status: Status) {
+        self.streams.fail(status);
+    }
+
+    /// Ends every open stream successfully.
+    pub fn close(&self) {
+        self.streams.close();
+    }
+
+    /// Returns the number of open streams.
+    pub fn subscribers(&self) -> usize {
+        self.streams.open()
+    }
+
+    /// Waits until at least one stream is open.
+    pub async fn wait_for_subscriber(&self) {
+        self.streams.wait_for_open().await;
+    }
+
+    /// Returns the requests of all calls so far, in order.
+    pub fn requests(&self) -> Vec<Req> {
+        self.requests.lock().expect("poisoned").clone()
+    }
+}
+
+/// The server side of a bidirectional-streaming method of a [`MockLnd`]. `In` is the message
+/// the client sends and `Out` the message the server sends.
+///
+/// Cloning is cheap: clones control the same streams.
+pub struct BidiHandle<In, Out> {
+    streams: Arc<Streams<Out>>,
+    received: Arc<tokio::sync::Mutex<mpsc::UnboundedReceiver<In>>>,
+}
+
+impl<In, Out> Clone for BidiHandle<In, Out> {
+    fn clone(&self) -> Self {
+        Self {
+            streams: self.streams.clone(),
+            received: self.received.clone(),
+        }
+    }
+}
+
+impl<In, Out: Clone> BidiHandle<In, Out> {
+    /// Sends `message` to every open stream and returns how many streams it was sent to.
+    pub fn send(&self, message: Out) -> usize {
+        self.streams.send(message)
+    }
+
+    /// Waits for the next message from any client. Returns `None` once the mock is dropped.
+    pub async fn recv(&self) -> Option<In> {
+        self.received.lock().await.recv().await
+    }
+
+    /// Ends every open stream with `status`.
+    pub fn fail(&self, status: Status) {
+        self.streams.fail(status);
+    }
+
+    /// Ends every open stream successfully.
+    pub fn close(&self) {
+        self.streams.close();
+    }
+
+    /// Returns the number of open streams.
+    pub fn subscribers(&self) -> usize {
+        self.streams.open()
+    }
+
+    /// Waits until at least one stream is open.
+    pub async fn wait_for_subscriber(&self) {
+        self.streams.wait_for_open().await;
+    }
+}
+
+/// The open response streams of one method.
+struct Streams<T> {
+    senders: Mutex<Vec<mpsc::UnboundedSender<std::result::Result<T, Status>>>>,
+    opened: watch::Sender<usize>,
+}
+
+impl<T: Clone> Streams<T> {
+    fn new() -> Self {
+        Self {
+            senders: Mutex::new(Vec::new()),
+            opened: watch::Sender::new(0),
+        }
+    }
+
+    fn subscribe(&self) -> UnboundedReceiverStream<std::result::Result<T, Status>> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        self.senders.lock().expect("poisoned").push(sender);
+        self.opened.send_modify(|opened| *opened += 1);
+        UnboundedReceiverStream::new(receiver)
+    }
+
+    fn send(&self, message: T) -> usize {
+        let mut senders = self.senders.lock().expect("poisoned");
+        senders.retain(|sender| sender.send(Ok(message.clone())).is_ok());
+        senders.len()
+    }
+
+    fn fail(&self, status: Status) {
+        for sender in self.senders.lock().expect("poisoned").drain(..) {
+            let _ = sender.send(Err(status.clone()));
+        }
+    }
+
+    fn close(&self) {
+        self.senders.lock().expect("poisoned").clear();
+    }
+
+    fn open(&self) -> usize {
+        let mut senders = self.senders.lock().expect("poisoned");
+        senders.retain(|sender| !sender.is_closed());
+        senders.len()
+    }
+
+    async fn wait_for_open(&self) {
+        let mut opened = self.opened.subscribe();
+        while self.open() == 0 {
+            if opened.changed().await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+/// Serves one gRPC method.
+trait Handler: Send + Sync + 'static {
+    fn call(&self, request: http::Request<Body>) -> BoxFuture<http::Response<Body>>;
+}
+
+struct Unary<F, Req, Resp> {
+    handler: Arc<F>,
+    _messages: PhantomData<fn(Req) -> Resp>,
+}
+
+impl<F, Req, Resp> Handler for Unary<F, Req, Resp>
+where
+    Req: prost::Message + Default + Send + 'static,
+    Resp: prost::Message + Send + 'static,
+    F: Fn(Req) -> std::result::Result<Resp, Status> + Send + Sync + 'static,
+{
+    fn call(&self, request: http::Request<Body>) -> BoxFuture<http::Response<Body>> {
+        let handler = self.handler.clone();
+        let service = ServiceFn(move |request: Request<Req>| {
+            std::future::ready(handler(request.into_inner()).map(Response::new))
+        });
+        Box::pin(async move {
+            Grpc::new(ProstCodec::<Resp, Req>::default()).unary(service, request).await
+        })
+    }
+}
+
+impl<Req, Resp> Handler for StreamHandle<Req, Resp>
+where
+    Req: prost::Message + Default + Clone + Send + 'static,
+    Resp: prost::Message + Clone + Send + 'static,
+{
+    fn call(&self, request: http::Request<Body>) -> BoxFuture<http::Response<Body>> {
+        let handle = self.clone();
+        let service = ServiceFn(move |request: Request<Req>| {
+            handle.requests.lock().expect("poisoned").push(request.into_inner());
+            std::future::ready(Ok(Response::new(handle.streams.subscribe())))
+        });
+        Box::pin(async move {
+            Grpc::new(ProstCodec::<Resp, Req>::default()).server_streaming(service, request).await
+        })
+    }
+}
+
+struct Bidi<In, Out> {
+    streams: Arc<Streams<Out>>,
+    incoming: mpsc::UnboundedSender<In>,
+}
+
+impl<In, Out> Handler for Bidi<In, Out>
+where
+    In: prost::Message + Default + Send + 'static,
+    Out: prost::Message + Clone + Send + 'static,
+{
+    fn call(&self, request: http::Request<Body>) -> BoxFuture<http::Response<Body>> {
+        let streams = self.streams.clone();
+        let incoming = self.incoming.clone();
+        let service = ServiceFn(move |request: Request<Streaming<In>>| {
+            let mut messages = request.into_inner();
+            let incoming = incoming.clone();
+            tokio::spawn(async move {
+                while let Ok(Some(message)) = messages.message().await {
+                    if incoming.send(message).is_err() {
+                        break;
+                    }
+                }
+            });
+            std::future::ready(Ok(Response::new(streams.subscribe())))
+        });
+        Box::pin(async move {
+            Grpc::new(ProstCodec::<Out, In>::default()).streaming(service, request).await
+        })
+    }
+}
+
+/// Adapts a closure to the `Service` that `Grpc` expects.
+struct ServiceFn<F>(F);
+
+impl<F, Req, Fut, Resp> Service<Request<Req>> for ServiceFn<F>
+where
+    F: FnMut(Request<Req>) -> Fut,
+    Fut: Future<Output = std::result::Result<Response<Resp>, Status>>,
+{
+    type Response = Response<Resp>;
+    type Error = Status;
+    type Future = Fut;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<std::result::Result<(), Status>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<Req>) -> Fut {
+        (self.0)(request)
+    }
+}
+
+/// Checks the macaroon and routes calls to the registered handlers.
+#[derive(Clone)]
+struct Dispatcher {
+    handlers: Arc<Mutex<HashMap<String, Arc<dyn Handler>>>>,
+    peers: Arc<Mutex<Vec<(String, SocketAddr)>>>,
+    macaroon: String,
+}
+
+impl Dispatcher {
+    fn call(&self, request: http::Request<Body>) -> BoxFuture<http::Response<Body>> {
+        let path = request.uri().path();
+        let peer = request
+            .extensions()
+            .get::<TlsConnectInfo<TcpConnectInfo>>()
+            .and_then(|info| info.get_ref().remote_addr());
+        if let Some(peer) = peer {
+            self.peers.lock().expect("poisoned").push((path.to_owned(), peer));
+        }
+        // Like LND, the State service is available without a macaroon.
+        let authenticated = path.starts_with("/lnrpc.State/")
+            || request
+                .headers()
+                .get("macaroon")
+                .is_some_and(|macaroon| macaroon.as_bytes() == self.macaroon.as_bytes());
+        if !authenticated {
+            let status = Status::unauthenticated("verification failed: invalid macaroon");
+            return Box::pin(std::future::ready(status.into_http()));
+        }
+
+        let handler = self.handlers.lock().expect("poisoned").get(path).cloned();
+        match handler {
+            Some(handler) => handler.call(request),
+            None => {
+                let status = Status::unimplemented(format!("no mock handler for {path}"));
+                Box::pin(std::future::ready(status.into_http()))
+            }
+        }
+    }
+}
+
+macro_rules! mock_services {
+    ($($service:ident => $name:literal,)*) => {
+        /// Routes every service to `dispatcher`.
+        fn routes(dispatcher: &Dispatcher) -> Routes {
+            let mut routes = Routes::builder();
+            $(routes.add_service($service(dispatcher.clone()));)*
+            routes.routes()
+        }
+
+        $(
+            #[derive(Clone)]
+            struct $service(Dispatcher);
+
+            impl NamedService for $service {
+                const NAME: &'static str = $name;
+            }
+
+            impl Service<http::Request<Body>> for $service {
+                type Response = http::Response<Body>;
+                type Error = Infallible;
+                type Future = Pin<Box<dyn Future<Output = std::result::Result<Self::Response, Infallible>> + Send>>;
+
+                fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<std::result::Result<(), Infallible>> {
+                    Poll::Ready(Ok(()))
+                }
+
+                fn call(&mut self, request: http::Request<Body>) -> Self::Future {
+                    let response = self.0.call(request);
+                    Box::pin(async move { Ok(response.await) })
+                }
+            }
+        )*
+    };
+}
+
+mock_services! {
+    LightningService => "lnrpc.Lightning",
+    StateService => "lnrpc.State",
+    RouterService => "routerrpc.Router",
+    InvoicesService => "invoicesrpc.Invoices",
+    WalletKitService => "walletrpc.WalletKit",
+    SignerService => "signrpc.Signer",
+    PeersService => "peersrpc.Peers",
+    VersionerService => "verrpc.Versioner",
+    ChainNotifierService => "chainrpc.ChainNotifier",
+    ChainKitService => "chainrpc.ChainKit",
+    TaprootAssetsService => "taprpc.TaprootAssets",
+    AssetWalletService => "assetwalletrpc.AssetWallet",
+    MintService => "mintrpc.Mint",
+    PriceOracleService => "priceoraclerpc.PriceOracle",
+    RfqService => "rfqrpc.Rfq",
+    TaprootAssetChannelsService => "tapchannelrpc.TaprootAssetChannels",
+    TapDevService => "tapdevrpc.TapDev",
+    UniverseService => "universerpc.Universe",
+}
+
+/// Returns `len` bytes that differ between calls. Not cryptographically secure, which doesn't
+/// matter for a mock macaroon.
+fn random_bytes(len: usize) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(len);
+    while bytes.len() < len {
+        bytes.extend_from_slice(&RandomState::new().build_hasher().finish().to_le_bytes());
+    }
+    bytes.truncate(len);
+    bytes
+}
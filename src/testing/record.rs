@@ -0,0 +1,739 @@
+//! Recording gRPC sessions against a real node and replaying them from a [`MockLnd`].
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{ready, Context, Poll};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use http_body::{Frame, SizeHint};
+use prost::encoding::{decode_key, decode_varint, WireType};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tonic::body::Body;
+use tonic::codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder};
+use tonic::server::Grpc;
+use tonic::{Code, Request, Response, Status, Streaming};
+use tower::{Layer, Service};
+
+use super::{BoxFuture, Handler, MockLnd, ServiceFn};
+
+/// A recorded set of gRPC calls, in the order they were started.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Session {
+    pub calls: Vec<RecordedCall>,
+}
+
+/// One recorded gRPC call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedCall {
+    /// The gRPC path of the method, for example `/lnrpc.Lightning/GetInfo`.
+    pub method: String,
+    /// The messages exchanged, in the order they were seen.
+    pub events: Vec<Event>,
+}
+
+/// Something that happened during a recorded call. Messages are stored encoded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Event {
+    /// A message sent by the client.
+    Request(#[serde(with = "hex")] Vec<u8>),
+    /// A message sent by the server.
+    Response(#[serde(with = "hex")] Vec<u8>),
+    /// The call ended with this status. Calls that were still open when the session was saved
+    /// have none.
+    Status {
+        code: i32,
+        message: String,
+    },
+}
+
+impl Session {
+    /// Reads a session saved with [`Recorder::save`].
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let file = std::fs::File::open(path)?;
+        Ok(serde_json::from_reader(std::io::BufReader::new(file))?)
+    }
+
+    /// Writes the session as JSON.
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let file = std::fs::File::create(path)?;
+        serde_json::to_writer_pretty(std::io::BufWriter::new(file), self)?;
+        Ok(())
+    }
+}
+
+/// Records the calls made by a [`Client`](crate::Client), for replaying them later with
+/// [`MockLnd::replay`].
+///
+/// The recorder is a [`Layer`]: wrap the channel to a real node with it and pass the result to
+/// [`Client::from_channel`](crate::Client::from_channel). Every call made through the client is
+/// added to the session, including the messages of streaming calls in the order they were sent
+/// and received.
+///
+/// ```rust,no_run
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// use tonic::transport::{Certificate, ClientTlsConfig, Endpoint};
+/// use tower::Layer;
+/// use voltage_tonic_lnd::testing::Recorder;
+/// use voltage_tonic_lnd::Client;
+///
+/// # let (cert_pem, macaroon_hex) = (String::new(), String::new());
+/// let channel = Endpoint::from_static("https://localhost:10009")
+///     .tls_config(ClientTlsConfig::new().ca_certificate(Certificate::from_pem(cert_pem)))?
+///     .connect()
+///     .await?;
+/// let recorder = Recorder::new();
+/// let origin = "https://localhost:10009".parse()?;
+/// let client = Client::from_channel(recorder.layer(channel), macaroon_hex, origin);
+/// // ... make calls through `client`, then:
+/// recorder.save("session.json")?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Messages are recorded as they appear on the wire, so compression must be off while recording.
+///
+/// Cloning is cheap: clones record into the same session.
+#[derive(Debug, Clone, Default)]
+pub struct Recorder {
+    session: Arc<Mutex<Session>>,
+}
+
+impl Recorder {
+    /// Creates a recorder with an empty session.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a copy of everything recorded so far.
+    pub fn session(&self) -> Session {
+        self.session.lock().expect("poisoned").clone()
+    }
+
+    /// Writes everything recorded so far to `path` as JSON. See [`Session::load`].
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        self.session().save(path)
+    }
+
+    /// Starts recording a call to `method`.
+    fn start(&self, method: &str) -> RecordingCall {
+        let mut session = self.session.lock().expect("poisoned");
+        session.calls.push(RecordedCall {
+            method: method.to_owned(),
+            events: Vec::new(),
+        });
+        RecordingCall {
+            session: self.session.clone(),
+            index: session.calls.len() - 1,
+        }
+    }
+}
+
+impl<S> Layer<S> for Recorder {
+    type Service = Recording<S>;
+
+    fn layer(&self, inner: S) -> Recording<S> {
+        Recording {
+            inner,
+            recorder: self.clone(),
+        }
+    }
+}
+
+/// A service that records the calls passing through it into a [`Recorder`]. See
+/// [`Recorder::layer`](Layer::layer).
+#[derive(Debug, Clone)]
+pub struct Recording<S> {
+    inner: S,
+    recorder: Recorder,
+}
+
+impl<S> Service<http::Request<Body>> for Recording<S>
+where
+    S: Service<http::Request<Body>, Response = http::Response<Body>>,
+    S::Future: Send + 'static,
+{
+    type Response = http::Response<Body>;
+    type Error = S::Error;
+    type Future = BoxFuture<Result<http::Response<Body>, S::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), S::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<Body>) -> Self::Future {
+        let call = self.recorder.start(request.uri().path());
+        let response = self.inner.call(call.request(request));
+        Box::pin(async move { Ok(call.response(response.await?)) })
+    }
+}
+
+/// A call being recorded.
+#[derive(Clone)]
+struct RecordingCall {
+    session: Arc<Mutex<Session>>,
+    index: usize,
+}
+
+impl RecordingCall {
+    /// Wraps a request body so that the messages in it are recorded as they are sent.
+    fn request(&self, request: http::Request<Body>) -> http::Request<Body> {
+        let call = self.clone();
+        request.map(|body| Body::new(Tee::new(body, call, Event::Request)))
+    }
+
+    /// Wraps a response body so that the messages and the status in it are recorded as they are
+    /// received.
+    fn response(&self, response: http::Response<Body>) -> http::Response<Body> {
+        // Calls that fail immediately send the status in the headers.
+        self.record_status(response.headers());
+        let call = self.clone();
+        response.map(|body| Body::new(Tee::new(body, call, Event::Response)))
+    }
+
+    fn record(&self, event: Event) {
+        self.session.lock().expect("poisoned").calls[self.index].events.push(event);
+    }
+
+    fn record_status(&self, headers: &http::HeaderMap) {
+        let Some(code) = headers.get("grpc-status") else {
+            return;
+        };
+        let code =
+            code.to_str().ok().and_then(|code| code.parse().ok()).unwrap_or(Code::Unknown as i32);
+        let message = headers
+            .get("grpc-message")
+            .and_then(|message| message.to_str().ok())
+            .map(percent_decode)
+            .unwrap_or_default();
+        self.record(Event::Status {
+            code,
+            message,
+        });
+    }
+}
+
+/// A body that records the gRPC messages passing through it.
+struct Tee {
+    inner: Body,
+    call: RecordingCall,
+    event: fn(Vec<u8>) -> Event,
+    buffer: BytesMut,
+}
+
+impl Tee {
+    fn new(inner: Body, call: RecordingCall, event: fn(Vec<u8>) -> Event) -> Self {
+        Self {
+            inner,
+            call,
+            event,
+            buffer: BytesMut::new(),
+        }
+    }
+}
+
+impl http_body::Body for Tee {
+    type Data = Bytes;
+    type Error = Status;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Bytes>, Status>>> {
+        let this = self.get_mut();
+        let frame = ready!(Pin::new(&mut this.inner).poll_frame(cx));
+        if let Some(Ok(frame)) = &frame {
+            if let Some(data) = frame.data_ref() {
+                this.buffer.extend_from_slice(data);
+                // Each message is prefixed with a compression flag and a 4-byte length.
+                while this.buffer.len() >= 5 {
+                    let len = u32::from_be_bytes(this.buffer[1..5].try_into().expect("4 bytes"));
+                    if this.buffer.len() < 5 + len as usize {
+                        break;
+                    }
+                    let message = this.buffer.split_to(5 + len as usize).split_off(5);
+                    this.call.record((this.event)(message.to_vec()));
+                }
+            }
+            if let Some(trailers) = frame.trailers_ref() {
+                this.call.record_status(trailers);
+            }
+        }
+        Poll::Ready(frame)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+/// Decodes the `%XX` escapes gRPC uses in `grpc-message`.
+fn percent_decode(message: &str) -> String {
+    let mut bytes = Vec::with_capacity(message.len());
+    let mut rest = message.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        let escaped = (byte == b'%')
+            .then(|| tail.get(..2))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match escaped {
+            Some(decoded) => {
+                bytes.push(decoded);
+                rest = &tail[2..];
+            }
+            None => {
+                bytes.push(byte);
+                rest = tail;
+            }
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+impl MockLnd {
+    /// Serves the calls of `session` from this mock, for replaying traffic captured with a
+    /// [`Recorder`] without a node.
+    ///
+    /// Each incoming call is matched with the first recorded call to the same method that hasn't
+    /// been replayed yet and whose first request is equal. Calls whose recording starts with a
+    /// response (for example an HTLC interceptor waiting for HTLCs) are matched as soon as they
+    /// are opened. The recorded messages are then played back in their recorded order: responses
+    /// are sent, and each recorded request waits for the next request of the client, which must
+    /// match too. Fields that change between runs, such as timestamps, can be left out of the
+    /// comparison with [`Replay::ignore_field`].
+    ///
+    /// A call that matches no recording fails with `NOT_FOUND`, and a request that differs from
+    /// the recording fails the call with `INVALID_ARGUMENT`, as does a request that can't be
+    /// compared because it or the recording isn't valid protobuf.
+    pub fn replay(&self, session: Session) -> Replay {
+        let replay = Replay {
+            state: Arc::new(ReplayState {
+                calls: Mutex::new(session.calls.into_iter().map(Some).collect()),
+                ignored: Mutex::new(Vec::new()),
+            }),
+        };
+        let methods: HashSet<_> = replay
+            .state
+            .calls
+            .lock()
+            .expect("poisoned")
+            .iter()
+            .flatten()
+            .map(|call| call.method.clone())
+            .collect();
+        for method in methods {
+            self.register(
+                &method,
+                ReplayHandler {
+                    state: replay.state.clone(),
+                    method: method.clone(),
+                },
+            );
+        }
+        replay
+    }
+}
+
+/// The rules and progress of a session served by [`MockLnd::replay`].
+///
+/// Cloning is cheap: clones share the same state.
+#[derive(Clone)]
+pub struct Replay {
+    state: Arc<ReplayState>,
+}
+
+impl Replay {
+    /// Leaves a field out when comparing requests to `method` with the recording.
+    ///
+    /// # Arguments
+    /// * `method` - The gRPC path of the method, for example `/lnrpc.Lightning/AddInvoice`.
+    /// * `path` - The field numbers leading to the field, starting from the request message.
+    ///   For example `&[7]` is field 7 of the request and `&[2, 1]` is field 1 of the message in
+    ///   field 2. An empty path ignores the whole request.
+    pub fn ignore_field(&self, method: &str, path: &[u32]) -> &Self {
+        self.state.ignored.lock().expect("poisoned").push((method.to_owned(), path.to_vec()));
+        self
+    }
+
+    /// Returns the recorded calls that haven't been replayed, for checking that the code under
+    /// test made every call it made during the recording.
+    pub fn remaining(&self) -> Vec<RecordedCall> {
+        self.state.calls.lock().expect("poisoned").iter().flatten().cloned().collect()
+    }
+}
+
+struct ReplayState {
+    /// The recorded calls. Replayed calls are taken out.
+    calls: Mutex<Vec<Option<RecordedCall>>>,
+    ignored: Mutex<Vec<(String, Vec<u32>)>>,
+}
+
+impl ReplayState {
+    /// Takes the first unplayed call to `method` that starts with `request`, or with a response
+    /// if `request` is `None`.
+    fn take(&self, method: &str, request: Option<&[u8]>) -> Result<Option<RecordedCall>, Status> {
+        let mut calls = self.calls.lock().expect("poisoned");
+        for slot in calls.iter_mut() {
+            let Some(call) = slot else {
+                continue;
+            };
+            if call.method != method {
+                continue;
+            }
+            let matches = match (call.events.first(), request) {
+                (Some(Event::Request(recorded)), Some(request)) => {
+                    self.matches(method, recorded, request)?
+                }
+                (Some(Event::Request(_)), None) => false,
+                (_, None) => true,
+                (_, Some(_)) => false,
+            };
+            if matches {
+                return Ok(slot.take());
+            }
+        }
+        Ok(None)
+    }
+
+    /// Compares a request to `method` with a recorded one, leaving out the ignored fields.
+    ///
+    /// Fails with `INVALID_ARGUMENT` if either isn't valid protobuf, since the ignored fields
+    /// can't be found then.
+    fn matches(&self, method: &str, recorded: &[u8], request: &[u8]) -> Result<bool, Status> {
+        let ignored = self.ignored.lock().expect("poisoned");
+        let paths: Vec<&[u32]> = ignored
+            .iter()
+            .filter(|(ignored_method, _)| ignored_method == method)
+            .map(|(_, path)| path.as_slice())
+            .collect();
+        if paths.iter().any(|path| path.is_empty()) {
+            return Ok(true);
+        }
+        let invalid = |which| {
+            Status::invalid_argument(format!(
+                "replay: the {which} request to {method} isn't valid protobuf"
+            ))
+        };
+        let recorded = canonical(recorded, &paths).ok_or_else(|| invalid("recorded"))?;
+        let request = canonical(request, &paths).ok_or_else(|| invalid("incoming"))?;
+        Ok(recorded == request)
+    }
+}
+
+/// A protobuf field as read from the wire.
+#[derive(Debug, PartialEq)]
+enum Value {
+    Varint(u64),
+    Fixed64(u64),
+    Fixed32(u32),
+    LengthDelimited(Vec<u8>),
+    Message(Vec<(u32, Value)>),
+}
+
+/// Parses an encoded message into its fields, sorted by number, without the fields at `ignored`.
+/// Returns `None` if `message` isn't valid protobuf.
+fn canonical(message: &[u8], ignored: &[&[u32]]) -> Option<Vec<(u32, Value)>> {
+    let mut buf = message;
+    let mut fields = Vec::new();
+    while buf.has_remaining() {
+        let (number, wire_type) = decode_key(&mut buf).ok()?;
+        let value = match wire_type {
+            WireType::Varint => Value::Varint(decode_varint(&mut buf).ok()?),
+            WireType::SixtyFourBit if buf.remaining() >= 8 => Value::Fixed64(buf.get_u64_le()),
+            WireType::ThirtyTwoBit if buf.remaining() >= 4 => Value::Fixed32(buf.get_u32_le()),
+            WireType::LengthDelimited => {
+                let len = usize::try_from(decode_varint(&mut buf).ok()?).ok()?;
+                if buf.remaining() < len {
+                    return None;
+                }
+                let (value, rest) = buf.split_at(len);
+                buf = rest;
+                let nested: Vec<&[u32]> = ignored
+                    .iter()
+                    .filter(|path| path.len() > 1 && path[0] == number)
+                    .map(|path| &path[1..])
+                    .collect();
+                // Only descend where a rule needs it; elsewhere the bytes may not be a message.
+                match nested.is_empty() {
+                    true => Value::LengthDelimited(value.to_vec()),
+                    false => Value::Message(canonical(value, &nested)?),
+                }
+            }
+            _ => return None,
+        };
+        if !ignored.iter().any(|path| path == &[number]) {
+            fields.push((number, value));
+        }
+    }
+    // Stable, so repeated fields keep their order.
+    fields.sort_by_key(|(number, _)| *number);
+    Some(fields)
+}
+
+/// Plays back the recorded calls to one method.
+struct ReplayHandler {
+    state: Arc<ReplayState>,
+    method: String,
+}
+
+impl Handler for ReplayHandler {
+    fn call(&self, request: http::Request<Body>) -> BoxFuture<http::Response<Body>> {
+        let state = self.state.clone();
+        let method = self.method.clone();
+        let service = ServiceFn(move |request: Request<Streaming<Bytes>>| {
+            let (responses, stream) = mpsc::unbounded_channel();
+            tokio::spawn(play(state.clone(), method.clone(), request.into_inner(), responses));
+            std::future::ready(Ok(Response::new(UnboundedReceiverStream::new(stream))))
+        });
+        Box::pin(async move { Grpc::new(RawCodec).streaming(service, request).await })
+    }
+}
+
+async fn play(
+    state: Arc<ReplayState>,
+    method: String,
+    mut requests: Streaming<Bytes>,
+    responses: mpsc::UnboundedSender<Result<Bytes, Status>>,
+) {
+    let mut first = None;
+    let taken = match state.take(&method, None) {
+        Ok(None) => {
+            let Ok(Some(request)) = requests.message().await else {
+                return;
+            };
+            let taken = state.take(&method, Some(&request));
+            first = Some(request);
+            taken
+        }
+        taken => taken,
+    };
+    let call = match taken {
+        Ok(Some(call)) => call,
+        Ok(None) => {
+            let status = Status::not_found(format!(
+                "replay: no recorded call to {method} matches the request"
+            ));
+            let _ = responses.send(Err(status));
+            return;
+        }
+        Err(status) => {
+            let _ = responses.send(Err(status));
+            return;
+        }
+    };
+
+    for (index, event) in call.events.into_iter().enumerate() {
+        match event {
+            Event::Request(recorded) => {
+                let request = match first.take() {
+                    Some(request) => request,
+                    None => match requests.message().await {
+                        Ok(Some(request)) => request,
+                        _ => return,
+                    },
+                };
+                let status = match state.matches(&method, &recorded, &request) {
+                    Ok(true) => continue,
+                    Ok(false) => Status::invalid_argument(format!(
+                        "replay: request at event {index} of {method} differs from the recording"
+                    )),
+                    Err(status) => status,
+                };
+                let _ = responses.send(Err(status));
+                return;
+            }
+            Event::Response(message) => {
+                if responses.send(Ok(message.into())).is_err() {
+                    return;
+                }
+            }
+            Event::Status {
+                code,
+                message,
+            } => {
+                if code != Code::Ok as i32 {
+                    let _ = responses.send(Err(Status::new(code.into(), message)));
+                }
+                return;
+            }
+        }
+    }
+    // The recording ended with the call still open, like a subscription. Keep it open until the
+    // client goes away.
+    responses.closed().await;
+}
+
+/// Passes encoded messages through as they are.
+struct RawCodec;
+
+impl Codec for RawCodec {
+    type Encode = Bytes;
+    type Decode = Bytes;
+    type Encoder = RawCodec;
+    type Decoder = RawCodec;
+
+    fn encoder(&mut self) -> RawCodec {
+        RawCodec
+    }
+
+    fn decoder(&mut self) -> RawCodec {
+        RawCodec
+    }
+}
+
+impl Encoder for RawCodec {
+    type Item = Bytes;
+    type Error = Status;
+
+    fn encode(&mut self, item: Bytes, dst: &mut EncodeBuf<'_>) -> Result<(), Status> {
+        dst.put(item);
+        Ok(())
+    }
+}
+
+impl Decoder for RawCodec {
+    type Item = Bytes;
+    type Error = Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Bytes>, Status> {
+        Ok(Some(src.copy_to_bytes(src.remaining())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+
+    use super::*;
+    use crate::lnrpc;
+
+    fn state(ignored: &[(&str, &[u32])]) -> ReplayState {
+        ReplayState {
+            calls: Mutex::new(Vec::new()),
+            ignored: Mutex::new(
+                ignored.iter().map(|(method, path)| (method.to_string(), path.to_vec())).collect(),
+            ),
+        }
+    }
+
+    fn invoice(memo: &str, creation_date: i64) -> Vec<u8> {
+        lnrpc::Invoice {
+            memo: memo.to_owned(),
+            value: 1000,
+            creation_date,
+            ..Default::default()
+        }
+        .encode_to_vec()
+    }
+
+    fn close(output_index: u32, force: bool) -> Vec<u8> {
+        lnrpc::CloseChannelRequest {
+            channel_point: Some(lnrpc::ChannelPoint {
+                funding_txid: Some(lnrpc::channel_point::FundingTxid::FundingTxidStr("ab".into())),
+                output_index,
+            }),
+            force,
+            ..Default::default()
+        }
+        .encode_to_vec()
+    }
+
+    const ADD_INVOICE: &str = "/lnrpc.Lightning/AddInvoice";
+    const CLOSE_CHANNEL: &str = "/lnrpc.Lightning/CloseChannel";
+
+    #[test]
+    fn ignores_fields() {
+        let state = state(&[(ADD_INVOICE, &[7])]);
+        assert!(state.matches(ADD_INVOICE, &invoice("a", 1), &invoice("a", 2)).unwrap());
+        assert!(!state.matches(ADD_INVOICE, &invoice("a", 1), &invoice("b", 1)).unwrap());
+        // The rules only apply to their method.
+        assert!(!state
+            .matches("/lnrpc.Lightning/Other", &invoice("a", 1), &invoice("a", 2))
+            .unwrap());
+    }
+
+    #[test]
+    fn ignores_nested_fields() {
+        let state = state(&[(CLOSE_CHANNEL, &[1, 3])]);
+        assert!(state.matches(CLOSE_CHANNEL, &close(0, true), &close(1, true)).unwrap());
+        assert!(!state.matches(CLOSE_CHANNEL, &close(0, true), &close(0, false)).unwrap());
+    }
+
+    #[test]
+    fn ignores_whole_request() {
+        let state = state(&[(ADD_INVOICE, &[])]);
+        assert!(state.matches(ADD_INVOICE, &invoice("a", 1), &invoice("b", 2)).unwrap());
+    }
+
+    #[test]
+    fn field_order_doesnt_matter() {
+        // Field 5 (value) before field 1 (memo), as another encoder could write them.
+        let mut reordered = Vec::new();
+        prost::encoding::int64::encode(5, &1000, &mut reordered);
+        prost::encoding::string::encode(1, &"a".to_owned(), &mut reordered);
+        assert_ne!(reordered, invoice("a", 0));
+        assert!(state(&[]).matches(ADD_INVOICE, &invoice("a", 0), &reordered).unwrap());
+    }
+
+    #[test]
+    fn canonical_rejects_invalid_protobuf() {
+        assert_eq!(canonical(&[], &[]), Some(Vec::new()));
+        // A length-delimited field 1 claiming 5 bytes with only 1 left.
+        assert_eq!(canonical(&[0x0a, 0x05, 0x00], &[]), None);
+        // Wire type 7 doesn't exist.
+        assert_eq!(canonical(&[0x0f], &[]), None);
+    }
+
+    #[test]
+    fn invalid_protobuf_fails_the_match() {
+        let error = state(&[]).matches(ADD_INVOICE, &invoice("a", 0), &[0x0f]).unwrap_err();
+        assert_eq!(error.code(), Code::InvalidArgument);
+        // Even where the bytes are equal.
+        assert!(state(&[]).matches(ADD_INVOICE, &[0x0f], &[0x0f]).is_err());
+        // Unless the whole request is ignored.
+        assert!(state(&[(ADD_INVOICE, &[])]).matches(ADD_INVOICE, &[0x0f], &[]).unwrap());
+    }
+
+    #[test]
+    fn takes_calls_in_order() {
+        let call = |method: &str, events: Vec<Event>| {
+            Some(RecordedCall {
+                method: method.to_owned(),
+                events,
+            })
+        };
+        let state = state(&[]);
+        *state.calls.lock().unwrap() = vec![
+            call(ADD_INVOICE, vec![Event::Request(invoice("a", 0)), Event::Response(vec![1])]),
+            call(ADD_INVOICE, vec![Event::Request(invoice("b", 0)), Event::Response(vec![2])]),
+            call(ADD_INVOICE, vec![Event::Request(invoice("a", 0)), Event::Response(vec![3])]),
+            call("/routerrpc.Router/HtlcInterceptor", vec![Event::Response(vec![4])]),
+        ];
+
+        let first = state.take(ADD_INVOICE, Some(&invoice("a", 0))).unwrap().unwrap();
+        assert_eq!(first.events[1], Event::Response(vec![1]));
+        let second = state.take(ADD_INVOICE, Some(&invoice("a", 0))).unwrap().unwrap();
+        assert_eq!(second.events[1], Event::Response(vec![3]));
+        assert_eq!(state.take(ADD_INVOICE, Some(&invoice("a", 0))).unwrap(), None);
+        // Calls recorded with a request first wait for it.
+        assert_eq!(state.take(ADD_INVOICE, None).unwrap(), None);
+
+        assert!(state.take("/routerrpc.Router/HtlcInterceptor", None).unwrap().is_some());
+        assert_eq!(state.calls.lock().unwrap().iter().flatten().count(), 1);
+    }
+
+    #[test]
+    fn decodes_grpc_message() {
+        assert_eq!(percent_decode("invoice%20not%20found"), "invoice not found");
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz"), "%zz");
+    }
+}
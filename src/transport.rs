@@ -0,0 +1,297 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+#[cfg(feature = "staterpc")]
+use std::time::Duration;
+
+use tonic::body::Body;
+use tonic::codegen::{Service, StdError};
+use tonic::transport::Channel;
+use tower::util::BoxCloneSyncService;
+use tower::ServiceExt;
+
+#[cfg(not(out_dir_protos))]
+include!("generated/streaming_methods.rs");
+#[cfg(out_dir_protos)]
+include!(concat!(env!("OUT_DIR"), "/streaming_methods.rs"));
+
+/// Returns `true` if the gRPC method at `path` (`/package.Service/Method`) streams requests,
+/// responses or both.
+pub(crate) fn is_streaming_method(path: &str) -> bool {
+    STREAMING_METHODS.binary_search(&path).is_ok()
+}
+
+/// How calls are distributed when a [`Client`](crate::Client) is configured with several
+/// addresses through [`ClientBuilder::addresses`](crate::ClientBuilder::addresses).
+///
+/// Whatever the strategy, these rules apply:
+///
+/// - A call is dispatched to exactly one endpoint and stays there until it completes. Streams are
+///   never migrated: if their endpoint dies, the stream ends with an error and the caller has to
+///   subscribe again, which dispatches the new call to an endpoint that is still up.
+/// - Streaming RPCs (for example `SubscribeInvoices`, `SendPaymentV2`, `SubscribeHtlcEvents` or
+///   `HtlcInterceptor`) are pinned to the *primary* endpoint: the first endpoint, in the order
+///   given, that is currently up. This keeps subscriptions and interceptors on one node.
+/// - An endpoint is marked down when it can't be connected to while building the client, or when a
+///   call to it fails at the transport level (the connection could not be established or broke).
+///   With
+///   [`ClientBuilder::health_check_interval`](crate::ClientBuilder::health_check_interval), the
+///   `State` service of every endpoint is polled and endpoints are marked up again once they
+///   report `SERVER_ACTIVE`. Without health checks, an endpoint that was marked down is only
+///   retried once all endpoints are down.
+/// - If every endpoint is down, calls rotate through all of them, so the first one that recovers
+///   is found again.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BalanceStrategy {
+    /// Send every call to the primary endpoint, switching to the next one only when it goes
+    /// down.
+    #[default]
+    Failover,
+    /// Spread unary calls round robin over all endpoints that are up. Streaming RPCs still go to
+    /// the primary endpoint.
+    RoundRobin,
+}
+
+/// The transport shared by all service clients of a [`Client`](crate::Client).
+///
+/// It holds one or more HTTP/2 connections to each configured LND endpoint. Calls are spread
+/// over the connections of an endpoint round robin, and streaming RPCs (for example
+/// `SubscribeHtlcEvents` or `HtlcInterceptor`) can be kept on a connection of their own so they
+/// don't share flow control with bulk queries. See
+/// [`ClientBuilder::connection_pool_size`](crate::ClientBuilder::connection_pool_size) and
+/// [`ClientBuilder::dedicated_streaming_connection`](crate::ClientBuilder::dedicated_streaming_connection).
+/// How calls are split between endpoints is described on [`BalanceStrategy`].
+///
+/// Cloning is cheap: clones share the same connections.
+#[derive(Clone, Debug)]
+pub struct Transport {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    nodes: Vec<Node>,
+    strategy: BalanceStrategy,
+    next: AtomicUsize,
+    /// The share of a [`Fleet`](crate::fleet::Fleet)'s connection budget these connections use,
+    /// given back once the last clone of the transport is dropped.
+    #[cfg(feature = "fleet")]
+    permit: Option<tokio::sync::OwnedSemaphorePermit>,
+}
+
+/// A connection to an endpoint: a [`Channel`], or the service passed to
+/// [`Client::from_channel`](crate::Client::from_channel), which may wrap one.
+type Connection = BoxCloneSyncService<http::Request<Body>, http::Response<Body>, ConnectionError>;
+
+/// The error of a [`Connection`].
+///
+/// A named type rather than [`StdError`] itself, with which the compiler can't prove the health
+/// check task to be `Send`.
+#[derive(Debug)]
+struct ConnectionError(StdError);
+
+impl std::fmt::Display for ConnectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for ConnectionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.0.source()
+    }
+}
+
+/// The connections to a single endpoint.
+#[derive(Debug)]
+pub(crate) struct Node {
+    channels: Vec<Connection>,
+    streaming: Option<Connection>,
+    next: AtomicUsize,
+    up: AtomicBool,
+}
+
+impl Node {
+    /// Creates a node that round robins calls over `channels` and, if given, sends every
+    /// streaming RPC over `streaming`.
+    ///
+    /// # Panics
+    /// Panics if `channels` is empty.
+    pub(crate) fn new(channels: Vec<Channel>, streaming: Option<Channel>) -> Self {
+        assert!(!channels.is_empty(), "node needs at least one channel");
+        Self {
+            channels: channels.into_iter().map(connection).collect(),
+            streaming: streaming.map(connection),
+            next: AtomicUsize::new(0),
+            up: AtomicBool::new(true),
+        }
+    }
+
+    fn is_up(&self) -> bool {
+        self.up.load(Ordering::Relaxed)
+    }
+
+    /// Marks the endpoint down until a call to it succeeds or a health check finds it active.
+    pub(crate) fn mark_down(&self) {
+        self.up.store(false, Ordering::Relaxed);
+    }
+
+    /// Returns the connection a call should use.
+    fn select(&self, streaming: bool) -> &Connection {
+        if let (true, Some(channel)) = (streaming, &self.streaming) {
+            return channel;
+        }
+        if self.channels.len() == 1 {
+            return &self.channels[0];
+        }
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % self.channels.len();
+        &self.channels[index]
+    }
+}
+
+impl Transport {
+    /// Creates a transport over the given endpoints, in order of preference.
+    ///
+    /// # Panics
+    /// Panics if `nodes` is empty.
+    pub(crate) fn new(nodes: Vec<Node>, strategy: BalanceStrategy) -> Self {
+        assert!(!nodes.is_empty(), "transport needs at least one node");
+        Self {
+            inner: Arc::new(Inner {
+                nodes,
+                strategy,
+                next: AtomicUsize::new(0),
+                #[cfg(feature = "fleet")]
+                permit: None,
+            }),
+        }
+    }
+
+    /// Keeps `permit` until every clone of the transport is dropped.
+    ///
+    /// # Panics
+    /// Panics if the transport was already cloned.
+    #[cfg(feature = "fleet")]
+    pub(crate) fn holding(mut self, permit: tokio::sync::OwnedSemaphorePermit) -> Self {
+        Arc::get_mut(&mut self.inner).expect("transport already shared").permit = Some(permit);
+        self
+    }
+
+    /// Creates a transport sending every call to `service`.
+    pub(crate) fn from_service<S>(service: S) -> Self
+    where
+        S: Service<http::Request<Body>, Response = http::Response<Body>>
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+        S::Future: Send + 'static,
+        S::Error: Into<StdError>,
+    {
+        let node = Node {
+            channels: vec![connection(service)],
+            streaming: None,
+            next: AtomicUsize::new(0),
+            up: AtomicBool::new(true),
+        };
+        Self::new(vec![node], BalanceStrategy::default())
+    }
+
+    /// Returns the index of the node a call should go to.
+    fn select(&self, streaming: bool) -> usize {
+        let inner = &*self.inner;
+        let Some(primary) = inner.nodes.iter().position(Node::is_up) else {
+            return inner.next.fetch_add(1, Ordering::Relaxed) % inner.nodes.len();
+        };
+        if streaming || inner.strategy == BalanceStrategy::Failover || inner.nodes.len() == 1 {
+            return primary;
+        }
+
+        let start = inner.next.fetch_add(1, Ordering::Relaxed);
+        (0..inner.nodes.len())
+            .map(|offset| (start + offset) % inner.nodes.len())
+            .find(|&index| inner.nodes[index].is_up())
+            .unwrap_or(primary)
+    }
+
+    /// Polls the `State` service of every endpoint each `interval` and marks endpoints up or down
+    /// accordingly. The task stops once every clone of the transport is dropped.
+    #[cfg(feature = "staterpc")]
+    pub(crate) fn spawn_health_check(&self, interval: Duration) {
+        use crate::lnrpc::state_client::StateClient;
+        use crate::lnrpc::{GetStateRequest, WalletState};
+
+        let inner = Arc::downgrade(&self.inner);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let Some(inner) = inner.upgrade() else {
+                    break;
+                };
+                for node in &inner.nodes {
+                    // `State` is available without a macaroon, so the raw connection will do.
+                    let mut state = StateClient::new(node.channels[0].clone());
+                    let response =
+                        tokio::time::timeout(interval, state.get_state(GetStateRequest {})).await;
+                    let up = matches!(
+                        response,
+                        Ok(Ok(response)) if response.get_ref().state() == WalletState::ServerActive
+                    );
+                    node.up.store(up, Ordering::Relaxed);
+                }
+            }
+        });
+    }
+}
+
+impl From<Channel> for Transport {
+    fn from(channel: Channel) -> Self {
+        Self::from_service(channel)
+    }
+}
+
+fn connection<S>(service: S) -> Connection
+where
+    S: Service<http::Request<Body>, Response = http::Response<Body>>
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+    S::Future: Send + 'static,
+    S::Error: Into<StdError>,
+{
+    BoxCloneSyncService::new(service.map_err(|error| ConnectionError(error.into())))
+}
+
+impl Service<http::Request<Body>> for Transport {
+    type Response = http::Response<Body>;
+    type Error = StdError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // Readiness is checked on the selected channel in `call`, since which channel is used
+        // depends on the request.
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<Body>) -> Self::Future {
+        let streaming = is_streaming_method(request.uri().path());
+        let index = self.select(streaming);
+        let mut channel = self.inner.nodes[index].select(streaming).clone();
+        let inner = self.inner.clone();
+        Box::pin(async move {
+            let result = async {
+                std::future::poll_fn(|cx| channel.poll_ready(cx)).await?;
+                channel.call(request).await
+            }
+            .await;
+            inner.nodes[index].up.store(result.is_ok(), Ordering::Relaxed);
+            result.map_err(|error| error.0)
+        })
+    }
+}
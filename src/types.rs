@@ -0,0 +1,817 @@
+//! Typed wrappers for the hashes, keys, IDs and amounts that generated messages carry as raw
+//! bytes, strings or integers.
+//!
+//! Each type checks its length on construction and displays and parses in the format LND uses:
+//! hex for hashes, preimages and node IDs, reversed hex for transaction IDs, `txid:index` for
+//! outpoints and channel points and `BLOCKxTXxOUT` for short channel IDs. Conversions from proto
+//! fields are fallible, conversions back are not.
+//!
+//! Amounts are either [`Amount`] in satoshis or [`MilliSats`], so the two can't be mixed up.
+//! Their arithmetic is checked, converting millisatoshis to satoshis names the rounding, and
+//! messages such as `Invoice`, `Payment`, `Channel`, `ForwardingEvent` and `SendPaymentRequest`
+//! have accessors returning them.
+//!
+//! # Example
+//! ```rust
+//! use voltage_tonic_lnd::types::{ChannelPoint, PaymentHash, Preimage};
+//!
+//! let preimage = Preimage::from([1; 32]);
+//! let hash: PaymentHash = preimage.payment_hash();
+//! assert_eq!(hash, PaymentHash::try_from(Vec::from(hash)).unwrap());
+//!
+//! let point: ChannelPoint =
+//!     "2b2ba6b6bb4d9bd6df2fd2c4b7f5a1e5c4f0d5b0b3c1d2e3f4a5b6c7d8e9f0a1:1".parse().unwrap();
+//! assert_eq!(point.output_index, 1);
+//! assert_eq!(point.funding_txid.as_bytes()[0], 0xa1);
+//! assert_eq!(point.to_string().parse::<ChannelPoint>().unwrap(), point);
+//! ```
+//!
+//! ```rust
+//! use voltage_tonic_lnd::types::{Amount, MilliSats};
+//!
+//! let amount = MilliSats::from_msat(1_500_500);
+//! assert_eq!(amount.to_sat_floor(), Amount::from_sat(1500));
+//! assert_eq!(amount.to_sat_ceil(), Amount::from_sat(1501));
+//! assert_eq!(amount.to_sat_exact(), None);
+//! assert_eq!(amount.checked_sub(MilliSats::from_msat(2_000_000)), None);
+//!
+//! let capacity: Amount = "0.01 BTC".parse().unwrap();
+//! assert_eq!(capacity, Amount::from_sat(1_000_000));
+//! assert_eq!(capacity.to_string(), "1000000 sat");
+//! assert_eq!(capacity.to_btc_string(), "0.01000000");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use sha2::{Digest, Sha256};
+
+pub use self::amount::{Amount, MilliSats};
+use crate::error::ParseError;
+
+mod amount;
+
+macro_rules! byte_array {
+    ($(#[$attr:meta])* $name:ident, $len:expr) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name([u8; $len]);
+
+        impl $name {
+            /// The length in bytes.
+            pub const LEN: usize = $len;
+
+            /// Returns the bytes.
+            pub fn to_bytes(self) -> [u8; $len] {
+                self.0
+            }
+
+            /// Returns the bytes as a slice.
+            pub fn as_bytes(&self) -> &[u8; $len] {
+                &self.0
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl TryFrom<&[u8]> for $name {
+            type Error = ParseError;
+
+            fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+                let bytes = <[u8; $len]>::try_from(bytes).map_err(|_| ParseError::InvalidLength {
+                    expected: $len,
+                    actual: bytes.len(),
+                })?;
+                Self::from_array(bytes)
+            }
+        }
+
+        impl TryFrom<Vec<u8>> for $name {
+            type Error = ParseError;
+
+            fn try_from(bytes: Vec<u8>) -> Result<Self, ParseError> {
+                Self::try_from(bytes.as_slice())
+            }
+        }
+
+        impl TryFrom<&Vec<u8>> for $name {
+            type Error = ParseError;
+
+            fn try_from(bytes: &Vec<u8>) -> Result<Self, ParseError> {
+                Self::try_from(bytes.as_slice())
+            }
+        }
+
+        impl From<$name> for Vec<u8> {
+            fn from(value: $name) -> Self {
+                value.0.to_vec()
+            }
+        }
+
+        #[cfg(feature = "bytes")]
+        impl TryFrom<prost::bytes::Bytes> for $name {
+            type Error = ParseError;
+
+            fn try_from(bytes: prost::bytes::Bytes) -> Result<Self, ParseError> {
+                Self::try_from(&bytes[..])
+            }
+        }
+
+        #[cfg(feature = "bytes")]
+        impl TryFrom<&prost::bytes::Bytes> for $name {
+            type Error = ParseError;
+
+            fn try_from(bytes: &prost::bytes::Bytes) -> Result<Self, ParseError> {
+                Self::try_from(&bytes[..])
+            }
+        }
+
+        #[cfg(feature = "bytes")]
+        impl From<$name> for prost::bytes::Bytes {
+            fn from(value: $name) -> Self {
+                Self::copy_from_slice(&value.0)
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}({})", stringify!($name), self)
+            }
+        }
+    };
+}
+
+/// Implements hex `Display` and `FromStr` in byte order.
+macro_rules! hex_display {
+    ($name:ident) => {
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&hex::encode(self.0))
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, ParseError> {
+                Self::try_from(hex::decode(s)?)
+            }
+        }
+    };
+}
+
+/// Implements infallible construction from an array, for types where any bytes are valid.
+macro_rules! from_array {
+    ($name:ident, $len:expr) => {
+        impl $name {
+            fn from_array(bytes: [u8; $len]) -> Result<Self, ParseError> {
+                Ok(Self(bytes))
+            }
+        }
+
+        impl From<[u8; $len]> for $name {
+            fn from(bytes: [u8; $len]) -> Self {
+                Self(bytes)
+            }
+        }
+    };
+}
+
+byte_array! {
+    /// The SHA-256 hash of a [`Preimage`], identifying a payment. Found in `r_hash` and
+    /// `payment_hash` fields.
+    PaymentHash, 32
+}
+from_array!(PaymentHash, 32);
+hex_display!(PaymentHash);
+
+byte_array! {
+    /// The secret revealed when a payment settles. Found in `r_preimage` and `payment_preimage`
+    /// fields.
+    Preimage, 32
+}
+from_array!(Preimage, 32);
+hex_display!(Preimage);
+
+impl Preimage {
+    /// Returns the payment hash of this preimage, its SHA-256 hash.
+    pub fn payment_hash(&self) -> PaymentHash {
+        PaymentHash(Sha256::digest(self.0).into())
+    }
+}
+
+byte_array! {
+    /// A node's public key, as a 33-byte compressed secp256k1 point. Found in `pub_key`,
+    /// `identity_pubkey` and `dest` fields.
+    ///
+    /// Only the length and the prefix byte are checked, not that the key is on the curve.
+    NodeId, 33
+}
+hex_display!(NodeId);
+
+impl NodeId {
+    fn from_array(bytes: [u8; 33]) -> Result<Self, ParseError> {
+        match bytes[0] {
+            0x02 | 0x03 => Ok(Self(bytes)),
+            prefix => Err(ParseError::InvalidFormat(format!(
+                "public key prefix must be 02 or 03, got {prefix:02x}"
+            ))),
+        }
+    }
+}
+
+byte_array! {
+    /// A transaction ID.
+    ///
+    /// Bytes are kept in the order of `*_bytes` proto fields such as `txid_bytes` and
+    /// `funding_txid_bytes`, which is the reverse of the hex shown by LND and block explorers.
+    /// `Display` and `FromStr` use the reversed hex, like `txid_str` fields.
+    Txid, 32
+}
+from_array!(Txid, 32);
+
+impl fmt::Display for Txid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut reversed = self.0;
+        reversed.reverse();
+        f.write_str(&hex::encode(reversed))
+    }
+}
+
+impl FromStr for Txid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let mut bytes = hex::decode(s)?;
+        bytes.reverse();
+        Self::try_from(bytes)
+    }
+}
+
+/// An output of a transaction, displayed and parsed as `txid:index`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OutPoint {
+    pub txid: Txid,
+    pub output_index: u32,
+}
+
+impl OutPoint {
+    pub fn new(txid: Txid, output_index: u32) -> Self {
+        Self {
+            txid,
+            output_index,
+        }
+    }
+}
+
+impl fmt::Display for OutPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.txid, self.output_index)
+    }
+}
+
+impl FromStr for OutPoint {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let (txid, index) = s
+            .split_once(':')
+            .ok_or_else(|| ParseError::InvalidFormat(format!("expected txid:index, got {s}")))?;
+        let output_index = index
+            .parse()
+            .map_err(|_| ParseError::InvalidFormat(format!("invalid output index {index}")))?;
+        Ok(Self::new(txid.parse()?, output_index))
+    }
+}
+
+/// The funding output of a channel, displayed and parsed as `txid:index` like the
+/// `channel_point` fields of LND.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChannelPoint {
+    pub funding_txid: Txid,
+    pub output_index: u32,
+}
+
+impl ChannelPoint {
+    pub fn new(funding_txid: Txid, output_index: u32) -> Self {
+        Self {
+            funding_txid,
+            output_index,
+        }
+    }
+}
+
+impl fmt::Display for ChannelPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        OutPoint::from(*self).fmt(f)
+    }
+}
+
+impl FromStr for ChannelPoint {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        s.parse::<OutPoint>().map(Self::from)
+    }
+}
+
+impl From<ChannelPoint> for OutPoint {
+    fn from(point: ChannelPoint) -> Self {
+        Self::new(point.funding_txid, point.output_index)
+    }
+}
+
+impl From<OutPoint> for ChannelPoint {
+    fn from(outpoint: OutPoint) -> Self {
+        Self::new(outpoint.txid, outpoint.output_index)
+    }
+}
+
+/// A short channel ID, the `chan_id` of LND: the block height, the index of the funding
+/// transaction in the block and the index of the funding output, packed into a `u64`.
+///
+/// Displayed as `BLOCKxTXxOUT`. Parsed from that, from LND's `BLOCK:TX:OUT` and from the
+/// decimal `u64`.
+///
+/// # Example
+/// ```rust
+/// use voltage_tonic_lnd::types::ShortChannelId;
+///
+/// let scid: ShortChannelId = "800000:1234:1".parse().unwrap();
+/// assert_eq!(u64::from(scid), 879609302301671425);
+/// assert_eq!(scid.to_string(), "800000x1234x1");
+/// assert!(!scid.is_alias());
+/// assert!(ShortChannelId::new(16_000_000, 0, 1).unwrap().is_alias());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ShortChannelId(u64);
+
+impl ShortChannelId {
+    /// The range of block heights LND assigns SCID aliases from. No real channel is that deep.
+    pub const ALIAS_BLOCKS: std::ops::Range<u32> = 16_000_000..16_250_000;
+
+    /// Packs a short channel ID. Returns `None` if `block` doesn't fit in 24 bits or `tx_index`
+    /// in 24 bits.
+    pub fn new(block: u32, tx_index: u32, output_index: u16) -> Option<Self> {
+        if block >= 1 << 24 || tx_index >= 1 << 24 {
+            return None;
+        }
+        Some(Self(u64::from(block) << 40 | u64::from(tx_index) << 16 | u64::from(output_index)))
+    }
+
+    /// The height of the block containing the funding transaction.
+    pub fn block(&self) -> u32 {
+        (self.0 >> 40) as u32
+    }
+
+    /// The index of the funding transaction in its block.
+    pub fn tx_index(&self) -> u32 {
+        (self.0 >> 16) as u32 & 0xff_ffff
+    }
+
+    /// The index of the funding output in its transaction.
+    pub fn output_index(&self) -> u16 {
+        self.0 as u16
+    }
+
+    /// Returns `true` if this is an alias assigned by LND rather than the location of a funding
+    /// output, judging from [`ALIAS_BLOCKS`](Self::ALIAS_BLOCKS).
+    pub fn is_alias(&self) -> bool {
+        Self::ALIAS_BLOCKS.contains(&self.block())
+    }
+}
+
+impl From<u64> for ShortChannelId {
+    fn from(chan_id: u64) -> Self {
+        Self(chan_id)
+    }
+}
+
+impl From<ShortChannelId> for u64 {
+    fn from(scid: ShortChannelId) -> Self {
+        scid.0
+    }
+}
+
+impl fmt::Debug for ShortChannelId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ShortChannelId({self})")
+    }
+}
+
+impl fmt::Display for ShortChannelId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}x{}x{}", self.block(), self.tx_index(), self.output_index())
+    }
+}
+
+impl FromStr for ShortChannelId {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let invalid = || ParseError::InvalidFormat(format!("invalid short channel id {s}"));
+        let separator = match (s.contains('x'), s.contains(':')) {
+            (true, false) => 'x',
+            (false, true) => ':',
+            (false, false) => return s.parse().map(Self).map_err(|_| invalid()),
+            (true, true) => return Err(invalid()),
+        };
+        let mut parts = s.split(separator);
+        let (Some(block), Some(tx_index), Some(output_index), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        Self::new(
+            block.parse().map_err(|_| invalid())?,
+            tx_index.parse().map_err(|_| invalid())?,
+            output_index.parse().map_err(|_| invalid())?,
+        )
+        .ok_or_else(invalid)
+    }
+}
+
+/// Implements `Serialize` and `Deserialize` as the `Display` string.
+#[cfg(feature = "serde")]
+macro_rules! serde_display {
+    ($($name:ident),*) => {
+        $(
+            impl serde::Serialize for $name {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for $name {
+                fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                    s.parse().map_err(serde::de::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "serde")]
+serde_display!(PaymentHash, Preimage, NodeId, Txid, OutPoint, ChannelPoint, ShortChannelId);
+
+/// Maps between the short channel IDs, SCID aliases and channel points of a node's channels,
+/// built from the responses of `ListChannels` and `ListAliases`.
+///
+/// Forwarding events, HTLC events and graph updates identify channels by short channel ID, which
+/// may be an alias for private or zero-conf channels. Resolving them through the index gives the
+/// channel point regardless of which ID was used.
+///
+/// # Example
+/// ```rust,no_run
+/// use voltage_tonic_lnd::lnrpc::{
+///     ForwardingHistoryRequest, ListAliasesRequest, ListChannelsRequest,
+/// };
+/// use voltage_tonic_lnd::types::ChannelIndex;
+///
+/// # async fn example(client: voltage_tonic_lnd::Client) -> voltage_tonic_lnd::Result<()> {
+/// let mut lightning = client.lightning()?;
+/// let channels = lightning.list_channels(ListChannelsRequest::default()).await?.into_inner();
+/// let aliases = lightning.list_aliases(ListAliasesRequest {}).await?.into_inner();
+/// let mut index = ChannelIndex::from_channels(&channels.channels)?;
+/// index.add_aliases(&aliases.alias_maps);
+///
+/// let history = lightning
+///     .forwarding_history(ForwardingHistoryRequest::default())
+///     .await?
+///     .into_inner();
+/// for event in history.forwarding_events {
+///     let (incoming, outgoing) = (event.chan_id_in, event.chan_id_out);
+///     println!("{:?} -> {:?}", index.channel_point(incoming), index.channel_point(outgoing));
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "lightningrpc")]
+#[derive(Debug, Clone, Default)]
+pub struct ChannelIndex {
+    /// Every known ID, real or alias, to the channel it identifies.
+    points: std::collections::HashMap<ShortChannelId, ChannelPoint>,
+    /// Each channel to the ID LND reports as its `chan_id`.
+    ids: std::collections::HashMap<ChannelPoint, ShortChannelId>,
+    /// Aliases to the ID they stand for.
+    bases: std::collections::HashMap<ShortChannelId, ShortChannelId>,
+    /// IDs to their aliases.
+    aliases: std::collections::HashMap<ShortChannelId, Vec<ShortChannelId>>,
+}
+
+#[cfg(feature = "lightningrpc")]
+impl ChannelIndex {
+    /// Creates an empty index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an index of `channels`, as returned by `ListChannels`.
+    ///
+    /// # Errors
+    /// Returns an error if a channel point can't be parsed.
+    pub fn from_channels(channels: &[crate::lnrpc::Channel]) -> Result<Self, ParseError> {
+        let mut index = Self::new();
+        for channel in channels {
+            index.add_channel(channel)?;
+        }
+        Ok(index)
+    }
+
+    /// Adds a channel as returned by `ListChannels`, with its `chan_id`, its aliases and, for
+    /// zero-conf channels, its confirmed short channel ID.
+    ///
+    /// # Errors
+    /// Returns an error if the channel point can't be parsed.
+    pub fn add_channel(&mut self, channel: &crate::lnrpc::Channel) -> Result<(), ParseError> {
+        let point: ChannelPoint = channel.channel_point.parse()?;
+        let chan_id = ShortChannelId(channel.chan_id);
+        let base = match channel.zero_conf_confirmed_scid {
+            0 => chan_id,
+            confirmed => ShortChannelId(confirmed),
+        };
+        self.ids.insert(point, chan_id);
+        self.points.insert(base, point);
+        let aliases = channel
+            .alias_scids
+            .iter()
+            .chain([&channel.chan_id, &channel.peer_scid_alias])
+            .filter(|&&alias| alias != 0 && alias != u64::from(base))
+            .map(|&alias| ShortChannelId(alias));
+        for alias in aliases {
+            self.points.insert(alias, point);
+            self.add_alias(base, alias);
+        }
+        Ok(())
+    }
+
+    /// Adds the aliases returned by `ListAliases`, including those of channels that aren't
+    /// open anymore or not added with [`add_channel`](Self::add_channel).
+    pub fn add_aliases(&mut self, alias_maps: &[crate::lnrpc::AliasMap]) {
+        for map in alias_maps {
+            let base = ShortChannelId(map.base_scid);
+            for &alias in &map.aliases {
+                let alias = ShortChannelId(alias);
+                if let Some(&point) = self.points.get(&base) {
+                    self.points.insert(alias, point);
+                }
+                self.add_alias(base, alias);
+            }
+        }
+    }
+
+    fn add_alias(&mut self, base: ShortChannelId, alias: ShortChannelId) {
+        self.bases.insert(alias, base);
+        let aliases = self.aliases.entry(base).or_default();
+        if !aliases.contains(&alias) {
+            aliases.push(alias);
+        }
+    }
+
+    /// Returns the channel identified by `scid`, which may be a real short channel ID or an
+    /// alias.
+    pub fn channel_point(&self, scid: impl Into<ShortChannelId>) -> Option<ChannelPoint> {
+        let scid = scid.into();
+        self.points.get(&scid).or_else(|| self.points.get(self.bases.get(&scid)?)).copied()
+    }
+
+    /// Returns the ID LND reports as the `chan_id` of the channel at `point`.
+    pub fn short_channel_id(&self, point: &ChannelPoint) -> Option<ShortChannelId> {
+        self.ids.get(point).copied()
+    }
+
+    /// Returns the ID `alias` stands for, or `alias` itself if it isn't a known alias.
+    pub fn base(&self, alias: impl Into<ShortChannelId>) -> ShortChannelId {
+        let alias = alias.into();
+        self.bases.get(&alias).copied().unwrap_or(alias)
+    }
+
+    /// Returns the known aliases of `scid`.
+    pub fn aliases(&self, scid: impl Into<ShortChannelId>) -> &[ShortChannelId] {
+        self.aliases.get(&self.base(scid)).map_or(&[], Vec::as_slice)
+    }
+}
+
+#[cfg(feature = "lightningrpc")]
+mod lnrpc_conversions {
+    use super::*;
+    use crate::lnrpc;
+    use crate::lnrpc::channel_point::FundingTxid;
+
+    impl TryFrom<&lnrpc::ChannelPoint> for ChannelPoint {
+        type Error = ParseError;
+
+        fn try_from(point: &lnrpc::ChannelPoint) -> Result<Self, ParseError> {
+            let funding_txid = match &point.funding_txid {
+                Some(FundingTxid::FundingTxidBytes(bytes)) => Txid::try_from(bytes)?,
+                Some(FundingTxid::FundingTxidStr(s)) => s.parse()?,
+                None => return Err(ParseError::InvalidFormat("missing funding txid".to_owned())),
+            };
+            Ok(Self::new(funding_txid, point.output_index))
+        }
+    }
+
+    impl TryFrom<lnrpc::ChannelPoint> for ChannelPoint {
+        type Error = ParseError;
+
+        fn try_from(point: lnrpc::ChannelPoint) -> Result<Self, ParseError> {
+            Self::try_from(&point)
+        }
+    }
+
+    impl From<ChannelPoint> for lnrpc::ChannelPoint {
+        fn from(point: ChannelPoint) -> Self {
+            Self {
+                funding_txid: Some(FundingTxid::FundingTxidBytes(point.funding_txid.into())),
+                output_index: point.output_index,
+            }
+        }
+    }
+
+    impl TryFrom<&lnrpc::OutPoint> for OutPoint {
+        type Error = ParseError;
+
+        /// Uses `txid_bytes` if set, `txid_str` otherwise.
+        fn try_from(outpoint: &lnrpc::OutPoint) -> Result<Self, ParseError> {
+            let txid = match outpoint.txid_bytes.is_empty() {
+                true => outpoint.txid_str.parse()?,
+                false => Txid::try_from(&outpoint.txid_bytes)?,
+            };
+            Ok(Self::new(txid, outpoint.output_index))
+        }
+    }
+
+    impl TryFrom<lnrpc::OutPoint> for OutPoint {
+        type Error = ParseError;
+
+        fn try_from(outpoint: lnrpc::OutPoint) -> Result<Self, ParseError> {
+            Self::try_from(&outpoint)
+        }
+    }
+
+    /// Sets both `txid_bytes` and `txid_str`.
+    impl From<OutPoint> for lnrpc::OutPoint {
+        fn from(outpoint: OutPoint) -> Self {
+            Self {
+                txid_bytes: outpoint.txid.into(),
+                txid_str: outpoint.txid.to_string(),
+                output_index: outpoint.output_index,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TXID: &str = "2b2ba6b6bb4d9bd6df2fd2c4b7f5a1e5c4f0d5b0b3c1d2e3f4a5b6c7d8e9f0a1";
+
+    #[test]
+    fn byte_arrays() {
+        assert_eq!(
+            PaymentHash::try_from(vec![0; 31]),
+            Err(ParseError::InvalidLength {
+                expected: 32,
+                actual: 31
+            })
+        );
+        assert!(matches!("zz".parse::<Preimage>(), Err(ParseError::InvalidHex(_))));
+
+        let node_id = format!("02{}", "11".repeat(32));
+        assert_eq!(node_id.parse::<NodeId>().unwrap().to_string(), node_id);
+        let bad_prefix = format!("04{}", "11".repeat(32));
+        assert!(matches!(bad_prefix.parse::<NodeId>(), Err(ParseError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn txid_is_reversed() {
+        let txid: Txid = TXID.parse().unwrap();
+        assert_eq!(txid.as_bytes()[0], 0xa1);
+        assert_eq!(txid.as_bytes()[31], 0x2b);
+        assert_eq!(txid.to_string(), TXID);
+    }
+
+    #[test]
+    fn outpoints() {
+        let outpoint: OutPoint = format!("{TXID}:3").parse().unwrap();
+        assert_eq!(outpoint.output_index, 3);
+        assert_eq!(outpoint.to_string(), format!("{TXID}:3"));
+        assert_eq!(ChannelPoint::from(outpoint).to_string(), outpoint.to_string());
+
+        for invalid in [TXID.to_owned(), format!("{TXID}:x"), format!("{TXID}:-1"), "ab:0".into()] {
+            assert!(invalid.parse::<OutPoint>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn short_channel_id_ranges() {
+        let max = ShortChannelId::new((1 << 24) - 1, (1 << 24) - 1, u16::MAX).unwrap();
+        assert_eq!(u64::from(max), u64::MAX);
+        assert_eq!(
+            (max.block(), max.tx_index(), max.output_index()),
+            (16_777_215, 16_777_215, 65_535)
+        );
+        assert_eq!(ShortChannelId::new(1 << 24, 0, 0), None);
+        assert_eq!(ShortChannelId::new(0, 1 << 24, 0), None);
+
+        let min = ShortChannelId::new(0, 0, 0).unwrap();
+        assert_eq!(u64::from(min), 0);
+        assert_eq!(ShortChannelId::from(1), ShortChannelId::new(0, 0, 1).unwrap());
+    }
+
+    #[test]
+    fn aliases() {
+        let scid = |block| ShortChannelId::new(block, 0, 0).unwrap();
+        assert!(!scid(15_999_999).is_alias());
+        assert!(scid(16_000_000).is_alias());
+        assert!(scid(16_249_999).is_alias());
+        assert!(!scid(16_250_000).is_alias());
+    }
+
+    #[test]
+    fn parse_short_channel_id() {
+        let scid = ShortChannelId::new(800_000, 1234, 1).unwrap();
+        assert_eq!("800000x1234x1".parse(), Ok(scid));
+        assert_eq!("800000:1234:1".parse(), Ok(scid));
+        assert_eq!("879609302301671425".parse(), Ok(scid));
+        assert_eq!(scid.to_string().parse(), Ok(scid));
+        assert_eq!(format!("{scid:?}"), "ShortChannelId(800000x1234x1)");
+
+        for invalid in [
+            "",
+            "800000x1234",
+            "800000x1234x1x0",
+            "800000:1234x1",
+            "16777216x0x0",
+            "0x16777216x0",
+            "0x0x65536",
+            "-1",
+            "18446744073709551616",
+        ] {
+            assert!(invalid.parse::<ShortChannelId>().is_err(), "{invalid}");
+        }
+    }
+
+    #[cfg(feature = "lightningrpc")]
+    #[test]
+    fn channel_index() {
+        use crate::lnrpc::{AliasMap, Channel};
+
+        let alias = |n| u64::from(ShortChannelId::new(16_000_000, n, 0).unwrap());
+        let confirmed = u64::from(ShortChannelId::new(800_000, 1, 0).unwrap());
+        let point: ChannelPoint = format!("{TXID}:0").parse().unwrap();
+        // A zero-conf channel LND reports under its alias, confirmed since.
+        let channel = Channel {
+            channel_point: point.to_string(),
+            chan_id: alias(1),
+            zero_conf_confirmed_scid: confirmed,
+            alias_scids: vec![alias(1), alias(2)],
+            peer_scid_alias: alias(3),
+            ..Default::default()
+        };
+        let mut index = ChannelIndex::from_channels(&[channel]).unwrap();
+        // An alias LND only reports through `ListAliases`.
+        index.add_aliases(&[AliasMap {
+            base_scid: confirmed,
+            aliases: vec![alias(4)],
+        }]);
+
+        for scid in [confirmed, alias(1), alias(2), alias(3), alias(4)] {
+            assert_eq!(index.channel_point(scid), Some(point), "{scid}");
+            assert_eq!(index.base(scid), ShortChannelId::from(confirmed));
+        }
+        assert_eq!(index.short_channel_id(&point), Some(ShortChannelId::from(alias(1))));
+        let aliases: Vec<_> = [1, 2, 3, 4].map(|n| ShortChannelId::from(alias(n))).into();
+        assert_eq!(index.aliases(alias(2)), aliases);
+        assert_eq!(index.channel_point(alias(5)), None);
+        assert_eq!(index.aliases(alias(5)), []);
+
+        let invalid = Channel {
+            channel_point: "not a channel point".into(),
+            ..Default::default()
+        };
+        assert!(ChannelIndex::from_channels(&[invalid]).is_err());
+    }
+
+    #[cfg(feature = "lightningrpc")]
+    #[test]
+    fn proto_outpoints() {
+        let outpoint: OutPoint = format!("{TXID}:1").parse().unwrap();
+        let proto = crate::lnrpc::OutPoint::from(outpoint);
+        assert_eq!(proto.txid_str, TXID);
+        assert_eq!(OutPoint::try_from(&proto), Ok(outpoint));
+
+        let from_str = crate::lnrpc::OutPoint {
+            txid_str: TXID.into(),
+            output_index: 1,
+            ..Default::default()
+        };
+        assert_eq!(OutPoint::try_from(from_str), Ok(outpoint));
+    }
+}
@@ -0,0 +1,462 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+/// Implements the representation and arithmetic shared by [`Amount`] and [`MilliSats`].
+macro_rules! amount {
+    ($(#[$attr:meta])* $name:ident, $unit:literal, $from:ident, $to:ident) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
+        pub struct $name(
+            #[cfg_attr(
+                feature = "serde",
+                serde(
+                    serialize_with = "crate::serde_helpers::serialize::<crate::serde_helpers::Int64, _, _>",
+                    deserialize_with = "crate::serde_helpers::deserialize::<crate::serde_helpers::Int64, _, _>"
+                )
+            )]
+            u64,
+        );
+
+        impl $name {
+            pub const ZERO: Self = Self(0);
+            pub const MAX: Self = Self(u64::MAX);
+
+            #[doc = concat!("Creates an amount of `", $unit, "`.")]
+            pub const fn $from(value: u64) -> Self {
+                Self(value)
+            }
+
+            #[doc = concat!("Returns the amount in ", $unit, ".")]
+            pub const fn $to(self) -> u64 {
+                self.0
+            }
+
+            /// Returns `true` if the amount is zero.
+            pub const fn is_zero(self) -> bool {
+                self.0 == 0
+            }
+
+            /// Adds `other`, or returns `None` on overflow.
+            pub const fn checked_add(self, other: Self) -> Option<Self> {
+                match self.0.checked_add(other.0) {
+                    Some(value) => Some(Self(value)),
+                    None => None,
+                }
+            }
+
+            /// Subtracts `other`, or returns `None` if it's larger.
+            pub const fn checked_sub(self, other: Self) -> Option<Self> {
+                match self.0.checked_sub(other.0) {
+                    Some(value) => Some(Self(value)),
+                    None => None,
+                }
+            }
+
+            /// Multiplies by `factor`, or returns `None` on overflow.
+            pub const fn checked_mul(self, factor: u64) -> Option<Self> {
+                match self.0.checked_mul(factor) {
+                    Some(value) => Some(Self(value)),
+                    None => None,
+                }
+            }
+
+            /// Divides by `divisor`, rounding down, or returns `None` if it's zero.
+            pub const fn checked_div(self, divisor: u64) -> Option<Self> {
+                match self.0.checked_div(divisor) {
+                    Some(value) => Some(Self(value)),
+                    None => None,
+                }
+            }
+
+            /// Adds `other`, stopping at [`MAX`](Self::MAX).
+            pub const fn saturating_add(self, other: Self) -> Self {
+                Self(self.0.saturating_add(other.0))
+            }
+
+            /// Subtracts `other`, stopping at zero.
+            pub const fn saturating_sub(self, other: Self) -> Self {
+                Self(self.0.saturating_sub(other.0))
+            }
+
+            /// Adds up `amounts`, or returns `None` on overflow.
+            pub fn checked_sum(amounts: impl IntoIterator<Item = Self>) -> Option<Self> {
+                amounts.into_iter().try_fold(Self::ZERO, Self::checked_add)
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}({self})", stringify!($name))
+            }
+        }
+
+        impl TryFrom<i64> for $name {
+            type Error = ParseError;
+
+            /// Converts one of the signed amount fields of LND, which are never negative.
+            fn try_from(value: i64) -> Result<Self, ParseError> {
+                u64::try_from(value)
+                    .map(Self)
+                    .map_err(|_| ParseError::OutOfRange(format!("negative amount {value}")))
+            }
+        }
+
+        impl TryFrom<$name> for i64 {
+            type Error = ParseError;
+
+            fn try_from(amount: $name) -> Result<Self, ParseError> {
+                i64::try_from(amount.0)
+                    .map_err(|_| ParseError::OutOfRange(format!("{amount} doesn't fit in an int64")))
+            }
+        }
+    };
+}
+
+amount!(
+    /// An amount in satoshis, for the `value`, `amt`, `capacity` and `*_sat` fields of LND.
+    ///
+    /// Displayed as `1234 sat`. Parsed from that, from a plain integer and from a BTC amount
+    /// with up to 8 decimals such as `0.00001234 BTC`.
+    Amount,
+    "satoshis",
+    from_sat,
+    sat
+);
+
+amount!(
+    /// An amount in millisatoshis, for the `*_msat` fields of LND.
+    ///
+    /// Displayed as `1234 msat`. Parsed from that and from a plain integer, like the
+    /// string-encoded `msat` fields of LND.
+    MilliSats,
+    "millisatoshis",
+    from_msat,
+    msat
+);
+
+impl Amount {
+    /// The number of satoshis in a bitcoin.
+    pub const SAT_PER_BTC: u64 = 100_000_000;
+
+    /// Converts to millisatoshis, or returns `None` on overflow.
+    pub const fn checked_to_msat(self) -> Option<MilliSats> {
+        match self.0.checked_mul(1000) {
+            Some(msat) => Some(MilliSats(msat)),
+            None => None,
+        }
+    }
+
+    /// Formats the amount in BTC with 8 decimals, such as `0.00001234`.
+    pub fn to_btc_string(self) -> String {
+        format!("{}.{:08}", self.0 / Self::SAT_PER_BTC, self.0 % Self::SAT_PER_BTC)
+    }
+}
+
+impl MilliSats {
+    /// Converts to satoshis, dropping any fraction like LND does.
+    pub const fn to_sat_floor(self) -> Amount {
+        Amount(self.0 / 1000)
+    }
+
+    /// Converts to satoshis, rounding any fraction up. Use this for amounts a payment must at
+    /// least cover.
+    pub const fn to_sat_ceil(self) -> Amount {
+        Amount(self.0.div_ceil(1000))
+    }
+
+    /// Converts to satoshis, rounding to the nearest and half up.
+    pub const fn to_sat_round(self) -> Amount {
+        Amount(self.0 / 1000 + (self.0 % 1000 >= 500) as u64)
+    }
+
+    /// Converts to satoshis, or returns `None` if the amount has a fraction of a satoshi.
+    pub const fn to_sat_exact(self) -> Option<Amount> {
+        match self.0 % 1000 {
+            0 => Some(Amount(self.0 / 1000)),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<Amount> for MilliSats {
+    type Error = ParseError;
+
+    fn try_from(amount: Amount) -> Result<Self, ParseError> {
+        amount
+            .checked_to_msat()
+            .ok_or_else(|| ParseError::OutOfRange(format!("{amount} doesn't fit in msat")))
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} sat", self.0)
+    }
+}
+
+impl fmt::Display for MilliSats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} msat", self.0)
+    }
+}
+
+impl FromStr for Amount {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let invalid = || ParseError::InvalidFormat(format!("invalid amount {s}"));
+        let s = s.trim();
+        if let Some(btc) = s.strip_suffix("BTC").or_else(|| s.strip_suffix("btc")) {
+            let btc = btc.trim_end();
+            let (whole, fraction) = btc.split_once('.').unwrap_or((btc, ""));
+            if fraction.len() > 8 || !fraction.bytes().all(|byte| byte.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            let whole: u64 = whole.parse().map_err(|_| invalid())?;
+            let fraction: u64 = format!("{fraction:0<8}").parse().map_err(|_| invalid())?;
+            return whole
+                .checked_mul(Self::SAT_PER_BTC)
+                .and_then(|sat| sat.checked_add(fraction))
+                .map(Self)
+                .ok_or_else(invalid);
+        }
+        let sat = s.strip_suffix("sats").or_else(|| s.strip_suffix("sat")).unwrap_or(s);
+        sat.trim_end().parse().map(Self).map_err(|_| invalid())
+    }
+}
+
+impl FromStr for MilliSats {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let s = s.trim();
+        let msat = s.strip_suffix("msat").unwrap_or(s);
+        msat.trim_end()
+            .parse()
+            .map(Self)
+            .map_err(|_| ParseError::InvalidFormat(format!("invalid amount {s}")))
+    }
+}
+
+#[cfg(feature = "lightningrpc")]
+mod lnrpc_accessors {
+    use super::*;
+    use crate::lnrpc;
+
+    impl From<&lnrpc::Amount> for MilliSats {
+        /// Uses `msat`, which LND sets along with `sat`.
+        fn from(amount: &lnrpc::Amount) -> Self {
+            Self(amount.msat)
+        }
+    }
+
+    impl From<MilliSats> for lnrpc::Amount {
+        fn from(amount: MilliSats) -> Self {
+            Self {
+                sat: amount.to_sat_floor().sat(),
+                msat: amount.msat(),
+            }
+        }
+    }
+
+    impl lnrpc::Invoice {
+        /// Returns the amount requested, from `value_msat`. Zero means the payer chooses it.
+        pub fn amount(&self) -> Result<MilliSats, ParseError> {
+            self.value_msat.try_into()
+        }
+
+        /// Sets the amount to request in `value_msat`, clearing `value`, as the two are
+        /// mutually exclusive.
+        pub fn set_amount(&mut self, amount: MilliSats) -> Result<(), ParseError> {
+            self.value_msat = amount.try_into()?;
+            self.value = 0;
+            Ok(())
+        }
+
+        /// Returns the amount received, from `amt_paid_msat`. It can be more than requested.
+        pub fn amount_paid(&self) -> Result<MilliSats, ParseError> {
+            self.amt_paid_msat.try_into()
+        }
+    }
+
+    impl lnrpc::Payment {
+        /// Returns the amount paid to the destination, from `value_msat`.
+        pub fn amount(&self) -> Result<MilliSats, ParseError> {
+            self.value_msat.try_into()
+        }
+
+        /// Returns the routing fees paid, from `fee_msat`.
+        pub fn fee(&self) -> Result<MilliSats, ParseError> {
+            self.fee_msat.try_into()
+        }
+    }
+
+    impl lnrpc::Channel {
+        /// Returns the total funds of the channel.
+        pub fn capacity(&self) -> Result<Amount, ParseError> {
+            self.capacity.try_into()
+        }
+
+        /// Returns this node's balance, rounded down to satoshis by LND.
+        pub fn local_balance(&self) -> Result<Amount, ParseError> {
+            self.local_balance.try_into()
+        }
+
+        /// Returns the peer's balance, rounded down to satoshis by LND.
+        pub fn remote_balance(&self) -> Result<Amount, ParseError> {
+            self.remote_balance.try_into()
+        }
+    }
+
+    impl lnrpc::ForwardingEvent {
+        /// Returns the amount received on the incoming channel, from `amt_in_msat`.
+        pub fn amount_in(&self) -> MilliSats {
+            MilliSats(self.amt_in_msat)
+        }
+
+        /// Returns the amount sent on the outgoing channel, from `amt_out_msat`.
+        pub fn amount_out(&self) -> MilliSats {
+            MilliSats(self.amt_out_msat)
+        }
+
+        /// Returns the fee earned, from `fee_msat`.
+        pub fn fee(&self) -> MilliSats {
+            MilliSats(self.fee_msat)
+        }
+    }
+}
+
+#[cfg(feature = "routerrpc")]
+mod routerrpc_accessors {
+    use super::*;
+    use crate::routerrpc;
+
+    /// Returns the amount of a pair of mutually exclusive `*_sat` and `*_msat` fields.
+    fn either(sat: i64, msat: i64) -> Result<MilliSats, ParseError> {
+        match msat {
+            0 => MilliSats::try_from(Amount::try_from(sat)?),
+            msat => msat.try_into(),
+        }
+    }
+
+    impl routerrpc::SendPaymentRequest {
+        /// Returns the amount to pay, from `amt_msat` or `amt`. Zero means the amount of the
+        /// payment request.
+        pub fn amount(&self) -> Result<MilliSats, ParseError> {
+            either(self.amt, self.amt_msat)
+        }
+
+        /// Sets the amount to pay in `amt_msat`, clearing `amt`, as the two are mutually
+        /// exclusive.
+        pub fn set_amount(&mut self, amount: MilliSats) -> Result<(), ParseError> {
+            self.amt_msat = amount.try_into()?;
+            self.amt = 0;
+            Ok(())
+        }
+
+        /// Returns the maximum routing fee, from `fee_limit_msat` or `fee_limit_sat`.
+        pub fn fee_limit(&self) -> Result<MilliSats, ParseError> {
+            either(self.fee_limit_sat, self.fee_limit_msat)
+        }
+
+        /// Sets the maximum routing fee in `fee_limit_msat`, clearing `fee_limit_sat`, as the
+        /// two are mutually exclusive.
+        pub fn set_fee_limit(&mut self, fee_limit: MilliSats) -> Result<(), ParseError> {
+            self.fee_limit_msat = fee_limit.try_into()?;
+            self.fee_limit_sat = 0;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_amount() {
+        assert_eq!("1234".parse(), Ok(Amount::from_sat(1234)));
+        assert_eq!("1234 sat".parse(), Ok(Amount::from_sat(1234)));
+        assert_eq!(" 1234sats ".parse(), Ok(Amount::from_sat(1234)));
+        assert_eq!("0.00001234 BTC".parse(), Ok(Amount::from_sat(1234)));
+        assert_eq!("1.5btc".parse(), Ok(Amount::from_sat(150_000_000)));
+        assert_eq!("21 BTC".parse(), Ok(Amount::from_sat(2_100_000_000)));
+
+        for invalid in ["", "-1", "1.5", "0.000000001 BTC", "1.-5 BTC", "BTC", "1 msat"] {
+            assert!(invalid.parse::<Amount>().is_err(), "{invalid}");
+        }
+        // u64::MAX satoshis is about 184 billion BTC.
+        assert!("200000000000 BTC".parse::<Amount>().is_err());
+    }
+
+    #[test]
+    fn parse_msat() {
+        assert_eq!("1234".parse(), Ok(MilliSats::from_msat(1234)));
+        assert_eq!("1234 msat".parse(), Ok(MilliSats::from_msat(1234)));
+        assert!("1234 sat".parse::<MilliSats>().is_err());
+        assert!("-1".parse::<MilliSats>().is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Amount::from_sat(1234).to_string(), "1234 sat");
+        assert_eq!(Amount::from_sat(1234).to_btc_string(), "0.00001234");
+        assert_eq!(Amount::from_sat(2_100_000_000).to_btc_string(), "21.00000000");
+        assert_eq!(MilliSats::from_msat(1234).to_string(), "1234 msat");
+        assert_eq!(format!("{:?}", MilliSats::from_msat(1)), "MilliSats(1 msat)");
+    }
+
+    #[test]
+    fn sat_conversions() {
+        let msat = MilliSats::from_msat(1500);
+        assert_eq!(msat.to_sat_floor(), Amount::from_sat(1));
+        assert_eq!(msat.to_sat_ceil(), Amount::from_sat(2));
+        assert_eq!(msat.to_sat_round(), Amount::from_sat(2));
+        assert_eq!(MilliSats::from_msat(1499).to_sat_round(), Amount::from_sat(1));
+        assert_eq!(msat.to_sat_exact(), None);
+        assert_eq!(MilliSats::from_msat(2000).to_sat_exact(), Some(Amount::from_sat(2)));
+
+        assert_eq!(MilliSats::try_from(Amount::from_sat(2)), Ok(MilliSats::from_msat(2000)));
+        assert!(MilliSats::try_from(Amount::MAX).is_err());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let one = Amount::from_sat(1);
+        assert_eq!(Amount::MAX.checked_add(one), None);
+        assert_eq!(Amount::ZERO.checked_sub(one), None);
+        assert_eq!(Amount::MAX.saturating_add(one), Amount::MAX);
+        assert_eq!(Amount::ZERO.saturating_sub(one), Amount::ZERO);
+        assert_eq!(Amount::MAX.checked_mul(2), None);
+        assert_eq!(one.checked_div(0), None);
+        assert_eq!(Amount::checked_sum([one, one, one]), Some(Amount::from_sat(3)));
+        assert_eq!(Amount::checked_sum([Amount::MAX, one]), None);
+    }
+
+    #[test]
+    fn int64_fields() {
+        assert_eq!(Amount::try_from(5_i64), Ok(Amount::from_sat(5)));
+        assert!(matches!(Amount::try_from(-1_i64), Err(ParseError::OutOfRange(_))));
+        assert_eq!(i64::try_from(MilliSats::from_msat(5)), Ok(5));
+        assert!(matches!(i64::try_from(MilliSats::MAX), Err(ParseError::OutOfRange(_))));
+    }
+
+    #[cfg(feature = "routerrpc")]
+    #[test]
+    fn send_payment_amount() {
+        let mut request = crate::routerrpc::SendPaymentRequest {
+            amt: 2,
+            ..Default::default()
+        };
+        assert_eq!(request.amount(), Ok(MilliSats::from_msat(2000)));
+
+        request.set_amount(MilliSats::from_msat(1500)).unwrap();
+        assert_eq!((request.amt, request.amt_msat), (0, 1500));
+        assert_eq!(request.amount(), Ok(MilliSats::from_msat(1500)));
+        assert!(request.set_amount(MilliSats::MAX).is_err());
+    }
+}
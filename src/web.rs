@@ -0,0 +1,463 @@
+//! A client speaking gRPC-web, for browsers and other places without HTTP/2.
+//!
+//! Browsers can't make gRPC calls themselves, but proxies such as litd's translate gRPC-web into
+//! gRPC. [`WebClient`] has the same service clients as [`Client`](crate::Client), but sends their
+//! calls as gRPC-web over any HTTP/1.1 service. It only takes in-memory credentials, so it builds
+//! without the `transport` feature, which needs the filesystem and tonic's HTTP/2 transport, and
+//! compiles to `wasm32-unknown-unknown`. There, [`WebClientBuilder::build`] sends the calls with
+//! the browser's `fetch`:
+//!
+//! ```rust,ignore
+//! use voltage_tonic_lnd::lnrpc::{GetInfoRequest, InvoiceSubscription};
+//! use voltage_tonic_lnd::web::WebClient;
+//!
+//! let client = WebClient::builder()
+//!     .address("https://localhost:8443")
+//!     .macaroon_contents(hex_macaroon)
+//!     .build()?;
+//!
+//! let mut lightning = client.lightning()?;
+//! println!("{:?}", lightning.get_info(GetInfoRequest {}).await?.into_inner());
+//!
+//! let mut invoices = lightning.subscribe_invoices(InvoiceSubscription::default()).await?.into_inner();
+//! while let Some(invoice) = invoices.message().await? {
+//!     println!("{invoice:?}");
+//! }
+//! ```
+//!
+//! Elsewhere, pass the HTTP client to [`WebClientBuilder::build_with`].
+//!
+//! gRPC-web sends each request in one piece, so RPCs that stream requests, such as the HTLC
+//! interceptor, fail. RPCs that stream responses work.
+
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use bytes::Bytes;
+use tonic::body::Body;
+use tonic::codegen::{Service as TowerService, StdError};
+use tonic::service::interceptor::InterceptedService;
+use tonic_web::{GrpcWebCall, GrpcWebClientService};
+use tower::util::BoxCloneSyncService;
+use tower::ServiceExt;
+use zeroize::Zeroizing;
+
+use crate::error::{Error, Result};
+#[cfg(services)]
+use crate::protos::*;
+#[cfg(services)]
+use crate::service::configure_client;
+use crate::service::{MacaroonInterceptor, MessageOptions, ServiceKind};
+
+pub(crate) type Service = InterceptedService<
+    BoxCloneSyncService<http::Request<Body>, http::Response<Body>, StdError>,
+    MacaroonInterceptor,
+>;
+
+macro_rules! web_clients {
+    ($(
+        $(#[$attr:meta])*
+        $feature:literal, $accessor:ident, $kind:ident, $alias:ident = $($client:ident)::+;
+    )*) => {
+        $(
+            $(#[$attr])*
+            #[cfg(feature = $feature)]
+            pub type $alias = $($client)::+<Service>;
+        )*
+
+        impl WebClient {
+            $(
+                #[doc = concat!("Returns the gRPC-web version of [`Client::", stringify!($accessor), "`](crate::Client::", stringify!($accessor), ").")]
+                ///
+                /// # Errors
+                /// Returns [`Error::ServiceDisabled`] if the service was not enabled with
+                /// [`WebClientBuilder::services`].
+                #[cfg(feature = $feature)]
+                pub fn $accessor(&self) -> Result<$alias> {
+                    let options = self.options(ServiceKind::$kind)?;
+                    Ok(configure_client!(
+                        $($client)::+::with_origin(self.service.clone(), self.origin.clone()),
+                        options
+                    ))
+                }
+            )*
+        }
+    };
+}
+
+web_clients! {
+    /// Convenience type alias for gRPC-web lightning client.
+    "lightningrpc", lightning, Lightning,
+    LightningClient = lnrpc::lightning_client::LightningClient;
+    /// Convenience type alias for gRPC-web wallet client.
+    "walletrpc", wallet, WalletKit,
+    WalletKitClient = walletrpc::wallet_kit_client::WalletKitClient;
+    /// Convenience type alias for gRPC-web signer client.
+    "signrpc", signer, Signer, SignerClient = signrpc::signer_client::SignerClient;
+    /// Convenience type alias for gRPC-web versioner service client.
+    "versionrpc", versioner, Versioner,
+    VersionerClient = verrpc::versioner_client::VersionerClient;
+    /// Convenience type alias for gRPC-web peers service client.
+    "peersrpc", peers, Peers, PeersClient = peersrpc::peers_client::PeersClient;
+    /// Convenience type alias for gRPC-web router client.
+    "routerrpc", router, Router, RouterClient = routerrpc::router_client::RouterClient;
+    /// Convenience type alias for gRPC-web invoices client.
+    "invoicesrpc", invoices, Invoices,
+    InvoicesClient = invoicesrpc::invoices_client::InvoicesClient;
+    /// Convenience type alias for gRPC-web state service client.
+    "staterpc", state, State, StateClient = lnrpc::state_client::StateClient;
+    /// Convenience type alias for gRPC-web chain notifier client.
+    "chainrpc", chain_notifier, ChainNotifier,
+    ChainNotifierClient = chainrpc::chain_notifier_client::ChainNotifierClient;
+    /// Convenience type alias for gRPC-web chain kit client.
+    "chainrpc", chain_kit, ChainKit,
+    ChainKitClient = chainrpc::chain_kit_client::ChainKitClient;
+    /// Convenience type alias for gRPC-web taproot assets client.
+    "taprpc", taproot_assets, TaprootAssets,
+    TaprootAssetsClient = taprpc::taproot_assets_client::TaprootAssetsClient;
+    /// Convenience type alias for gRPC-web asset wallet client.
+    "assetwalletrpc", asset_wallet, AssetWallet,
+    AssetWalletClient = assetwalletrpc::asset_wallet_client::AssetWalletClient;
+    /// Convenience type alias for gRPC-web mint client.
+    "mintrpc", mint, Mint, MintClient = mintrpc::mint_client::MintClient;
+    /// Convenience type alias for gRPC-web price oracle client.
+    "priceoraclerpc", price_oracle, PriceOracle,
+    PriceOracleClient = priceoraclerpc::price_oracle_client::PriceOracleClient;
+    /// Convenience type alias for gRPC-web RFQ client.
+    "rfqrpc", rfq, Rfq, RfqClient = rfqrpc::rfq_client::RfqClient;
+    /// Convenience type alias for gRPC-web tap channel client.
+    "tapchannelrpc", tap_channel, TapChannel,
+    TapChannelClient = tapchannelrpc::taproot_asset_channels_client::TaprootAssetChannelsClient;
+    /// Convenience type alias for gRPC-web tap dev client.
+    "tapdevrpc", tap_dev, TapDev, TapDevClient = tapdevrpc::tap_dev_client::TapDevClient;
+    /// Convenience type alias for gRPC-web universal client.
+    "universerpc", universal, Universe,
+    UniversalClient = universerpc::universe_client::UniverseClient;
+}
+
+/// A builder for a [`WebClient`].
+///
+/// Unlike [`ClientBuilder`](crate::ClientBuilder), it takes no certificate: TLS is up to the
+/// HTTP service, which in a browser trusts what the browser trusts.
+#[derive(Debug, Clone, Default)]
+pub struct WebClientBuilder {
+    address: Option<String>,
+    macaroon_contents: Option<Zeroizing<String>>,
+    services: Option<HashSet<ServiceKind>>,
+    message_options: MessageOptions,
+    service_options: HashMap<ServiceKind, MessageOptions>,
+}
+
+impl WebClientBuilder {
+    /// Creates a new [`WebClientBuilder`] with no fields set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the address (URL) of the gRPC-web proxy, for example litd's.
+    ///
+    /// # Arguments
+    /// * `address` - The URL the proxy serves gRPC-web on (e.g., "https://localhost:8443").
+    pub fn address(mut self, address: impl ToString) -> Self {
+        self.address = Some(address.to_string());
+        self
+    }
+
+    /// Sets the contents of the macaroon for authentication, as a hex-encoded string.
+    ///
+    /// # Arguments
+    /// * `contents` - The macaroon as a hex-encoded string.
+    pub fn macaroon_contents(mut self, contents: impl ToString) -> Self {
+        self.macaroon_contents = Some(Zeroizing::new(contents.to_string()));
+        self
+    }
+
+    /// Limits the client to the given services, like
+    /// [`ClientBuilder::services`](crate::ClientBuilder::services).
+    ///
+    /// # Arguments
+    /// * `services` - The services to enable.
+    pub fn services(mut self, services: impl IntoIterator<Item = ServiceKind>) -> Self {
+        self.services = Some(services.into_iter().collect());
+        self
+    }
+
+    /// Sets the maximum size of a decoded (received) message for all services, in bytes.
+    ///
+    /// # Arguments
+    /// * `limit` - The limit in bytes.
+    pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+        self.message_options = self.message_options.max_decoding_message_size(limit);
+        self
+    }
+
+    /// Sets the maximum size of an encoded (sent) message for all services, in bytes.
+    ///
+    /// # Arguments
+    /// * `limit` - The limit in bytes.
+    pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+        self.message_options = self.message_options.max_encoding_message_size(limit);
+        self
+    }
+
+    /// Sets the message options of one service, overriding the settings for all services.
+    ///
+    /// # Arguments
+    /// * `service` - The service the options apply to.
+    /// * `options` - The options for that service.
+    pub fn service_options(mut self, service: ServiceKind, options: MessageOptions) -> Self {
+        self.service_options.insert(service, options);
+        self
+    }
+
+    /// Finalizes the builder, sending calls with the browser's `fetch`.
+    ///
+    /// Only available on `wasm32`.
+    ///
+    /// # Errors
+    /// Returns an error if the address or macaroon is missing, or the address is invalid.
+    #[cfg(target_arch = "wasm32")]
+    pub fn build(self) -> Result<WebClient> {
+        self.build_with(FetchService::new())
+    }
+
+    /// Finalizes the builder, sending calls over `http`.
+    ///
+    /// `http` receives the gRPC-web requests with absolute URIs and has to support HTTP/1.1,
+    /// for example a `hyper_util` client, or a test server wrapped in `tonic_web::GrpcWebLayer`.
+    ///
+    /// # Errors
+    /// Returns an error if the address or macaroon is missing, or the address is invalid.
+    pub fn build_with<S, B>(self, http: S) -> Result<WebClient>
+    where
+        S: TowerService<http::Request<GrpcWebCall<Body>>, Response = http::Response<B>>
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+        S::Error: Into<StdError>,
+        S::Future: Send + 'static,
+        B: http_body::Body<Data = Bytes> + Send + 'static,
+        B::Error: std::fmt::Display,
+    {
+        let address = self.address.as_ref().ok_or(Error::MissingAddress)?;
+        let origin = http::Uri::from_str(address)?;
+        let macaroon = self.macaroon_contents.clone().ok_or(Error::MissingMacaroon)?;
+
+        let http = GrpcWebClientService::new(http)
+            .map_response(|response: http::Response<GrpcWebCall<B>>| response.map(Body::new))
+            .map_err(Into::into);
+        let options = ServiceKind::ALL
+            .iter()
+            .filter(|service| {
+                self.services.as_ref().map_or(true, |services| services.contains(service))
+            })
+            .map(|&service| {
+                let options = self.service_options.get(&service).copied().unwrap_or_default();
+                (service, options.or(self.message_options))
+            })
+            .collect();
+
+        Ok(WebClient {
+            service: InterceptedService::new(
+                BoxCloneSyncService::new(http),
+                MacaroonInterceptor::new(macaroon),
+            ),
+            origin,
+            options: std::sync::Arc::new(options),
+        })
+    }
+}
+
+/// A client for LND behind a gRPC-web proxy, built with [`WebClientBuilder`].
+///
+/// Its accessors mirror those of [`Client`](crate::Client) and hand out clients sharing the same
+/// HTTP service.
+#[derive(Clone)]
+// The fields are only read by the service accessors.
+#[cfg_attr(not(services), allow(dead_code))]
+pub struct WebClient {
+    service: Service,
+    origin: http::Uri,
+    /// The message options of every enabled service.
+    options: std::sync::Arc<HashMap<ServiceKind, MessageOptions>>,
+}
+
+impl WebClient {
+    /// Returns a builder for a client.
+    pub fn builder() -> WebClientBuilder {
+        WebClientBuilder::new()
+    }
+
+    #[cfg(services)]
+    fn options(&self, service: ServiceKind) -> Result<MessageOptions> {
+        self.options.get(&service).copied().ok_or(Error::ServiceDisabled(service))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub use fetch::{FetchBody, FetchService};
+
+#[cfg(target_arch = "wasm32")]
+mod fetch {
+    use std::future::{poll_fn, Future};
+    use std::pin::{pin, Pin};
+    use std::task::{Context, Poll};
+
+    use bytes::Bytes;
+    use futures_util::StreamExt;
+    use http_body::{Body, Frame};
+    use tokio::sync::{mpsc, oneshot};
+    use tonic::codegen::{Service, StdError};
+    use wasm_bindgen::prelude::*;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::{spawn_local, JsFuture};
+    use web_sys::{AbortController, Headers, RequestInit, RequestMode};
+
+    #[wasm_bindgen]
+    extern "C" {
+        // The global `fetch`, which unlike `Window::fetch_with_request` works in workers too.
+        #[wasm_bindgen(js_name = fetch)]
+        fn fetch_with_request(request: &web_sys::Request) -> js_sys::Promise;
+    }
+
+    /// An HTTP service sending requests with the browser's `fetch`.
+    ///
+    /// The calls run on the browser's event loop, so neither the service nor its responses hold
+    /// JavaScript values and they can be sent between threads as tonic requires.
+    #[derive(Debug, Clone, Default)]
+    pub struct FetchService {
+        _priv: (),
+    }
+
+    impl FetchService {
+        /// Creates a new [`FetchService`].
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl<B> Service<http::Request<B>> for FetchService
+    where
+        B: Body<Data = Bytes> + Send + 'static,
+        B::Error: Into<StdError>,
+    {
+        type Response = http::Response<FetchBody>;
+        type Error = StdError;
+        type Future =
+            Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send + 'static>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: http::Request<B>) -> Self::Future {
+            let (tx, rx) = oneshot::channel();
+            spawn_local(async move {
+                let _ = tx.send(fetch(request).await);
+            });
+            Box::pin(async move {
+                match rx.await {
+                    Ok(response) => response.map_err(Into::into),
+                    Err(_) => Err("fetch was dropped".into()),
+                }
+            })
+        }
+    }
+
+    /// The body of a response received with [`FetchService`].
+    ///
+    /// Dropping it before the end aborts the request.
+    #[derive(Debug)]
+    pub struct FetchBody {
+        chunks: mpsc::Receiver<Result<Bytes, String>>,
+    }
+
+    impl Body for FetchBody {
+        type Data = Bytes;
+        type Error = StdError;
+
+        fn poll_frame(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Option<Result<Frame<Bytes>, Self::Error>>> {
+            self.chunks
+                .poll_recv(cx)
+                .map(|chunk| chunk.map(|chunk| chunk.map(Frame::data).map_err(Into::into)))
+        }
+    }
+
+    async fn fetch<B>(request: http::Request<B>) -> Result<http::Response<FetchBody>, String>
+    where
+        B: Body<Data = Bytes>,
+        B::Error: Into<StdError>,
+    {
+        let (parts, body) = request.into_parts();
+        let mut body = pin!(body);
+        let mut buf = Vec::new();
+        while let Some(frame) = poll_fn(|cx| body.as_mut().poll_frame(cx)).await {
+            let frame = frame.map_err(|error| error.into().to_string())?;
+            if let Ok(data) = frame.into_data() {
+                buf.extend_from_slice(&data);
+            }
+        }
+
+        let headers = Headers::new().map_err(js_error)?;
+        for (name, value) in &parts.headers {
+            let value = value.to_str().map_err(|error| error.to_string())?;
+            headers.append(name.as_str(), value).map_err(js_error)?;
+        }
+        let controller = AbortController::new().map_err(js_error)?;
+        let init = RequestInit::new();
+        init.set_method(parts.method.as_str());
+        init.set_mode(RequestMode::Cors);
+        init.set_headers(&headers);
+        init.set_body(&js_sys::Uint8Array::from(&buf[..]));
+        init.set_signal(Some(&controller.signal()));
+        let request = web_sys::Request::new_with_str_and_init(&parts.uri.to_string(), &init)
+            .map_err(js_error)?;
+
+        let response: web_sys::Response =
+            JsFuture::from(fetch_with_request(&request)).await.map_err(js_error)?.unchecked_into();
+        let mut builder = http::Response::builder().status(response.status());
+        for entry in response.headers().entries() {
+            let entry: js_sys::Array = entry.map_err(js_error)?.unchecked_into();
+            if let (Some(name), Some(value)) = (entry.get(0).as_string(), entry.get(1).as_string())
+            {
+                builder = builder.header(name, value);
+            }
+        }
+
+        let (tx, chunks) = mpsc::channel(16);
+        if let Some(stream) = response.body() {
+            spawn_local(async move {
+                let mut stream = wasm_streams::ReadableStream::from_raw(stream).into_stream();
+                while let Some(chunk) = stream.next().await {
+                    let chunk = chunk
+                        .map(|chunk| Bytes::from(js_sys::Uint8Array::new(&chunk).to_vec()))
+                        .map_err(js_error);
+                    let failed = chunk.is_err();
+                    if tx.send(chunk).await.is_err() {
+                        controller.abort();
+                        return;
+                    }
+                    if failed {
+                        return;
+                    }
+                }
+            });
+        }
+        builder
+            .body(FetchBody {
+                chunks,
+            })
+            .map_err(|error| error.to_string())
+    }
+
+    fn js_error(value: JsValue) -> String {
+        match value.dyn_ref::<js_sys::Error>() {
+            Some(error) => String::from(error.message()),
+            None => value.as_string().unwrap_or_else(|| format!("{value:?}")),
+        }
+    }
+}
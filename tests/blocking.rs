@@ -0,0 +1,48 @@
+//! Calling a mock node through the blocking client.
+
+use voltage_tonic_lnd::lnrpc::{GetInfoRequest, GetInfoResponse, Invoice, InvoiceSubscription};
+use voltage_tonic_lnd::testing::MockLnd;
+
+#[test]
+fn unary_and_server_stream() {
+    // The mock node runs on a runtime of its own; the client brings its own.
+    let server = tokio::runtime::Runtime::new().unwrap();
+    let mock = server.block_on(MockLnd::start()).unwrap();
+    mock.respond(
+        "/lnrpc.Lightning/GetInfo",
+        GetInfoResponse {
+            alias: "mock".to_owned(),
+            ..Default::default()
+        },
+    );
+    let invoices = mock.subscribe_invoices();
+
+    let client = mock.client_builder().build_blocking().unwrap();
+    let mut lightning = client.lightning().unwrap();
+    assert_eq!(lightning.get_info(GetInfoRequest {}).unwrap().into_inner().alias, "mock");
+
+    let stream = lightning.subscribe_invoices(InvoiceSubscription::default()).unwrap().into_inner();
+    server.block_on(invoices.wait_for_subscriber());
+    for memo in ["first", "second"] {
+        invoices.send(Invoice {
+            memo: memo.to_owned(),
+            ..Default::default()
+        });
+    }
+    invoices.close();
+    let memos: Vec<_> = stream.map(|invoice| invoice.unwrap().memo).collect();
+    assert_eq!(memos, ["first", "second"]);
+}
+
+#[test]
+fn drop_within_async_context() {
+    let server = tokio::runtime::Runtime::new().unwrap();
+    let mock = server.block_on(MockLnd::start()).unwrap();
+    let client = mock.client_builder().build_blocking().unwrap();
+    let lightning = client.lightning().unwrap();
+
+    server.block_on(async move {
+        drop(client);
+        drop(lightning);
+    });
+}
@@ -0,0 +1,197 @@
+//! Connecting to and calling a mock node through the normal client.
+
+use std::collections::HashSet;
+
+use voltage_tonic_lnd::lnrpc::{GetInfoRequest, GetInfoResponse, InvoiceSubscription};
+use voltage_tonic_lnd::testing::MockLnd;
+use voltage_tonic_lnd::tonic::Code;
+use voltage_tonic_lnd::{Client, ClientBuilder, Error, ServiceKind};
+
+fn info(alias: &str) -> GetInfoResponse {
+    GetInfoResponse {
+        alias: alias.to_owned(),
+        ..Default::default()
+    }
+}
+
+async fn alias(client: &Client) -> String {
+    let mut lightning = client.lightning().unwrap();
+    lightning.get_info(GetInfoRequest {}).await.unwrap().into_inner().alias
+}
+
+#[tokio::test]
+async fn credentials_from_files() {
+    let mock = MockLnd::start().await.unwrap();
+    mock.respond("/lnrpc.Lightning/GetInfo", info("mock"));
+    let dir = std::env::temp_dir().join(format!("tonic-lnd-client-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let (cert_path, macaroon_path) = mock.write_credentials(&dir).unwrap();
+
+    let client = ClientBuilder::new()
+        .address(mock.address())
+        .cert_path(cert_path)
+        .macaroon_path(macaroon_path)
+        .build()
+        .await
+        .unwrap();
+    assert_eq!(alias(&client).await, "mock");
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn missing_credentials() {
+    let mock = MockLnd::start().await.unwrap();
+    let result = ClientBuilder::new().address(mock.address()).build().await;
+    assert!(matches!(result, Err(Error::MissingMacaroon)));
+    let result = ClientBuilder::new().macaroon_contents(mock.macaroon_hex()).build().await;
+    assert!(matches!(result, Err(Error::MissingAddress)));
+}
+
+#[tokio::test]
+async fn rejects_wrong_macaroon() {
+    let mock = MockLnd::start().await.unwrap();
+    mock.respond("/lnrpc.Lightning/GetInfo", info("mock"));
+    let client = mock.client_builder().macaroon_contents("00").build().await.unwrap();
+
+    let error = client.lightning().unwrap().get_info(GetInfoRequest {}).await.unwrap_err();
+    assert_eq!(error.code(), Code::Unauthenticated);
+}
+
+#[tokio::test]
+async fn unknown_method() {
+    let mock = MockLnd::start().await.unwrap();
+    let client = mock.client_builder().build().await.unwrap();
+
+    let error = client.lightning().unwrap().get_info(GetInfoRequest {}).await.unwrap_err();
+    assert_eq!(error.code(), Code::Unimplemented);
+}
+
+#[tokio::test]
+async fn disabled_services() {
+    let mock = MockLnd::start().await.unwrap();
+    let client = mock.client_builder().services([ServiceKind::Lightning]).build().await.unwrap();
+
+    assert!(client.lightning().is_ok());
+    assert!(matches!(client.router(), Err(Error::ServiceDisabled(ServiceKind::Router))));
+    assert!(matches!(client.state(), Err(Error::ServiceDisabled(ServiceKind::State))));
+}
+
+#[tokio::test]
+async fn message_size_limit() {
+    let mock = MockLnd::start().await.unwrap();
+    mock.respond("/lnrpc.Lightning/GetInfo", info(&"a".repeat(1000)));
+
+    let client = mock.client_builder().max_decoding_message_size(100).build().await.unwrap();
+    let error = client.lightning().unwrap().get_info(GetInfoRequest {}).await.unwrap_err();
+    assert_eq!(error.code(), Code::OutOfRange);
+
+    let client = mock.client_builder().max_decoding_message_size(2000).build().await.unwrap();
+    assert_eq!(alias(&client).await.len(), 1000);
+}
+
+#[tokio::test]
+async fn pooled_connections() {
+    let mock = MockLnd::start().await.unwrap();
+    mock.respond("/lnrpc.Lightning/GetInfo", info("mock"));
+    let invoices = mock.subscribe_invoices();
+    let client = mock
+        .client_builder()
+        .connection_pool_size(3)
+        .dedicated_streaming_connection(true)
+        .build()
+        .await
+        .unwrap();
+    assert_eq!(mock.connections(), 4);
+
+    for _ in 0..6 {
+        assert_eq!(alias(&client).await, "mock");
+    }
+    let unary: HashSet<_> = mock.peers("/lnrpc.Lightning/GetInfo").into_iter().collect();
+    assert_eq!(unary.len(), 3);
+
+    let mut lightning = client.lightning().unwrap();
+    let _stream = lightning.subscribe_invoices(InvoiceSubscription::default()).await.unwrap();
+    invoices.wait_for_subscriber().await;
+    let streaming = mock.peers("/lnrpc.Lightning/SubscribeInvoices");
+    assert_eq!(streaming.len(), 1);
+    assert!(!unary.contains(&streaming[0]));
+    assert_eq!(mock.connections(), 4);
+}
+
+/// Calls `GetInfo` until a call gets through, returning the alias of the node that answered.
+/// Calls to an endpoint that went down fail until it's marked down.
+async fn alias_after_failover(client: &Client) -> String {
+    for _ in 0..3 {
+        match client.lightning().unwrap().get_info(GetInfoRequest {}).await {
+            Ok(response) => return response.into_inner().alias,
+            Err(status) => assert_eq!(status.code(), Code::Unavailable, "{status}"),
+        }
+    }
+    panic!("no endpoint answered");
+}
+
+/// Returns an address nothing listens on.
+async fn closed_address() -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    format!("https://{}", listener.local_addr().unwrap())
+}
+
+#[tokio::test]
+async fn unreachable_address_at_build() {
+    let mock = MockLnd::start().await.unwrap();
+    mock.respond("/lnrpc.Lightning/GetInfo", info("up"));
+    let closed = closed_address().await;
+
+    let client =
+        mock.client_builder().addresses([closed.clone(), mock.address()]).build().await.unwrap();
+    assert_eq!(alias(&client).await, "up");
+
+    let result = mock.client_builder().address(closed).build().await;
+    assert!(matches!(result, Err(Error::Tonic(_))));
+}
+
+#[tokio::test]
+async fn failover_to_replica() {
+    let mut primary = MockLnd::start().await.unwrap();
+    primary.respond("/lnrpc.Lightning/GetInfo", info("primary"));
+    let replica = primary.replica().await.unwrap();
+    replica.respond("/lnrpc.Lightning/GetInfo", info("replica"));
+    let client = primary
+        .client_builder()
+        .addresses([primary.address(), replica.address()])
+        .build()
+        .await
+        .unwrap();
+    assert_eq!(alias(&client).await, "primary");
+
+    primary.stop().await;
+    assert_eq!(alias_after_failover(&client).await, "replica");
+    assert_eq!(alias(&client).await, "replica");
+}
+
+#[tokio::test]
+async fn recovers_after_every_address_was_down() {
+    let mut first = MockLnd::start().await.unwrap();
+    first.respond("/lnrpc.Lightning/GetInfo", info("first"));
+    let mut second = first.replica().await.unwrap();
+    second.respond("/lnrpc.Lightning/GetInfo", info("second"));
+    let client = first
+        .client_builder()
+        .addresses([first.address(), second.address()])
+        .build()
+        .await
+        .unwrap();
+
+    first.stop().await;
+    second.stop().await;
+    // Each failed call marks the address it went to down.
+    for _ in 0..2 {
+        let error = client.lightning().unwrap().get_info(GetInfoRequest {}).await.unwrap_err();
+        assert_eq!(error.code(), Code::Unavailable);
+    }
+
+    // Only the second address comes back, and calls find it.
+    second.restart().await.unwrap();
+    assert_eq!(alias_after_failover(&client).await, "second");
+    assert_eq!(alias(&client).await, "second");
+}
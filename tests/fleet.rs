@@ -0,0 +1,138 @@
+//! Managing clients for several mock nodes through a fleet.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use voltage_tonic_lnd::fleet::Fleet;
+use voltage_tonic_lnd::lnrpc::{GetInfoRequest, GetInfoResponse};
+use voltage_tonic_lnd::testing::MockLnd;
+use voltage_tonic_lnd::{ClientBuilder, Error, ServiceKind};
+
+/// Starts a mock node answering `GetInfo` with `alias`.
+async fn node(alias: &str) -> MockLnd {
+    let mock = MockLnd::start().await.unwrap();
+    mock.respond(
+        "/lnrpc.Lightning/GetInfo",
+        GetInfoResponse {
+            alias: alias.to_owned(),
+            ..Default::default()
+        },
+    );
+    mock
+}
+
+fn sorted(mut nodes: Vec<String>) -> Vec<String> {
+    nodes.sort();
+    nodes
+}
+
+async fn alias(fleet: &Fleet<HashMap<String, ClientBuilder>>, node: &str) -> String {
+    let client = fleet.client(node).await.unwrap();
+    let mut lightning = client.lightning().unwrap();
+    lightning.get_info(GetInfoRequest {}).await.unwrap().into_inner().alias
+}
+
+#[tokio::test]
+async fn builds_clients_lazily() {
+    let (a, b) = (node("a").await, node("b").await);
+    let source =
+        HashMap::from([("a".to_owned(), a.client_builder()), ("b".to_owned(), b.client_builder())]);
+    let fleet = Fleet::builder(source).build();
+    assert!(fleet.connected_nodes().is_empty());
+
+    assert_eq!(alias(&fleet, "a").await, "a");
+    assert_eq!(fleet.connected_nodes(), ["a"]);
+    assert_eq!(alias(&fleet, "a").await, "a");
+    assert_eq!(fleet.connected_nodes(), ["a"]);
+
+    assert!(matches!(fleet.client("c").await, Err(Error::UnknownNode(node)) if node == "c"));
+    fleet.evict("a");
+    assert!(fleet.connected_nodes().is_empty());
+}
+
+#[tokio::test]
+async fn evicts_least_recently_used() {
+    let (a, b, c) = (node("a").await, node("b").await, node("c").await);
+    let source = HashMap::from([
+        ("a".to_owned(), a.client_builder()),
+        ("b".to_owned(), b.client_builder()),
+        ("c".to_owned(), c.client_builder()),
+    ]);
+    let fleet = Fleet::builder(source).max_clients(2).build();
+
+    alias(&fleet, "a").await;
+    alias(&fleet, "b").await;
+    alias(&fleet, "a").await;
+    assert_eq!(alias(&fleet, "c").await, "c");
+    assert_eq!(sorted(fleet.connected_nodes()), ["a", "c"]);
+}
+
+#[tokio::test]
+async fn evicts_unhealthy_nodes() {
+    let (mut a, b, c) = (node("a").await, node("b").await, node("c").await);
+    let source = HashMap::from([
+        ("a".to_owned(), a.client_builder()),
+        ("b".to_owned(), b.client_builder()),
+        ("c".to_owned(), c.client_builder().services([ServiceKind::Lightning])),
+    ]);
+    let fleet = Fleet::builder(source).health_check_timeout(Duration::from_secs(1)).build();
+    for node in ["a", "b", "c"] {
+        fleet.client(node).await.unwrap();
+    }
+
+    assert!(fleet.evict_unhealthy().await.is_empty());
+    a.stop().await;
+    assert_eq!(fleet.evict_unhealthy().await, ["a"]);
+    // `c` can't be checked without the `State` service, so it is kept.
+    assert_eq!(sorted(fleet.connected_nodes()), ["b", "c"]);
+}
+
+#[tokio::test]
+async fn fans_out_to_every_node() {
+    let (a, b) = (node("a").await, node("b").await);
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let closed = a.client_builder().address(format!("https://{}", listener.local_addr().unwrap()));
+    drop(listener);
+    let source = HashMap::from([
+        ("a".to_owned(), a.client_builder()),
+        ("b".to_owned(), b.client_builder()),
+        ("closed".to_owned(), closed),
+    ]);
+    let fleet = Fleet::builder(source).max_clients(1).build();
+
+    let results = fleet
+        .fan_out(|_node, client| async move {
+            let info = client.lightning()?.get_info(GetInfoRequest {}).await?;
+            Ok(info.into_inner().alias)
+        })
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results["a"].as_ref().unwrap(), "a");
+    assert_eq!(results["b"].as_ref().unwrap(), "b");
+    assert!(matches!(results["closed"], Err(Error::Tonic(_))));
+}
+
+#[tokio::test]
+async fn waits_for_connections_held_by_callers() {
+    let (a, b) = (node("a").await, node("b").await);
+    let source = HashMap::from([
+        ("a".to_owned(), a.client_builder().connection_pool_size(2)),
+        ("b".to_owned(), b.client_builder().connection_pool_size(2)),
+    ]);
+    let fleet = Fleet::builder(source).max_connections(3).build();
+
+    let held = fleet.client("a").await.unwrap();
+    let pending = tokio::spawn({
+        let fleet = fleet.clone();
+        async move { alias(&fleet, "b").await }
+    });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    // `a` was dropped from the fleet to make room, but its connections are still in use.
+    assert!(!pending.is_finished());
+    assert!(fleet.connected_nodes().is_empty());
+
+    drop(held);
+    assert_eq!(pending.await.unwrap(), "b");
+    assert_eq!(fleet.connected_nodes(), ["b"]);
+}
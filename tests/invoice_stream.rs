@@ -0,0 +1,259 @@
+//! Resuming invoice subscriptions against a mock node.
+
+use std::time::Duration;
+
+use futures_util::{Stream, StreamExt};
+use voltage_tonic_lnd::invoice_stream::{
+    self, FileStore, IndexStore, InvoiceEvent, InvoiceIndices, MemoryStore, RetryOptions,
+};
+use voltage_tonic_lnd::lnrpc::invoice::InvoiceState;
+use voltage_tonic_lnd::lnrpc::{Invoice, InvoiceSubscription};
+use voltage_tonic_lnd::testing::{MockLnd, StreamHandle};
+use voltage_tonic_lnd::tonic::{Code, Status};
+use voltage_tonic_lnd::Client;
+
+fn invoice(add_index: u64, settle_index: u64, state: InvoiceState) -> Invoice {
+    let mut invoice = Invoice {
+        add_index,
+        settle_index,
+        ..Default::default()
+    };
+    invoice.set_state(state);
+    invoice
+}
+
+fn indices(add_index: u64, settle_index: u64) -> InvoiceIndices {
+    InvoiceIndices {
+        add_index,
+        settle_index,
+    }
+}
+
+fn retry() -> RetryOptions {
+    RetryOptions::new().min_delay(Duration::from_millis(10))
+}
+
+/// Sends `updates` once the next subscription is open, as LND does after a (re)subscription.
+fn send_when_subscribed(
+    handle: &StreamHandle<InvoiceSubscription, Invoice>,
+    updates: Vec<Invoice>,
+) {
+    let handle = handle.clone();
+    tokio::spawn(async move {
+        handle.wait_for_subscriber().await;
+        for update in updates {
+            handle.send(update);
+        }
+    });
+}
+
+/// Returns the next event as `(kind, index)`: the `settle_index` of settlements, the `add_index`
+/// of the others.
+async fn next(
+    events: &mut (impl Stream<Item = voltage_tonic_lnd::Result<InvoiceEvent>> + Unpin),
+) -> (&'static str, u64) {
+    let event = tokio::time::timeout(Duration::from_secs(5), events.next())
+        .await
+        .expect("timed out")
+        .expect("stream ended")
+        .expect("stream failed");
+    match event {
+        InvoiceEvent::Added(invoice) => ("added", invoice.add_index),
+        InvoiceEvent::Settled(invoice) => ("settled", invoice.settle_index),
+        InvoiceEvent::Accepted(invoice) => ("accepted", invoice.add_index),
+        InvoiceEvent::Canceled(invoice) => ("canceled", invoice.add_index),
+    }
+}
+
+async fn client(mock: &MockLnd) -> Client {
+    mock.client_builder().build().await.unwrap()
+}
+
+#[tokio::test]
+async fn resumes_after_reconnect_and_restart() {
+    let mock = MockLnd::start().await.unwrap();
+    let handle = mock.subscribe_invoices();
+    let client = client(&mock).await;
+    let store = MemoryStore::new(indices(10, 5));
+
+    // While disconnected, invoice 3 (added before the saved indices) was settled with index 6,
+    // then invoice 11 was added and settled with index 7. LND sends the invoices added since
+    // add_index 10, then the ones settled since settle_index 5.
+    let add_backlog = [invoice(11, 7, InvoiceState::Settled)];
+    let settle_backlog =
+        [invoice(3, 6, InvoiceState::Settled), invoice(11, 7, InvoiceState::Settled)];
+    send_when_subscribed(&handle, add_backlog.into_iter().chain(settle_backlog).collect());
+
+    let mut events =
+        Box::pin(invoice_stream::subscribe(client.lightning().unwrap(), store.clone(), retry()));
+    assert_eq!(next(&mut events).await, ("added", 11));
+    assert_eq!(next(&mut events).await, ("settled", 6));
+    assert_eq!(next(&mut events).await, ("settled", 7));
+    assert_eq!(
+        handle.requests(),
+        [InvoiceSubscription {
+            add_index: 10,
+            settle_index: 5
+        }]
+    );
+
+    // The node restarts. The new subscription resumes from the delivered events, and repeated
+    // updates are skipped.
+    handle.fail(Status::unavailable("shutting down"));
+    send_when_subscribed(
+        &handle,
+        vec![
+            invoice(11, 7, InvoiceState::Settled),
+            invoice(12, 0, InvoiceState::Open),
+            invoice(12, 0, InvoiceState::Accepted),
+        ],
+    );
+    assert_eq!(next(&mut events).await, ("added", 12));
+    assert_eq!(next(&mut events).await, ("accepted", 12));
+    assert_eq!(
+        handle.requests()[1],
+        InvoiceSubscription {
+            add_index: 11,
+            settle_index: 7
+        }
+    );
+    assert_eq!(store.indices(), indices(12, 7));
+
+    // The process stops after handling the settlement of invoice 12, but before asking for the
+    // next event, so only that event is delivered again.
+    send_when_subscribed(&handle, vec![invoice(12, 8, InvoiceState::Settled)]);
+    assert_eq!(next(&mut events).await, ("settled", 8));
+    drop(events);
+    handle.close();
+    assert_eq!(store.indices(), indices(12, 7));
+
+    send_when_subscribed(
+        &handle,
+        vec![invoice(12, 8, InvoiceState::Settled), invoice(13, 0, InvoiceState::Open)],
+    );
+    let mut events =
+        Box::pin(invoice_stream::subscribe(client.lightning().unwrap(), store.clone(), retry()));
+    assert_eq!(next(&mut events).await, ("settled", 8));
+    assert_eq!(next(&mut events).await, ("added", 13));
+    assert_eq!(
+        handle.requests().last(),
+        Some(&InvoiceSubscription {
+            add_index: 12,
+            settle_index: 7
+        })
+    );
+}
+
+#[tokio::test]
+async fn canceled_invoices() {
+    let mock = MockLnd::start().await.unwrap();
+    let handle = mock.subscribe_invoices();
+    let client = client(&mock).await;
+
+    // Invoice 11 was added and canceled while disconnected, so it only comes in the add backlog.
+    // Invoice 4 is canceled live.
+    send_when_subscribed(
+        &handle,
+        vec![invoice(11, 0, InvoiceState::Canceled), invoice(4, 0, InvoiceState::Canceled)],
+    );
+    let store = MemoryStore::new(indices(10, 5));
+    let mut events =
+        Box::pin(invoice_stream::subscribe(client.lightning().unwrap(), store.clone(), retry()));
+    assert_eq!(next(&mut events).await, ("added", 11));
+    assert_eq!(next(&mut events).await, ("canceled", 11));
+    assert_eq!(next(&mut events).await, ("canceled", 4));
+    assert_eq!(store.indices(), indices(11, 5));
+}
+
+#[tokio::test]
+async fn file_store_survives_restarts() {
+    let mock = MockLnd::start().await.unwrap();
+    let handle = mock.subscribe_invoices();
+    let client = client(&mock).await;
+    let dir = std::env::temp_dir().join(format!("invoice-stream-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let store = FileStore::new(dir.join("indices"));
+    assert_eq!(store.load().await.unwrap(), InvoiceIndices::default());
+
+    // Without saved indices the stream starts with the live updates.
+    send_when_subscribed(
+        &handle,
+        vec![invoice(4, 2, InvoiceState::Settled), invoice(5, 0, InvoiceState::Open)],
+    );
+    let mut events =
+        Box::pin(invoice_stream::subscribe(client.lightning().unwrap(), store.clone(), retry()));
+    assert_eq!(next(&mut events).await, ("settled", 2));
+    assert_eq!(next(&mut events).await, ("added", 5));
+    send_when_subscribed(&handle, vec![invoice(6, 0, InvoiceState::Open)]);
+    assert_eq!(next(&mut events).await, ("added", 6));
+    drop(events);
+    handle.close();
+
+    assert_eq!(
+        std::fs::read_to_string(dir.join("indices")).unwrap(),
+        "add_index=5 settle_index=2\n"
+    );
+    send_when_subscribed(&handle, vec![invoice(6, 0, InvoiceState::Open)]);
+    let mut events =
+        Box::pin(invoice_stream::subscribe(client.lightning().unwrap(), store, retry()));
+    assert_eq!(next(&mut events).await, ("added", 6));
+    assert_eq!(
+        handle.requests().last(),
+        Some(&InvoiceSubscription {
+            add_index: 5,
+            settle_index: 2
+        })
+    );
+
+    std::fs::write(dir.join("indices"), "garbage").unwrap();
+    let store = FileStore::new(dir.join("indices"));
+    let mut events =
+        Box::pin(invoice_stream::subscribe(client.lightning().unwrap(), store, retry()));
+    assert!(events.next().await.unwrap().is_err());
+    assert!(events.next().await.is_none());
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn retries_only_transient_errors() {
+    let mock = MockLnd::start().await.unwrap();
+    let handle = mock.subscribe_invoices();
+    let client = client(&mock).await;
+
+    let failing = handle.clone();
+    let failures = tokio::spawn(async move {
+        loop {
+            failing.wait_for_subscriber().await;
+            failing.fail(Status::unavailable("starting"));
+        }
+    });
+    let options = retry().max_retries(3);
+    let mut events = Box::pin(invoice_stream::subscribe(
+        client.lightning().unwrap(),
+        MemoryStore::default(),
+        options,
+    ));
+    let error = events.next().await.unwrap().unwrap_err();
+    assert!(
+        matches!(error, voltage_tonic_lnd::Error::Status(status) if status.code() == Code::Unavailable)
+    );
+    assert!(events.next().await.is_none());
+    failures.abort();
+    assert_eq!(handle.requests().len(), 4);
+
+    let denied = handle.clone();
+    tokio::spawn(async move {
+        denied.wait_for_subscriber().await;
+        denied.fail(Status::permission_denied("macaroon revoked"));
+    });
+    let mut events = Box::pin(invoice_stream::subscribe(
+        client.lightning().unwrap(),
+        MemoryStore::default(),
+        retry(),
+    ));
+    let error = events.next().await.unwrap().unwrap_err();
+    assert!(
+        matches!(error, voltage_tonic_lnd::Error::Status(status) if status.code() == Code::PermissionDenied)
+    );
+    assert!(events.next().await.is_none());
+}
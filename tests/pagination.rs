@@ -0,0 +1,204 @@
+//! Paging through list RPCs of a mock node.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, UNIX_EPOCH};
+
+use futures_util::TryStreamExt;
+use voltage_tonic_lnd::lnrpc::{
+    ForwardingHistoryRequest, ForwardingHistoryResponse, GetTransactionsRequest, Invoice,
+    ListInvoiceRequest, ListInvoiceResponse, Transaction, TransactionDetails,
+};
+use voltage_tonic_lnd::pagination::{self, PageOptions};
+use voltage_tonic_lnd::testing::MockLnd;
+use voltage_tonic_lnd::tonic::{Code, Status};
+use voltage_tonic_lnd::LightningClient;
+
+/// Serves `ListInvoices` from invoices with the add indices `1..=count` like LND does, recording
+/// the requests.
+fn serve_invoices(mock: &MockLnd, count: u64) -> Arc<Mutex<Vec<ListInvoiceRequest>>> {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = requests.clone();
+    mock.unary("/lnrpc.Lightning/ListInvoices", move |request: ListInvoiceRequest| {
+        recorded.lock().unwrap().push(request);
+        let max = request.num_max_invoices;
+        let indices: Vec<u64> = match request.reversed {
+            // The page ends right before the offset, or at the last invoice without one.
+            true => {
+                let end = match request.index_offset {
+                    0 => count,
+                    offset => offset - 1,
+                };
+                (end.saturating_sub(max) + 1..=end).collect()
+            }
+            false => (request.index_offset + 1..=count).take(max as usize).collect(),
+        };
+        Ok(ListInvoiceResponse {
+            first_index_offset: indices.first().copied().unwrap_or(0),
+            last_index_offset: indices.last().copied().unwrap_or(0),
+            invoices: indices
+                .into_iter()
+                .map(|add_index| Invoice {
+                    add_index,
+                    ..Default::default()
+                })
+                .collect(),
+        })
+    });
+    requests
+}
+
+async fn client(mock: &MockLnd) -> LightningClient {
+    mock.client_builder().build().await.unwrap().lightning().unwrap()
+}
+
+#[tokio::test]
+async fn invoices_in_order() {
+    let mock = MockLnd::start().await.unwrap();
+    let requests = serve_invoices(&mock, 25);
+    let options = PageOptions::new().page_size(10);
+
+    let invoices: Vec<_> =
+        pagination::invoices(client(&mock).await, ListInvoiceRequest::default(), options)
+            .try_collect()
+            .await
+            .unwrap();
+    let indices: Vec<_> = invoices.iter().map(|invoice| invoice.add_index).collect();
+    assert_eq!(indices, (1..=25).collect::<Vec<_>>());
+
+    let offsets: Vec<_> = requests.lock().unwrap().iter().map(|r| r.index_offset).collect();
+    assert_eq!(offsets, [0, 10, 20]);
+}
+
+#[tokio::test]
+async fn invoices_reversed() {
+    let mock = MockLnd::start().await.unwrap();
+    let requests = serve_invoices(&mock, 25);
+    let options = PageOptions::new().page_size(10).reversed(true);
+
+    let invoices: Vec<_> =
+        pagination::invoices(client(&mock).await, ListInvoiceRequest::default(), options)
+            .try_collect()
+            .await
+            .unwrap();
+    let indices: Vec<_> = invoices.iter().map(|invoice| invoice.add_index).collect();
+    assert_eq!(indices, (1..=25).rev().collect::<Vec<_>>());
+
+    let offsets: Vec<_> = requests.lock().unwrap().iter().map(|r| r.index_offset).collect();
+    assert_eq!(offsets, [0, 16, 6]);
+}
+
+#[tokio::test]
+async fn full_last_page() {
+    let mock = MockLnd::start().await.unwrap();
+    let requests = serve_invoices(&mock, 20);
+    let options = PageOptions::new().page_size(10);
+
+    let invoices: Vec<_> =
+        pagination::invoices(client(&mock).await, ListInvoiceRequest::default(), options)
+            .try_collect()
+            .await
+            .unwrap();
+    assert_eq!(invoices.len(), 20);
+    // A full page could be followed by another, so the stream only ends at an empty one.
+    assert_eq!(requests.lock().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn passes_on_request_and_times() {
+    let mock = MockLnd::start().await.unwrap();
+    let requests = serve_invoices(&mock, 0);
+    let start = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let options = PageOptions::new().page_size(0).start_time(start);
+    let request = ListInvoiceRequest {
+        pending_only: true,
+        creation_date_end: 1_800_000_000,
+        ..Default::default()
+    };
+
+    let invoices: Vec<_> =
+        pagination::invoices(client(&mock).await, request, options).try_collect().await.unwrap();
+    assert!(invoices.is_empty());
+
+    let request = requests.lock().unwrap()[0];
+    assert!(request.pending_only);
+    assert_eq!(request.num_max_invoices, 1);
+    assert_eq!(request.creation_date_start, 1_700_000_000);
+    assert_eq!(request.creation_date_end, 1_800_000_000);
+}
+
+#[tokio::test]
+async fn unsupported_options_fail() {
+    let mock = MockLnd::start().await.unwrap();
+    let lightning = client(&mock).await;
+
+    let options = PageOptions::new().start_height(100);
+    let result: Result<Vec<_>, Status> =
+        pagination::invoices(lightning.clone(), ListInvoiceRequest::default(), options)
+            .try_collect()
+            .await;
+    assert_eq!(result.unwrap_err().code(), Code::InvalidArgument);
+
+    let options = PageOptions::new().reversed(true);
+    let result: Result<Vec<_>, Status> =
+        pagination::forwarding_events(lightning, ForwardingHistoryRequest::default(), options)
+            .try_collect()
+            .await;
+    assert_eq!(result.unwrap_err().code(), Code::InvalidArgument);
+}
+
+#[tokio::test]
+async fn error_ends_stream() {
+    let mock = MockLnd::start().await.unwrap();
+    mock.unary("/lnrpc.Lightning/ForwardingHistory", |request: ForwardingHistoryRequest| {
+        match request.index_offset {
+            0 => Ok(ForwardingHistoryResponse {
+                forwarding_events: vec![Default::default(); 2],
+                last_offset_index: 2,
+            }),
+            _ => Err(Status::unavailable("shutting down")),
+        }
+    });
+    let options = PageOptions::new().page_size(2);
+
+    let mut events = Box::pin(pagination::forwarding_events(
+        client(&mock).await,
+        ForwardingHistoryRequest::default(),
+        options,
+    ));
+    assert!(events.try_next().await.unwrap().is_some());
+    assert!(events.try_next().await.unwrap().is_some());
+    assert_eq!(events.try_next().await.unwrap_err().code(), Code::Unavailable);
+    assert!(events.try_next().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn transactions_by_height() {
+    let mock = MockLnd::start().await.unwrap();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = requests.clone();
+    mock.unary("/lnrpc.Lightning/GetTransactions", move |request: GetTransactionsRequest| {
+        recorded.lock().unwrap().push(request.clone());
+        let remaining = 5_u32.saturating_sub(request.index_offset);
+        Ok(TransactionDetails {
+            transactions: vec![
+                Transaction::default();
+                remaining.min(request.max_transactions) as usize
+            ],
+            ..Default::default()
+        })
+    });
+    let options = PageOptions::new().page_size(2).start_height(100).reversed(true);
+
+    let transactions: Vec<_> =
+        pagination::transactions(client(&mock).await, GetTransactionsRequest::default(), options)
+            .try_collect()
+            .await
+            .unwrap();
+    assert_eq!(transactions.len(), 5);
+
+    let requests = requests.lock().unwrap();
+    let offsets: Vec<_> = requests.iter().map(|r| r.index_offset).collect();
+    assert_eq!(offsets, [0, 2, 4]);
+    // Reading in reverse swaps the heights.
+    assert_eq!((requests[0].start_height, requests[0].end_height), (i32::MAX, 100));
+}
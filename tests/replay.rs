@@ -0,0 +1,92 @@
+//! Recording calls against a mock node and replaying them from another.
+
+use tower::Layer;
+use voltage_tonic_lnd::lnrpc::{AddInvoiceResponse, GetInfoRequest, GetInfoResponse, Invoice};
+use voltage_tonic_lnd::testing::{MockLnd, Recorder, Session};
+use voltage_tonic_lnd::tonic::Code;
+use voltage_tonic_lnd::Client;
+
+fn invoice(memo: &str, creation_date: i64) -> Invoice {
+    Invoice {
+        memo: memo.to_owned(),
+        value: 1000,
+        creation_date,
+        ..Default::default()
+    }
+}
+
+/// Records a `GetInfo` and an `AddInvoice` call.
+async fn record() -> Session {
+    let node = MockLnd::start().await.unwrap();
+    node.respond(
+        "/lnrpc.Lightning/GetInfo",
+        GetInfoResponse {
+            alias: "recorded".to_owned(),
+            ..Default::default()
+        },
+    );
+    node.unary("/lnrpc.Lightning/AddInvoice", |invoice: Invoice| {
+        Ok(AddInvoiceResponse {
+            payment_request: format!("lnbc-{}", invoice.memo),
+            ..Default::default()
+        })
+    });
+
+    let recorder = Recorder::new();
+    let channel = recorder.layer(node.channel().await.unwrap());
+    let client =
+        Client::from_channel(channel, node.macaroon_hex(), node.address().parse().unwrap());
+    let mut lightning = client.lightning().unwrap();
+    lightning.get_info(GetInfoRequest {}).await.unwrap();
+    lightning.add_invoice(invoice("coffee", 1)).await.unwrap();
+    recorder.session()
+}
+
+#[tokio::test]
+async fn replays_recorded_calls() {
+    let session = record().await;
+    let methods: Vec<_> = session.calls.iter().map(|call| call.method.as_str()).collect();
+    assert!(methods.ends_with(&["/lnrpc.Lightning/GetInfo", "/lnrpc.Lightning/AddInvoice"]));
+
+    let mock = MockLnd::start().await.unwrap();
+    let replay = mock.replay(session);
+    replay.ignore_field("/lnrpc.Lightning/AddInvoice", &[7]);
+    let mut lightning = mock.client_builder().build().await.unwrap().lightning().unwrap();
+
+    let info = lightning.get_info(GetInfoRequest {}).await.unwrap().into_inner();
+    assert_eq!(info.alias, "recorded");
+    // The creation date differs from the recording, but is ignored.
+    let response = lightning.add_invoice(invoice("coffee", 2)).await.unwrap().into_inner();
+    assert_eq!(response.payment_request, "lnbc-coffee");
+
+    // Every recorded call was replayed, and each only once.
+    assert!(replay.remaining().iter().all(|call| call.method.starts_with("/lnrpc.State/")));
+    let error = lightning.get_info(GetInfoRequest {}).await.unwrap_err();
+    assert_eq!(error.code(), Code::NotFound);
+}
+
+#[tokio::test]
+async fn rejects_other_requests() {
+    let mock = MockLnd::start().await.unwrap();
+    let replay = mock.replay(record().await);
+    let mut lightning = mock.client_builder().build().await.unwrap().lightning().unwrap();
+
+    let error = lightning.add_invoice(invoice("tea", 1)).await.unwrap_err();
+    assert_eq!(error.code(), Code::NotFound);
+    // Without ignoring it, the creation date has to match too.
+    let error = lightning.add_invoice(invoice("coffee", 2)).await.unwrap_err();
+    assert_eq!(error.code(), Code::NotFound);
+    assert!(replay.remaining().iter().any(|call| call.method == "/lnrpc.Lightning/AddInvoice"));
+}
+
+#[tokio::test]
+async fn sessions_round_trip_through_json() {
+    let session = record().await;
+    let dir = std::env::temp_dir().join(format!("tonic-lnd-replay-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("session.json");
+
+    session.save(&path).unwrap();
+    assert_eq!(Session::load(&path).unwrap(), session);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
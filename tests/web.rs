@@ -0,0 +1,82 @@
+//! Calling a mock node through the gRPC-web client.
+
+use tonic_web::GrpcWebLayer;
+use tower::Layer;
+use voltage_tonic_lnd::lnrpc::{GetInfoRequest, GetInfoResponse, Invoice, InvoiceSubscription};
+use voltage_tonic_lnd::testing::MockLnd;
+use voltage_tonic_lnd::tonic::Code;
+use voltage_tonic_lnd::web::{WebClient, WebClientBuilder};
+use voltage_tonic_lnd::{Error, ServiceKind};
+
+/// Returns a mock node answering `GetInfo`, and a builder for a client sending gRPC-web to it.
+async fn mock() -> (MockLnd, WebClientBuilder) {
+    let mock = MockLnd::start().await.unwrap();
+    mock.respond(
+        "/lnrpc.Lightning/GetInfo",
+        GetInfoResponse {
+            alias: "mock".to_owned(),
+            ..Default::default()
+        },
+    );
+    let builder =
+        WebClient::builder().address("http://mock.test").macaroon_contents(mock.macaroon_hex());
+    (mock, builder)
+}
+
+#[tokio::test]
+async fn unary_call() {
+    let (mock, builder) = mock().await;
+    let client = builder.build_with(GrpcWebLayer::new().layer(mock.routes())).unwrap();
+
+    let info = client.lightning().unwrap().get_info(GetInfoRequest {}).await.unwrap();
+    assert_eq!(info.into_inner().alias, "mock");
+}
+
+#[tokio::test]
+async fn server_stream() {
+    let (mock, builder) = mock().await;
+    let invoices = mock.subscribe_invoices();
+    let client = builder.build_with(GrpcWebLayer::new().layer(mock.routes())).unwrap();
+
+    let mut lightning = client.lightning().unwrap();
+    let mut stream =
+        lightning.subscribe_invoices(InvoiceSubscription::default()).await.unwrap().into_inner();
+    invoices.wait_for_subscriber().await;
+    for memo in ["first", "second"] {
+        invoices.send(Invoice {
+            memo: memo.to_owned(),
+            ..Default::default()
+        });
+    }
+    invoices.close();
+
+    let mut memos = Vec::new();
+    while let Some(invoice) = stream.message().await.unwrap() {
+        memos.push(invoice.memo);
+    }
+    assert_eq!(memos, ["first", "second"]);
+}
+
+#[tokio::test]
+async fn sends_macaroon() {
+    let (mock, builder) = mock().await;
+    let client = builder
+        .macaroon_contents("00")
+        .build_with(GrpcWebLayer::new().layer(mock.routes()))
+        .unwrap();
+
+    let error = client.lightning().unwrap().get_info(GetInfoRequest {}).await.unwrap_err();
+    assert_eq!(error.code(), Code::Unauthenticated);
+}
+
+#[tokio::test]
+async fn disabled_services() {
+    let (mock, builder) = mock().await;
+    let client = builder
+        .services([ServiceKind::Lightning])
+        .build_with(GrpcWebLayer::new().layer(mock.routes()))
+        .unwrap();
+
+    assert!(client.lightning().is_ok());
+    assert!(matches!(client.router(), Err(Error::ServiceDisabled(ServiceKind::Router))));
+}